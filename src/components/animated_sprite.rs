@@ -0,0 +1,170 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+use bevy::prelude::{Component, Timer, TimerMode};
+
+/// [Component] cycling the glyph of an `entity`'s [crate::components::ascii_sprite::AsciiSprite] through a
+/// sequence of frames, e.g., to let a monster shimmer, see [crate::entities::monster_factory::MonsterFactory]. Every frame is shown for the same
+/// duration, after the last frame the animation starts over.
+///
+/// The [crate::plugins::game_state_systems::animation::sprite_animation_system] advances the animation and
+/// copies the current frame into the [crate::components::ascii_sprite::AsciiSprite], which is then rendered as
+/// usual.
+///
+/// # Examples
+///
+/// ```
+/// let mut animated_sprite = AnimatedSprite::new(vec!['m', 'M'], Duration::from_millis(250));
+///
+/// animated_sprite.tick(Duration::from_millis(300));
+///
+/// assert_eq!('M', animated_sprite.glyph());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Component)]
+pub struct AnimatedSprite {
+    /// (Private) The glyphs the animation cycles through.
+    frames: Vec<char>,
+    /// (Private) The timer measuring how long the current frame has been shown.
+    timer: Timer,
+    /// (Private) The index of the current frame in the `frames`.
+    index: usize,
+}
+
+impl AnimatedSprite {
+    /// Creates a new [AnimatedSprite] starting at the first of the passed `frames`.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames`: The glyphs the animation cycles through.
+    /// * `frame_duration`: How long every frame is shown.
+    ///
+    /// returns: [AnimatedSprite]
+    ///
+    /// # Panics
+    ///
+    /// * If no `frames` are passed.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(frames: Vec<char>, frame_duration: Duration) -> Self {
+        assert!(
+            !frames.is_empty(),
+            "ECS -> Components -> AnimatedSprite -> At least one frame is required!"
+        );
+
+        Self {
+            frames,
+            timer: Timer::new(frame_duration, TimerMode::Repeating),
+            index: 0,
+        }
+    }
+
+    /// Advances the animation by the passed `delta` time, moving on by one frame for every elapsed frame
+    /// duration and wrapping around after the last frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta`: The time which passed since the last tick.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn tick(&mut self, delta: Duration) {
+        self.timer.tick(delta);
+
+        let elapsed_frames = self.timer.times_finished_this_tick() as usize;
+
+        self.index = (self.index + elapsed_frames) % self.frames.len();
+    }
+
+    /// Returns the glyph of the current frame.
+    ///
+    /// returns: char
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn glyph(&self) -> char {
+        self.frames[self.index]
+    }
+}
+
+impl Debug for AnimatedSprite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> AnimatedSprite {{ \
+        frames: {:?}, \
+        timer: {:?}, \
+        index: {} \
+        }}",
+            self.frames, self.timer, self.index
+        )
+    }
+}
+
+impl Display for AnimatedSprite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {})", self.glyph(), self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_advances_and_wraps_around() {
+        let mut animated_sprite =
+            AnimatedSprite::new(vec!['a', 'b', 'c'], Duration::from_millis(100));
+
+        animated_sprite.tick(Duration::from_millis(60));
+        assert_eq!('a', animated_sprite.glyph());
+
+        animated_sprite.tick(Duration::from_millis(60));
+        assert_eq!('b', animated_sprite.glyph());
+
+        animated_sprite.tick(Duration::from_millis(200));
+        assert_eq!('a', animated_sprite.glyph());
+    }
+}
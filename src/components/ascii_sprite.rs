@@ -21,10 +21,8 @@
 
 use std::fmt::{Debug, Display, Formatter};
 
-use bevy::prelude::{Color, Component, Mut};
-use bevy_ascii_terminal::{Terminal, TileFormatter};
+use bevy::prelude::{Color, Component};
 
-use crate::core::position_2d::Position2d;
 use crate::ui::colors;
 use crate::ui::tile::Tile;
 
@@ -164,21 +162,8 @@ impl Tile for AsciiSprite {
         true
     }
 
-    fn render(
-        &self,
-        position: &impl Position2d,
-        terminal: &mut Mut<Terminal>,
-        _is_seen: bool,
-        is_visible: bool,
-    ) {
-        if is_visible {
-            terminal.put_char(
-                position.as_array(),
-                self.glyph
-                    .fg(self.foreground_color)
-                    .bg(self.background_color),
-            )
-        }
+    fn is_remembered(&self) -> bool {
+        false
     }
 }
 
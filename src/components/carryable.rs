@@ -0,0 +1,60 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+/// Marker [Component] denoting an [crate::components::item::Item] as one the player can pick up and carry in the
+/// [crate::components::inventory::Inventory]. Items without it, e.g., a heavy statue, stay where they lie.
+///
+/// # Examples
+///
+/// ```
+/// commands.spawn((
+///     Coord2d::from_position(position),
+///     ascii_sprite!('!', Color::PURPLE),
+///     NameTag::new("Health Potion"),
+///     Item,
+///     Carryable, // The item can be picked up by the player.
+/// )).insert(GameStateLabel);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
+pub struct Carryable;
+
+impl Debug for Carryable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Components -> Carryable {{ (Marker) }}")
+    }
+}
+
+impl Display for Carryable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Marker(Carryable)")
+    }
+}
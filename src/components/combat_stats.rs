@@ -0,0 +1,143 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+/// [Component] describing the offensive and defensive capabilities of the associated `entity` in combat.
+///
+/// # Properties
+///
+/// * `power`: The raw damage the `entity` deals with an attack.
+/// * `defense`: The amount of damage the `entity` negates when being attacked.
+///
+/// # Examples
+///
+/// ```
+/// commands.spawn((
+///     ...,
+///     CombatStats::new(4, 1),
+///     ...,
+/// ));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
+pub struct CombatStats {
+    /// The raw damage the `entity` deals with an attack.
+    pub power: i32,
+    /// The amount of damage the `entity` negates when being attacked.
+    pub defense: i32,
+}
+
+impl CombatStats {
+    /// Creates a new [CombatStats] [Component] with the passed `power` and `defense`.
+    ///
+    /// # Arguments
+    ///
+    /// * `power`: The raw damage the `entity` deals with an attack.
+    /// * `defense`: The amount of damage the `entity` negates when being attacked.
+    ///
+    /// returns: [CombatStats]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(power: i32, defense: i32) -> Self {
+        Self { power, defense }
+    }
+
+    /// Calculates the damage an attack with these [CombatStats] deals to the passed `target`, i.e., the `power`
+    /// reduced by the `target's` defense. An attack always deals at least `1` damage, a `target` without any
+    /// [CombatStats] doesn't negate any damage.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The optional [CombatStats] of the attacked `entity`.
+    ///
+    /// returns: i32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let attacker = CombatStats::new(4, 1);
+    ///
+    /// assert_eq!(3, attacker.damage_against(Some(&CombatStats::new(2, 1))));
+    /// assert_eq!(1, attacker.damage_against(Some(&CombatStats::new(2, 8))));
+    /// assert_eq!(4, attacker.damage_against(None));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn damage_against(&self, target: Option<&CombatStats>) -> i32 {
+        (self.power - target.map_or(0, |target| target.defense)).max(1)
+    }
+}
+
+impl Debug for CombatStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> CombatStats {{ power: {:?}, defense: {:?} }}",
+            self.power, self.defense
+        )
+    }
+}
+
+impl Display for CombatStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.power, self.defense)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage_against() {
+        let attacker = CombatStats::new(4, 1);
+
+        assert_eq!(3, attacker.damage_against(Some(&CombatStats::new(2, 1))));
+        assert_eq!(4, attacker.damage_against(None));
+    }
+
+    #[test]
+    fn test_damage_against_deals_at_least_one_damage() {
+        let attacker = CombatStats::new(4, 1);
+
+        assert_eq!(1, attacker.damage_against(Some(&CombatStats::new(2, 4))));
+        assert_eq!(1, attacker.damage_against(Some(&CombatStats::new(2, 12))));
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::prelude::Component;
+
+/// [Component] marking an item as consumable and determining the effect it has once the `player` uses it. A used
+/// item is removed from the [crate::components::inventory::Inventory] and despawned.
+///
+/// # Examples
+///
+/// ```
+/// commands.spawn((
+///     Coord2d::from_position(position),
+///     ascii_sprite!('?', Color::PURPLE),
+///     NameTag::new("Scroll of Mapping"),
+///     Item,
+///     Consumable::Mapping, // Using the item reveals the whole map.
+/// ));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub enum Consumable {
+    /// Marks every tile of the current [crate::ui::game_map::GameMap] as seen, without making it visible.
+    Mapping,
+    /// Restores the given amount of hit points of the `player`, without exceeding their maximum
+    /// [crate::components::health::Health].
+    Healing(i32),
+}
@@ -42,7 +42,10 @@ use bevy::prelude::Component;
 ///
 /// Since: `0.1.9`
 ///
-#[derive(Debug, Component)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
 pub enum EnemyType {
+    /// A sluggish mass of stitched together flesh, lurking on every floor.
     Mended,
+    /// A frail, but quick scavenger, which only shows up further down the dungeon.
+    Ghoul,
 }
@@ -0,0 +1,167 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+/// [Component] holding the direction the associated `entity` looks in, which is updated whenever the `entity`
+/// moves, or tries to move. Waiting keeps the last direction.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(Some(Facing::East), Facing::from_delta(&[1, 0]));
+/// assert_eq!(Some(Facing::NorthWest), Facing::from_delta(&[-1, 1]));
+/// assert_eq!(None, Facing::from_delta(&[0, 0]));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Component)]
+pub enum Facing {
+    /// Looking up, towards the top of the map.
+    North,
+    /// Looking up and to the right.
+    NorthEast,
+    /// Looking to the right.
+    East,
+    /// Looking down and to the right.
+    SouthEast,
+    /// Looking down, towards the bottom of the map.
+    #[default]
+    South,
+    /// Looking down and to the left.
+    SouthWest,
+    /// Looking to the left.
+    West,
+    /// Looking up and to the left.
+    NorthWest,
+}
+
+impl Facing {
+    /// Determines the [Facing] of a movement by the passed `delta`. Only the signs of the `delta` are
+    /// considered, so longer movements face the same direction as single steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta`: The movement on the `x` and `y` axis, with the positive `y` axis pointing north.
+    ///
+    /// returns: [Option]<[Facing]> - [None] if the `delta` doesn't move at all.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn from_delta(delta: &[i32; 2]) -> Option<Self> {
+        match [delta[0].signum(), delta[1].signum()] {
+            [0, 1] => Some(Facing::North),
+            [1, 1] => Some(Facing::NorthEast),
+            [1, 0] => Some(Facing::East),
+            [1, -1] => Some(Facing::SouthEast),
+            [0, -1] => Some(Facing::South),
+            [-1, -1] => Some(Facing::SouthWest),
+            [-1, 0] => Some(Facing::West),
+            [-1, 1] => Some(Facing::NorthWest),
+            _ => None,
+        }
+    }
+
+    /// Returns the single step in the direction of the [Facing].
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!([1, -1], Facing::SouthEast.delta());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn delta(&self) -> [i32; 2] {
+        match self {
+            Facing::North => [0, 1],
+            Facing::NorthEast => [1, 1],
+            Facing::East => [1, 0],
+            Facing::SouthEast => [1, -1],
+            Facing::South => [0, -1],
+            Facing::SouthWest => [-1, -1],
+            Facing::West => [-1, 0],
+            Facing::NorthWest => [-1, 1],
+        }
+    }
+}
+
+impl Debug for Facing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Components -> Facing::{}", self)
+    }
+}
+
+impl Display for Facing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Facing::North => write!(f, "North"),
+            Facing::NorthEast => write!(f, "NorthEast"),
+            Facing::East => write!(f, "East"),
+            Facing::SouthEast => write!(f, "SouthEast"),
+            Facing::South => write!(f, "South"),
+            Facing::SouthWest => write!(f, "SouthWest"),
+            Facing::West => write!(f, "West"),
+            Facing::NorthWest => write!(f, "NorthWest"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_delta_round_trip() {
+        for facing in [
+            Facing::North,
+            Facing::NorthEast,
+            Facing::East,
+            Facing::SouthEast,
+            Facing::South,
+            Facing::SouthWest,
+            Facing::West,
+            Facing::NorthWest,
+        ] {
+            assert_eq!(Some(facing), Facing::from_delta(&facing.delta()));
+        }
+
+        assert_eq!(Some(Facing::West), Facing::from_delta(&[-6, 0]));
+        assert_eq!(None, Facing::from_delta(&[0, 0]));
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Color, Component};
+use serde::Deserialize;
+
+use crate::ui::colors;
+
+/// [Component] determining the allegiance of an `entity`, which is used to color code the `entities` on the map.
+///
+/// The colors are configurable through the [crate::res::palette::Palette].
+///
+/// # Examples
+///
+/// ```
+/// commands.spawn((
+///     Coord2d::from_position(position),
+///     ascii_sprite!('m', palette.faction_color(Faction::Hostile)),
+///     Faction::Hostile // Marks the entity as hostile towards the player.
+/// ));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [crate::res::palette::Palette::faction_color]
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Deserialize, Component)]
+pub enum Faction {
+    /// The `player entity` itself.
+    Player,
+    /// Monsters attacking the player.
+    Hostile,
+    /// Npcs on the side of the player.
+    Friendly,
+    /// Npcs ignoring the player.
+    Neutral,
+    /// Items lying on the map.
+    Item,
+}
+
+impl Faction {
+    /// Returns the default color `entities` of the [Faction] are rendered in, as defined by the
+    /// game's color scheme in [colors].
+    ///
+    /// # Arguments
+    ///
+    /// returns: [Color]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(colors::FACTION_HOSTILE, Faction::Hostile.default_color());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn default_color(&self) -> Color {
+        match self {
+            Faction::Player => colors::FACTION_PLAYER,
+            Faction::Hostile => colors::FACTION_HOSTILE,
+            Faction::Friendly => colors::FACTION_FRIENDLY,
+            Faction::Neutral => colors::FACTION_NEUTRAL,
+            Faction::Item => colors::FACTION_ITEM,
+        }
+    }
+}
+
+impl Debug for Faction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Components -> Faction::{}", self)
+    }
+}
+
+impl Display for Faction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Faction::Player => write!(f, "Player"),
+            Faction::Hostile => write!(f, "Hostile"),
+            Faction::Friendly => write!(f, "Friendly"),
+            Faction::Neutral => write!(f, "Neutral"),
+            Faction::Item => write!(f, "Item"),
+        }
+    }
+}
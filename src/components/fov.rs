@@ -24,6 +24,7 @@ use std::fmt::{Debug, Display, Formatter};
 use bevy::prelude::Component;
 
 use crate::core::position_2d::Position2d;
+use crate::res::fov_algorithm::FovAlgorithm;
 
 /// [Component] tracking the [Coord2d] based positions currently in the `field of view` of the associated `entity`.
 ///
@@ -31,6 +32,8 @@ use crate::core::position_2d::Position2d;
 ///
 /// * `radius`: The radius of the `field of view`.
 /// * `is_dirty`: If the `field of view` needs to be recalculated.
+/// * `algorithm`: The [FovAlgorithm] the current positions were calculated with, [None] before the
+///   first calculation.
 ///
 /// # About
 ///
@@ -44,6 +47,8 @@ pub struct Fov {
     pub radius: i32,
     /// If the `field of view` needs to be recalculated.
     pub is_dirty: bool,
+    /// The [FovAlgorithm] the current positions were calculated with, [None] before the first calculation.
+    pub algorithm: Option<FovAlgorithm>,
     /// (Private) List of tuple based [Position2d]s currently in the `field of view`.
     coordinates: Vec<(i32, i32)>,
 }
@@ -69,6 +74,7 @@ impl Fov {
         Self {
             radius,
             is_dirty: true,
+            algorithm: None,
             coordinates: Vec::new(),
         }
     }
@@ -103,6 +109,26 @@ impl Fov {
         self.coordinates.push(position.as_tuple());
     }
 
+    /// Replaces all positions currently in the [Fov] with the passed `positions`, e.g., with a
+    /// previously calculated result from the [crate::res::fov_cache::FovCache].
+    ///
+    /// # Arguments
+    ///
+    /// * `positions`: The tuple based positions now in the `field of view`.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn replace_positions(&mut self, positions: &[(i32, i32)]) {
+        self.coordinates.clear();
+        self.coordinates.extend_from_slice(positions);
+    }
+
     /// Checks if the passed [Position2d] is in the `field of view`.
     ///
     /// # Arguments
@@ -121,6 +147,44 @@ impl Fov {
         self.coordinates.contains(&position.as_tuple())
     }
 
+    /// Returns the number of positions currently in the `field of view`.
+    ///
+    /// returns: usize
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn positions_len(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    /// Iterates over the positions currently in the `field of view`, e.g., to diff them against a previous
+    /// calculation.
+    ///
+    /// returns: impl [Iterator]<Item = impl [Position2d]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fov = Fov::new(8);
+    /// fov.push_position(&[5, 5]);
+    ///
+    /// assert!(fov.iter_positions().all(|position| position.as_array() == [5, 5]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn iter_positions(&self) -> impl Iterator<Item = impl Position2d> + '_ {
+        self.coordinates.iter().copied()
+    }
+
     /// Removes all [Coord2d]s currently in the field of view, making it empty.
     ///
     /// # About
@@ -141,9 +205,10 @@ impl Debug for Fov {
             "ECS -> Components -> Fov {{ \
         radius: {:?}, \
         is_dirty: {:?}, \
+        algorithm: {:?}, \
         coordinates: {:?} \
         }}",
-            self.radius, self.is_dirty, self.coordinates
+            self.radius, self.is_dirty, self.algorithm, self.coordinates
         )
     }
 }
@@ -155,7 +220,45 @@ impl Display for Fov {
             "({}, {}, {})",
             self.radius,
             self.is_dirty,
-            self.coordinates.len()
+            self.positions_len()
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_clear_positions() {
+        let mut fov = Fov::new(8);
+
+        fov.push_position(&[1, 2]);
+        fov.push_position(&[3, 4]);
+
+        assert_eq!(2, fov.positions_len());
+        assert!(fov.contains(&[1, 2]));
+        assert!(fov.contains(&[3, 4]));
+        assert_eq!("(8, true, 2)", fov.to_string());
+
+        fov.clear();
+
+        assert_eq!(0, fov.positions_len());
+        assert!(!fov.contains(&[1, 2]));
+    }
+
+    #[test]
+    fn test_iter_positions_yields_all_positions() {
+        let mut fov = Fov::new(8);
+
+        fov.push_position(&[1, 2]);
+        fov.push_position(&[3, 4]);
+
+        let positions: Vec<[i32; 2]> = fov
+            .iter_positions()
+            .map(|position| position.as_array())
+            .collect();
+
+        assert_eq!(vec![[1, 2], [3, 4]], positions);
+    }
+}
@@ -0,0 +1,217 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+use serde::{Deserialize, Serialize};
+
+/// [Component] tracking the hit points of the associated `entity`, e.g., the `player` or a monster.
+///
+/// # Properties
+///
+/// * `current`: The hit points the `entity` currently has left.
+/// * `max`: The maximum amount of hit points the `entity` can have.
+///
+/// # Examples
+///
+/// ```
+/// commands.spawn((
+///     ...,
+///     Health::new(16),
+///     ...,
+/// ));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, Component)]
+pub struct Health {
+    /// The hit points the `entity` currently has left.
+    pub current: i32,
+    /// The maximum amount of hit points the `entity` can have.
+    pub max: i32,
+}
+
+impl Health {
+    /// Creates a new [Health] [Component] with the passed `max` hit points, which are also used as the
+    /// `current` hit points.
+    ///
+    /// # Arguments
+    ///
+    /// * `max`: The maximum amount of hit points of the `entity`.
+    ///
+    /// returns: [Health]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let health = Health::new(30);
+    ///
+    /// assert_eq!(30, health.current);
+    /// assert_eq!(30, health.max);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(max: i32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Checks if the `entity` has run out of hit points.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut health = Health::new(10);
+    ///
+    /// assert!(!health.is_dead());
+    ///
+    /// health.apply_damage(10);
+    ///
+    /// assert!(health.is_dead());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_dead(&self) -> bool {
+        self.current <= 0
+    }
+
+    /// Deducts the passed `amount` from the `current` hit points, which never drop below `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount`: The amount of damage dealt to the `entity`.
+    ///
+    /// returns: ()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut health = Health::new(10);
+    ///
+    /// health.apply_damage(4);
+    /// assert_eq!(6, health.current);
+    ///
+    /// health.apply_damage(20);
+    /// assert_eq!(0, health.current);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn apply_damage(&mut self, amount: i32) {
+        self.current = (self.current - amount).clamp(0, self.max);
+    }
+
+    /// Restores the passed `amount` of hit points, which never exceed the `max` hit points.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount`: The amount of hit points restored.
+    ///
+    /// returns: ()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut health = Health::new(10);
+    ///
+    /// health.apply_damage(4);
+    /// health.heal(20);
+    ///
+    /// assert_eq!(10, health.current);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn heal(&mut self, amount: i32) {
+        self.current = (self.current + amount).clamp(0, self.max);
+    }
+}
+
+impl Debug for Health {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> Health {{ current: {:?}, max: {:?} }}",
+            self.current, self.max
+        )
+    }
+}
+
+impl Display for Health {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}/{})", self.current, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_damage_clamps_to_zero() {
+        let mut health = Health::new(10);
+
+        health.apply_damage(3);
+        assert_eq!(7, health.current);
+        assert!(!health.is_dead());
+
+        health.apply_damage(12);
+        assert_eq!(0, health.current);
+        assert!(health.is_dead());
+    }
+
+    #[test]
+    fn test_heal_clamps_to_max() {
+        let mut health = Health::new(10);
+
+        health.apply_damage(6);
+        health.heal(2);
+        assert_eq!(6, health.current);
+
+        health.heal(50);
+        assert_eq!(10, health.current);
+    }
+}
@@ -0,0 +1,214 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Component, Entity};
+
+/// [Component] holding the item `entities` carried by the associated `entity`, e.g., the `player`.
+///
+/// # Properties
+///
+/// * `items`: The carried item `entities`, in the order they were added.
+/// * `capacity`: The maximum number of items which can be carried.
+///
+/// # Examples
+///
+/// ```
+/// let mut inventory = Inventory::new(2);
+///
+/// inventory.push(potion);
+/// inventory.push(scroll);
+///
+/// assert!(inventory.is_full());
+/// assert!(!inventory.push(sword));
+///
+/// assert_eq!(Some(potion), inventory.take_selected());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Eq, PartialEq, Hash, Component)]
+pub struct Inventory {
+    /// The carried item `entities`, in the order they were added.
+    pub items: Vec<Entity>,
+    /// The maximum number of items which can be carried.
+    pub capacity: usize,
+    /// (Private) Index of the currently selected item.
+    selected: usize,
+}
+
+impl Inventory {
+    /// Creates a new, empty [Inventory] with the passed `capacity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: The maximum number of items which can be carried.
+    ///
+    /// returns: [Inventory]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+            capacity,
+            selected: 0,
+        }
+    }
+
+    /// Checks if the [Inventory] can't hold any more items.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= self.capacity
+    }
+
+    /// Adds the passed `item` to the [Inventory], if there is room left.
+    ///
+    /// # Arguments
+    ///
+    /// * `item`: The item `entity` to carry.
+    ///
+    /// returns: bool - `true` if the `item` was added, `false` if the [Inventory] is full.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn push(&mut self, item: Entity) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.items.push(item);
+
+        true
+    }
+
+    /// Returns the currently selected item, if any item is carried.
+    ///
+    /// returns: [Option]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn selected_item(&self) -> Option<Entity> {
+        self.items.get(self.selected).copied()
+    }
+
+    /// Removes the currently selected item from the [Inventory] and returns it. The selection moves
+    /// to the preceding item if the last one was removed.
+    ///
+    /// returns: [Option]<[Entity]> - The removed item or [None] if the [Inventory] is empty.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn take_selected(&mut self) -> Option<Entity> {
+        if self.selected >= self.items.len() {
+            return None;
+        }
+
+        let item = self.items.remove(self.selected);
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+
+        Some(item)
+    }
+}
+
+impl Debug for Inventory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> Inventory {{ \
+        items: {:?}, \
+        capacity: {:?}, \
+        selected: {:?} \
+        }}",
+            self.items, self.capacity, self.selected
+        )
+    }
+}
+
+impl Display for Inventory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}/{}, {})",
+            self.items.len(),
+            self.capacity,
+            self.selected
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_selected() {
+        let mut inventory = Inventory::new(3);
+
+        inventory.items.push(Entity::from_raw(1));
+        inventory.items.push(Entity::from_raw(2));
+        inventory.selected = 1;
+
+        assert_eq!(Some(Entity::from_raw(2)), inventory.take_selected());
+        assert_eq!(Some(Entity::from_raw(1)), inventory.selected_item());
+        assert_eq!(Some(Entity::from_raw(1)), inventory.take_selected());
+        assert_eq!(None, inventory.take_selected());
+    }
+
+    #[test]
+    fn test_push_respects_capacity() {
+        let mut inventory = Inventory::new(1);
+
+        assert!(inventory.push(Entity::from_raw(1)));
+        assert!(inventory.is_full());
+        assert!(!inventory.push(Entity::from_raw(2)));
+        assert_eq!(vec![Entity::from_raw(1)], inventory.items);
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+use crate::core::position_2d::Position2d;
+
+/// [Component] tying the associated `NPC entity` to its spawn position. An `entity`, which isn't chasing the
+/// `player`, returns towards its `home` once it wandered off farther than the leash radius, preventing
+/// monsters from gathering in remote regions of the map.
+///
+/// # Properties
+///
+/// * `home`: The spawn position the `entity` is tied to.
+///
+/// # Examples
+///
+/// ```
+/// let leash = Leash::new(&[4, 4]);
+///
+/// assert!(!leash.is_exceeded(&[8, 6], 4));
+/// assert!(leash.is_exceeded(&[9, 4], 4));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
+pub struct Leash {
+    /// The spawn position the `entity` is tied to.
+    pub home: [i32; 2],
+}
+
+impl Leash {
+    /// Creates a new [Leash] [Component] tying the `entity` to the passed `home` position.
+    ///
+    /// # Arguments
+    ///
+    /// * `home`: The spawn position the `entity` is tied to.
+    ///
+    /// returns: [Leash]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(home: &impl Position2d) -> Self {
+        Self {
+            home: home.as_array(),
+        }
+    }
+
+    /// Checks if the passed `position` lies farther than `radius` away from the `home` position, measured as
+    /// the chebyshev distance. A `radius` of `0` or less disables the leash.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The current position of the `entity`.
+    /// * `radius`: The maximum distance the `entity` may have from its `home`.
+    ///
+    /// returns: bool - `true` if the `entity` has to return home and `false` otherwise.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_exceeded(&self, position: &impl Position2d, radius: i32) -> bool {
+        radius > 0 && self.home.chebyshev_distance(position) > radius
+    }
+}
+
+impl Debug for Leash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Components -> Leash {{ home: {:?} }}", self.home)
+    }
+}
+
+impl Display for Leash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.home[0], self.home[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_exceeded() {
+        let leash = Leash::new(&[4, 4]);
+
+        assert!(!leash.is_exceeded(&[4, 4], 3));
+        assert!(!leash.is_exceeded(&[7, 1], 3));
+        assert!(leash.is_exceeded(&[8, 4], 3));
+        assert!(leash.is_exceeded(&[4, 0], 3));
+        assert!(!leash.is_exceeded(&[40, 40], 0));
+    }
+}
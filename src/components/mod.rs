@@ -28,13 +28,25 @@
 //! Since: `0.1.5`
 //!
 
+pub mod animated_sprite;
 pub mod ascii_sprite;
+pub mod carryable;
 pub mod collision;
+pub mod combat_stats;
+pub mod consumable;
 pub mod coord_2d;
 pub mod enemy_type;
+pub mod facing;
+pub mod faction;
 pub mod fov;
 pub mod game_terminal;
+pub mod health;
+pub mod inventory;
+pub mod item;
+pub mod leash;
 pub mod name_tag;
 pub mod npc_state;
 pub mod player;
+pub mod speed;
+pub mod spyglass;
 pub mod state_label;
@@ -30,6 +30,9 @@ use bevy::prelude::Component;
 ///
 /// * `is_seeing_player`: Flag for storing if the `player entity` is currently in the `field of view´ of the
 /// NPC entity.
+/// * `last_known_player_pos`: The position the `player entity` was last seen at, either by the NPC itself or
+///   by a nearby NPC alerting it. [None] while the NPC is unaware of the `player`. Retained after losing sight
+///   of the `player`, so the NPC hunts them at that position, until it arrives there or exceeds its leash.
 ///
 /// # About
 ///
@@ -40,12 +43,14 @@ use bevy::prelude::Component;
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
 pub struct NpcState {
     pub is_seeing_player: bool,
+    pub last_known_player_pos: Option<[i32; 2]>,
 }
 
 impl Default for NpcState {
     fn default() -> Self {
         Self {
             is_seeing_player: false,
+            last_known_player_pos: None,
         }
     }
 }
@@ -54,14 +59,21 @@ impl Debug for NpcState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "ECS -> Component -> MonsterState {{ has_seen_player: {:?} }}",
-            self.is_seeing_player
+            "ECS -> Component -> MonsterState {{ \
+            has_seen_player: {:?}, \
+            last_known_player_pos: {:?} \
+            }}",
+            self.is_seeing_player, self.last_known_player_pos
         )
     }
 }
 
 impl Display for NpcState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({:?})", self.is_seeing_player)
+        write!(
+            f,
+            "({:?}, {:?})",
+            self.is_seeing_player, self.last_known_player_pos
+        )
     }
 }
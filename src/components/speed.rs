@@ -0,0 +1,151 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+/// [Component] determining how often the associated `NPC entity` moves, by letting it act only every
+/// `interval`-th turn of the `NPC entities`. Slow monsters thereby visibly lag behind the `player`.
+///
+/// # Properties
+///
+/// * `interval`: The number of turns between two moves of the `entity`. An interval of `1` moves the `entity`
+///   every turn, `2` every other turn and so on.
+///
+/// # Examples
+///
+/// ```
+/// let mut speed = Speed::new(2);
+///
+/// assert!(!speed.tick());
+/// assert!(speed.tick());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
+pub struct Speed {
+    /// The number of turns between two moves of the `entity`.
+    pub interval: u32,
+    /// (Private) The number of turns passed since the last move of the `entity`.
+    turns_waited: u32,
+}
+
+impl Speed {
+    /// Creates a new [Speed] [Component] with the passed `interval`, which is at least `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval`: The number of turns between two moves of the `entity`.
+    ///
+    /// returns: [Speed]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(interval: u32) -> Self {
+        Self {
+            interval: interval.max(1),
+            turns_waited: 0,
+        }
+    }
+
+    /// Advances the [Speed] by one turn and checks if the `entity` moves in it.
+    ///
+    /// returns: bool - `true` if the `entity` moves in the current turn and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut speed = Speed::new(1);
+    ///
+    /// assert!(speed.tick());
+    /// assert!(speed.tick());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn tick(&mut self) -> bool {
+        self.turns_waited += 1;
+
+        if self.turns_waited < self.interval {
+            return false;
+        }
+
+        self.turns_waited = 0;
+        true
+    }
+}
+
+impl Default for Speed {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Debug for Speed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> Speed {{ interval: {:?}, turns_waited: {:?} }}",
+            self.interval, self.turns_waited
+        )
+    }
+}
+
+impl Display for Speed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}/{})", self.turns_waited, self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick() {
+        let mut normal = Speed::new(1);
+        let mut slow = Speed::new(2);
+
+        assert_eq!(
+            vec![true, true, true, true],
+            (0..4).map(|_| normal.tick()).collect::<Vec<bool>>()
+        );
+        assert_eq!(
+            vec![false, true, false, true],
+            (0..4).map(|_| slow.tick()).collect::<Vec<bool>>()
+        );
+        assert_eq!(1, Speed::new(0).interval);
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Component;
+
+use crate::components::fov::Fov;
+
+/// [Component] allowing the associated `entity` to temporarily extend the radius of its [Fov], e.g., the
+/// spyglass of the `player`.
+///
+/// While in use, the [Fov::radius] is raised by the `radius_bonus`, which is reverted once the `duration`
+/// has passed. Every change of the radius marks the [Fov] as dirty, so it's recalculated.
+///
+/// # Properties
+///
+/// * `radius_bonus`: The number of tiles the [Fov] radius is extended by while in use.
+/// * `duration`: The number of turns the [Spyglass] stays in use.
+///
+/// # Examples
+///
+/// ```
+/// let mut fov = Fov::new(8);
+/// let mut spyglass = Spyglass::new(4, 2);
+///
+/// spyglass.activate(&mut fov);
+/// assert_eq!(12, fov.radius);
+///
+/// spyglass.tick(&mut fov);
+/// spyglass.tick(&mut fov);
+/// assert_eq!(8, fov.radius);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Component)]
+pub struct Spyglass {
+    /// The number of tiles the [Fov] radius is extended by while in use.
+    pub radius_bonus: i32,
+    /// The number of turns the [Spyglass] stays in use.
+    pub duration: u32,
+    /// (Private) The number of turns left until the [Spyglass] expires, `0` while not in use.
+    remaining_turns: u32,
+}
+
+impl Spyglass {
+    /// Creates a new, unused [Spyglass] with the passed `radius_bonus` and `duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius_bonus`: The number of tiles the [Fov] radius is extended by while in use.
+    /// * `duration`: The number of turns the [Spyglass] stays in use.
+    ///
+    /// returns: [Spyglass]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(radius_bonus: i32, duration: u32) -> Self {
+        Self {
+            radius_bonus,
+            duration,
+            remaining_turns: 0,
+        }
+    }
+
+    /// Checks if the [Spyglass] is currently in use.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_active(&self) -> bool {
+        self.remaining_turns > 0
+    }
+
+    /// Returns the number of turns left until the [Spyglass] expires, `0` while not in use.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn remaining_turns(&self) -> u32 {
+        self.remaining_turns
+    }
+
+    /// Puts the [Spyglass] to use, extending the radius of the passed `fov`. Using a [Spyglass], which is already
+    /// in use, only restarts its `duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fov`: The [Fov] of the `entity` using the [Spyglass].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn activate(&mut self, fov: &mut Fov) {
+        if !self.is_active() {
+            fov.radius += self.radius_bonus;
+            fov.is_dirty = true;
+        }
+
+        self.remaining_turns = self.duration;
+    }
+
+    /// Advances the [Spyglass] by one turn, reverting the radius of the passed `fov` once its `duration` has
+    /// passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `fov`: The [Fov] of the `entity` using the [Spyglass].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn tick(&mut self, fov: &mut Fov) {
+        if !self.is_active() {
+            return;
+        }
+
+        self.remaining_turns -= 1;
+
+        if !self.is_active() {
+            fov.radius -= self.radius_bonus;
+            fov.is_dirty = true;
+        }
+    }
+}
+
+impl Debug for Spyglass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Components -> Spyglass {{ \
+            radius_bonus: {:?}, \
+            duration: {:?}, \
+            remaining_turns: {:?} \
+            }}",
+            self.radius_bonus, self.duration, self.remaining_turns
+        )
+    }
+}
+
+impl Display for Spyglass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "(+{}, {}/{})",
+            self.radius_bonus, self.remaining_turns, self.duration
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activate_and_expire() {
+        let mut fov = Fov::new(8);
+        let mut spyglass = Spyglass::new(4, 2);
+
+        fov.is_dirty = false;
+        spyglass.activate(&mut fov);
+
+        assert!(spyglass.is_active());
+        assert!(fov.is_dirty);
+        assert_eq!(12, fov.radius);
+
+        fov.is_dirty = false;
+        spyglass.tick(&mut fov);
+
+        assert!(!fov.is_dirty);
+        assert_eq!(12, fov.radius);
+
+        spyglass.tick(&mut fov);
+
+        assert!(!spyglass.is_active());
+        assert!(fov.is_dirty);
+        assert_eq!(8, fov.radius);
+
+        spyglass.tick(&mut fov);
+
+        assert_eq!(8, fov.radius);
+    }
+
+    #[test]
+    fn test_reactivation_restarts_duration() {
+        let mut fov = Fov::new(8);
+        let mut spyglass = Spyglass::new(4, 2);
+
+        spyglass.activate(&mut fov);
+        spyglass.tick(&mut fov);
+        spyglass.activate(&mut fov);
+
+        assert_eq!(12, fov.radius);
+        assert_eq!(2, spyglass.remaining_turns());
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::prelude::Entity;
+
+/// An intent of an `entity` to act during its turn, e.g., to move or to attack.
+///
+/// Producers like the `player's` input or the AI of the `NPC entities` don't alter the game's world
+/// themselves, but push [Action]s into the [crate::res::action_queue::ActionQueue]. The queued actions are
+/// resolved against the world in a single place, the
+/// [crate::plugins::game_state_systems::action::action_system].
+///
+/// # Examples
+///
+/// ```
+/// fn some_input_system(mut action_queue: ResMut<ActionQueue>, player_query: Query<Entity, With<Player>>) {
+///     action_queue.push(Action::Move {
+///         actor: player_query.single(),
+///         delta: [0, 1],
+///     });
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Action {
+    /// Moves the `actor` by the `delta`, unless the target position is blocked by the map or another `entity`.
+    Move {
+        /// The moving `entity`.
+        actor: Entity,
+        /// The offset to move the `actor` by.
+        delta: [i32; 2],
+    },
+    /// The `attacker` attacks the `target`.
+    Attack {
+        /// The attacking `entity`.
+        attacker: Entity,
+        /// The attacked `entity`.
+        target: Entity,
+    },
+    /// The `actor` passes its turn without doing anything.
+    Wait {
+        /// The waiting `entity`.
+        actor: Entity,
+    },
+}
+
+impl Action {
+    /// Returns the `entity` performing the [Action].
+    ///
+    /// returns: [Entity]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn actor(&self) -> Entity {
+        match self {
+            Action::Move { actor, .. } => *actor,
+            Action::Attack { attacker, .. } => *attacker,
+            Action::Wait { actor } => *actor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actor() {
+        let actor = Entity::from_raw(1);
+        let target = Entity::from_raw(2);
+
+        assert_eq!(
+            actor,
+            Action::Move {
+                actor,
+                delta: [1, 0]
+            }
+            .actor()
+        );
+        assert_eq!(
+            actor,
+            Action::Attack {
+                attacker: actor,
+                target
+            }
+            .actor()
+        );
+        assert_eq!(actor, Action::Wait { actor }.actor());
+    }
+}
@@ -21,19 +21,34 @@
 
 use bevy::log::debug;
 
+use crate::components::facing::Facing;
 use crate::components::fov::Fov;
 use crate::core::position_2d::Position2d;
+use crate::res::fov_algorithm::FovAlgorithm;
+use crate::res::fov_cache::FovCache;
+use crate::res::fov_shape::FovShape;
 use crate::ui::tile::Tile;
 use crate::ui::tile_map::TileMap;
 
 /// Calculates the `field of view` for the passed `position` on the given `map` and updates the supplied `fov`
 /// [bevy::prelude::Component] with the result.
 ///
+/// Before calculating, the `cache` is consulted for a previous result with the same origin and radius on a map
+/// with the same [TileMap::fingerprint], which is reused if present. The `algorithm` used for the calculation is
+/// recorded in [Fov::algorithm], while the `shape` determines which positions lie within the radius.
+///
+/// A directional `shape`, see [FovShape::is_directional], additionally restricts the result by the `facing` of the
+/// `entity`. As the result then depends on the `facing`, it bypasses the `cache`.
+///
 /// # Arguments
 ///
 /// * `fov`: The [Fov] component to update.
 /// * `position`: The center [Position2d] starting from which the `field of view` will be calculated.
-/// * `map`: The [TileMap] on which the `field of view` is calculated. Required for bounds and collision checking.
+/// * `map`: The [TileMap] on which the `field of view` is calculated. Required for bounds and opacity checking.
+/// * `cache`: The [FovCache] storing previously calculated results.
+/// * `algorithm`: The [FovAlgorithm] used to calculate the `field of view`.
+/// * `shape`: The [FovShape] determining the distance metric of the radius check.
+/// * `facing`: The [Facing] of the `entity`, considered by directional `shape`s only.
 ///
 /// returns: ()
 ///
@@ -56,8 +71,9 @@ use crate::ui::tile_map::TileMap;
 /// let fov = Fov::new(8);
 /// let position = (5, 5);
 /// let map = TileMapImpl::new(...);
+/// let cache = FovCache::default();
 ///
-/// field_of_view(&fov, &position, &map);
+/// field_of_view(&fov, &position, &map, &mut cache, FovAlgorithm::Bresenham, FovShape::Circle, None);
 /// ```
 ///
 /// # About
@@ -70,24 +86,88 @@ pub fn field_of_view<T: Tile>(
     fov: &mut Fov,
     position: &impl Position2d,
     map: &mut impl TileMap<T>,
+    cache: &mut FovCache,
+    algorithm: FovAlgorithm,
+    shape: FovShape,
+    facing: Option<Facing>,
 ) {
     if !fov.is_dirty {
         return;
     }
 
+    let fingerprint = map.fingerprint();
+    let facing = facing.filter(|_| shape.is_directional());
+
+    if facing.is_none() {
+        if let Some(positions) = cache.get(position, fov.radius, fingerprint, algorithm, shape) {
+            fov.replace_positions(positions);
+            fov.algorithm = Some(algorithm);
+            fov.is_dirty = false;
+            return;
+        }
+    }
+
     debug!(
-        "Calculating field of view with {:?} at {:?}.",
-        fov.radius, position
+        "Calculating field of view with {:?} at {:?} using {}.",
+        fov.radius, position, algorithm
     );
 
     fov.clear();
     fov.push_position(position);
 
+    match algorithm {
+        FovAlgorithm::Bresenham => bresenham_field_of_view(fov, position, map, shape, facing),
+        FovAlgorithm::Shadowcast => shadowcast_field_of_view(fov, position, map, shape, facing),
+    }
+
+    if facing.is_none() {
+        cache.insert(
+            position,
+            fov.radius,
+            fingerprint,
+            algorithm,
+            shape,
+            fov.positions()
+                .iter()
+                .map(|position| position.as_tuple())
+                .collect(),
+        );
+    }
+
+    fov.algorithm = Some(algorithm);
+    fov.is_dirty = false
+}
+
+/// Fills the passed `fov` by checking the line of sight from the `position` to every position within the radius.
+///
+/// # Arguments
+///
+/// * `fov`: The [Fov] component to fill.
+/// * `position`: The center [Position2d] starting from which the `field of view` will be calculated.
+/// * `map`: The [TileMap] on which the `field of view` is calculated. Required for bounds and opacity checking.
+/// * `shape`: The [FovShape] determining the distance metric of the radius check.
+/// * `facing`: The [Facing] of the `entity`, considered by directional `shape`s only.
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn bresenham_field_of_view<T: Tile>(
+    fov: &mut Fov,
+    position: &impl Position2d,
+    map: &impl TileMap<T>,
+    shape: FovShape,
+    facing: Option<Facing>,
+) {
     for x in (position.x_coordinate() - fov.radius)..(position.x_coordinate() + fov.radius) {
         for y in (position.y_coordinate() - fov.radius)..(position.y_coordinate() + fov.radius) {
             let target = [x, y];
 
-            if calculate_distance(position, &target) < fov.radius
+            if shape.contains(position, &target, fov.radius, facing)
                 && map.is_in_bounds(&target)
                 && is_in_line_of_sight(position, &target, map)
             {
@@ -95,39 +175,169 @@ pub fn field_of_view<T: Tile>(
             }
         }
     }
+}
 
-    fov.is_dirty = false
+/// The lit section of an octant scanned by [cast_light], starting at the `row` at the given distance from the
+/// origin and spanning from the `start_slope` to the `end_slope`.
+#[derive(Copy, Clone)]
+struct ScanSection {
+    /// The distance of the first row to scan from the origin.
+    row: i32,
+    /// The slope at which the lit section starts.
+    start_slope: f64,
+    /// The slope at which the lit section ends.
+    end_slope: f64,
 }
 
-/// Calculates the step distance between the passed `start` and `end` [Position2d].
+/// Multipliers transforming the `[column, row]` offsets of the first octant into the remaining seven, in the order
+/// `[xx, xy, yx, yy]`.
+const OCTANT_MULTIPLIERS: [[i32; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// Fills the passed `fov` through recursive shadowcasting, scanning the eight octants around the `position`.
 ///
 /// # Arguments
 ///
-/// * `start`: The [Position2d] from which the distance should be calculated.
-/// * `end`: The [Position2d] to which the distance should be calculated.
+/// * `fov`: The [Fov] component to fill.
+/// * `position`: The center [Position2d] starting from which the `field of view` will be calculated.
+/// * `map`: The [TileMap] on which the `field of view` is calculated. Required for bounds and opacity checking.
+/// * `shape`: The [FovShape] determining the distance metric of the radius check.
+/// * `facing`: The [Facing] of the `entity`, considered by directional `shape`s only.
 ///
-/// returns: i32 - The distance between `start` and `end` [Position2d] in steps.
+/// returns: ()
 ///
-/// # Examples
+/// # See also
 ///
-/// ```
-/// let start_position = (2, 2);
-/// let end_position = (10, 10);
+/// * [cast_light]
 ///
-/// assert_eq!(5, calculate_distance(&start_position, &end_position);
-/// ```
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn shadowcast_field_of_view<T: Tile>(
+    fov: &mut Fov,
+    position: &impl Position2d,
+    map: &impl TileMap<T>,
+    shape: FovShape,
+    facing: Option<Facing>,
+) {
+    for octant in OCTANT_MULTIPLIERS {
+        let section = ScanSection {
+            row: 1,
+            start_slope: 1.0,
+            end_slope: 0.0,
+        };
+
+        cast_light(fov, position, map, shape, facing, section, octant);
+    }
+}
+
+/// Scans the rows of a single octant outwards from the `origin`, adding every lit position to the `fov`. Whenever a
+/// blocking position ends a lit section, the section is scanned recursively starting from the next row.
+///
+/// # Arguments
+///
+/// * `fov`: The [Fov] component to fill.
+/// * `origin`: The center [Position2d] starting from which the `field of view` is calculated.
+/// * `map`: The [TileMap] on which the `field of view` is calculated. Required for bounds and opacity checking.
+/// * `shape`: The [FovShape] determining the distance metric of the radius check.
+/// * `facing`: The [Facing] of the `entity`, considered by directional `shape`s only.
+/// * `section`: The [ScanSection] to scan.
+/// * `octant`: The [OCTANT_MULTIPLIERS] entry of the scanned octant.
+///
+/// returns: ()
 ///
 /// # About
 ///
 /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
 ///
-/// Since: `0.1.7`
+/// Since: `0.1.10`
 ///
-fn calculate_distance(start: &impl Position2d, end: &impl Position2d) -> i32 {
-    let [x_delta, y_delta] = end.delta(start);
-    (((x_delta * x_delta) + (y_delta * y_delta)) as f64)
-        .sqrt()
-        .floor() as i32
+fn cast_light<T: Tile>(
+    fov: &mut Fov,
+    origin: &impl Position2d,
+    map: &impl TileMap<T>,
+    shape: FovShape,
+    facing: Option<Facing>,
+    section: ScanSection,
+    octant: [i32; 4],
+) {
+    let ScanSection {
+        row,
+        mut start_slope,
+        end_slope,
+    } = section;
+
+    if start_slope < end_slope {
+        return;
+    }
+
+    let [xx, xy, yx, yy] = octant;
+    let mut next_start_slope = start_slope;
+
+    for distance in row..=fov.radius {
+        let mut is_blocked = false;
+        let row_delta = -distance;
+
+        for column_delta in -distance..=0 {
+            let left_slope = (column_delta as f64 - 0.5) / (row_delta as f64 + 0.5);
+            let right_slope = (column_delta as f64 + 0.5) / (row_delta as f64 - 0.5);
+
+            if start_slope < right_slope {
+                continue;
+            } else if end_slope > left_slope {
+                break;
+            }
+
+            let target = [
+                origin.x_coordinate() + column_delta * xx + row_delta * xy,
+                origin.y_coordinate() + column_delta * yx + row_delta * yy,
+            ];
+            let is_in_bounds = map.is_in_bounds(&target);
+
+            if is_in_bounds
+                && shape.contains(origin, &target, fov.radius, facing)
+                && !fov.contains(&target)
+            {
+                fov.push_position(&target);
+            }
+
+            let is_opaque = !is_in_bounds || map.tile_is_opaque(&target);
+
+            if is_blocked {
+                if is_opaque {
+                    next_start_slope = right_slope;
+                } else {
+                    is_blocked = false;
+                    start_slope = next_start_slope;
+                }
+            } else if is_opaque && distance < fov.radius {
+                is_blocked = true;
+                let section = ScanSection {
+                    row: distance + 1,
+                    start_slope,
+                    end_slope: left_slope,
+                };
+
+                cast_light(fov, origin, map, shape, facing, section, octant);
+                next_start_slope = right_slope;
+            }
+        }
+
+        if is_blocked {
+            break;
+        }
+    }
 }
 
 /// Checks if the passed `end` position is in the line of sight of the set `start` position on the given [TileMap].
@@ -136,7 +346,7 @@ fn calculate_distance(start: &impl Position2d, end: &impl Position2d) -> i32 {
 ///
 /// * `start`: The starting [Position2d], from which the line of sight should be checked.
 /// * `end`: The ending [Position2d], to which the line of sight should be checked.
-/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and collision checking.
+/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and opacity checking.
 ///
 /// returns: bool - `true` if the `end` position is in the line of sight of the `start` position and `false` otherwise.
 ///
@@ -195,10 +405,11 @@ fn is_in_line_of_sight<T: Tile>(
 /// * `end`: The ending [Position2d] from which the slope is calculated.
 /// * `delta`: The delta between the `start` and `end` [Position2d].
 /// * `delta_signed`: The sign-multiplier for the slopes `x` and `y-coordinates`.
-/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and collision checking.
+/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and opacity checking.
 ///
 /// returns: bool - `true` if a horizontal slope can be calculated from the `end` to the `start` [Position2d],
-/// without going out of bounds or hitting a position with collision.
+/// without going out of bounds, hitting an opaque position or squeezing through a blocked corner, see
+/// [is_corner_blocked].
 ///
 /// # Examples
 ///
@@ -250,6 +461,8 @@ fn calculate_horizontal_slope_in_line_of_sight<T: Tile>(
     let mut theta = (delta.y_coordinate() * 2) - delta.x_coordinate();
 
     loop {
+        let previous = [x, y];
+
         if theta >= 0 {
             y += delta_signed.y_coordinate();
             theta -= delta.x_coordinate() * 2;
@@ -258,11 +471,17 @@ fn calculate_horizontal_slope_in_line_of_sight<T: Tile>(
         x += delta_signed.x_coordinate();
         theta += delta.y_coordinate() * 2;
 
+        // Walls stay visible in the corners of rooms, only looking past them through the diagonal is blocked.
+        if !map.tile_is_opaque(&previous) && is_corner_blocked(&previous, &[x, y], map) {
+            break;
+        }
+
         if start.as_array() == [x, y] {
             return true;
         }
 
-        if map.tile_has_collision(&[x, y]) {
+        // Leaving the map would read the opacity of an unrelated tile, so the line of sight ends there.
+        if !map.is_in_bounds(&[x, y]) || map.tile_is_opaque(&[x, y]) {
             break;
         }
     }
@@ -279,10 +498,11 @@ fn calculate_horizontal_slope_in_line_of_sight<T: Tile>(
 /// * `end`: The ending [Position2d] from which the slope is calculated.
 /// * `delta`: The delta between the `start` and `end` [Position2d].
 /// * `delta_signed`: The sign-multiplier for the slopes `x` and `y-coordinates`.
-/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and collision checking.
+/// * `map`: The [TileMap] on which the slope is calculated. Required for bounds and opacity checking.
 ///
 /// returns: bool - `true` if a vertical slope can be calculated from the `end` to the `start` [Position2d],
-/// without going out of bounds or hitting a position with collision.
+/// without going out of bounds, hitting an opaque position or squeezing through a blocked corner, see
+/// [is_corner_blocked].
 ///
 /// # Examples
 ///
@@ -338,6 +558,8 @@ fn calculate_vertical_slope_in_line_of_sight<T: Tile>(
     let mut theta = (delta.x_coordinate() * 2) - delta.y_coordinate();
 
     loop {
+        let previous = [x, y];
+
         if theta >= 0 {
             x += delta_signed.x_coordinate();
             theta -= delta.y_coordinate() * 2;
@@ -346,11 +568,17 @@ fn calculate_vertical_slope_in_line_of_sight<T: Tile>(
         y += delta_signed.y_coordinate();
         theta += delta.x_coordinate() * 2;
 
+        // Walls stay visible in the corners of rooms, only looking past them through the diagonal is blocked.
+        if !map.tile_is_opaque(&previous) && is_corner_blocked(&previous, &[x, y], map) {
+            break;
+        }
+
         if start.as_array() == [x, y] {
             return true;
         }
 
-        if map.tile_has_collision(&[x, y]) {
+        // Leaving the map would read the opacity of an unrelated tile, so the line of sight ends there.
+        if !map.is_in_bounds(&[x, y]) || map.tile_is_opaque(&[x, y]) {
             break;
         }
     }
@@ -358,6 +586,60 @@ fn calculate_vertical_slope_in_line_of_sight<T: Tile>(
     false
 }
 
+/// Checks if the step from the `from` to the `to` [Position2d] is a diagonal one squeezing between two positions
+/// which are opaque, i.e., if both orthogonal neighbors forming the diagonal block the line of sight.
+///
+/// # Arguments
+///
+/// * `from`: The [Position2d] the step starts at.
+/// * `to`: The [Position2d] the step ends at.
+/// * `map`: The [TileMap] on which the step is taken. Required for bounds and opacity checking.
+///
+/// returns: bool - `true` if the step is diagonal and both orthogonal neighbors block the line of sight, `false`
+/// otherwise.
+///
+/// # Examples
+///
+/// Given [TileMap]:
+///
+/// ```text
+/// ######
+/// |1#  |
+/// |#2  |
+/// ######
+/// ```
+///
+/// The check will look something like this:
+///
+/// ```
+/// let map = TileMapImpl::new(...);
+///
+/// assert!(is_corner_blocked(&(1, 1), &(2, 2), &map));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn is_corner_blocked<T: Tile>(
+    from: &impl Position2d,
+    to: &impl Position2d,
+    map: &impl TileMap<T>,
+) -> bool {
+    if from.x_coordinate() == to.x_coordinate() || from.y_coordinate() == to.y_coordinate() {
+        return false;
+    }
+
+    [
+        [to.x_coordinate(), from.y_coordinate()],
+        [from.x_coordinate(), to.y_coordinate()],
+    ]
+    .iter()
+    .all(|neighbor| !map.is_in_bounds(neighbor) || map.tile_is_opaque(neighbor))
+}
+
 /// Determines the sign multiplier of the coordinates for the passed `position` and returns them as an
 /// array with a fixed length of `2`. The array contains the sign multiplier for the `x-coordinate` and `y-coordinate`
 /// at the first and second index respectively.
@@ -400,6 +682,7 @@ fn get_sign_multiplier(position: &impl Position2d) -> [i32; 2] {
 mod tests {
     use crate::core::dimension_2d::Dimension2d;
     use crate::ui::game_map::GameMap;
+    use crate::ui::tile::MapTile;
     use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
 
     use super::*;
@@ -417,11 +700,19 @@ mod tests {
 
     #[test]
     fn test_fov_calculation() {
-        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator);
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
 
         let mut fov = Fov::new(8);
 
-        field_of_view(&mut fov, &map.center(), &mut map);
+        field_of_view(
+            &mut fov,
+            &map.center(),
+            &mut map,
+            &mut FovCache::default(),
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
 
         for position in fov.positions() {
             map.mark_tile_as_seen(position);
@@ -444,4 +735,362 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_repeated_fov_calculation_hits_cache() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        let mut cache = FovCache::default();
+
+        let mut fov = Fov::new(8);
+        field_of_view(
+            &mut fov,
+            &map.center(),
+            &mut map,
+            &mut cache,
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
+
+        let mut repeated_fov = Fov::new(8);
+        field_of_view(
+            &mut repeated_fov,
+            &map.center(),
+            &mut map,
+            &mut cache,
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
+
+        assert_eq!(1, cache.misses());
+        assert_eq!(1, cache.hits());
+        assert_eq!(fov, repeated_fov);
+    }
+
+    #[test]
+    fn test_fov_calculation_after_map_change_misses_cache() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        let mut cache = FovCache::default();
+
+        let mut fov = Fov::new(8);
+        field_of_view(
+            &mut fov,
+            &map.center(),
+            &mut map,
+            &mut cache,
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
+
+        map.set_tile_at(&[6, 5], MapTile::floor('.'));
+        map.refresh_fingerprint();
+
+        fov.is_dirty = true;
+        field_of_view(
+            &mut fov,
+            &map.center(),
+            &mut map,
+            &mut cache,
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
+
+        assert_eq!(2, cache.misses());
+        assert_eq!(0, cache.hits());
+        assert!(fov.contains(&[7, 5]));
+    }
+
+    #[test]
+    fn test_shadowcast_fov_calculation() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        let mut fov = Fov::new(8);
+
+        field_of_view(
+            &mut fov,
+            &map.center(),
+            &mut map,
+            &mut FovCache::default(),
+            FovAlgorithm::Shadowcast,
+            FovShape::Circle,
+            None,
+        );
+
+        assert_eq!(Some(FovAlgorithm::Shadowcast), fov.algorithm);
+
+        for x in 0..map.width - 1 {
+            for y in 0..map.height - 1 {
+                let position = [x, y];
+
+                assert_eq!(
+                    [4, 5, 6].contains(&x) && [4, 5, 6].contains(&y),
+                    fov.contains(&position),
+                    "Unexpected visibility of {:?}.",
+                    position
+                );
+            }
+        }
+    }
+
+    fn open_map() -> GameMap {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        map
+    }
+
+    #[test]
+    fn test_shadowcast_fov_is_symmetric_in_open_room() {
+        let mut map = open_map();
+        let mut cache = FovCache::default();
+        let origins: Vec<[i32; 2]> = (0..map.width)
+            .flat_map(|x| (0..map.height).map(move |y| [x, y]))
+            .collect();
+
+        let fovs: Vec<Fov> = origins
+            .iter()
+            .map(|origin| {
+                let mut fov = Fov::new(6);
+
+                field_of_view(
+                    &mut fov,
+                    origin,
+                    &mut map,
+                    &mut cache,
+                    FovAlgorithm::Shadowcast,
+                    FovShape::Circle,
+                    None,
+                );
+
+                fov
+            })
+            .collect();
+
+        for (a, a_fov) in origins.iter().zip(&fovs) {
+            for (b, b_fov) in origins.iter().zip(&fovs) {
+                assert_eq!(
+                    a_fov.contains(b),
+                    b_fov.contains(a),
+                    "Asymmetric visibility between {:?} and {:?}.",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_of_sight_stops_at_map_edge() {
+        let map = open_map();
+
+        // A wrong direction walks away from the `start` and off the map, where the tiles would be read from the
+        // wrong row or past the end of the map.
+        assert!(!calculate_horizontal_slope_in_line_of_sight(
+            &[0, 0],
+            &[1, 1],
+            &[1, 1],
+            &[1, 1],
+            &map
+        ));
+        assert!(!calculate_vertical_slope_in_line_of_sight(
+            &[0, 0],
+            &[1, 1],
+            &[1, 1],
+            &[1, 1],
+            &map
+        ));
+    }
+
+    #[test]
+    fn test_fov_calculation_from_map_corner() {
+        let mut map = open_map();
+
+        let mut fov = Fov::new(8);
+
+        field_of_view(
+            &mut fov,
+            &[0, 0],
+            &mut map,
+            &mut FovCache::default(),
+            FovAlgorithm::Bresenham,
+            FovShape::Circle,
+            None,
+        );
+
+        assert!(fov
+            .positions()
+            .iter()
+            .all(|position| map.is_in_bounds(position)));
+        assert!(fov.contains(&[7, 0]));
+        assert!(fov.contains(&[0, 7]));
+        assert!(fov.contains(&[5, 5]));
+    }
+
+    #[test]
+    fn test_fov_calculation_from_last_row_and_column() {
+        let mut map = open_map();
+
+        for algorithm in [FovAlgorithm::Bresenham, FovAlgorithm::Shadowcast] {
+            let mut fov = Fov::new(8);
+
+            field_of_view(
+                &mut fov,
+                &[9, 9],
+                &mut map,
+                &mut FovCache::default(),
+                algorithm,
+                FovShape::Circle,
+                None,
+            );
+
+            assert!(fov.contains(&[9, 9]), "{:?}", algorithm);
+            assert!(fov.contains(&[9, 2]), "{:?}", algorithm);
+            assert!(fov.contains(&[2, 9]), "{:?}", algorithm);
+            assert!(!fov.contains(&[10, 9]), "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn test_square_fov_includes_corners_excluded_by_circle() {
+        let mut map = open_map();
+        let center = [5, 5];
+
+        for algorithm in [FovAlgorithm::Bresenham, FovAlgorithm::Shadowcast] {
+            let mut circle_fov = Fov::new(4);
+            let mut square_fov = Fov::new(4);
+            let mut diamond_fov = Fov::new(4);
+
+            field_of_view(
+                &mut circle_fov,
+                &center,
+                &mut map,
+                &mut FovCache::default(),
+                algorithm,
+                FovShape::Circle,
+                None,
+            );
+            field_of_view(
+                &mut square_fov,
+                &center,
+                &mut map,
+                &mut FovCache::default(),
+                algorithm,
+                FovShape::Square,
+                None,
+            );
+            field_of_view(
+                &mut diamond_fov,
+                &center,
+                &mut map,
+                &mut FovCache::default(),
+                algorithm,
+                FovShape::Diamond,
+                None,
+            );
+
+            for corner in [[2, 2], [8, 2], [2, 8], [8, 8]] {
+                assert!(square_fov.contains(&corner), "{} {:?}", algorithm, corner);
+                assert!(!circle_fov.contains(&corner), "{} {:?}", algorithm, corner);
+                assert!(!diamond_fov.contains(&corner), "{} {:?}", algorithm, corner);
+            }
+
+            assert!(diamond_fov.contains(&[8, 5]));
+            assert!(!diamond_fov.contains(&[7, 7]));
+            assert!(circle_fov.contains(&[7, 7]));
+        }
+    }
+
+    #[test]
+    fn test_cone_fov_follows_facing() {
+        let mut map = open_map();
+        let center = [5, 5];
+        let cone = FovShape::Cone {
+            angle: 90,
+            awareness: 1,
+        };
+
+        for algorithm in [FovAlgorithm::Bresenham, FovAlgorithm::Shadowcast] {
+            let mut cache = FovCache::default();
+            let mut fov = Fov::new(5);
+
+            field_of_view(
+                &mut fov,
+                &center,
+                &mut map,
+                &mut cache,
+                algorithm,
+                cone,
+                Some(Facing::East),
+            );
+
+            assert!(fov.contains(&[8, 5]), "{}", algorithm);
+            assert!(fov.contains(&[8, 6]), "{}", algorithm);
+            assert!(fov.contains(&[4, 5]), "{}", algorithm);
+            assert!(!fov.contains(&[2, 5]), "{}", algorithm);
+            assert!(!fov.contains(&[5, 8]), "{}", algorithm);
+
+            fov.is_dirty = true;
+
+            field_of_view(
+                &mut fov,
+                &center,
+                &mut map,
+                &mut cache,
+                algorithm,
+                cone,
+                Some(Facing::West),
+            );
+
+            assert!(fov.contains(&[2, 5]), "{}", algorithm);
+            assert!(!fov.contains(&[8, 5]), "{}", algorithm);
+            assert_eq!(0, cache.len());
+        }
+    }
+
+    #[test]
+    fn test_line_of_sight_is_blocked_by_diagonal_wall_corners() {
+        let mut map = open_map();
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                if (x + y) % 2 == 1 {
+                    map.set_tile_at(&[x, y], MapTile::default());
+                }
+            }
+        }
+
+        assert!(is_corner_blocked(&[4, 4], &[5, 5], &map));
+        assert!(!is_corner_blocked(&[4, 4], &[5, 4], &map));
+        assert!(!is_in_line_of_sight(&[4, 4], &[5, 5], &map));
+        assert!(!is_in_line_of_sight(&[4, 4], &[3, 5], &map));
+
+        let mut fov = Fov::new(8);
+
+        field_of_view(
+            &mut fov,
+            &[4, 4],
+            &mut map,
+            &mut FovCache::default(),
+            FovAlgorithm::Bresenham,
+            FovShape::Square,
+            None,
+        );
+
+        let mut positions: Vec<[i32; 2]> = fov
+            .positions()
+            .iter()
+            .map(|position| position.as_array())
+            .collect();
+        positions.sort();
+
+        assert_eq!(vec![[3, 4], [4, 3], [4, 4], [4, 5], [5, 4]], positions);
+    }
 }
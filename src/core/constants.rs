@@ -78,3 +78,257 @@ pub const MAP_MIN_ROOM_SIZE: i32 = 6;
 /// Since: `0.1.7`
 ///
 pub const MAP_MAX_ROOM_SIZE: i32 = 10;
+
+/// The minimum width and height of a configured map in tiles, which fits the largest room along with its walls.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_MIN_SIZE: i32 = MAP_MAX_ROOM_SIZE + 2;
+
+/// The minimum size of a room in tiles, from which on it is decorated with pillars and rubble, see
+/// [crate::ui::game_map::GameMap::decorate_rooms].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_DECORATION_MIN_ROOM_SIZE: i32 = 8;
+
+/// The minimum distance in tiles between two dead ends of a maze, which are picked as the rooms to spawn monsters
+/// and items in, see [crate::ui::tile_map_layout_generator::MazeTileMapGenerator].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_MAZE_ALCOVE_SPACING: i32 = 8;
+
+/// The lowest difficulty multiplier the game accepts, lower configured values are clamped to it.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MIN_DIFFICULTY: f32 = 0.25;
+
+/// The highest difficulty multiplier the game accepts, higher configured values are clamped to it.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAX_DIFFICULTY: f32 = 4.0;
+
+/// The maximum number of `field of view` results kept in the [crate::res::fov_cache::FovCache].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FOV_CACHE_CAPACITY: usize = 64;
+
+/// The maximum number of items the player can carry in their inventory.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const PLAYER_INVENTORY_CAPACITY: usize = 26;
+
+/// The maximum hit points of the player at the start of a run.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const PLAYER_MAX_HEALTH: i32 = 30;
+
+/// The raw damage the player deals with an attack at the start of a run.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const PLAYER_POWER: i32 = 5;
+
+/// The amount of damage the player negates when being attacked at the start of a run.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const PLAYER_DEFENSE: i32 = 1;
+
+/// The amount of hit points a health potion restores when the player drinks it.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const HEALTH_POTION_HEALING: i32 = 10;
+
+/// The maximum number of messages kept in the [crate::res::message_log::MessageLog].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MESSAGE_LOG_CAPACITY: usize = 50;
+
+/// The number of terminal rows reserved for rendering the most recent messages of the
+/// [crate::res::message_log::MessageLog].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MESSAGE_LOG_HEIGHT: i32 = 5;
+
+/// The number of terminal columns reserved right of the [crate::ui::game_map::GameMap] for the sidebar, see
+/// [crate::res::terminal_layout::TerminalLayout].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const SIDEBAR_WIDTH: i32 = 20;
+
+/// The number of messages of the [crate::res::message_log::MessageLog] history visible at once in the
+/// [crate::res::log_view::LogView].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_VIEW_PAGE_SIZE: usize = 20;
+
+/// The minimum distance in tiles between the starting position of the `player` and a room monsters
+/// are spawned in, see [crate::ui::rectangle::Rectangle::distance_to].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MONSTER_SPAWN_DISTANCE: i32 = 3;
+
+/// The number of tiles the spyglass of the `player` extends their `field of view` by, see
+/// [crate::components::spyglass::Spyglass].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const SPYGLASS_RADIUS_BONUS: i32 = 4;
+
+/// The number of turns the spyglass of the `player` stays in use, see
+/// [crate::components::spyglass::Spyglass].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const SPYGLASS_DURATION: u32 = 10;
+
+/// The number of turns after which a remembered tile, which is out of the `player's` sight, is faded the most,
+/// see [crate::res::gameplay_config::GameplayConfig::sight_memory_fade].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const SIGHT_MEMORY_FADE_TURNS: u32 = 50;
+
+/// The maximum factor by which remembered tiles are faded towards the background color, which keeps
+/// even long forgotten tiles faintly visible.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const SIGHT_MEMORY_MAX_FADE: f32 = 0.6;
+
+/// The number of milliseconds between two ticks of animated tile backgrounds, e.g., the rippling of water,
+/// see [crate::ui::tile::Tile::animated_background].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const TILE_ANIMATION_TICK_MS: u64 = 500;
+
+/// The number of milliseconds every frame of the shimmer of a monster is shown for, see
+/// [crate::components::animated_sprite::AnimatedSprite].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MONSTER_SHIMMER_FRAME_MS: u64 = 700;
+
+/// The maximum distance along each axis, at which the player can aim in the targeting mode, see
+/// [crate::res::targeting_mode::TargetingMode].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const TARGETING_RANGE: i32 = 6;
+
+/// The environment variable, which overrides the [crate::plugins::states::AppState] the game boots into, e.g.,
+/// `BROUGE_INITIAL_STATE=Game`. Only evaluated with the `debug_tools` feature enabled.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const INITIAL_STATE_ENV_VAR: &str = "BROUGE_INITIAL_STATE";
@@ -0,0 +1,293 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::grid::Grid;
+use crate::core::position_2d::Position2d;
+use crate::ui::tile::Tile;
+use crate::ui::tile_map::TileMap;
+
+/// Distance field over a [TileMap], holding the cost of the cheapest orthogonal walk from every position to the
+/// nearest of a set of goal positions, e.g., the position of the `player`. Every step costs the
+/// [Tile::movement_cost] of the tile it enters.
+///
+/// The distances are flood-filled from the goals outwards and never cross tiles with collision, so following the
+/// decreasing distances routes around walls. Positions that can't reach any goal don't have a distance.
+///
+/// # Examples
+///
+/// ```
+/// let dijkstra_map = DijkstraMap::new(&game_map, &[player_position]);
+///
+/// if let Some(step) = dijkstra_map.downhill_neighbors(&monster_position).first() {
+///     // Step towards the player.
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Eq, PartialEq)]
+pub struct DijkstraMap {
+    /// (Private) The distance of every position to the nearest goal, [None] if no goal can be reached.
+    distances: Grid<Option<u32>>,
+}
+
+impl DijkstraMap {
+    /// Creates a new [DijkstraMap] by flood-filling the passed `map` from the given `goals`. Goals outside of the
+    /// `map` or on tiles with collision are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `map`: The [TileMap] providing the dimension, the collision and the movement cost of the tiles.
+    /// * `goals`: The positions the distances are measured to.
+    ///
+    /// returns: [DijkstraMap]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dijkstra_map = DijkstraMap::new(&game_map, &[[4, 2]]);
+    ///
+    /// assert_eq!(Some(0), dijkstra_map.distance_at(&[4, 2]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new<T: Tile>(map: &impl TileMap<T>, goals: &[impl Position2d]) -> Self {
+        let mut distances = Grid::new(&[map.width(), map.height()], None);
+        let mut frontier = BinaryHeap::new();
+
+        for goal in goals {
+            let goal = goal.as_array();
+
+            if map.is_in_bounds(&goal) && !map.tile_has_collision(&goal) {
+                distances.set(&goal, Some(0));
+                frontier.push(Reverse((0, goal)));
+            }
+        }
+
+        while let Some(Reverse((distance, position))) = frontier.pop() {
+            // A cheaper walk to the position has been expanded already.
+            if distances
+                .get(&position)
+                .copied()
+                .flatten()
+                .is_some_and(|known_distance| known_distance < distance)
+            {
+                continue;
+            }
+
+            for neighbor in position.cardinal_neighbors() {
+                if !map.is_in_bounds(&neighbor) || map.tile_has_collision(&neighbor) {
+                    continue;
+                }
+
+                let neighbor_distance = distance + map.tile_movement_cost(&neighbor) as u32;
+
+                if distances
+                    .get(&neighbor)
+                    .copied()
+                    .flatten()
+                    .is_some_and(|known_distance| known_distance <= neighbor_distance)
+                {
+                    continue;
+                }
+
+                distances.set(&neighbor, Some(neighbor_distance));
+                frontier.push(Reverse((neighbor_distance, neighbor)));
+            }
+        }
+
+        Self { distances }
+    }
+
+    /// Returns the cost of the cheapest walk from the passed `position` to the nearest goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to look up.
+    ///
+    /// returns: [Option]<u32> - [None] if the `position` lies outside of the map or can't reach any goal.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn distance_at(&self, position: &impl Position2d) -> Option<u32> {
+        self.distances.get(position).copied().flatten()
+    }
+
+    /// Returns all orthogonal neighbors of the passed `position`, which lie closer to the nearest goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to step away from.
+    ///
+    /// returns: [Vec]<[i32; 2]> - Empty, if the `position` is a goal itself or can't reach any goal.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn downhill_neighbors(&self, position: &impl Position2d) -> Vec<[i32; 2]> {
+        let Some(distance) = self.distance_at(position) else {
+            return Vec::new();
+        };
+
+        position
+            .cardinal_neighbors()
+            .into_iter()
+            .filter(|neighbor| {
+                self.distance_at(neighbor)
+                    .is_some_and(|neighbor_distance| neighbor_distance < distance)
+            })
+            .collect()
+    }
+}
+
+impl Debug for DijkstraMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Core -> DijkstraMap {{ \
+        width: {}, \
+        height: {}, \
+        reachable: {} \
+        }}",
+            self.distances.width(),
+            self.distances.height(),
+            self.distances.cells().iter().flatten().count()
+        )
+    }
+}
+
+impl Display for DijkstraMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {})",
+            self.distances.width(),
+            self.distances.height()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::split_room;
+
+    use super::*;
+
+    #[test]
+    fn test_distance_increases_away_from_goal() {
+        let game_map = split_room();
+        let dijkstra_map = DijkstraMap::new(&game_map, &[[2, 3]]);
+
+        assert_eq!(Some(0), dijkstra_map.distance_at(&[2, 3]));
+        assert_eq!(Some(1), dijkstra_map.distance_at(&[3, 3]));
+        assert_eq!(Some(2), dijkstra_map.distance_at(&[4, 3]));
+        assert_eq!(Some(3), dijkstra_map.distance_at(&[4, 4]));
+    }
+
+    #[test]
+    fn test_distance_routes_around_walls() {
+        let game_map = split_room();
+        let dijkstra_map = DijkstraMap::new(&game_map, &[[4, 3]]);
+
+        // The direct neighbor behind the wall has to walk through the gap at [5, 5].
+        assert_eq!(None, dijkstra_map.distance_at(&[5, 3]));
+        assert_eq!(Some(3), dijkstra_map.distance_at(&[5, 5]));
+        assert_eq!(Some(6), dijkstra_map.distance_at(&[6, 3]));
+        assert_eq!(None, dijkstra_map.distance_at(&[0, 0]));
+        assert_eq!(None, dijkstra_map.distance_at(&[20, 3]));
+    }
+
+    #[test]
+    fn test_distance_includes_movement_cost() {
+        let mut game_map = split_room();
+        game_map.set_tile_at(&[3, 3], MapTile::rubble());
+
+        let dijkstra_map = DijkstraMap::new(&game_map, &[[2, 3]]);
+
+        // Entering the rubble costs two instead of one, which carries over to the tiles behind it.
+        assert_eq!(Some(2), dijkstra_map.distance_at(&[3, 3]));
+        assert_eq!(Some(3), dijkstra_map.distance_at(&[4, 3]));
+        assert_eq!(Some(2), dijkstra_map.distance_at(&[3, 2]));
+    }
+
+    #[test]
+    fn test_downhill_neighbors_lead_to_goal() {
+        let game_map = split_room();
+        let dijkstra_map = DijkstraMap::new(&game_map, &[[4, 3]]);
+
+        let mut position = [6, 3];
+        let mut path = Vec::new();
+
+        while let Some(&step) = dijkstra_map.downhill_neighbors(&position).first() {
+            position = step;
+            path.push(step);
+        }
+
+        assert_eq!([4, 3], position);
+        assert_eq!(6, path.len());
+        assert!(path.contains(&[5, 5]));
+        assert!(path.iter().all(|step| !game_map.tile_has_collision(step)));
+    }
+
+    #[test]
+    fn test_debug_and_display() {
+        let dijkstra_map = DijkstraMap::new(&split_room(), &[[4, 3]]);
+
+        assert_eq!(
+            "ECS -> Core -> DijkstraMap { width: 12, height: 8, reachable: 41 }",
+            format!("{:?}", dijkstra_map)
+        );
+        assert_eq!("(12, 8)", dijkstra_map.to_string());
+    }
+
+    #[test]
+    fn test_multiple_goals() {
+        let game_map = split_room();
+        let dijkstra_map = DijkstraMap::new(&game_map, &[[1, 1], [9, 1]]);
+
+        assert_eq!(Some(0), dijkstra_map.distance_at(&[9, 1]));
+        assert_eq!(Some(1), dijkstra_map.distance_at(&[8, 1]));
+        assert_eq!(Some(2), dijkstra_map.distance_at(&[3, 1]));
+        assert!(dijkstra_map.downhill_neighbors(&[1, 1]).is_empty());
+    }
+}
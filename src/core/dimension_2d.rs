@@ -131,6 +131,8 @@ pub trait Dimension2d: Debug + Clone {
     /// let dimension = [400, 200];
     ///
     /// assert!(dimension.is_in_bounds([24, 18]));
+    /// assert!(dimension.is_in_bounds([399, 199]));
+    /// assert!(!dimension.is_in_bounds([400, 18]));
     /// assert!(!dimension.is_in_bounds([500, 250]));
     /// assert!(!dimension.is_in_bounds([-2, -30]));
     /// ```
@@ -142,8 +144,8 @@ pub trait Dimension2d: Debug + Clone {
     /// Since: `0.1.7`
     ///
     fn is_in_bounds(&self, position: &impl Position2d) -> bool {
-        (0..self.width() - 1).contains(&position.x_coordinate())
-            && (0..self.height() - 1).contains(&position.y_coordinate())
+        (0..self.width()).contains(&position.x_coordinate())
+            && (0..self.height()).contains(&position.y_coordinate())
     }
 
     /// Creates a new `i32` array with a fixed length of `2`, which contains the [Dimension2d]'s
@@ -300,6 +302,12 @@ mod tests {
         assert!(dimension.is_in_bounds(&[24, 58]));
         assert!(!dimension.is_in_bounds(&[500, 300]));
         assert!(!dimension.is_in_bounds(&[-2, -300]));
+
+        assert!([80, 50].is_in_bounds(&[79, 0]));
+        assert!([80, 50].is_in_bounds(&[79, 49]));
+        assert!(![80, 50].is_in_bounds(&[80, 0]));
+        assert!(![80, 50].is_in_bounds(&[0, 50]));
+        assert!(![80, 50].is_in_bounds(&[-1, 0]));
     }
 
     //noinspection ALL
@@ -0,0 +1,321 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::position_2d::Position2d;
+
+/// Two dimensional container storing one value per position of a rectangular area, e.g., the tiles of a map or
+/// whether they have been seen by the `player`.
+///
+/// The values are stored row by row in a linear vector, starting at the bottom left `[0, 0]` position. All
+/// position based accessors check the bounds of the grid, so a position outside of it never maps onto the value
+/// of another position.
+///
+/// # Examples
+///
+/// ```
+/// let mut grid = Grid::new(&[80, 50], false);
+///
+/// grid.set(&[3, 4], true);
+///
+/// assert_eq!(Some(&true), grid.get(&[3, 4]));
+/// assert_eq!(Some(&false), grid.get(&[4, 3]));
+/// assert_eq!(None, grid.get(&[80, 0]));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    /// (Private) The width of the grid.
+    width: i32,
+    /// (Private) The height of the grid.
+    height: i32,
+    /// (Private) The values of the grid as a linear vector, stored row by row.
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a new [Grid] with the passed `dimension`, which holds the given `value` at every position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension`: The [Dimension2d] of the grid.
+    /// * `value`: The initial value of every position.
+    ///
+    /// returns: [Grid]<T>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grid = Grid::new(&[80, 50], MapTile::default());
+    ///
+    /// assert_eq!(4000, grid.cells().len());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(dimension: &impl Dimension2d, value: T) -> Self {
+        Self {
+            width: dimension.width(),
+            height: dimension.height(),
+            cells: vec![value; dimension.area()],
+        }
+    }
+
+    /// Sets every position of the grid to the passed `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The value to set at every position.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    /// Checks if the passed `position` lies within the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to bounds-check.
+    ///
+    /// returns: bool - `true` if the `position` is in bounds and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grid = Grid::new(&[80, 50], false);
+    ///
+    /// assert!(grid.in_bounds(&[79, 49]));
+    /// assert!(!grid.in_bounds(&[80, 49]));
+    /// assert!(!grid.in_bounds(&[-1, 0]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn in_bounds(&self, position: &impl Position2d) -> bool {
+        (0..self.width).contains(&position.x_coordinate())
+            && (0..self.height).contains(&position.y_coordinate())
+    }
+
+    /// Returns the value at the passed `position`, or [None] if it lies outside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] of the value.
+    ///
+    /// returns: [Option]<&T>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn get(&self, position: &impl Position2d) -> Option<&T> {
+        self.index_of(position)
+            .and_then(|index| self.cells.get(index))
+    }
+
+    /// Sets the passed `value` at the given `position`. Positions outside of the grid are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] at which the `value` is set.
+    /// * `value`: The value to set.
+    ///
+    /// returns: bool - `true` if the `value` was set and `false` if the `position` lies outside of the grid.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn set(&mut self, position: &impl Position2d, value: T) -> bool {
+        match self
+            .index_of(position)
+            .and_then(|index| self.cells.get_mut(index))
+        {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over all positions of the grid, row by row starting at the bottom left `[0, 0]`
+    /// position.
+    ///
+    /// returns: impl [Iterator]<Item = [i32; 2]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grid = Grid::new(&[2, 2], false);
+    ///
+    /// assert_eq!(
+    ///     vec![[0, 0], [1, 0], [0, 1], [1, 1]],
+    ///     grid.iter_positions().collect::<Vec<[i32; 2]>>()
+    /// );
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn iter_positions(&self) -> impl Iterator<Item = [i32; 2]> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| [x, y]))
+    }
+
+    /// Returns all values of the grid as a linear vector, stored row by row.
+    ///
+    /// returns: &[Vec]<T>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn cells(&self) -> &Vec<T> {
+        &self.cells
+    }
+
+    /// Returns all values of the grid as a mutable linear vector, stored row by row.
+    ///
+    /// returns: &mut [Vec]<T>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn cells_mut(&mut self) -> &mut Vec<T> {
+        &mut self.cells
+    }
+
+    /// (Private) Converts the passed `position` into the index of its value, or [None] if it lies outside of the
+    /// grid.
+    fn index_of(&self, position: &impl Position2d) -> Option<usize> {
+        if !self.in_bounds(position) {
+            return None;
+        }
+
+        Some((position.y_coordinate() * self.width + position.x_coordinate()) as usize)
+    }
+}
+
+impl<T> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.width, self.height, self.cells.len())
+    }
+}
+
+impl<T: Debug + Clone> Dimension2d for Grid<T> {
+    fn width(&self) -> i32 {
+        self.width
+    }
+
+    fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = Grid::new(&[4, 3], 0);
+
+        assert!(grid.set(&[3, 2], 7));
+        assert!(grid.set(&[0, 1], 5));
+
+        assert_eq!(Some(&7), grid.get(&[3, 2]));
+        assert_eq!(Some(&5), grid.get(&[0, 1]));
+        assert_eq!(Some(&0), grid.get(&[1, 1]));
+        assert_eq!(5, grid.cells()[4]);
+        assert_eq!(7, grid.cells()[11]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = Grid::new(&[4, 3], 0);
+
+        assert!(grid.in_bounds(&[0, 0]));
+        assert!(grid.in_bounds(&[3, 2]));
+        assert!(!grid.in_bounds(&[4, 0]));
+        assert!(!grid.in_bounds(&[0, 3]));
+        assert!(!grid.in_bounds(&[-1, 1]));
+
+        // Out of bounds positions must not wrap around onto the previous or next row.
+        assert!(!grid.set(&[-1, 1], 1));
+        assert!(!grid.set(&[4, 0], 1));
+        assert_eq!(None, grid.get(&[4, 0]));
+        assert!(grid.cells().iter().all(|cell| *cell == 0));
+    }
+
+    #[test]
+    fn test_iter_positions_and_fill() {
+        let mut grid = Grid::new(&[2, 2], false);
+
+        assert_eq!(
+            vec![[0, 0], [1, 0], [0, 1], [1, 1]],
+            grid.iter_positions().collect::<Vec<[i32; 2]>>()
+        );
+
+        grid.fill(true);
+
+        assert!(grid.cells().iter().all(|cell| *cell));
+    }
+}
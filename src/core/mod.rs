@@ -28,8 +28,16 @@
 //! Since: `0.1.5`
 //!
 
+pub mod action;
 pub mod algorithm;
 pub mod constants;
+pub mod dijkstra_map;
 pub mod dimension_2d;
+pub mod grid;
+pub mod monster_spawn_table;
+pub mod monster_stats;
+pub mod pathfinding;
 pub mod position_2d;
 pub mod rng;
+pub mod save_game;
+pub mod spatial_query;
@@ -0,0 +1,248 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use crate::components::enemy_type::EnemyType;
+use crate::core::rng::RandomNumberGenerator;
+
+/// A single entry of the [MonsterSpawnTable], making the `enemy_type` spawnable on the floors within the
+/// `depths` range with the passed `weight`.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpawnEntry {
+    /// The range of [crate::res::dungeon_depth::DungeonDepth]s on which the entry applies.
+    pub depths: RangeInclusive<u32>,
+    /// The [EnemyType] to spawn.
+    pub enemy_type: EnemyType,
+    /// The relative chance of the entry being picked. Entries with a weight of `0` are never picked.
+    pub weight: u32,
+}
+
+impl SpawnEntry {
+    /// Creates a new [SpawnEntry] from the passed `depths`, `enemy_type` and `weight`.
+    ///
+    /// # Arguments
+    ///
+    /// * `depths`: The range of depths on which the entry applies.
+    /// * `enemy_type`: The [EnemyType] to spawn.
+    /// * `weight`: The relative chance of the entry being picked.
+    ///
+    /// returns: [SpawnEntry]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(depths: RangeInclusive<u32>, enemy_type: EnemyType, weight: u32) -> Self {
+        Self {
+            depths,
+            enemy_type,
+            weight,
+        }
+    }
+}
+
+/// Table of weighted [SpawnEntry]s, from which the monster of each spawn position is rolled, so the variety of
+/// monsters grows the deeper the player descends. Entries of the same [EnemyType] with overlapping `depths` add
+/// up their weights.
+///
+/// # Examples
+///
+/// ```
+/// let table = MonsterSpawnTable::default();
+/// let mut rng = RandomNumberGenerator::new();
+///
+/// assert_eq!(EnemyType::Mended, table.roll(1, &mut rng));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MonsterSpawnTable {
+    /// (Private) The entries to roll from.
+    entries: Vec<SpawnEntry>,
+}
+
+impl MonsterSpawnTable {
+    /// Creates a new [MonsterSpawnTable] from the passed `entries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries`: The [SpawnEntry]s to roll from.
+    ///
+    /// returns: [MonsterSpawnTable]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(entries: Vec<SpawnEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Rolls the [EnemyType] to spawn on a floor of the passed `depth`, picking one of the entries applying to the
+    /// `depth` with a chance proportional to its weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth`: The [crate::res::dungeon_depth::DungeonDepth] of the floor to spawn the monster on.
+    /// * `rng`: The [RandomNumberGenerator] used for the roll.
+    ///
+    /// returns: [EnemyType]
+    ///
+    /// # Panics
+    ///
+    /// * If no entry with a weight above `0` applies to the `depth`.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn roll(&self, depth: u32, rng: &mut RandomNumberGenerator) -> EnemyType {
+        let candidates: Vec<&SpawnEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.weight > 0 && entry.depths.contains(&depth))
+            .collect();
+
+        let total_weight: u32 = candidates.iter().map(|entry| entry.weight).sum();
+
+        if total_weight == 0 {
+            panic!(
+                "MonsterSpawnTable -> No monster can spawn at depth {}!",
+                depth
+            );
+        }
+
+        let mut roll = rng.range(0..total_weight);
+
+        for entry in candidates {
+            if roll < entry.weight {
+                return entry.enemy_type;
+            }
+
+            roll -= entry.weight;
+        }
+
+        unreachable!("The roll is always below the total weight of the candidates.")
+    }
+}
+
+impl Default for MonsterSpawnTable {
+    fn default() -> Self {
+        Self::new(vec![
+            SpawnEntry::new(1..=u32::MAX, EnemyType::Mended, 4),
+            SpawnEntry::new(2..=3, EnemyType::Ghoul, 1),
+            SpawnEntry::new(4..=u32::MAX, EnemyType::Ghoul, 3),
+        ])
+    }
+}
+
+impl Display for MonsterSpawnTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_weight_entries_are_never_rolled() {
+        let table = MonsterSpawnTable::new(vec![
+            SpawnEntry::new(1..=5, EnemyType::Mended, 0),
+            SpawnEntry::new(1..=5, EnemyType::Ghoul, 1),
+        ]);
+        let mut rng = RandomNumberGenerator::seeded(7);
+
+        for _ in 0..100 {
+            assert_eq!(EnemyType::Ghoul, table.roll(3, &mut rng));
+        }
+    }
+
+    #[test]
+    fn test_roll_only_considers_entries_of_the_depth() {
+        let table = MonsterSpawnTable::default();
+        let mut rng = RandomNumberGenerator::seeded(7);
+
+        for _ in 0..100 {
+            assert_eq!(EnemyType::Mended, table.roll(1, &mut rng));
+        }
+
+        let deep_rolls: Vec<EnemyType> = (0..100).map(|_| table.roll(5, &mut rng)).collect();
+
+        assert!(deep_rolls.contains(&EnemyType::Mended));
+        assert!(deep_rolls.contains(&EnemyType::Ghoul));
+    }
+
+    #[test]
+    fn test_seeded_roll_is_deterministic() {
+        let table = MonsterSpawnTable::default();
+
+        let roll = |seed: u64| {
+            let mut rng = RandomNumberGenerator::seeded(seed);
+            (0..8)
+                .map(|_| table.roll(4, &mut rng))
+                .collect::<Vec<EnemyType>>()
+        };
+
+        assert_eq!(
+            vec![
+                EnemyType::Ghoul,
+                EnemyType::Ghoul,
+                EnemyType::Ghoul,
+                EnemyType::Mended,
+                EnemyType::Mended,
+                EnemyType::Ghoul,
+                EnemyType::Mended,
+                EnemyType::Ghoul,
+            ],
+            roll(1337)
+        );
+        assert_ne!(roll(1337), roll(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_roll_panics_without_entries_for_the_depth() {
+        MonsterSpawnTable::new(vec![SpawnEntry::new(2..=3, EnemyType::Ghoul, 1)])
+            .roll(1, &mut RandomNumberGenerator::seeded(7));
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use crate::components::enemy_type::EnemyType;
+
+/// The base stats of a monster, before the difficulty of the [crate::res::gameplay_config::GameplayConfig]
+/// is applied. All monsters are tuned in the stat table of this module, which is consumed by the
+/// [crate::entities::monster_factory::MonsterFactory].
+///
+/// # Examples
+///
+/// ```
+/// let stats = MonsterStats::of(&EnemyType::Mended);
+///
+/// commands.spawn((Fov::new(stats.fov_radius), Speed::new(stats.speed)));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MonsterStats {
+    /// The name of the monster, shown in the message log.
+    pub name: &'static str,
+    /// The symbol to use when rendering the monster.
+    pub glyph: char,
+    /// The maximum hit points, scaled by the difficulty.
+    pub max_health: i32,
+    /// The attack power, scaled by the difficulty.
+    pub power: i32,
+    /// The defense, reducing the damage of incoming attacks.
+    pub defense: i32,
+    /// The radius of the monster's `field of view`.
+    pub fov_radius: i32,
+    /// The number of turns between two moves of the monster, see [crate::components::speed::Speed].
+    pub speed: u32,
+}
+
+/// The stats of a [EnemyType::Mended], a sluggish mass of stitched together flesh.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MENDED_STATS: MonsterStats = MonsterStats {
+    name: "Mended",
+    glyph: 'm',
+    max_health: 16,
+    power: 4,
+    defense: 1,
+    fov_radius: 8,
+    speed: 2,
+};
+
+/// The stats of a [EnemyType::Ghoul], which makes up for its frailty by moving every turn.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const GHOUL_STATS: MonsterStats = MonsterStats {
+    name: "Ghoul",
+    glyph: 'g',
+    max_health: 10,
+    power: 3,
+    defense: 1,
+    fov_radius: 10,
+    speed: 1,
+};
+
+impl MonsterStats {
+    /// Looks up the base stats of the passed `enemy_type` in the stat table.
+    ///
+    /// # Arguments
+    ///
+    /// * `enemy_type`: The [EnemyType] whose stats to look up.
+    ///
+    /// returns: [MonsterStats]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn of(enemy_type: &EnemyType) -> Self {
+        match enemy_type {
+            EnemyType::Mended => MENDED_STATS,
+            EnemyType::Ghoul => GHOUL_STATS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_blocks_are_complete() {
+        for enemy_type in [EnemyType::Mended, EnemyType::Ghoul] {
+            let stats = MonsterStats::of(&enemy_type);
+
+            assert!(!stats.name.is_empty(), "{:?}", enemy_type);
+            assert!(stats.max_health > 0, "{:?}", enemy_type);
+            assert!(stats.power > 0, "{:?}", enemy_type);
+            assert!(stats.defense > 0, "{:?}", enemy_type);
+            assert!(stats.fov_radius > 0, "{:?}", enemy_type);
+            assert!(stats.speed > 0, "{:?}", enemy_type);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::core::position_2d::Position2d;
+use crate::ui::tile::Tile;
+use crate::ui::tile_map::TileMap;
+
+/// Searches the shortest path from the `start` to the `goal` position on the passed `map` with the A* algorithm.
+/// Tiles with collision block the path and every step costs the [Tile::movement_cost] of the tile it enters, so the
+/// path detours around costly tiles like rubble, if that's cheaper than crossing them.
+///
+/// With `allow_diagonal` enabled, the path may also step diagonally, which is estimated with the chebyshev
+/// distance, otherwise only orthogonal steps are taken and estimated with the manhattan distance.
+///
+/// # Arguments
+///
+/// * `start`: The [Position2d] the path begins at.
+/// * `goal`: The [Position2d] the path leads to.
+/// * `map`: The [TileMap] providing the collision of the tiles.
+/// * `allow_diagonal`: Whether the path may step diagonally.
+///
+/// returns: [Option]<[Vec]<[i32; 2]>> - The positions of the path including the `start` and the `goal`, or [None]
+/// if the `goal` can't be reached or either position lies outside of the `map` or on a tile with collision.
+///
+/// # Examples
+///
+/// ```
+/// let path = a_star(&[1, 1], &[3, 1], &game_map, false).unwrap();
+///
+/// assert_eq!(vec![[1, 1], [2, 1], [3, 1]], path);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn a_star<T: Tile>(
+    start: &impl Position2d,
+    goal: &impl Position2d,
+    map: &impl TileMap<T>,
+    allow_diagonal: bool,
+) -> Option<Vec<[i32; 2]>> {
+    let start = start.as_array();
+    let goal = goal.as_array();
+
+    if !is_walkable(&start, map) || !is_walkable(&goal, map) {
+        return None;
+    }
+
+    let heuristic = |position: &[i32; 2]| {
+        if allow_diagonal {
+            position.chebyshev_distance(&goal)
+        } else {
+            position.manhattan_distance(&goal)
+        }
+    };
+
+    // The diagonal neighbors follow the cardinal ones, so the first four are the orthogonal steps.
+    let neighbor_count = if allow_diagonal { 8 } else { 4 };
+
+    let mut open = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut came_from: HashMap<[i32; 2], [i32; 2]> = HashMap::new();
+
+    while let Some(Reverse((_, cost, position))) = open.pop() {
+        if position == goal {
+            return Some(reconstruct_path(&came_from, goal));
+        }
+
+        // A cheaper route to the position has been expanded already.
+        if costs
+            .get(&position)
+            .is_some_and(|&known_cost| known_cost < cost)
+        {
+            continue;
+        }
+
+        for neighbor in position
+            .diagonal_neighbors()
+            .into_iter()
+            .take(neighbor_count)
+        {
+            if !is_walkable(&neighbor, map) {
+                continue;
+            }
+
+            let neighbor_cost = cost + map.tile_movement_cost(&neighbor);
+
+            if costs
+                .get(&neighbor)
+                .is_some_and(|&known_cost| known_cost <= neighbor_cost)
+            {
+                continue;
+            }
+
+            costs.insert(neighbor, neighbor_cost);
+            came_from.insert(neighbor, position);
+            open.push(Reverse((
+                neighbor_cost + heuristic(&neighbor),
+                neighbor_cost,
+                neighbor,
+            )));
+        }
+    }
+
+    None
+}
+
+/// (Private) Checks if the passed `position` lies on the `map` and can be walked on.
+fn is_walkable<T: Tile>(position: &[i32; 2], map: &impl TileMap<T>) -> bool {
+    map.is_in_bounds(position) && !map.tile_has_collision(position)
+}
+
+/// (Private) Follows the `came_from` links back from the `goal` and returns the path in walking order.
+fn reconstruct_path(came_from: &HashMap<[i32; 2], [i32; 2]>, goal: [i32; 2]) -> Vec<[i32; 2]> {
+    let mut path = vec![goal];
+    let mut position = goal;
+
+    while let Some(&previous) = came_from.get(&position) {
+        path.push(previous);
+        position = previous;
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::game_map::GameMap;
+    use crate::ui::rectangle::Rectangle;
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::{split_room, TestTileMapGenerator};
+
+    use super::*;
+
+    /// Checks that every step of the `path` moves onto a neighboring, walkable tile.
+    fn assert_connected(path: &[[i32; 2]], map: &GameMap, allow_diagonal: bool) {
+        for step in path.windows(2) {
+            let distance = if allow_diagonal {
+                step[0].chebyshev_distance(&step[1])
+            } else {
+                step[0].manhattan_distance(&step[1])
+            };
+
+            assert_eq!(1, distance);
+            assert!(!map.tile_has_collision(&step[1]));
+        }
+    }
+
+    #[test]
+    fn test_a_star_straight_corridor() {
+        let mut game_map = GameMap::new(&[12, 5], &TestTileMapGenerator, None);
+        Rectangle::new([0, 1], [11, 2]).add_to_map(&mut game_map);
+
+        let path = a_star(&[1, 2], &[9, 2], &game_map, false).unwrap();
+
+        assert_eq!((1..=9).map(|x| [x, 2]).collect::<Vec<[i32; 2]>>(), path);
+        assert_eq!(Some(path), a_star(&[1, 2], &[9, 2], &game_map, true));
+    }
+
+    #[test]
+    fn test_a_star_goes_around_wall() {
+        let game_map = split_room();
+
+        let path = a_star(&[4, 3], &[6, 3], &game_map, false).unwrap();
+
+        assert_eq!(Some(&[4, 3]), path.first());
+        assert_eq!(Some(&[6, 3]), path.last());
+        assert_eq!(7, path.len());
+        assert!(path.contains(&[5, 5]));
+        assert_connected(&path, &game_map, false);
+
+        let diagonal_path = a_star(&[4, 3], &[6, 3], &game_map, true).unwrap();
+
+        assert_eq!(5, diagonal_path.len());
+        assert!(diagonal_path.contains(&[5, 5]));
+        assert_connected(&diagonal_path, &game_map, true);
+    }
+
+    #[test]
+    fn test_a_star_detours_around_costly_tiles() {
+        let mut game_map = GameMap::new(&[12, 6], &TestTileMapGenerator, None);
+        Rectangle::new([0, 0], [10, 4]).add_to_map(&mut game_map);
+
+        for x in 4..=6 {
+            game_map.set_tile_at(&[x, 2], MapTile::rubble());
+        }
+
+        // Crossing the rubble costs 11, while the two extra steps around it only add up to 10.
+        let path = a_star(&[1, 2], &[9, 2], &game_map, false).unwrap();
+
+        assert_eq!(11, path.len());
+        assert!((4..=6).all(|x| !path.contains(&[x, 2])));
+        assert_connected(&path, &game_map, false);
+    }
+
+    #[test]
+    fn test_a_star_unreachable_goal() {
+        let mut game_map = split_room();
+        game_map.set_tile_at(&[5, 5], MapTile::default());
+
+        assert_eq!(None, a_star(&[4, 3], &[6, 3], &game_map, false));
+        assert_eq!(None, a_star(&[4, 3], &[5, 3], &game_map, false));
+        assert_eq!(None, a_star(&[4, 3], &[20, 3], &game_map, true));
+    }
+
+    #[test]
+    fn test_a_star_start_is_goal() {
+        let game_map = split_room();
+
+        assert_eq!(
+            Some(vec![[2, 2]]),
+            a_star(&[2, 2], &[2, 2], &game_map, true)
+        );
+    }
+}
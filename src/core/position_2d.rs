@@ -23,6 +23,12 @@ use std::fmt::Debug;
 
 use bevy::math::{IVec2, UVec2, Vec2};
 
+/// The offsets of the cardinal neighbors of a position.
+const CARDINAL_OFFSETS: [[i32; 2]; 4] = [[1, 0], [-1, 0], [0, 1], [0, -1]];
+
+/// The offsets of the diagonal neighbors of a position.
+const DIAGONAL_OFFSETS: [[i32; 2]; 4] = [[1, 1], [-1, 1], [1, -1], [-1, -1]];
+
 /// Describes a position in a two dimensional state, consisting of a `x` and `y``coordinate.
 ///
 /// The trait is implemented by the default for the following types:
@@ -111,6 +117,119 @@ pub trait Position2d: Debug + Copy + Clone + PartialEq {
         ]
     }
 
+    /// Calculates the chebyshev distance between the calling and the passed [Position2d], i.e., the number of
+    /// steps between them if diagonal moves are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The [Position2d] to which the distance should be calculated.
+    ///
+    /// returns: i32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let start = [8, 4];
+    /// let end = [6, 3];
+    ///
+    /// assert_eq!(2, start.chebyshev_distance(&end));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn chebyshev_distance(&self, other: &impl Position2d) -> i32 {
+        let [dx, dy] = self.delta(other);
+
+        dx.abs().max(dy.abs())
+    }
+
+    /// Calculates the manhattan distance between the calling and the passed [Position2d], i.e., the number of
+    /// steps between them if only orthogonal moves are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The [Position2d] to which the distance should be calculated.
+    ///
+    /// returns: i32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let start = [8, 4];
+    /// let end = [6, 3];
+    ///
+    /// assert_eq!(3, start.manhattan_distance(&end));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn manhattan_distance(&self, other: &impl Position2d) -> i32 {
+        let [dx, dy] = self.delta(other);
+
+        dx.abs() + dy.abs()
+    }
+
+    /// Returns the four orthogonal neighbors of the [Position2d], in the order east, west, north and south.
+    ///
+    /// returns: [[i32; 2]; 4]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!([[3, 2], [1, 2], [2, 3], [2, 1]], [2, 2].cardinal_neighbors());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn cardinal_neighbors(&self) -> [[i32; 2]; 4] {
+        let [x, y] = self.as_array();
+
+        CARDINAL_OFFSETS.map(|[dx, dy]| [x + dx, y + dy])
+    }
+
+    /// Returns all eight neighbors of the [Position2d], i.e., the [Position2d::cardinal_neighbors] followed by
+    /// the diagonal ones in the order north-east, north-west, south-east and south-west.
+    ///
+    /// returns: [[i32; 2]; 8]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let neighbors = [2, 2].diagonal_neighbors();
+    ///
+    /// assert_eq!([2, 2].cardinal_neighbors(), neighbors[..4]);
+    /// assert_eq!([[3, 3], [1, 3], [3, 1], [1, 1]], neighbors[4..]);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn diagonal_neighbors(&self) -> [[i32; 2]; 8] {
+        let [x, y] = self.as_array();
+        let [east, west, north, south] = self.cardinal_neighbors();
+        let [north_east, north_west, south_east, south_west] =
+            DIAGONAL_OFFSETS.map(|[dx, dy]| [x + dx, y + dy]);
+
+        [
+            east, west, north, south, north_east, north_west, south_east, south_west,
+        ]
+    }
+
     /// Creates a new `i32` array with a fixed length of `2`, which contains the [Position2d]'s `x` coordinate
     /// at the first index and the `y` coordinate at the last.
     ///
@@ -267,4 +386,57 @@ mod tests {
         assert_eq!([80, 50], F_TUPLE.as_array());
         assert_eq!([80, 50], USIZE_TUPLE.as_array());
     }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        assert_eq!(0, I32_ARRAY.chebyshev_distance(&I_TUPLE));
+        assert_eq!(2, [8, 4].chebyshev_distance(&[6, 3]));
+        assert_eq!(3, [0, 0].chebyshev_distance(&[-3, 3]));
+        assert_eq!(8, [2, 2].chebyshev_distance(&[10, 6]));
+        assert_eq!(8, [10, 6].chebyshev_distance(&(2, 2)));
+        assert_eq!(8, IVec2::new(2, 2).chebyshev_distance(&[10u32, 6u32]));
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(0, I32_ARRAY.manhattan_distance(&I_TUPLE));
+        assert_eq!(3, [8, 4].manhattan_distance(&[6, 3]));
+        assert_eq!(6, [0, 0].manhattan_distance(&[-3, 3]));
+        assert_eq!(12, [2, 2].manhattan_distance(&[10, 6]));
+        assert_eq!(12, [10, 6].manhattan_distance(&(2, 2)));
+        assert_eq!(12, IVec2::new(2, 2).manhattan_distance(&[10u32, 6u32]));
+    }
+
+    #[test]
+    fn test_cardinal_neighbors() {
+        assert_eq!(
+            [[81, 50], [79, 50], [80, 51], [80, 49]],
+            I32_ARRAY.cardinal_neighbors()
+        );
+        assert_eq!(
+            [[1, -1], [-1, -1], [0, 0], [0, -2]],
+            [0, -1].cardinal_neighbors()
+        );
+    }
+
+    #[test]
+    fn test_diagonal_neighbors() {
+        assert_eq!(
+            [
+                [1, -1],
+                [-1, -1],
+                [0, 0],
+                [0, -2],
+                [1, 0],
+                [-1, 0],
+                [1, -2],
+                [-1, -2]
+            ],
+            (0, -1).diagonal_neighbors()
+        );
+        assert_eq!(
+            I_VEC2.cardinal_neighbors(),
+            I_VEC2.diagonal_neighbors()[..4]
+        );
+    }
 }
@@ -22,11 +22,12 @@
 use std::fmt::{Display, Formatter};
 
 use rand::distributions::uniform::{SampleRange, SampleUniform};
-use rand::rngs::ThreadRng;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-/// An OS based random number generator, which provides functionality to pick random values from ranges and roll
-/// classic D&D style dice.
+/// A random number generator, which provides functionality to pick random values from ranges and roll
+/// classic D&D style dice. It is either seeded by the OS or by a fixed seed, in order to reproduce runs, see
+/// [RandomNumberGenerator::seeded].
 ///
 /// This struct is not thread safe!
 ///
@@ -57,11 +58,11 @@ use rand::Rng;
 ///
 /// # See also
 ///
-/// * [ThreadRng]
+/// * [StdRng]
 ///
 #[derive(Debug)]
 pub struct RandomNumberGenerator {
-    generator: ThreadRng,
+    generator: StdRng,
 }
 
 impl RandomNumberGenerator {
@@ -75,7 +76,37 @@ impl RandomNumberGenerator {
     ///
     pub fn new() -> Self {
         Self {
-            generator: rand::thread_rng(),
+            generator: StdRng::from_entropy(),
+        }
+    }
+
+    /// Creates a new [RandomNumberGenerator] instance based on the passed `seed`. Generators created from the
+    /// same `seed` produce the same sequence of values, e.g., to reproduce a map layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed`: The seed to initialize the generator with.
+    ///
+    /// returns: [RandomNumberGenerator]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut rng = RandomNumberGenerator::seeded(42);
+    /// let mut other_rng = RandomNumberGenerator::seeded(42);
+    ///
+    /// assert_eq!(rng.roll_dice(3, 6), other_rng.roll_dice(3, 6));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            generator: StdRng::seed_from_u64(seed),
         }
     }
 
@@ -178,4 +209,28 @@ mod tests {
         assert!(three_d_six >= 3 && three_d_six <= 18);
         assert!(ten_d_twelve >= 10 && ten_d_twelve <= 120);
     }
+
+    #[test]
+    fn test_same_seed_produces_same_dice_rolls() {
+        let mut rng = RandomNumberGenerator::seeded(1337);
+        let mut other_rng = RandomNumberGenerator::seeded(1337);
+
+        let rolls: Vec<i32> = (0..20).map(|_| rng.roll_dice(3, 6)).collect();
+        let other_rolls: Vec<i32> = (0..20).map(|_| other_rng.roll_dice(3, 6)).collect();
+
+        assert_eq!(rolls, other_rolls);
+        assert!(rolls.iter().all(|roll| (3..=18).contains(roll)));
+        assert_eq!(rng.range(0..1000), other_rng.range(0..1000));
+    }
+
+    #[test]
+    fn test_different_seeds_produce_different_sequences() {
+        let mut rng = RandomNumberGenerator::seeded(1);
+        let mut other_rng = RandomNumberGenerator::seeded(2);
+
+        let rolls: Vec<i32> = (0..20).map(|_| rng.roll_dice(10, 12)).collect();
+        let other_rolls: Vec<i32> = (0..20).map(|_| other_rng.roll_dice(10, 12)).collect();
+
+        assert_ne!(rolls, other_rolls);
+    }
 }
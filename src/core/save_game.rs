@@ -0,0 +1,333 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Display, Formatter};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::components::health::Health;
+use crate::core::constants;
+use crate::core::position_2d::Position2d;
+use crate::os::file_system;
+use crate::res::dungeon_depth::DungeonDepth;
+
+/// The persisted state of a run, which is written to and restored from a save file, see [SaveGame::save] and
+/// [SaveGame::load]. It holds the position and [Health] of the `player`, the [DungeonDepth] and the seed of the
+/// current [crate::ui::game_map::GameMap], from which its layout is regenerated.
+///
+/// The run is saved whenever the `player` reaches a new floor and continued from the title screen, which inserts
+/// the loaded [SaveGame] as a [Resource] for the startup of the next run to restore.
+///
+/// Every save carries the `version` of its format. Saves written by an older version of the game are upgraded
+/// through [SaveGame::migrate] before they are deserialized, while saves of a newer, unknown version are
+/// rejected with a [SaveError] instead of failing to deserialize.
+///
+/// # Examples
+///
+/// ```
+/// let raw = serde_json::to_string(&SaveGame::default()).unwrap();
+///
+/// assert_eq!(Ok(SaveGame::default()), SaveGame::migrate(&raw));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Resource)]
+pub struct SaveGame {
+    /// The version of the save format, see [SaveGame::CURRENT_VERSION].
+    pub version: u32,
+    /// The position of the `player`.
+    pub player_position: [i32; 2],
+    /// The [Health] of the `player`.
+    pub health: Health,
+    /// The floor of the dungeon the `player` is on.
+    pub dungeon_depth: DungeonDepth,
+    /// The seed the layout of the current [crate::ui::game_map::GameMap] was generated from.
+    pub seed: u64,
+}
+
+/// A single migration step, upgrading the raw json of a save by one version.
+type Migration = fn(&mut Value);
+
+impl SaveGame {
+    /// The version of the save format written by this version of the game.
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// The path of the save file, which is used as the key of the local storage in the browser.
+    pub const PATH: &'static str = "save.json";
+
+    /// (Private) The migration steps, where the step at index `i` upgrades a save from version `i + 1` to
+    /// version `i + 2`. A new step has to be appended whenever the [SaveGame::CURRENT_VERSION] is raised.
+    const MIGRATIONS: [Migration; (Self::CURRENT_VERSION - 1) as usize] = [];
+
+    /// Creates a new [SaveGame] of the [SaveGame::CURRENT_VERSION] from the passed state of the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_position`: The [Position2d] of the `player`.
+    /// * `health`: The [Health] of the `player`.
+    /// * `dungeon_depth`: The floor of the dungeon the `player` is on.
+    /// * `seed`: The seed of the current [crate::ui::game_map::GameMap], see [crate::ui::game_map::GameMap::seed].
+    ///
+    /// returns: [SaveGame]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let save = SaveGame::new(&[5, 5], &Health::new(30), &DungeonDepth::default(), game_map.seed());
+    ///
+    /// save.save();
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(
+        player_position: &impl Position2d,
+        health: &Health,
+        dungeon_depth: &DungeonDepth,
+        seed: u64,
+    ) -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            player_position: player_position.as_array(),
+            health: *health,
+            dungeon_depth: *dungeon_depth,
+            seed,
+        }
+    }
+
+    /// Writes the save to the [SaveGame::PATH], replacing any previous save. In the browser, the save is written
+    /// to the local storage instead.
+    ///
+    /// returns: bool `true` if the save was written successfully and `false` otherwise.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn save(&self) -> bool {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => file_system::write_file(Self::PATH, &json),
+            Err(error) => {
+                log::error!("Unable to serialize {}: {}", self, error);
+                false
+            }
+        }
+    }
+
+    /// Restores the save from the [SaveGame::PATH], upgrading it to the [SaveGame::CURRENT_VERSION] through
+    /// [SaveGame::migrate]. In the browser, the save is read from the local storage instead.
+    ///
+    /// returns: [Result]<[SaveGame], [SaveError]> - The restored save, or the reason it can't be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// match SaveGame::load() {
+    ///     Ok(save) => // Restore the run
+    ///     Err(SaveError::Missing) => // Start a new run
+    ///     Err(error) => warn!("Unable to load save: {}", error),
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn load() -> Result<SaveGame, SaveError> {
+        let raw = file_system::read_file(Self::PATH).ok_or(SaveError::Missing)?;
+
+        Self::migrate(&raw)
+    }
+
+    /// Parses the passed `raw` json of a save and upgrades it to the [SaveGame::CURRENT_VERSION] by applying all
+    /// migration steps from its version onwards. A save without a `version` is treated as one of version `1`, while
+    /// every other field has to be present after the migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw`: The json content of the save file.
+    ///
+    /// returns: [Result]<[SaveGame], [SaveError]> - The upgraded save, or the reason it can't be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let save = SaveGame::migrate(&file_system::load_file("save.json"))?;
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn migrate(raw: &str) -> Result<SaveGame, SaveError> {
+        let mut json: Value =
+            serde_json::from_str(raw).map_err(|error| SaveError::Malformed(error.to_string()))?;
+
+        let version = match json.get("version") {
+            None => 1,
+            Some(version) => version
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .filter(|version| *version > 0)
+                .ok_or_else(|| SaveError::Malformed(format!("Invalid version {}", version)))?,
+        };
+
+        if version > Self::CURRENT_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+
+        for migration in Self::MIGRATIONS.iter().skip(version as usize - 1) {
+            migration(&mut json);
+        }
+
+        json["version"] = Value::from(Self::CURRENT_VERSION);
+
+        serde_json::from_value(json).map_err(|error| SaveError::Malformed(error.to_string()))
+    }
+}
+
+impl Default for SaveGame {
+    fn default() -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            player_position: [0, 0],
+            health: Health::new(constants::PLAYER_MAX_HEALTH),
+            dungeon_depth: DungeonDepth::default(),
+            seed: 0,
+        }
+    }
+}
+
+impl Display for SaveGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {:?}, {}, {}, {})",
+            self.version, self.player_position, self.health, self.dungeon_depth, self.seed
+        )
+    }
+}
+
+/// The reason a save couldn't be loaded, as reported by [SaveGame::migrate].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SaveError {
+    /// There is no save to load.
+    Missing,
+    /// The save isn't valid json or doesn't match the format of its version.
+    Malformed(String),
+    /// The save was written by a newer version of the game, whose format is unknown.
+    UnsupportedVersion(u32),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Missing => write!(f, "Missing"),
+            SaveError::Malformed(reason) => write!(f, "Malformed({})", reason),
+            SaveError::UnsupportedVersion(version) => write!(f, "UnsupportedVersion({})", version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_current_version() {
+        let raw = serde_json::to_string(&SaveGame::default()).unwrap();
+
+        assert_eq!(Ok(SaveGame::default()), SaveGame::migrate(&raw));
+    }
+
+    #[test]
+    fn test_reject_partial_save() {
+        assert!(matches!(
+            SaveGame::migrate("{}"),
+            Err(SaveError::Malformed(_))
+        ));
+        assert!(matches!(
+            SaveGame::migrate("{\"version\": 1, \"player_position\": [5, 5], \"seed\": 42}"),
+            Err(SaveError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_save() {
+        let mut dungeon_depth = DungeonDepth::default();
+        dungeon_depth.descend();
+
+        let mut health = Health::new(30);
+        health.apply_damage(12);
+
+        let save = SaveGame::new(&[12, 7], &health, &dungeon_depth, 42);
+        let raw = serde_json::to_string(&save).unwrap();
+        let loaded = SaveGame::migrate(&raw).unwrap();
+
+        assert_eq!(save, loaded);
+        assert_eq!([12, 7], loaded.player_position);
+        assert_eq!(18, loaded.health.current);
+        assert_eq!(2, loaded.dungeon_depth.current());
+        assert_eq!(42, loaded.seed);
+    }
+
+    #[test]
+    fn test_reject_unsupported_save() {
+        let future_version = SaveGame::CURRENT_VERSION + 1;
+
+        assert_eq!(
+            Err(SaveError::UnsupportedVersion(future_version)),
+            SaveGame::migrate(&format!("{{\"version\": {}}}", future_version))
+        );
+        assert!(matches!(
+            SaveGame::migrate("{\"version\": 0}"),
+            Err(SaveError::Malformed(_))
+        ));
+        assert!(matches!(
+            SaveGame::migrate("not a save"),
+            Err(SaveError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::query::ReadOnlyWorldQuery;
+use bevy::prelude::{Entity, Query};
+
+use crate::components::coord_2d::Coord2d;
+use crate::core::position_2d::Position2d;
+
+/// Extension of a [Query] over the positions of `entities`, to look up which `entities` are located at or near
+/// a [Position2d]. The lookup respects the filter of the query, e.g., `With<Collision>` to only find blocking
+/// `entities`.
+///
+/// # Examples
+///
+/// ```
+/// fn system(target_query: Query<(Entity, &Coord2d), With<Collision>>) {
+///     let blockers = target_query.entities_at(&[4, 2]);
+///     let nearby = target_query.entities_within(&[4, 2], 3);
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub trait SpatialQuery {
+    /// Collects all `entities` located exactly at the passed `position`.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to look up.
+    ///
+    /// returns: [Vec]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn entities_at(&self, position: &impl Position2d) -> Vec<Entity>;
+
+    /// Collects all `entities` whose chebyshev distance to the passed `position` is at most `radius`, including
+    /// the ones located at the `position` itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] in the center of the lookup.
+    /// * `radius`: The maximum distance of a found `entity` to the `position`.
+    ///
+    /// returns: [Vec]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn entities_within(&self, position: &impl Position2d, radius: i32) -> Vec<Entity>;
+}
+
+impl<F: ReadOnlyWorldQuery> SpatialQuery for Query<'_, '_, (Entity, &Coord2d), F> {
+    fn entities_at(&self, position: &impl Position2d) -> Vec<Entity> {
+        self.entities_within(position, 0)
+    }
+
+    fn entities_within(&self, position: &impl Position2d, radius: i32) -> Vec<Entity> {
+        self.iter()
+            .filter(|(_, coord)| coord.chebyshev_distance(position) <= radius)
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+    use bevy::prelude::{With, World};
+
+    use crate::components::collision::Collision;
+
+    use super::*;
+
+    #[test]
+    fn test_entities_at() {
+        let mut world = World::new();
+
+        let blocker = world.spawn((Coord2d::new(4, 2), Collision)).id();
+        world.spawn(Coord2d::new(4, 2));
+        world.spawn((Coord2d::new(5, 2), Collision));
+
+        let mut state: SystemState<Query<(Entity, &Coord2d), With<Collision>>> =
+            SystemState::new(&mut world);
+        let query = state.get(&world);
+
+        assert_eq!(vec![blocker], query.entities_at(&[4, 2]));
+        assert!(query.entities_at(&[3, 2]).is_empty());
+
+        world.get_mut::<Coord2d>(blocker).unwrap().x = 3;
+
+        let query = state.get(&world);
+
+        assert_eq!(vec![blocker], query.entities_at(&[3, 2]));
+        assert!(query.entities_at(&[4, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_entities_within() {
+        let mut world = World::new();
+
+        let center = world.spawn(Coord2d::new(10, 10)).id();
+        let near = world.spawn(Coord2d::new(12, 8)).id();
+        let far = world.spawn(Coord2d::new(14, 10)).id();
+
+        let mut state: SystemState<Query<(Entity, &Coord2d)>> = SystemState::new(&mut world);
+
+        let mut found = state.get(&world).entities_within(&[10, 10], 2);
+        found.sort();
+
+        assert_eq!(vec![center, near], found);
+
+        world.get_mut::<Coord2d>(far).unwrap().x = 11;
+
+        let mut found = state.get(&world).entities_within(&[10, 10], 2);
+        found.sort();
+
+        assert_eq!(vec![center, near, far], found);
+        assert_eq!(
+            vec![center],
+            state.get(&world).entities_within(&[10, 10], 0)
+        );
+    }
+}
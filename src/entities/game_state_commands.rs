@@ -0,0 +1,72 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::{Bundle, Commands};
+
+use crate::components::state_label::GameStateLabel;
+
+/// Extension of [Commands] for spawning `entities`, which belong to the
+/// [crate::plugins::game_state_plugin::GameStatePlugin].
+///
+/// The spawned `entities` always carry the [GameStateLabel], so they are cleaned up by the
+/// [crate::plugins::game_state_systems::lifecycle::shutdown_system] when the game's state machine leaves the
+/// [crate::plugins::states::AppState::Game] state.
+///
+/// # Examples
+///
+/// ```
+/// fn spawner_system(mut commands: Commands) {
+///     commands.spawn_in_game_state((Coord2d::new(40, 25), NameTag::new("Mended")));
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub trait GameStateCommands<'w, 's> {
+    /// Spawns a new `entity` with the passed `bundle` and the [GameStateLabel].
+    ///
+    /// # Arguments
+    ///
+    /// * `bundle`: The [Bundle] of [bevy::prelude::Component]s to spawn the `entity` with.
+    ///
+    /// returns: [EntityCommands] - The commands of the spawned `entity`, e.g., to insert further components.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn spawn_in_game_state<'a>(&'a mut self, bundle: impl Bundle) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> GameStateCommands<'w, 's> for Commands<'w, 's> {
+    fn spawn_in_game_state<'a>(&'a mut self, bundle: impl Bundle) -> EntityCommands<'w, 's, 'a> {
+        let mut entity_commands = self.spawn(bundle);
+        entity_commands.insert(GameStateLabel);
+        entity_commands
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Commands, Entity};
+
+use crate::ascii_sprite;
+use crate::components::carryable::Carryable;
+use crate::components::consumable::Consumable;
+use crate::components::coord_2d::Coord2d;
+use crate::components::faction::Faction;
+use crate::components::item::Item;
+use crate::components::name_tag::NameTag;
+use crate::core::constants;
+use crate::core::position_2d::Position2d;
+use crate::entities::game_state_commands::GameStateCommands;
+use crate::res::palette::Palette;
+
+/// Factory defining the markup of item entities lying on the floor of the map and the handling of their creation
+/// logic.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub struct ItemFactory;
+
+impl ItemFactory {
+    /// Creates and spawns a new scroll of mapping [Entity] at the passed `position`. Using the scroll marks the
+    /// whole map as seen, see [Consumable::Mapping].
+    ///
+    /// # Arguments
+    ///
+    /// * `commands`: [Commands] queue required to spawn the item entity.
+    /// * `position`: The position of the item in the game world.
+    /// * `palette`: The [Palette] providing the color of the item.
+    ///
+    /// returns: [Entity]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// fn spawner_system(mut commands: Commands, palette: Res<Palette>) {
+    ///     ItemFactory::spawn_scroll_of_mapping(&mut commands, &[40, 25], &palette);
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn spawn_scroll_of_mapping(
+        commands: &mut Commands,
+        position: &impl Position2d,
+        palette: &Palette,
+    ) -> Entity {
+        commands
+            .spawn_in_game_state((
+                Coord2d::from_position(position),
+                ascii_sprite!('?', palette.faction_color(Faction::Item)),
+                NameTag::new("Scroll of Mapping"),
+                Faction::Item,
+                Item,
+                Carryable,
+                Consumable::Mapping,
+            ))
+            .id()
+    }
+
+    /// Creates and spawns a new health potion [Entity] at the passed `position`, which the player can pick up
+    /// and carry. Drinking it restores [constants::HEALTH_POTION_HEALING] hit points.
+    ///
+    /// # Arguments
+    ///
+    /// * `commands`: [Commands] queue required to spawn the item entity.
+    /// * `position`: The position of the item in the game world.
+    /// * `palette`: The [Palette] providing the color of the item.
+    ///
+    /// returns: [Entity]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// fn spawner_system(mut commands: Commands, palette: Res<Palette>) {
+    ///     ItemFactory::spawn_health_potion(&mut commands, &[40, 25], &palette);
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn spawn_health_potion(
+        commands: &mut Commands,
+        position: &impl Position2d,
+        palette: &Palette,
+    ) -> Entity {
+        commands
+            .spawn_in_game_state((
+                Coord2d::from_position(position),
+                ascii_sprite!('!', palette.faction_color(Faction::Item)),
+                NameTag::new("Health Potion"),
+                Faction::Item,
+                Item,
+                Carryable,
+                Consumable::Healing(constants::HEALTH_POTION_HEALING),
+            ))
+            .id()
+    }
+}
+
+impl Debug for ItemFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Entities -> ItemFactory")
+    }
+}
+
+impl Display for ItemFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ItemFactory")
+    }
+}
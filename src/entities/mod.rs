@@ -28,6 +28,8 @@
 //! Since: `0.1.5`
 //!
 
+pub mod game_state_commands;
+pub mod item_factory;
 pub mod monster_factory;
 pub mod player_factory;
 pub mod terminal_factory;
@@ -20,18 +20,29 @@
  */
 
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
-use bevy::prelude::{Color, Commands, Entity};
+use bevy::prelude::{Commands, Entity};
 
 use crate::ascii_sprite;
+use crate::components::animated_sprite::AnimatedSprite;
 use crate::components::collision::Collision;
+use crate::components::combat_stats::CombatStats;
 use crate::components::coord_2d::Coord2d;
 use crate::components::enemy_type::EnemyType;
+use crate::components::faction::Faction;
 use crate::components::fov::Fov;
+use crate::components::health::Health;
+use crate::components::leash::Leash;
 use crate::components::name_tag::NameTag;
 use crate::components::npc_state::NpcState;
-use crate::components::state_label::GameStateLabel;
+use crate::components::speed::Speed;
+use crate::core::constants;
+use crate::core::monster_stats::MonsterStats;
 use crate::core::position_2d::Position2d;
+use crate::entities::game_state_commands::GameStateCommands;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::palette::Palette;
 
 /// Factory defining the markup of enemy entities and the handling of their creation logic.
 ///
@@ -44,18 +55,83 @@ use crate::core::position_2d::Position2d;
 pub struct MonsterFactory;
 
 impl MonsterFactory {
-    pub fn spawn_mended(commands: &mut Commands, position: &impl Position2d) -> Entity {
+    /// Creates and spawns a new monster [Entity] of the passed `enemy_type` at the passed `position`.
+    ///
+    /// The monster's stats are taken from the [MonsterStats] table, with its maximum hit points and attack power
+    /// scaled by the difficulty of the passed `gameplay_config`, which may also override the radius of its [Fov].
+    /// Its color is taken from the [Faction::Hostile] color of the passed `palette`. The monster is tied to its
+    /// spawn position by a [Leash] and shimmers between the lower and upper case of its glyph through an
+    /// [AnimatedSprite].
+    ///
+    /// # Arguments
+    ///
+    /// * `commands`: [Commands] queue required to spawn the monster entity.
+    /// * `enemy_type`: The [EnemyType] of the monster, determining its name, glyph and stats.
+    /// * `position`: The position of the monster in the game world.
+    /// * `gameplay_config`: The [GameplayConfig] providing the difficulty to apply and the optional [Fov] radius.
+    /// * `palette`: The [Palette] providing the color of the monster.
+    ///
+    /// returns: [Entity]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// fn spawner_system(
+    ///     mut commands: Commands,
+    ///     gameplay_config: Res<GameplayConfig>,
+    ///     palette: Res<Palette>,
+    /// ) {
+    ///     MonsterFactory::spawn(
+    ///         &mut commands,
+    ///         EnemyType::Mended,
+    ///         &[40, 25],
+    ///         &gameplay_config,
+    ///         &palette,
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.9`
+    ///
+    pub fn spawn(
+        commands: &mut Commands,
+        enemy_type: EnemyType,
+        position: &impl Position2d,
+        gameplay_config: &GameplayConfig,
+        palette: &Palette,
+    ) -> Entity {
+        let stats = MonsterStats::of(&enemy_type);
+
         commands
-            .spawn((
+            .spawn_in_game_state((
                 Coord2d::from_position(position),
-                ascii_sprite!('m', Color::YELLOW),
-                Fov::new(8),
-                NameTag::new("Mended"),
-                EnemyType::Mended,
+                ascii_sprite!(stats.glyph, palette.faction_color(Faction::Hostile)),
+                AnimatedSprite::new(
+                    vec![stats.glyph, stats.glyph.to_ascii_uppercase()],
+                    Duration::from_millis(constants::MONSTER_SHIMMER_FRAME_MS),
+                ),
+                Fov::new(
+                    gameplay_config
+                        .monster_fov_radius
+                        .unwrap_or(stats.fov_radius),
+                ),
+                NameTag::new(stats.name),
+                enemy_type,
+                Faction::Hostile,
                 NpcState::default(),
+                Leash::new(position),
+                Speed::new(stats.speed),
                 Collision,
+                Health::new(gameplay_config.scale_by_difficulty(stats.max_health)),
+                CombatStats::new(
+                    gameplay_config.scale_by_difficulty(stats.power),
+                    stats.defense,
+                ),
             ))
-            .insert(GameStateLabel)
             .id()
     }
 }
@@ -71,3 +147,148 @@ impl Display for MonsterFactory {
         write!(f, "MonsterFactory")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+    use bevy::prelude::{Color, World};
+
+    use crate::components::ascii_sprite::AsciiSprite;
+    use crate::ui::colors;
+
+    use super::*;
+
+    fn spawn_with_palette(palette: &Palette) -> AsciiSprite {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            MonsterFactory::spawn(
+                &mut commands,
+                EnemyType::Mended,
+                &[1, 1],
+                &GameplayConfig::default(),
+                palette,
+            )
+        };
+
+        queue.apply(&mut world);
+
+        assert_eq!(Some(&Faction::Hostile), world.get::<Faction>(entity));
+
+        *world.get::<AsciiSprite>(entity).unwrap()
+    }
+
+    fn spawn_with_difficulty(difficulty: f32) -> (Health, CombatStats) {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            MonsterFactory::spawn(
+                &mut commands,
+                EnemyType::Mended,
+                &[1, 1],
+                &GameplayConfig {
+                    difficulty,
+                    ..Default::default()
+                },
+                &Palette::default(),
+            )
+        };
+
+        queue.apply(&mut world);
+
+        (
+            *world.get::<Health>(entity).unwrap(),
+            *world.get::<CombatStats>(entity).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_difficulty_scales_monster_stats() {
+        let (normal_health, normal_stats) = spawn_with_difficulty(1.0);
+        let (hard_health, hard_stats) = spawn_with_difficulty(2.0);
+
+        assert_eq!(normal_health.max * 2, hard_health.max);
+        assert_eq!(hard_health.max, hard_health.current);
+        assert_eq!(normal_stats.power * 2, hard_stats.power);
+        assert_eq!(normal_stats.defense, hard_stats.defense);
+    }
+
+    #[test]
+    fn test_monster_uses_hostile_palette_color() {
+        let default_sprite = spawn_with_palette(&Palette::default());
+
+        assert_eq!(colors::FACTION_HOSTILE, default_sprite.foreground_color);
+
+        let mut palette = Palette::default();
+        palette.factions.insert(Faction::Hostile, Color::CYAN);
+
+        assert_eq!(Color::CYAN, spawn_with_palette(&palette).foreground_color);
+    }
+
+    #[test]
+    fn test_fov_radius_from_config() {
+        let spawn_fov_radius = |monster_fov_radius: Option<i32>| {
+            let mut world = World::new();
+            let mut queue = CommandQueue::default();
+
+            let entity = {
+                let mut commands = Commands::new(&mut queue, &world);
+                MonsterFactory::spawn(
+                    &mut commands,
+                    EnemyType::Mended,
+                    &[1, 1],
+                    &GameplayConfig {
+                        monster_fov_radius,
+                        ..Default::default()
+                    },
+                    &Palette::default(),
+                )
+            };
+
+            queue.apply(&mut world);
+
+            world.get::<Fov>(entity).unwrap().radius
+        };
+
+        assert_eq!(
+            MonsterStats::of(&EnemyType::Mended).fov_radius,
+            spawn_fov_radius(None)
+        );
+        assert_eq!(3, spawn_fov_radius(Some(3)));
+    }
+
+    #[test]
+    fn test_monster_takes_name_and_glyph_from_enemy_type() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            MonsterFactory::spawn(
+                &mut commands,
+                EnemyType::Ghoul,
+                &[1, 1],
+                &GameplayConfig::default(),
+                &Palette::default(),
+            )
+        };
+
+        queue.apply(&mut world);
+
+        assert_eq!(Some(&EnemyType::Ghoul), world.get::<EnemyType>(entity));
+        assert_eq!("Ghoul", world.get::<NameTag>(entity).unwrap().text);
+        assert_eq!('g', world.get::<AsciiSprite>(entity).unwrap().glyph);
+
+        let mut animated_sprite = world.get::<AnimatedSprite>(entity).unwrap().clone();
+
+        assert_eq!('g', animated_sprite.glyph());
+
+        animated_sprite.tick(Duration::from_millis(constants::MONSTER_SHIMMER_FRAME_MS));
+
+        assert_eq!('G', animated_sprite.glyph());
+    }
+}
@@ -21,14 +21,23 @@
 
 use std::fmt::{Debug, Display, Formatter};
 
-use bevy::prelude::{Color, Commands, Entity};
+use bevy::prelude::{Commands, Entity};
 
 use crate::ascii_sprite;
+use crate::components::combat_stats::CombatStats;
 use crate::components::coord_2d::Coord2d;
+use crate::components::facing::Facing;
+use crate::components::faction::Faction;
 use crate::components::fov::Fov;
+use crate::components::health::Health;
+use crate::components::inventory::Inventory;
 use crate::components::player::Player;
-use crate::components::state_label::GameStateLabel;
+use crate::components::spyglass::Spyglass;
+use crate::core::constants;
 use crate::core::position_2d::Position2d;
+use crate::entities::game_state_commands::GameStateCommands;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::palette::Palette;
 use crate::ui::colors;
 
 /// Factory defining the markup of the `player` `entity` and handling its creation logic.
@@ -42,20 +51,27 @@ use crate::ui::colors;
 pub struct PlayerFactory;
 
 impl PlayerFactory {
-    /// Creates and spawns a new player [Entity].
+    /// Creates and spawns a new player [Entity], colored in the [Faction::Player] color of the passed `palette`.
+    /// The radius of its [Fov] is taken from the passed `gameplay_config`.
     ///
     /// # Arguments
     ///
     /// * `commands`: [Commands] queue required to spawn the player entity.
     /// * `starting_position`: The position of the `player entity` in the game world.
+    /// * `gameplay_config`: The [GameplayConfig] providing the radius of the player's [Fov].
+    /// * `palette`: The [Palette] providing the color of the `player entity`.
     ///
     /// returns: [Entity]
     ///
     /// # Examples
     ///
     /// ```
-    /// fn spawner_system(mut commands: Commands) {
-    ///     PlayerBundle::spawn(&mut commands, [40, 25]);
+    /// fn spawner_system(
+    ///     mut commands: Commands,
+    ///     gameplay_config: Res<GameplayConfig>,
+    ///     palette: Res<Palette>,
+    /// ) {
+    ///     PlayerFactory::spawn(&mut commands, &[40, 25], &gameplay_config, &palette);
     /// }
     /// ```
     ///
@@ -65,14 +81,31 @@ impl PlayerFactory {
     ///
     /// Since: `0.1.5`
     ///
-    pub fn spawn(commands: &mut Commands, starting_position: &impl Position2d) -> Entity {
+    pub fn spawn(
+        commands: &mut Commands,
+        starting_position: &impl Position2d,
+        gameplay_config: &GameplayConfig,
+        palette: &Palette,
+    ) -> Entity {
         commands
-            .spawn((
+            .spawn_in_game_state((
                 Coord2d::from_position(starting_position),
-                ascii_sprite!('@', Color::ORANGE, colors::BACKGROUND),
-                Fov::new(8),
+                ascii_sprite!(
+                    '@',
+                    palette.faction_color(Faction::Player),
+                    colors::BACKGROUND
+                ),
+                Fov::new(gameplay_config.player_fov_radius),
+                Facing::default(),
+                Health::new(constants::PLAYER_MAX_HEALTH),
+                CombatStats::new(constants::PLAYER_POWER, constants::PLAYER_DEFENSE),
+                Inventory::new(constants::PLAYER_INVENTORY_CAPACITY),
+                Spyglass::new(
+                    constants::SPYGLASS_RADIUS_BONUS,
+                    constants::SPYGLASS_DURATION,
+                ),
             ))
-            .insert((Player, GameStateLabel))
+            .insert((Player, Faction::Player))
             .id()
     }
 }
@@ -88,3 +121,34 @@ impl Display for PlayerFactory {
         write!(f, "PlayerFactory")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+    use bevy::prelude::World;
+
+    use super::*;
+
+    #[test]
+    fn test_fov_radius_from_config() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            PlayerFactory::spawn(
+                &mut commands,
+                &[1, 1],
+                &GameplayConfig {
+                    player_fov_radius: 12,
+                    ..Default::default()
+                },
+                &Palette::default(),
+            )
+        };
+
+        queue.apply(&mut world);
+
+        assert_eq!(12, world.get::<Fov>(entity).unwrap().radius);
+    }
+}
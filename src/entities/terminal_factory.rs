@@ -20,7 +20,9 @@
  */
 
 use bevy::prelude::Commands;
-use bevy_ascii_terminal::{Terminal, TerminalBundle, TerminalFont, TileScaling, TiledCameraBundle};
+use bevy_ascii_terminal::{
+    Terminal, TerminalBundle, TerminalFont, TileScaling, TiledCameraBundle, ToWorld,
+};
 use std::fmt::{Debug, Display, Formatter};
 
 use crate::components::game_terminal::GameTerminal;
@@ -45,6 +47,7 @@ impl TerminalFactory {
     /// Sets up and creates the tile based UI components for the game, this includes
     /// * The required [Terminal]s
     /// * The corresponding [TerminalBundle]
+    /// * The [ToWorld] conversion between screen and terminal positions
     /// * The camera of the game through a [TiledCameraBundle]
     ///
     /// # Arguments
@@ -70,7 +73,7 @@ impl TerminalFactory {
                     .with_tile_scaling(TileScaling::World)
                     .with_font(font),
             )
-            .insert(GameTerminal);
+            .insert((GameTerminal, ToWorld::default()));
 
         commands.spawn(TiledCameraBundle::new().with_tile_count(tile_count));
     }
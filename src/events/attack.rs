@@ -0,0 +1,95 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Entity, Event};
+
+/// [Event] signaling that an `entity` attacks another one, e.g., the `player` bumping into a monster.
+///
+/// Systems visualizing or resolving the attack, like the animation of the hit, listen for it.
+///
+/// # Properties
+///
+/// * `attacker`: The attacking `entity`.
+/// * `target`: The attacked `entity`.
+///
+/// # Examples
+///
+/// ```
+/// fn bump_system(mut attack_writer: EventWriter<Attack>, ...) {
+///     attack_writer.send(Attack::new(player, monster));
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Event)]
+pub struct Attack {
+    /// The attacking `entity`.
+    pub attacker: Entity,
+    /// The attacked `entity`.
+    pub target: Entity,
+}
+
+impl Attack {
+    /// Creates a new [Attack] of the `attacker` on the `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attacker`: The attacking `entity`.
+    /// * `target`: The attacked `entity`.
+    ///
+    /// returns: [Attack]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(attacker: Entity, target: Entity) -> Self {
+        Self { attacker, target }
+    }
+}
+
+impl Debug for Attack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Events -> Attack {{ \
+        attacker: {:?}, \
+        target: {:?} \
+        }}",
+            self.attacker, self.target
+        )
+    }
+}
+
+impl Display for Attack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?})", self.attacker, self.target)
+    }
+}
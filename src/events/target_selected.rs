@@ -0,0 +1,87 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Event;
+
+/// [Event] signaling that the `player` confirmed a target in the
+/// [crate::res::targeting_mode::TargetingMode], e.g., to resolve a ranged attack on it.
+///
+/// # Properties
+///
+/// * `target`: The targeted position on the map.
+///
+/// # Examples
+///
+/// ```
+/// fn targeting_system(mut target_writer: EventWriter<TargetSelected>, ...) {
+///     target_writer.send(TargetSelected::new([5, 7]));
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Event)]
+pub struct TargetSelected {
+    /// The targeted position on the map.
+    pub target: [i32; 2],
+}
+
+impl TargetSelected {
+    /// Creates a new [TargetSelected] event for the passed `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The targeted position on the map.
+    ///
+    /// returns: [TargetSelected]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(target: [i32; 2]) -> Self {
+        Self { target }
+    }
+}
+
+impl Debug for TargetSelected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Events -> TargetSelected {{ target: {:?} }}",
+            self.target
+        )
+    }
+}
+
+impl Display for TargetSelected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?})", self.target)
+    }
+}
@@ -31,6 +31,7 @@ use crate::plugins::bootstrap_plugin::BootstrapPlugin;
 mod components;
 mod core;
 mod entities;
+mod events;
 mod js;
 mod os;
 mod plugins;
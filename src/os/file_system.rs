@@ -110,3 +110,154 @@ pub fn load_file(path: &str) -> String {
         None => panic!("Unable to load file at: {}!", path),
     }
 }
+
+/// Synchronously reads the contents of the file at the passed `path` like [load_file], but returns [None]
+/// instead of panicking, if the file doesn't exist or can't be read, e.g., for optional files like a save.
+///
+/// # Arguments
+///
+/// * `path`: The path to the file.
+///
+/// returns: [Option]<[String]> - The `UTF-8` encoded content of the file, if it could be read.
+///
+/// # Examples
+///
+/// ```
+/// if let Some(json) = file_system::read_file("save.json") {
+///     // Restore the save
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[cfg(not(target_family = "wasm"))]
+pub fn read_file(path: &str) -> Option<String> {
+    bevy::log::debug!("Reading file at: {}", path);
+
+    std::fs::read_to_string(path)
+        .map_err(|error| bevy::log::warn!("Unable to read file at {}: {}", path, error))
+        .ok()
+}
+
+#[cfg(target_family = "wasm")]
+/// Synchronously reads the contents of the local storage with the passed `path` as the key like [load_file],
+/// but returns [None] instead of panicking, if no value is stored for it.
+///
+/// # Arguments
+///
+/// * `path`: The path to use as the key to read the local storage.
+///
+/// returns: [Option]<[String]> - The `UTF-8` encoded value, if one is stored.
+///
+/// # Examples
+///
+/// ```
+/// if let Some(json) = file_system::read_file("save.json") {
+///     // Restore the save
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn read_file(path: &str) -> Option<String> {
+    local_storage::read_local_storage(path)
+}
+
+/// Synchronously writes the passed `contents` to the file at the passed `path`, replacing the file if it
+/// already exists. Missing parent directories are created along the way.
+///
+/// # Arguments
+///
+/// * `path`: The path to the file.
+/// * `contents`: The `UTF-8` encoded contents to write.
+///
+/// returns: bool `true` if the file was written successfully and `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let json = serde_json::to_string(&SaveGame::default()).unwrap();
+///
+/// assert!(file_system::write_file("save.json", &json));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[cfg(not(target_family = "wasm"))]
+pub fn write_file(path: &str, contents: &str) -> bool {
+    bevy::log::debug!("Writing file at: {}", path);
+
+    let parent_directory = std::path::Path::new(path)
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty());
+
+    parent_directory
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(path, contents))
+        .map_err(|error| bevy::log::error!("Unable to write file at {}: {}", path, error))
+        .is_ok()
+}
+
+#[cfg(target_family = "wasm")]
+/// Stores the passed `contents` in the local storage with the passed `path` as the key, replacing any value
+/// stored for it before.
+///
+/// # Arguments
+///
+/// * `path`: The path to use as the key to write the local storage.
+/// * `contents`: The `UTF-8` encoded contents to store.
+///
+/// returns: bool `true` if the value was stored successfully and `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let json = serde_json::to_string(&SaveGame::default()).unwrap();
+///
+/// assert!(file_system::write_file("save.json", &json));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn write_file(path: &str, contents: &str) -> bool {
+    local_storage::write_local_storage(path, contents)
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_file() {
+        let directory = std::env::temp_dir().join(format!("b_rouge_{}", std::process::id()));
+        let path = directory.join("saves").join("save.json");
+        let path = path.to_str().unwrap();
+
+        assert!(write_file(path, "{\"version\": 1}"));
+        assert_eq!("{\"version\": 1}", load_file(path));
+        assert_eq!(Some(String::from("{\"version\": 1}")), read_file(path));
+
+        assert!(write_file(path, "{}"));
+        assert_eq!("{}", load_file(path));
+
+        std::fs::remove_dir_all(directory).unwrap();
+
+        assert_eq!(None, read_file(path));
+    }
+}
@@ -19,18 +19,31 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
-use bevy::app::{App, Plugin, PluginGroup, PreStartup};
-use bevy::log::{Level, LogPlugin};
-use bevy::prelude::{ClearColor, Commands, Res};
+use bevy::app::{App, Plugin, PluginGroup, PreStartup, Update};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::log::{debug, Level, LogPlugin};
+use bevy::prelude::{
+    ClearColor, Commands, EventReader, IntoSystemConfigs, Query, Res, ResMut, State, Window, With,
+};
+use bevy::window::{PrimaryWindow, WindowResized};
 use bevy::DefaultPlugins;
-use bevy_ascii_terminal::{TerminalFont, TerminalPlugin};
+use bevy_ascii_terminal::{Terminal, TerminalFont, TerminalPlugin};
 
+use crate::components::game_terminal::GameTerminal;
 use crate::entities::terminal_factory::TerminalFactory;
+use crate::plugins::game_over_state_plugin::GameOverStatePlugin;
 use crate::plugins::game_state_plugin::GameStatePlugin;
+use crate::plugins::main_menu_state_plugin::MainMenuStatePlugin;
 use crate::plugins::plugin_provider::PluginProvider;
+use crate::plugins::settings_state_plugin::SettingsStatePlugin;
 use crate::plugins::states::AppState;
 use crate::res::config_file::ConfigFile;
-use crate::res::input_config::InputConfig;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::log_config::LogConfig;
+use crate::res::palette::Palette;
+use crate::res::terminal_layout::TerminalLayout;
 use crate::res::window_config;
 use crate::res::window_config::WindowConfig;
 use crate::ui::colors;
@@ -41,7 +54,7 @@ use crate::ui::colors;
 /// systems and ensures that they are operational and added to the created [App].
 ///
 /// After the bootstrapping process, it passes the control to the state [Plugin]
-/// corresponding to the default [AppState].
+/// corresponding to the initial [AppState], see [AppState::initial].
 ///
 /// # About
 ///
@@ -55,6 +68,9 @@ use crate::ui::colors;
 /// * [TerminalPlugin]
 /// * [WindowConfig]
 /// * [InputConfig]
+/// * [GameplayConfig]
+/// * [LogConfig]
+/// * [Palette]
 /// * [AppState]
 ///
 pub struct BootstrapPlugin;
@@ -82,9 +98,21 @@ impl Plugin for BootstrapPlugin {
         .insert_resource(ClearColor(colors::BACKGROUND))
         .insert_resource(window_config)
         .insert_resource(InputConfig::load())
+        .insert_resource(GameplayConfig::load())
+        .insert_resource(LogConfig::load())
+        .insert_resource(Palette::load())
         .add_systems(PreStartup, startup_system)
+        .add_systems(
+            Update,
+            (fullscreen_toggle_system, terminal_resize_system).chain(),
+        )
+        // Inserted before adding the state, so the state machine boots into it instead of the default.
+        .insert_resource(State::new(AppState::initial()))
         .add_state::<AppState>()
-        .add_plugins(GameStatePlugin);
+        .add_plugins(MainMenuStatePlugin)
+        .add_plugins(GameStatePlugin)
+        .add_plugins(SettingsStatePlugin)
+        .add_plugins(GameOverStatePlugin);
     }
 
     fn name(&self) -> &str {
@@ -97,7 +125,8 @@ impl Plugin for BootstrapPlugin {
 }
 
 /// Sets-up all of the game's required base components and bundles,
-/// e.g., the world, rendering and camera.
+/// e.g., the world, rendering and camera. The [TerminalLayout] is derived from the size of the spawned
+/// [GameTerminal] and kept in sync with it by the [terminal_resize_system].
 ///
 /// schedule: [PreStartup]
 ///
@@ -127,11 +156,277 @@ impl Plugin for BootstrapPlugin {
 /// * [bevy::ecs::system::Command]
 /// * [bevy::prelude::World]
 /// * [WindowConfig]
+/// * [TerminalLayout]
 ///
 fn startup_system(mut commands: Commands, window_config: Res<WindowConfig>) {
-    TerminalFactory::spawn(
-        &mut commands,
-        TerminalFont::ZxEvolution8x8,
-        &window_config.terminal_size(),
-    );
+    let terminal_size = window_config.terminal_size();
+
+    TerminalFactory::spawn(&mut commands, TerminalFont::ZxEvolution8x8, &terminal_size);
+    commands.insert_resource(TerminalLayout::new(&terminal_size));
+}
+
+/// System toggling the [PrimaryWindow] between windowed and borderless fullscreen mode once the
+/// [InputType::ToggleFullscreen] key is pressed. The new mode is stored in the [WindowConfig], so the
+/// preference is kept for the rest of the session. The toggle is available in every [AppState].
+///
+/// schedule: [Update]
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `window_config`: [WindowConfig] holding the preferred window mode.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+/// * `window_query`: [Query] to retrieve the [PrimaryWindow], whose mode is toggled.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [WindowConfig::window_mode]
+/// * [terminal_resize_system]
+///
+fn fullscreen_toggle_system(
+    input_config: Res<InputConfig>,
+    mut window_config: ResMut<WindowConfig>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released {
+            continue;
+        }
+
+        let is_toggle_input = event
+            .key_code
+            .and_then(|key_code| input_config.parse_input(key_code))
+            == Some(InputType::ToggleFullscreen);
+
+        if !is_toggle_input {
+            continue;
+        }
+
+        let Ok(mut window) = window_query.get_single_mut() else {
+            continue;
+        };
+
+        window_config.fullscreen = !window_config.fullscreen;
+        window.mode = window_config.window_mode();
+
+        debug!("Switched the window mode to {:?}.", window.mode);
+    }
+}
+
+/// System recomputing the size of the [GameTerminal] once the [PrimaryWindow] got resized, e.g., after
+/// toggling the fullscreen mode. The size is clamped to the window through [WindowConfig::clamped_terminal_size].
+///
+/// The actual size of the window is only known once it has been created, which is reported through a
+/// [WindowResized] event as well. The [GameTerminal], spawned with the configured size during the startup,
+/// is therefore clamped by this system, if the window turned out smaller than configured. The [TerminalLayout] is
+/// recomputed along with the terminal, so the panels always divide the actual terminal size.
+///
+/// schedule: [Update]
+///
+/// # Arguments
+///
+/// * `window_config`: [WindowConfig] required to calculate the terminal size.
+/// * `terminal_layout`: [TerminalLayout] to divide the resized terminal.
+/// * `resize_events`: [EventReader] stream of [WindowResized] events.
+/// * `window_query`: [Query] to retrieve the [PrimaryWindow], whose size the terminal is clamped to.
+/// * `terminal_query`: [Query] to retrieve the [Terminal] of the [GameTerminal].
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [WindowConfig] or [TerminalLayout] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [startup_system]
+/// * [fullscreen_toggle_system]
+///
+fn terminal_resize_system(
+    window_config: Res<WindowConfig>,
+    mut terminal_layout: ResMut<TerminalLayout>,
+    mut resize_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+) {
+    if resize_events.is_empty() {
+        return;
+    }
+
+    resize_events.clear();
+
+    let (Ok(window), Ok(mut terminal)) =
+        (window_query.get_single(), terminal_query.get_single_mut())
+    else {
+        return;
+    };
+
+    let terminal_size =
+        window_config.clamped_terminal_size(&[window.width() as i32, window.height() as i32]);
+
+    if [terminal.width() as i32, terminal.height() as i32] != terminal_size {
+        terminal.resize(terminal_size);
+        *terminal_layout = TerminalLayout::new(&terminal_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{Component, KeyCode};
+    use bevy::window::{WindowMode, WindowResolution};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<WindowResized>();
+        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.add_systems(
+            Update,
+            (fullscreen_toggle_system, terminal_resize_system).chain(),
+        );
+
+        app
+    }
+
+    fn press_key(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    #[test]
+    fn test_fullscreen_toggle_flips_window_mode() {
+        let mut app = setup_app();
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+
+        app.update();
+
+        assert_eq!(
+            WindowMode::Windowed,
+            app.world.get::<Window>(window).unwrap().mode
+        );
+
+        press_key(&mut app, KeyCode::F11);
+
+        assert_eq!(
+            WindowMode::BorderlessFullscreen,
+            app.world.get::<Window>(window).unwrap().mode
+        );
+        assert!(app.world.resource::<WindowConfig>().fullscreen);
+
+        press_key(&mut app, KeyCode::F11);
+
+        assert_eq!(
+            WindowMode::Windowed,
+            app.world.get::<Window>(window).unwrap().mode
+        );
+        assert!(!app.world.resource::<WindowConfig>().fullscreen);
+    }
+
+    #[test]
+    fn test_terminal_is_resized_with_window() {
+        let mut app = setup_app();
+        let window = app
+            .world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(400.0, 320.0),
+                    ..Default::default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        let terminal = app
+            .world
+            .spawn((Terminal::new([100, 80]), GameTerminal))
+            .id();
+
+        app.world.send_event(WindowResized {
+            window,
+            width: 400.0,
+            height: 320.0,
+        });
+        app.update();
+
+        let terminal = app.world.get::<Terminal>(terminal).unwrap();
+
+        assert_eq!([50, 40], [terminal.width(), terminal.height()]);
+        assert_eq!(
+            TerminalLayout::new(&[50, 40]),
+            *app.world.resource::<TerminalLayout>()
+        );
+    }
+
+    #[test]
+    fn test_terminal_is_clamped_to_window_smaller_than_configured() {
+        let mut app = setup_app();
+        let window = app
+            .world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(400.0, 320.0),
+                    ..Default::default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+
+        app.add_systems(PreStartup, startup_system);
+        app.update();
+
+        let mut terminal_query = app.world.query_filtered::<&Terminal, With<GameTerminal>>();
+        let terminal = terminal_query.single(&app.world);
+
+        // The window has not reported its actual size yet.
+        assert_eq!([100, 80], [terminal.width(), terminal.height()]);
+        assert_eq!(
+            TerminalLayout::new(&[100, 80]),
+            *app.world.resource::<TerminalLayout>()
+        );
+
+        app.world.send_event(WindowResized {
+            window,
+            width: 400.0,
+            height: 320.0,
+        });
+        app.update();
+
+        let terminal = terminal_query.single(&app.world);
+
+        assert_eq!([50, 40], [terminal.width(), terminal.height()]);
+    }
 }
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::app::{App, Plugin};
+use bevy::prelude::{in_state, IntoSystemConfigs, OnExit, Update};
+
+use crate::plugins::game_over_state_systems::screen;
+use crate::plugins::states::AppState;
+
+/// Plugin coupled with the [AppState::GameOver] state, which is entered once the `player` died. It shows the
+/// [crate::res::run_summary::RunSummary] of the finished run and returns to the [AppState::MainMenu] from where a
+/// new game can be started.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [Plugin]
+/// * [AppState::GameOver]
+///
+pub struct GameOverStatePlugin;
+
+impl Plugin for GameOverStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (screen::game_over_input_system, screen::render_system)
+                .chain()
+                .run_if(in_state(AppState::GameOver)),
+        )
+        .add_systems(OnExit(AppState::GameOver), screen::shutdown_system);
+    }
+
+    fn name(&self) -> &str {
+        "ECS -> Plugins -> GameOverState"
+    }
+
+    fn is_unique(&self) -> bool {
+        true
+    }
+}
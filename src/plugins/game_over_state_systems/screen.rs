@@ -0,0 +1,193 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{Commands, EventReader, NextState, Query, Res, ResMut, With};
+use bevy_ascii_terminal::{StringFormatter, Terminal};
+
+use crate::components::game_terminal::GameTerminal;
+use crate::plugins::states::AppState;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::run_summary::RunSummary;
+use crate::ui::colors;
+
+/// System handling the input of the game over screen. [InputType::Confirm] and [InputType::Cancel] return to
+/// the [AppState::MainMenu], from where a new game can be started.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `next_state`: [NextState] required to return to the [AppState::MainMenu].
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn game_over_input_system(
+    input_config: Res<InputConfig>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut key_events: EventReader<KeyboardInput>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released {
+            continue;
+        }
+
+        let input = event
+            .key_code
+            .and_then(|key_code| input_config.parse_input(key_code));
+
+        if matches!(input, Some(InputType::Confirm | InputType::Cancel)) {
+            next_state.set(AppState::MainMenu);
+        }
+    }
+}
+
+/// Renders the game over screen onto the [Terminal], showing the [RunSummary] of the finished run centered
+/// above a prompt to return to the main menu.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the screen.
+/// * `input_config`: The [InputConfig] providing the key shown in the prompt.
+/// * `run_summary`: The [RunSummary] of the finished run.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    input_config: Res<InputConfig>,
+    run_summary: Res<RunSummary>,
+) {
+    let mut terminal = terminal_query
+        .get_single_mut()
+        .expect("ECS -> Systems -> render_system -> Unable to retrieve {Terminal} component!");
+
+    terminal.clear();
+
+    let center = [terminal.width() as i32 / 2, terminal.height() as i32 / 2];
+    let lines = [
+        String::from("You died."),
+        format!(
+            "You reached depth {} after {} turns.",
+            run_summary.depth(),
+            run_summary.turns()
+        ),
+        format!(
+            "Press {:?} to return to the main menu.",
+            input_config.confirm
+        ),
+    ];
+
+    for (index, line) in lines.iter().enumerate() {
+        let position = [
+            center[0] - line.len() as i32 / 2,
+            center[1] + 1 - index as i32,
+        ];
+
+        if index == 0 {
+            terminal.put_string(position, line.as_str().fg(colors::MENU_SELECTION));
+        } else {
+            terminal.put_string(position, line.as_str());
+        }
+    }
+}
+
+/// Shutdown system of the [AppState::GameOver], which removes the [RunSummary] of the finished run.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the [RunSummary].
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn shutdown_system(mut commands: Commands) {
+    commands.remove_resource::<RunSummary>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode, OnExit, State};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    #[test]
+    fn test_confirm_returns_to_main_menu() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(State::new(AppState::GameOver));
+        app.add_state::<AppState>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(RunSummary::new(2, 40));
+        app.add_systems(Update, game_over_input_system);
+        app.add_systems(OnExit(AppState::GameOver), shutdown_system);
+
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::Return),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            AppState::MainMenu,
+            *app.world.resource::<State<AppState>>().get()
+        );
+        assert!(app.world.get_resource::<RunSummary>().is_none());
+    }
+}
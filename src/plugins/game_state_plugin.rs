@@ -20,10 +20,18 @@
  */
 
 use bevy::app::{App, Plugin, PostUpdate};
-use bevy::prelude::{in_state, IntoSystemConfigs, OnEnter, OnExit, Update};
+use bevy::prelude::{
+    in_state, resource_equals, Condition, IntoSystemConfigs, OnEnter, OnExit, Update,
+};
 
-use crate::plugins::game_state_systems::{enemy_ai, fov, graphics, input, lifecycle};
-use crate::plugins::states::AppState;
+use crate::events::attack::Attack;
+use crate::events::map_changed::MapChanged;
+use crate::events::target_selected::TargetSelected;
+use crate::plugins::game_state_systems::{
+    action, animation, camera, combat, enemy_ai, examine, fov, graphics, hud, input, inventory,
+    lifecycle, log_view, spyglass, targeting,
+};
+use crate::plugins::states::{AppState, GameTurnState};
 
 /// Plugin coupled with the [AppState::Game] state, which makes up the main gameplay state.
 /// In it the user moves the `player entity`, fights or otherwise interacts with the game.
@@ -43,14 +51,68 @@ pub struct GameStatePlugin;
 
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Game), lifecycle::startup_system)
+        app.add_event::<MapChanged>()
+            .add_event::<Attack>()
+            .add_event::<TargetSelected>()
+            .add_systems(OnEnter(AppState::Game), lifecycle::startup_system)
             .add_systems(
                 Update,
                 (
-                    input::keyboard_input_system,
-                    fov::fov_system,
-                    graphics::render_system,
+                    lifecycle::turn_delay_system,
                     enemy_ai::enemy_line_of_sight_system,
+                    enemy_ai::enemy_chase_system,
+                    (
+                        input::keyboard_input_system,
+                        input::gamepad_input_system,
+                        input::mouse_move_system,
+                        input::travel_system,
+                        inventory::drop_item_system,
+                        inventory::pickup_item_system,
+                        inventory::use_item_system,
+                        inventory::auto_pickup_system,
+                        examine::examine_system,
+                        log_view::log_view_system,
+                        spyglass::spyglass_system,
+                        lifecycle::descend_system,
+                    )
+                        .chain()
+                        // Nested run conditions are evaluated even if the state condition below fails, so they
+                        // check the state first, as the resources they read only exist in the AppState::Game.
+                        .run_if(
+                            in_state(AppState::Game)
+                                .and_then(animation::animations_finished)
+                                .and_then(lifecycle::turn_delay_elapsed)
+                                .and_then(resource_equals(GameTurnState::Player)),
+                        ),
+                    targeting::targeting_system.run_if(
+                        in_state(AppState::Game)
+                            .and_then(animation::animations_finished)
+                            .and_then(resource_equals(GameTurnState::Player)),
+                    ),
+                    targeting::target_selected_system,
+                    action::action_system,
+                    combat::melee_system,
+                    animation::attack_animation_system,
+                    animation::damage_number_system,
+                    combat::death_system,
+                    spyglass::spyglass_expiry_system,
+                    fov::fov_cache_invalidation_system,
+                    fov::fov_system,
+                    animation::sprite_animation_system,
+                    camera::camera_system,
+                    hud::cursor_hud_system,
+                    (
+                        graphics::render_system,
+                        graphics::message_log_render_system,
+                        graphics::sidebar_render_system,
+                        graphics::examine_render_system,
+                        graphics::targeting_render_system,
+                        graphics::animation_render_system,
+                        graphics::cursor_hud_render_system,
+                        graphics::log_view_render_system,
+                    )
+                        .chain(),
+                    animation::animation_system,
                 )
                     .chain()
                     .run_if(in_state(AppState::Game)),
@@ -60,6 +122,19 @@ impl Plugin for GameStatePlugin {
                 lifecycle::npc_turn_end_system.run_if(in_state(AppState::Game)),
             )
             .add_systems(OnExit(AppState::Game), lifecycle::shutdown_system);
+
+        #[cfg(feature = "debug_tools")]
+        app.add_systems(
+            Update,
+            (
+                fov::fov_algorithm_toggle_system.before(fov::fov_cache_invalidation_system),
+                hud::debug_footer_toggle_system,
+                graphics::debug_footer_render_system
+                    .after(graphics::cursor_hud_render_system)
+                    .before(graphics::log_view_render_system),
+            )
+                .run_if(in_state(AppState::Game)),
+        );
     }
 
     fn name(&self) -> &str {
@@ -70,3 +145,386 @@ impl Plugin for GameStatePlugin {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::AppExit;
+    use bevy::ecs::system::CommandQueue;
+    use bevy::input::gamepad::GamepadButtonInput;
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::mouse::MouseButtonInput;
+    use bevy::input::ButtonState;
+    use bevy::prelude::{Commands, Entity, Events, KeyCode, State, Time, With};
+    use bevy_ascii_terminal::{Terminal, TerminalBundle};
+
+    use crate::components::coord_2d::Coord2d;
+    use crate::components::enemy_type::EnemyType;
+    use crate::components::fov::Fov;
+    use crate::components::game_terminal::GameTerminal;
+    use crate::components::health::Health;
+    use crate::components::player::Player;
+    use crate::components::state_label::GameStateLabel;
+    use crate::core::constants;
+    use crate::core::dimension_2d::Dimension2d;
+    use crate::core::position_2d::Position2d;
+    use crate::entities::monster_factory::MonsterFactory;
+    use crate::plugins::game_state_systems::lifecycle::RUN_RESOURCES;
+    use crate::plugins::states::GameTurnState;
+    use crate::res::dungeon_depth::DungeonDepth;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::input_config::InputConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::palette::Palette;
+    use crate::res::targeting_mode::TargetingMode;
+    use crate::res::terminal_layout::TerminalLayout;
+    use crate::ui::game_map::GameMap;
+    use crate::ui::rectangle::Rectangle;
+    use crate::ui::tile::{MapTile, MapTileType};
+    use crate::ui::tile_map::TileMap;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    /// Sets up the full [GameStatePlugin] and replaces the randomly generated level with a fixed room,
+    /// in which the `player` stands at `[5, 5]` without any monsters around.
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<GamepadButtonInput>();
+        app.add_event::<MouseButtonInput>();
+        app.add_event::<AppExit>();
+        app.insert_resource(State::new(AppState::Game));
+        app.add_state::<AppState>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        // Descending the stairs mustn't write a save into the working directory.
+        app.insert_resource(GameplayConfig {
+            autosave: false,
+            ..Default::default()
+        });
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(Palette::default());
+        app.init_resource::<Time>();
+        app.add_plugins(GameStatePlugin);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let mut generated_entities: Vec<Entity> = app
+            .world
+            .query_filtered::<Entity, With<GameMap>>()
+            .iter(&app.world)
+            .collect();
+        generated_entities.extend(
+            app.world
+                .query_filtered::<Entity, With<EnemyType>>()
+                .iter(&app.world),
+        );
+
+        for entity in generated_entities {
+            app.world.despawn(entity);
+        }
+
+        let mut game_map = GameMap::new(&[20, 12], &TestTileMapGenerator, None);
+        Rectangle::new([1, 1], [16, 8]).add_to_map(&mut game_map);
+        app.world.spawn((game_map, GameStateLabel));
+
+        let (mut fov, mut position) = app
+            .world
+            .query_filtered::<(&mut Fov, &mut Coord2d), With<Player>>()
+            .single_mut(&mut app.world);
+        *position = Coord2d::new(5, 5);
+        fov.is_dirty = true;
+
+        app.update();
+
+        app
+    }
+
+    /// (Private) Boots an [App] with only the [GameStatePlugin] into the [AppState] parsed from the passed `value`.
+    fn boot_app(value: Option<&str>) -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<GamepadButtonInput>();
+        app.add_event::<MouseButtonInput>();
+        app.add_event::<AppExit>();
+        app.insert_resource(State::new(AppState::initial_from(value)));
+        app.add_state::<AppState>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(Palette::default());
+        app.init_resource::<Time>();
+        app.add_plugins(GameStatePlugin);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        app
+    }
+
+    #[test]
+    fn test_initial_state_spawns_map() {
+        let mut app = boot_app(Some("Game"));
+
+        assert_eq!(
+            AppState::Game,
+            *app.world.resource::<State<AppState>>().get()
+        );
+        assert_eq!(1, app.world.query::<&GameMap>().iter(&app.world).count());
+        assert_eq!(
+            1,
+            app.world
+                .query_filtered::<Entity, With<Player>>()
+                .iter(&app.world)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_default_initial_state_does_not_spawn_map() {
+        let mut app = boot_app(None);
+
+        assert_eq!(
+            AppState::MainMenu,
+            *app.world.resource::<State<AppState>>().get()
+        );
+        assert_eq!(0, app.world.query::<&GameMap>().iter(&app.world).count());
+        assert_eq!(
+            0,
+            app.world
+                .query_filtered::<Entity, With<Player>>()
+                .iter(&app.world)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_leaving_game_state_removes_run_resources() {
+        let mut app = setup_app();
+
+        for run_resource in RUN_RESOURCES {
+            assert!((run_resource.is_present)(&app.world));
+        }
+
+        app.world.run_schedule(OnExit(AppState::Game));
+
+        for run_resource in RUN_RESOURCES {
+            assert!(
+                !(run_resource.is_present)(&app.world),
+                "{} leaked out of the game state",
+                (run_resource.name)()
+            );
+        }
+
+        assert_eq!(
+            0,
+            app.world
+                .query_filtered::<Entity, With<GameStateLabel>>()
+                .iter(&app.world)
+                .count()
+        );
+    }
+
+    fn seen_tile_count(app: &mut App) -> usize {
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+
+        (0..game_map.width)
+            .flat_map(|x| (0..game_map.height).map(move |y| [x, y]))
+            .filter(|position| game_map.is_tile_seen(position))
+            .count()
+    }
+
+    #[test]
+    fn test_full_turn_cycle() {
+        let mut app = setup_app();
+
+        let seen_before = seen_tile_count(&mut app);
+        let window = app.world.spawn_empty().id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::D),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let player_position = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        assert_eq!(Coord2d::new(6, 5), player_position);
+        assert!(seen_tile_count(&mut app) > seen_before);
+        assert!(app
+            .world
+            .query::<&GameMap>()
+            .single(&app.world)
+            .is_tile_visible(&[13, 5]));
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+        assert_eq!(
+            '@',
+            app.world
+                .query::<&Terminal>()
+                .single(&app.world)
+                .get_char(player_position.as_array())
+        );
+    }
+
+    /// (Private) Spawns a monster of the passed [EnemyType] at the passed `position` into the fixed room of the
+    /// [setup_app] and lets it compute its `field of view`.
+    fn spawn_monster(app: &mut App, enemy_type: EnemyType, position: [i32; 2]) -> Entity {
+        let mut queue = CommandQueue::default();
+
+        let monster = {
+            let mut commands = Commands::new(&mut queue, &app.world);
+            MonsterFactory::spawn(
+                &mut commands,
+                enemy_type,
+                &position,
+                app.world.resource::<GameplayConfig>(),
+                app.world.resource::<Palette>(),
+            )
+        };
+
+        queue.apply(&mut app.world);
+        app.update();
+
+        monster
+    }
+
+    #[test]
+    fn test_monster_acts_after_player() {
+        let mut app = setup_app();
+        let monster = spawn_monster(&mut app, EnemyType::Ghoul, [10, 5]);
+        let window = app.world.spawn_empty().id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::D),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            Coord2d::new(6, 5),
+            *app.world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world)
+        );
+        assert_eq!(Some(&Coord2d::new(9, 5)), app.world.get::<Coord2d>(monster));
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+    }
+
+    #[test]
+    fn test_cancelling_targeting_does_not_close_the_game() {
+        let mut app = setup_app();
+        let window = app.world.spawn_empty().id();
+
+        for key_code in [KeyCode::F, KeyCode::Escape] {
+            app.world.send_event(KeyboardInput {
+                scan_code: 0,
+                key_code: Some(key_code),
+                state: ButtonState::Pressed,
+                window,
+            });
+
+            app.update();
+
+            assert!(app.world.resource::<Events<AppExit>>().is_empty());
+        }
+
+        assert!(!app.world.resource::<TargetingMode>().is_active());
+
+        // The input systems must have consumed the cancel key as well, instead of handling it once aiming ended.
+        for _ in 0..3 {
+            app.update();
+
+            assert!(app.world.resource::<Events<AppExit>>().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_descending_stairs_generates_next_floor() {
+        let mut app = setup_app();
+
+        app.world
+            .query::<&mut GameMap>()
+            .single_mut(&mut app.world)
+            .set_tile_at(&[5, 5], MapTile::stairs_down());
+
+        let (player, health) = app
+            .world
+            .query_filtered::<(Entity, &Health), With<Player>>()
+            .single(&app.world);
+        let health = *health;
+        let window = app.world.spawn_empty().id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::E),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(2, app.world.resource::<DungeonDepth>().current());
+
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+        let map_size = game_map.as_array();
+        let start_position = game_map.start_position();
+        let has_stairs = game_map
+            .walkable_positions()
+            .iter()
+            .any(|position| game_map.get_tile_at(position).kind == MapTileType::DownStairs);
+
+        assert_eq!(
+            [
+                100 - constants::SIDEBAR_WIDTH,
+                80 - constants::MESSAGE_LOG_HEIGHT
+            ],
+            map_size
+        );
+        assert!(has_stairs);
+        assert_eq!(
+            MapTileType::UpStairs,
+            game_map.get_tile_at(&start_position).kind
+        );
+
+        let (descended_player, position, descended_health) = app
+            .world
+            .query_filtered::<(Entity, &Coord2d, &Health), With<Player>>()
+            .single(&app.world);
+
+        assert_eq!(player, descended_player);
+        assert_eq!(start_position, position.as_array());
+        assert_eq!(health, *descended_health);
+        assert!(app.world.query::<&EnemyType>().iter(&app.world).count() > 0);
+    }
+}
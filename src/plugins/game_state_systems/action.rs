@@ -0,0 +1,417 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::log::debug;
+use bevy::prelude::{Entity, EventWriter, Has, Or, ParamSet, Query, ResMut, With};
+
+use crate::components::collision::Collision;
+use crate::components::coord_2d::Coord2d;
+use crate::components::facing::Facing;
+use crate::components::fov::Fov;
+use crate::components::name_tag::NameTag;
+use crate::components::player::Player;
+use crate::core::action::Action;
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::spatial_query::SpatialQuery;
+use crate::events::attack::Attack;
+use crate::res::action_queue::ActionQueue;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::ui::game_map::GameMap;
+use crate::ui::tile_map::TileMap;
+
+/// System resolving all [Action]s queued in the [ActionQueue] against the game's world, in the order they were
+/// pushed. It's the single place in which the intents of the `player` and the `NPC entities` alter the world:
+///
+/// * [Action::Move] turns the actor's [Facing] towards the movement and moves the actor, unless the target
+///   position is out of bounds, has collision on the [GameMap] or is occupied by the `player` or another `entity`
+///   with [Collision]. A moved or turned actor's [Fov] is marked as dirty.
+/// * [Action::Attack] informs the `player` about the attack and sends the respective [Attack] event.
+/// * [Action::Wait] does nothing.
+///
+/// Actions of `entities`, which were removed since they were queued, are dropped.
+///
+/// # Arguments
+///
+/// * `action_queue`: The [ActionQueue] resource holding the [Action]s to resolve.
+/// * `message_log`: The [MessageLog] resource to inform the `player` about attacks.
+/// * `attack_writer`: [EventWriter] to send the [Attack] events.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], which is used to check for collision.
+/// * `actor_queries`: [ParamSet] of the [Query] to retrieve the positions, [Fov]s, names and [Facing]s of all
+///   acting `entities`, and the [SpatialQuery] to look up the `entities` blocking a movement.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [ActionQueue] or [MessageLog] resource can't be retrieved from the ECS.
+/// * If an [Action::Move] is resolved, but the [GameMap] can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn action_system(
+    mut action_queue: ResMut<ActionQueue>,
+    mut message_log: ResMut<MessageLog>,
+    mut attack_writer: EventWriter<Attack>,
+    game_map_query: Query<&GameMap>,
+    mut actor_queries: ParamSet<(
+        Query<ActorComponents>,
+        Query<BlockerComponents, BlockerFilter>,
+    )>,
+) {
+    if action_queue.is_empty() {
+        return;
+    }
+
+    for action in action_queue.drain() {
+        // The actor might have been removed since the action was queued.
+        if actor_queries.p0().get(action.actor()).is_err() {
+            continue;
+        }
+
+        match action {
+            Action::Move { actor, delta } => {
+                let map = game_map_query.get_single().expect(
+                    "ECS -> Systems -> action_system -> Unable to retrieve {GameMap} component!",
+                );
+
+                resolve_move(actor, delta, map, &mut actor_queries);
+            }
+            Action::Attack { attacker, target } => {
+                message_log.push(
+                    attack_message(attacker, target, &actor_queries.p0()),
+                    LogCategory::Combat,
+                );
+                attack_writer.send(Attack::new(attacker, target));
+            }
+            Action::Wait { actor } => {
+                debug!("ECS -> Systems -> action_system -> {:?} waits", actor);
+            }
+        }
+    }
+}
+
+/// The components of an `entity` queried by the [action_system].
+type ActorComponents<'a> = (
+    Entity,
+    &'a mut Coord2d,
+    Option<&'a mut Fov>,
+    Option<&'a NameTag>,
+    Option<&'a mut Facing>,
+    Has<Player>,
+);
+
+/// The components of an `entity` looked up by the [action_system] to check if it blocks a movement.
+type BlockerComponents<'a> = (Entity, &'a Coord2d);
+
+/// The filter of the `entities` blocking a movement, which are the `player` and those with [Collision].
+type BlockerFilter = Or<(With<Collision>, With<Player>)>;
+
+/// (Private) Turns the `actor` towards the passed `delta` and moves it, unless the target position is blocked.
+fn resolve_move(
+    actor: Entity,
+    delta: [i32; 2],
+    map: &GameMap,
+    actor_queries: &mut ParamSet<(
+        Query<ActorComponents>,
+        Query<BlockerComponents, BlockerFilter>,
+    )>,
+) {
+    let mut actor_query = actor_queries.p0();
+
+    if let Ok((_, _, fov, _, Some(mut facing), ..)) = actor_query.get_mut(actor) {
+        match Facing::from_delta(&delta) {
+            Some(new_facing) if new_facing != *facing => {
+                *facing = new_facing;
+
+                // A directional `field of view` changes with the facing, even if the movement is blocked.
+                if let Some(mut fov) = fov {
+                    fov.is_dirty = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Ok((_, position, ..)) = actor_query.get(actor) else {
+        return;
+    };
+
+    let target = Coord2d::new(position.x + delta[0], position.y + delta[1]);
+
+    let is_occupied = actor_queries
+        .p1()
+        .entities_at(&target)
+        .into_iter()
+        .any(|entity| entity != actor);
+
+    if !map.is_in_bounds(&target) || map.tile_has_collision(&target) || is_occupied {
+        return;
+    }
+
+    if let Ok((_, mut position, fov, ..)) = actor_queries.p0().get_mut(actor) {
+        *position = target;
+
+        if let Some(mut fov) = fov {
+            fov.is_dirty = true;
+        }
+    }
+}
+
+/// (Private) Creates the [MessageLog] entry describing the attack of the `attacker` on the `target` from the
+/// perspective of the `player`.
+fn attack_message(
+    attacker: Entity,
+    target: Entity,
+    actor_query: &Query<ActorComponents>,
+) -> String {
+    let describe = |entity: Entity| {
+        actor_query.get(entity).map_or(
+            (String::from("creature"), false),
+            |(_, _, _, name_tag, _, is_player)| {
+                (
+                    name_tag.map_or(String::from("creature"), |name_tag| name_tag.text.clone()),
+                    is_player,
+                )
+            },
+        )
+    };
+
+    let (attacker_name, attacker_is_player) = describe(attacker);
+    let (target_name, target_is_player) = describe(target);
+
+    if attacker_is_player {
+        format!("You attack the {}.", target_name)
+    } else if target_is_player {
+        format!("The {} attacks you.", attacker_name)
+    } else {
+        format!("The {} attacks the {}.", attacker_name, target_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::Events;
+
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    fn setup_app() -> (App, Entity, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<Attack>();
+        app.insert_resource(ActionQueue::default());
+        app.insert_resource(MessageLog::default());
+        app.add_systems(Update, action_system);
+
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        map.set_tile_at(&[5, 6], MapTile::default());
+
+        app.world.spawn(map);
+
+        let player = app
+            .world
+            .spawn((Coord2d::new(5, 5), Fov::new(8), Facing::default(), Player))
+            .id();
+        let monster = app
+            .world
+            .spawn((Coord2d::new(6, 5), NameTag::new("Mended"), Collision))
+            .id();
+
+        (app, player, monster)
+    }
+
+    fn resolve(app: &mut App, action: Action) {
+        app.world.resource_mut::<ActionQueue>().push(action);
+        app.update();
+    }
+
+    fn position_of(app: &App, entity: Entity) -> Coord2d {
+        *app.world.get::<Coord2d>(entity).unwrap()
+    }
+
+    #[test]
+    fn test_queued_move() {
+        let (mut app, player, _) = setup_app();
+
+        app.world.get_mut::<Fov>(player).unwrap().is_dirty = false;
+
+        resolve(
+            &mut app,
+            Action::Move {
+                actor: player,
+                delta: [0, -1],
+            },
+        );
+
+        assert_eq!(Coord2d::new(5, 4), position_of(&app, player));
+        assert!(app.world.get::<Fov>(player).unwrap().is_dirty);
+        assert!(app.world.resource::<ActionQueue>().is_empty());
+    }
+
+    #[test]
+    fn test_queued_move_is_blocked() {
+        let (mut app, player, monster) = setup_app();
+
+        // Blocked by a wall, an entity with collision and the bounds of the map.
+        for delta in [[0, 1], [1, 0]] {
+            resolve(
+                &mut app,
+                Action::Move {
+                    actor: player,
+                    delta,
+                },
+            );
+        }
+
+        resolve(
+            &mut app,
+            Action::Move {
+                actor: player,
+                delta: [-6, 0],
+            },
+        );
+
+        assert_eq!(Coord2d::new(5, 5), position_of(&app, player));
+
+        // Monsters are blocked by the player.
+        resolve(
+            &mut app,
+            Action::Move {
+                actor: monster,
+                delta: [-1, 0],
+            },
+        );
+
+        assert_eq!(Coord2d::new(6, 5), position_of(&app, monster));
+    }
+
+    #[test]
+    fn test_move_updates_facing() {
+        let (mut app, player, _) = setup_app();
+
+        // Facing the monster, although it blocks the movement.
+        resolve(
+            &mut app,
+            Action::Move {
+                actor: player,
+                delta: [1, 0],
+            },
+        );
+
+        assert_eq!(Some(&Facing::East), app.world.get::<Facing>(player));
+
+        resolve(
+            &mut app,
+            Action::Move {
+                actor: player,
+                delta: [-1, 1],
+            },
+        );
+
+        assert_eq!(Coord2d::new(4, 6), position_of(&app, player));
+        assert_eq!(Some(&Facing::NorthWest), app.world.get::<Facing>(player));
+
+        resolve(&mut app, Action::Wait { actor: player });
+
+        assert_eq!(Some(&Facing::NorthWest), app.world.get::<Facing>(player));
+    }
+
+    #[test]
+    fn test_queued_attack() {
+        let (mut app, player, monster) = setup_app();
+
+        resolve(
+            &mut app,
+            Action::Attack {
+                attacker: player,
+                target: monster,
+            },
+        );
+
+        let events = app.world.resource::<Events<Attack>>();
+        let attacks: Vec<Attack> = events.get_reader().read(events).copied().collect();
+
+        assert_eq!(vec![Attack::new(player, monster)], attacks);
+        assert_eq!(
+            "You attack the Mended.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+
+        resolve(
+            &mut app,
+            Action::Attack {
+                attacker: monster,
+                target: player,
+            },
+        );
+
+        assert_eq!(
+            "The Mended attacks you.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_actions_of_removed_entities_are_dropped() {
+        let (mut app, player, monster) = setup_app();
+
+        app.world.despawn(monster);
+
+        resolve(
+            &mut app,
+            Action::Attack {
+                attacker: monster,
+                target: player,
+            },
+        );
+
+        assert!(app
+            .world
+            .resource::<MessageLog>()
+            .recent(1)
+            .next()
+            .is_none());
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::prelude::{EventReader, Query, Res, ResMut, Time};
+
+use crate::components::animated_sprite::AnimatedSprite;
+use crate::components::ascii_sprite::AsciiSprite;
+use crate::components::combat_stats::CombatStats;
+use crate::components::coord_2d::Coord2d;
+use crate::components::faction::Faction;
+use crate::events::attack::Attack;
+use crate::res::animation_queue::{Animation, AnimationKind, AnimationQueue};
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::palette::Palette;
+use crate::ui::colors;
+
+/// System enqueueing a flash [Animation] at the position of the target of every received [Attack].
+///
+/// # Arguments
+///
+/// * `gameplay_config`: [GameplayConfig] providing the number of frames the [Animation] is played for.
+/// * `animation_queue`: The [AnimationQueue] resource to enqueue the [Animation]s into.
+/// * `attack_reader`: [EventReader] receiving the [Attack] events.
+/// * `position_query`: [Query] to retrieve the positions of the attacked `entities`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [GameplayConfig] or [AnimationQueue] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn attack_animation_system(
+    gameplay_config: Res<GameplayConfig>,
+    mut animation_queue: ResMut<AnimationQueue>,
+    mut attack_reader: EventReader<Attack>,
+    position_query: Query<&Coord2d>,
+) {
+    for attack in attack_reader.read() {
+        if let Ok(position) = position_query.get(attack.target) {
+            animation_queue.enqueue(Animation {
+                position: *position,
+                kind: AnimationKind::Flash(colors::HIT_FLASH),
+                frames: gameplay_config.animation_frames,
+            });
+        }
+    }
+}
+
+/// System enqueueing a floating damage number [Animation] at the position of the target of every received
+/// [Attack], if enabled by the [GameplayConfig]. The damage is the [CombatStats::power] of the attacker reduced
+/// by the [CombatStats::defense] of the target, but at least `1`, see [CombatStats::damage_against]. The number
+/// is colored in the [Faction] color of the attacker.
+///
+/// Attacks of `entities` without [CombatStats] are skipped, as their damage is unknown. Has to run after the
+/// [attack_animation_system], so the damage number is shown after the flash of the hit.
+///
+/// # Arguments
+///
+/// * `gameplay_config`: [GameplayConfig] defining if and for how many frames the damage numbers are shown.
+/// * `palette`: The [Palette] providing the [Faction] colors.
+/// * `animation_queue`: The [AnimationQueue] resource to enqueue the [Animation]s into.
+/// * `attack_reader`: [EventReader] receiving the [Attack] events.
+/// * `combatant_query`: [Query] to retrieve the positions, [CombatStats] and [Faction]s of the attacking
+///   and attacked `entities`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [GameplayConfig], [Palette] or [AnimationQueue] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn damage_number_system(
+    gameplay_config: Res<GameplayConfig>,
+    palette: Res<Palette>,
+    mut animation_queue: ResMut<AnimationQueue>,
+    mut attack_reader: EventReader<Attack>,
+    combatant_query: Query<(&Coord2d, Option<&CombatStats>, Option<&Faction>)>,
+) {
+    if !gameplay_config.show_damage_numbers {
+        attack_reader.clear();
+        return;
+    }
+
+    for attack in attack_reader.read() {
+        let Ok((_, Some(attacker_stats), attacker_faction)) = combatant_query.get(attack.attacker)
+        else {
+            continue;
+        };
+
+        let Ok((position, target_stats, _)) = combatant_query.get(attack.target) else {
+            continue;
+        };
+
+        let damage = attacker_stats.damage_against(target_stats);
+        let color =
+            attacker_faction.map_or(colors::HIT_FLASH, |faction| palette.faction_color(*faction));
+
+        animation_queue.enqueue(Animation {
+            position: *position,
+            kind: AnimationKind::DamageNumber(damage, color),
+            frames: gameplay_config.damage_number_frames,
+        });
+    }
+}
+
+/// System advancing the currently playing [Animation] of the [AnimationQueue] by one frame.
+///
+/// Has to run after the [crate::plugins::game_state_systems::graphics::animation_render_system], so
+/// every frame of an [Animation] is rendered.
+///
+/// # Arguments
+///
+/// * `animation_queue`: The [AnimationQueue] resource to advance.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [AnimationQueue] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn animation_system(mut animation_queue: ResMut<AnimationQueue>) {
+    animation_queue.advance();
+}
+
+/// System advancing every [AnimatedSprite] by the duration of the last frame and copying its current frame
+/// into the [AsciiSprite] of the same `entity`, so the render systems pick it up.
+///
+/// Has to run before the [crate::plugins::game_state_systems::graphics::render_system].
+///
+/// # Arguments
+///
+/// * `time`: The [Time] resource providing the duration of the last frame.
+/// * `sprite_query`: [Query] to retrieve all `entities` with an [AnimatedSprite] and their [AsciiSprite].
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Time] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn sprite_animation_system(
+    time: Res<Time>,
+    mut sprite_query: Query<(&mut AnimatedSprite, &mut AsciiSprite)>,
+) {
+    for (mut animated_sprite, mut ascii_sprite) in sprite_query.iter_mut() {
+        animated_sprite.tick(time.delta());
+
+        if ascii_sprite.glyph != animated_sprite.glyph() {
+            ascii_sprite.glyph = animated_sprite.glyph();
+        }
+    }
+}
+
+/// Run condition for the systems handling the player's actions, which only holds once all queued
+/// [Animation]s have been played. This keeps the turn from advancing while animations are playing.
+///
+/// # Arguments
+///
+/// * `animation_queue`: The [AnimationQueue] resource to check.
+///
+/// returns: bool
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn animations_finished(animation_queue: Res<AnimationQueue>) -> bool {
+    animation_queue.is_finished()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::app::{App, AppExit, Update};
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::ButtonState;
+    use bevy::prelude::{Color, Component, Entity, IntoSystemConfigs, KeyCode};
+
+    use crate::components::collision::Collision;
+    use crate::components::player::Player;
+    use crate::plugins::game_state_systems::action::action_system;
+    use crate::plugins::game_state_systems::input::keyboard_input_system;
+    use crate::plugins::states::GameTurnState;
+    use crate::res::action_queue::ActionQueue;
+    use crate::res::examine_mode::ExamineMode;
+    use crate::res::input_config::InputConfig;
+    use crate::res::log_view::LogView;
+    use crate::res::message_log::MessageLog;
+    use crate::res::targeting_mode::TargetingMode;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    #[test]
+    fn test_attack_enqueues_flash_animation() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<Attack>();
+        app.add_event::<AppExit>();
+        app.insert_resource(GameplayConfig {
+            animation_frames: 4,
+            ..Default::default()
+        });
+        app.insert_resource(GameTurnState::Player);
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::default());
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(AnimationQueue::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_systems(
+            Update,
+            (
+                keyboard_input_system,
+                action_system,
+                attack_animation_system,
+            )
+                .chain()
+                .run_if(animations_finished),
+        );
+
+        app.world.spawn((Coord2d::new(5, 5), Player));
+        app.world.spawn((Coord2d::new(5, 6), Collision));
+
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::W),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        assert_eq!(
+            Some(&Animation {
+                position: Coord2d::new(5, 6),
+                kind: AnimationKind::Flash(colors::HIT_FLASH),
+                frames: 4,
+            }),
+            app.world.resource::<AnimationQueue>().current()
+        );
+    }
+
+    fn setup_damage_number_app(show_damage_numbers: bool) -> (App, Entity, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<Attack>();
+        app.insert_resource(GameplayConfig {
+            show_damage_numbers,
+            damage_number_frames: 3,
+            ..Default::default()
+        });
+        app.insert_resource(Palette::default());
+        app.insert_resource(AnimationQueue::default());
+        app.add_systems(Update, (damage_number_system, animation_system).chain());
+
+        let attacker = app
+            .world
+            .spawn((Coord2d::new(5, 5), CombatStats::new(5, 0), Faction::Hostile))
+            .id();
+        let target = app
+            .world
+            .spawn((Coord2d::new(5, 6), CombatStats::new(1, 2), Player))
+            .id();
+
+        (app, attacker, target)
+    }
+
+    #[test]
+    fn test_hit_enqueues_damage_number_at_target() {
+        let (mut app, attacker, target) = setup_damage_number_app(true);
+
+        app.world.send_event(Attack::new(attacker, target));
+        app.update();
+
+        let animation_queue = app.world.resource::<AnimationQueue>();
+
+        assert_eq!(
+            Some(&Animation {
+                position: Coord2d::new(5, 6),
+                kind: AnimationKind::DamageNumber(3, colors::FACTION_HOSTILE),
+                frames: 3,
+            }),
+            animation_queue.current()
+        );
+        assert_eq!(1, animation_queue.elapsed_frames());
+
+        app.update();
+        assert!(!app.world.resource::<AnimationQueue>().is_finished());
+
+        app.update();
+        assert!(app.world.resource::<AnimationQueue>().is_finished());
+    }
+
+    #[test]
+    fn test_damage_numbers_can_be_disabled() {
+        let (mut app, attacker, target) = setup_damage_number_app(false);
+
+        app.world.send_event(Attack::new(attacker, target));
+        app.update();
+
+        assert!(app.world.resource::<AnimationQueue>().is_finished());
+    }
+
+    #[test]
+    fn test_animation_system_drains_queue_after_configured_frames() {
+        let mut app = App::new();
+        let mut animation_queue = AnimationQueue::default();
+
+        animation_queue.enqueue(Animation {
+            position: Coord2d::new(1, 1),
+            kind: AnimationKind::Flash(colors::HIT_FLASH),
+            frames: 3,
+        });
+
+        app.insert_resource(animation_queue);
+        app.add_systems(Update, animation_system);
+
+        for _ in 0..2 {
+            app.update();
+            assert!(!app.world.resource::<AnimationQueue>().is_finished());
+        }
+
+        app.update();
+
+        assert!(app.world.resource::<AnimationQueue>().is_finished());
+    }
+
+    #[test]
+    fn test_sprite_animation_cycles_glyph() {
+        let mut app = App::new();
+
+        app.init_resource::<Time>();
+        app.add_systems(Update, sprite_animation_system);
+
+        let entity = app
+            .world
+            .spawn((
+                AnimatedSprite::new(vec!['m', 'M'], Duration::from_millis(100)),
+                AsciiSprite::new('m', Color::YELLOW, Color::BLACK),
+            ))
+            .id();
+
+        let mut glyphs = Vec::new();
+
+        for _ in 0..4 {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(120));
+            app.update();
+
+            glyphs.push(app.world.get::<AsciiSprite>(entity).unwrap().glyph);
+        }
+
+        assert_eq!(vec!['M', 'm', 'M', 'm'], glyphs);
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::prelude::{DetectChanges, Query, Res, ResMut, With};
+
+use crate::components::coord_2d::Coord2d;
+use crate::components::player::Player;
+use crate::res::map_camera::MapCamera;
+use crate::res::terminal_layout::TerminalLayout;
+use crate::ui::game_map::GameMap;
+use crate::ui::viewport::Viewport;
+
+/// System centering the [Viewport] on the `player`, clamped to the bounds of the [GameMap], so floors larger than
+/// the map panel scroll along with the `player`. Has to run before the render systems, which translate the
+/// [GameMap] positions through the [Viewport]. Once the [TerminalLayout] changes, e.g., after the window got
+/// resized, the [Viewport] is fitted to the new map panel first.
+///
+/// # Arguments
+///
+/// * `terminal_layout`: The [TerminalLayout] resource providing the map panel the [Viewport] renders into.
+/// * `map_camera`: The [MapCamera] resource, whose [Viewport] to center.
+/// * `player_query`: [Query] to retrieve the position of the `player entity`.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], whose bounds the [Viewport] is clamped to.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [TerminalLayout] or [MapCamera] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn camera_system(
+    terminal_layout: Res<TerminalLayout>,
+    mut map_camera: ResMut<MapCamera>,
+    player_query: Query<&Coord2d, With<Player>>,
+    game_map_query: Query<&GameMap>,
+) {
+    if terminal_layout.is_changed() {
+        map_camera.viewport = Viewport::new(&terminal_layout.map);
+    }
+
+    let (Ok(player_position), Ok(game_map)) =
+        (player_query.get_single(), game_map_query.get_single())
+    else {
+        return;
+    };
+
+    map_camera.viewport.center_on(player_position, game_map);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    #[test]
+    fn test_camera_follows_the_player_across_a_large_map() {
+        let mut app = App::new();
+        let terminal_layout = TerminalLayout::new(&[40, 15]);
+
+        app.insert_resource(terminal_layout);
+        app.insert_resource(MapCamera::new(&terminal_layout.map));
+        app.add_systems(Update, camera_system);
+
+        app.world
+            .spawn(GameMap::new(&[100, 50], &TestTileMapGenerator, None));
+        let player = app.world.spawn((Coord2d::new(50, 25), Player)).id();
+
+        app.update();
+
+        assert_eq!(
+            Some([10, 5]),
+            app.world
+                .resource::<MapCamera>()
+                .viewport
+                .world_to_screen(&[50, 25])
+        );
+
+        app.world.get_mut::<Coord2d>(player).unwrap().x = 98;
+        app.update();
+
+        let viewport = app.world.resource::<MapCamera>().viewport;
+
+        assert_eq!(Coord2d::new(80, 29), viewport.top_left);
+        assert_eq!(Some([18, 5]), viewport.world_to_screen(&[98, 25]));
+    }
+
+    #[test]
+    fn test_viewport_follows_the_resized_map_panel() {
+        let mut app = App::new();
+        let terminal_layout = TerminalLayout::new(&[100, 80]);
+
+        app.insert_resource(terminal_layout);
+        app.insert_resource(MapCamera::new(&terminal_layout.map));
+        app.add_systems(Update, camera_system);
+
+        app.world
+            .spawn(GameMap::new(&[100, 50], &TestTileMapGenerator, None));
+        app.world.spawn((Coord2d::new(50, 25), Player));
+
+        app.update();
+
+        *app.world.resource_mut::<TerminalLayout>() = TerminalLayout::new(&[40, 15]);
+        app.update();
+
+        let viewport = app.world.resource::<MapCamera>().viewport;
+
+        assert_eq!([20, 10], viewport.size);
+        assert_eq!(Coord2d::new(40, 29), viewport.top_left);
+        assert_eq!(Some([10, 5]), viewport.world_to_screen(&[50, 25]));
+    }
+}
@@ -0,0 +1,341 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::prelude::{Commands, Entity, EventReader, Has, NextState, Query, Res, ResMut};
+
+use crate::components::combat_stats::CombatStats;
+use crate::components::health::Health;
+use crate::components::name_tag::NameTag;
+use crate::components::player::Player;
+use crate::events::attack::Attack;
+use crate::plugins::states::AppState;
+use crate::res::dungeon_depth::DungeonDepth;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::run_summary::RunSummary;
+use crate::res::turn_counter::TurnCounter;
+
+/// System resolving the melee [Attack] events sent by the [crate::plugins::game_state_systems::action::action_system],
+/// deducting the damage from the [Health] of the attacked `entity`.
+///
+/// The damage is the attacker's power reduced by the target's defense, with every hit dealing at least `1` damage,
+/// see [CombatStats::damage_against]. Attackers without [CombatStats] and targets without [Health] are ignored.
+///
+/// # Arguments
+///
+/// * `attack_reader`: [EventReader] stream of the [Attack] events to resolve.
+/// * `stats_query`: [Query] to retrieve the optional [CombatStats] of the attacker and the target.
+/// * `health_query`: [Query] to retrieve the [Health] of the target.
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn melee_system(
+    mut attack_reader: EventReader<Attack>,
+    stats_query: Query<&CombatStats>,
+    mut health_query: Query<&mut Health>,
+) {
+    for attack in attack_reader.read() {
+        let Ok(attacker_stats) = stats_query.get(attack.attacker) else {
+            continue;
+        };
+
+        let Ok(mut health) = health_query.get_mut(attack.target) else {
+            continue;
+        };
+
+        health.apply_damage(attacker_stats.damage_against(stats_query.get(attack.target).ok()));
+    }
+}
+
+/// System removing every `entity` whose [Health] dropped to `0` from the game's world, announcing its death
+/// in the [MessageLog].
+///
+/// The `player entity` is kept, as the game can't continue without it. Its death ends the run instead, by recording
+/// the [RunSummary] and entering the [AppState::GameOver].
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to despawn the dead `entities`.
+/// * `message_log`: [MessageLog] to announce the deaths in.
+/// * `next_state`: [NextState] required to enter the [AppState::GameOver], once the `player` died.
+/// * `dungeon_depth`: The [DungeonDepth] the run ended on, recorded in the [RunSummary].
+/// * `turn_counter`: The [TurnCounter] holding the number of turns the run lasted, recorded in the [RunSummary].
+/// * `health_query`: [Query] to retrieve all `entities` with [Health], their optional [NameTag] and whether
+///   they're the `player`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn death_system(
+    mut commands: Commands,
+    mut message_log: ResMut<MessageLog>,
+    mut next_state: ResMut<NextState<AppState>>,
+    dungeon_depth: Res<DungeonDepth>,
+    turn_counter: Res<TurnCounter>,
+    health_query: Query<(Entity, &Health, Option<&NameTag>, Has<Player>)>,
+) {
+    for (entity, health, name_tag, is_player) in health_query.iter() {
+        if !health.is_dead() {
+            continue;
+        }
+
+        if is_player {
+            message_log.push("You die.", LogCategory::Death);
+            commands.insert_resource(RunSummary::new(
+                dungeon_depth.current(),
+                turn_counter.current(),
+            ));
+            next_state.set(AppState::GameOver);
+            continue;
+        }
+
+        let name = name_tag.map_or("creature", |name_tag| name_tag.text.as_str());
+
+        message_log.push(format!("The {} dies.", name), LogCategory::Death);
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, AppExit, Update};
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::ButtonState;
+    use bevy::prelude::{Component, Events, IntoSystemConfigs, KeyCode, State};
+
+    use crate::components::collision::Collision;
+    use crate::components::coord_2d::Coord2d;
+    use crate::plugins::game_state_systems::action::action_system;
+    use crate::plugins::game_state_systems::input::keyboard_input_system;
+    use crate::plugins::states::GameTurnState;
+    use crate::res::action_queue::ActionQueue;
+    use crate::res::examine_mode::ExamineMode;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::input_config::InputConfig;
+    use crate::res::log_view::LogView;
+    use crate::res::targeting_mode::TargetingMode;
+    use crate::ui::game_map::GameMap;
+    use crate::ui::rectangle::Rectangle;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> (App, Entity, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<Attack>();
+        app.add_event::<AppExit>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(GameTurnState::Player);
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::default());
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_systems(
+            Update,
+            (keyboard_input_system, action_system, melee_system).chain(),
+        );
+
+        let mut game_map = GameMap::new(&[20, 12], &TestTileMapGenerator, None);
+        Rectangle::new([1, 1], [8, 8]).add_to_map(&mut game_map);
+        app.world.spawn(game_map);
+
+        let player = app
+            .world
+            .spawn((Coord2d::new(5, 5), Player, CombatStats::new(5, 1)))
+            .id();
+        let monster = app
+            .world
+            .spawn((
+                Coord2d::new(6, 5),
+                NameTag::new("Mended"),
+                Collision,
+                Health::new(16),
+                CombatStats::new(4, 1),
+            ))
+            .id();
+
+        (app, player, monster)
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    fn sent_attacks(app: &mut App) -> Vec<Attack> {
+        let events = app.world.resource::<Events<Attack>>();
+        events.get_reader().read(events).copied().collect()
+    }
+
+    #[test]
+    fn test_bump_attack() {
+        let (mut app, player, monster) = setup_app();
+
+        press(&mut app, KeyCode::D);
+
+        assert_eq!(vec![Attack::new(player, monster)], sent_attacks(&mut app));
+        assert_eq!(
+            "You attack the Mended.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_bump_attack_damages_monster() {
+        let (mut app, player, monster) = setup_app();
+
+        press(&mut app, KeyCode::D);
+
+        assert_eq!(12, app.world.get::<Health>(monster).unwrap().current);
+        assert_eq!(
+            &Coord2d::new(5, 5),
+            app.world.get::<Coord2d>(player).unwrap()
+        );
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_bump_attack_into_empty_cell_deals_no_damage() {
+        let (mut app, _, monster) = setup_app();
+
+        press(&mut app, KeyCode::A);
+
+        assert_eq!(16, app.world.get::<Health>(monster).unwrap().current);
+    }
+
+    #[test]
+    fn test_death_system() {
+        let mut app = App::new();
+
+        app.add_state::<AppState>();
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(DungeonDepth::default());
+        app.insert_resource(TurnCounter::default());
+        app.add_systems(Update, death_system);
+
+        let mut wounded_health = Health::new(10);
+        wounded_health.apply_damage(9);
+        let mut dead_health = Health::new(10);
+        dead_health.apply_damage(10);
+
+        let wounded = app
+            .world
+            .spawn((NameTag::new("Mended"), wounded_health))
+            .id();
+        let dead = app.world.spawn((NameTag::new("Mended"), dead_health)).id();
+
+        app.update();
+
+        assert!(app.world.get_entity(wounded).is_some());
+        assert!(app.world.get_entity(dead).is_none());
+        assert_eq!(
+            "The Mended dies.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+        assert!(app.world.get_resource::<RunSummary>().is_none());
+        assert!(app.world.resource::<NextState<AppState>>().0.is_none());
+    }
+
+    #[test]
+    fn test_death_system_ends_run_on_player_death() {
+        let mut app = App::new();
+
+        app.add_state::<AppState>();
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(DungeonDepth::default());
+        app.insert_resource(TurnCounter::default());
+        app.add_systems(Update, death_system);
+
+        let mut health = Health::new(10);
+        health.apply_damage(10);
+
+        let player = app.world.spawn((Player, health)).id();
+
+        app.update();
+
+        assert!(app.world.get_entity(player).is_some());
+        assert_eq!(RunSummary::new(1, 0), *app.world.resource::<RunSummary>());
+        assert_eq!(
+            "You die.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+
+        app.update();
+
+        assert_eq!(
+            AppState::GameOver,
+            *app.world.resource::<State<AppState>>().get()
+        );
+    }
+
+    #[test]
+    fn test_bump_attack_into_empty_cell() {
+        let (mut app, _, _) = setup_app();
+
+        press(&mut app, KeyCode::A);
+
+        assert!(sent_attacks(&mut app).is_empty());
+    }
+}
@@ -19,25 +19,53 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
-use bevy::log::info;
-use bevy::prelude::{Query, Res, With};
+use std::collections::HashMap;
+
+use bevy::prelude::{Entity, Query, Res, ResMut, With, Without};
 
 use crate::components::coord_2d::Coord2d;
 use crate::components::enemy_type::EnemyType;
 use crate::components::fov::Fov;
+use crate::components::leash::Leash;
 use crate::components::name_tag::NameTag;
 use crate::components::npc_state::NpcState;
 use crate::components::player::Player;
+use crate::components::speed::Speed;
+use crate::core::action::Action;
+use crate::core::dijkstra_map::DijkstraMap;
+use crate::core::position_2d::Position2d;
 use crate::plugins::states::GameTurnState;
+use crate::res::action_queue::ActionQueue;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::npc_turn_queue::NpcTurnQueue;
+use crate::ui::game_map::GameMap;
 
 /// Computes the respective enemy's reaction to the `player` entering or being inside their `field of view`.
 ///
+/// An enemy spotting the `player` remembers the `player's` position and alerts all other enemies within the
+/// [GameplayConfig::alert_radius], which don't see the `player` themselves, by passing the position on to them,
+/// so they converge on the `player`.
+///
+/// An enemy, which lost sight of the `player`, forgets the `player's` last known position once it reached it or
+/// strayed beyond the [GameplayConfig::leash_radius] of its [Leash]. The movement of the enemies is handled by the
+/// [enemy_chase_system] afterwards.
+///
+/// To avoid stalling a frame, at most [GameplayConfig::ai_budget] enemies are processed per frame. The enemies
+/// are queued in the [NpcTurnQueue] at the beginning of the turn, the remaining ones act in the following frames
+/// before the turn ends.
+///
 /// This system is only executed if the game's [GameTurnState] matches [GameTurnState::Npc].
 ///
 /// # Arguments
 ///
 /// * `game_turn_state`: The [GameTurnState] resource required to verify that it's the enemy's turn.
-/// * `enemy_fov_query`: [Query] required to retrieve the [Fov] components of the respective enemies.
+/// * `gameplay_config`: The [GameplayConfig] resource providing the radius of the alert, the AI budget and the
+///   radius of the leash.
+/// * `npc_turn_queue`: The [NpcTurnQueue] resource holding the enemies, which still have to act in the turn.
+/// * `message_log`: The [MessageLog] resource to inform the player about the enemy's reaction.
+/// * `enemy_query`: [Query] required to retrieve the `entities`, positions, [Fov], [NpcState] and [Leash]
+///   components of the respective enemies.
 /// * `player_position_query`: [Query] to retrieve the `player entities` position.
 ///
 /// returns: ()
@@ -56,7 +84,10 @@ use crate::plugins::states::GameTurnState;
 ///
 pub fn enemy_line_of_sight_system(
     game_turn_state: Res<GameTurnState>,
-    mut enemy_query: Query<(&Fov, &NameTag, &EnemyType, &mut NpcState)>,
+    gameplay_config: Res<GameplayConfig>,
+    mut npc_turn_queue: ResMut<NpcTurnQueue>,
+    mut message_log: ResMut<MessageLog>,
+    mut enemy_query: Query<EnemyComponents, Without<Player>>,
     player_position_query: Query<&Coord2d, With<Player>>,
 ) {
     if game_turn_state.into_inner() != &GameTurnState::Npc {
@@ -67,25 +98,668 @@ pub fn enemy_line_of_sight_system(
         "ECS -> Systems -> enemy_view_contact_system -> Unable to retrieve the player's {Coord2d} component!",
     );
 
-    for (fov, name_tag, enemy_type, mut npc_state) in enemy_query.iter_mut() {
+    if !npc_turn_queue.is_active() {
+        npc_turn_queue.begin(enemy_query.iter().map(|(entity, ..)| entity).collect());
+    }
+
+    let mut alert_origins = Vec::new();
+
+    for entity in npc_turn_queue.next_batch(gameplay_config.ai_budget) {
+        // The enemy might have been removed since the beginning of the turn.
+        let Ok((_, position, fov, name_tag, enemy_type, mut npc_state, leash)) =
+            enemy_query.get_mut(entity)
+        else {
+            continue;
+        };
+
         if fov.contains(player_position) {
-            if npc_state.is_seeing_player {
-                return;
-            }
+            npc_state.last_known_player_pos = Some(player_position.as_array());
 
-            npc_state.is_seeing_player = true;
+            if !npc_state.is_seeing_player {
+                npc_state.is_seeing_player = true;
+                alert_origins.push(*position);
 
-            match enemy_type {
-                EnemyType::Mended => info!("{} gurgles and shifts at your presence.", name_tag),
+                match enemy_type {
+                    EnemyType::Mended => message_log.push(
+                        format!("{} gurgles and shifts at your presence.", name_tag.text),
+                        LogCategory::Warning,
+                    ),
+                    EnemyType::Ghoul => message_log.push(
+                        format!("{} shrieks and scrambles towards you.", name_tag.text),
+                        LogCategory::Warning,
+                    ),
+                }
             }
         } else {
             npc_state.is_seeing_player = false;
+
+            let is_leash_exceeded = leash
+                .is_some_and(|leash| leash.is_exceeded(position, gameplay_config.leash_radius));
+
+            if is_leash_exceeded || npc_state.last_known_player_pos == Some(position.as_array()) {
+                npc_state.last_known_player_pos = None;
+            }
+        }
+    }
+
+    let radius = gameplay_config.alert_radius;
+
+    if radius <= 0 {
+        return;
+    }
+
+    for origin in alert_origins {
+        for (_, position, _, _, _, mut npc_state, ..) in enemy_query.iter_mut() {
+            let [dx, dy] = origin.delta(position);
+
+            if !npc_state.is_seeing_player && dx * dx + dy * dy <= radius * radius {
+                npc_state.last_known_player_pos = Some(player_position.as_array());
+            }
         }
     }
 }
 
+/// The components of an enemy queried by the [enemy_line_of_sight_system].
+type EnemyComponents<'a> = (
+    Entity,
+    &'a Coord2d,
+    &'a Fov,
+    &'a NameTag,
+    &'a EnemyType,
+    &'a mut NpcState,
+    Option<&'a Leash>,
+);
+
+/// Moves the enemies of the current batch of the [NpcTurnQueue], see [enemy_line_of_sight_system], which are aware
+/// of the `player`, towards the `player's` last known position.
+///
+/// Each of these enemies pushes an [Action::Move] into the [ActionQueue], unless its [Speed] lets it skip the
+/// current turn. An enemy, which knows where the `player` is and already stands next to them, pushes an
+/// [Action::Attack] against the `player` instead. The step follows a [DijkstraMap] of the target, so the enemy routes around walls. The movement is
+/// resolved by the [crate::plugins::game_state_systems::action::action_system]. Idle enemies, which strayed beyond
+/// the [GameplayConfig::leash_radius] of their [Leash], walk back towards their home instead.
+///
+/// This system is only executed if the game's [GameTurnState] matches [GameTurnState::Npc].
+///
+/// # Arguments
+///
+/// * `game_turn_state`: The [GameTurnState] resource required to verify that it's the enemy's turn.
+/// * `gameplay_config`: The [GameplayConfig] resource providing the radius of the leash.
+/// * `npc_turn_queue`: The [NpcTurnQueue] resource holding the enemies acting in the current frame.
+/// * `action_queue`: The [ActionQueue] resource to push the movements and attacks of the enemies into.
+/// * `chaser_query`: [Query] required to retrieve the `entities`, positions, [NpcState], [Speed] and [Leash]
+///   components of the respective enemies.
+/// * `player_query`: [Query] to retrieve the `player entity` and its position.
+/// * `game_map_query`: [Query] to retrieve the [GameMap] the enemies move on.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the [Query] calls fail.
+/// * If any of the required components can't be retrieved from the ECS.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn enemy_chase_system(
+    game_turn_state: Res<GameTurnState>,
+    gameplay_config: Res<GameplayConfig>,
+    npc_turn_queue: Res<NpcTurnQueue>,
+    mut action_queue: ResMut<ActionQueue>,
+    mut chaser_query: Query<ChaserComponents, Without<Player>>,
+    player_query: Query<(Entity, &Coord2d), With<Player>>,
+    game_map_query: Query<&GameMap>,
+) {
+    if game_turn_state.into_inner() != &GameTurnState::Npc
+        || npc_turn_queue.current_batch().is_empty()
+    {
+        return;
+    }
+
+    let (player, player_position) = player_query.get_single().expect(
+        "ECS -> Systems -> enemy_chase_system -> Unable to retrieve the player's {Coord2d} component!",
+    );
+
+    let map = game_map_query.get_single().expect(
+        "ECS -> Systems -> enemy_chase_system -> Unable to retrieve the {GameMap} component!",
+    );
+
+    let mut dijkstra_maps: HashMap<[i32; 2], DijkstraMap> = HashMap::new();
+    let mut occupied_positions: Vec<Coord2d> = chaser_query
+        .iter()
+        .map(|(_, position, ..)| *position)
+        .chain([*player_position])
+        .collect();
+
+    for &entity in npc_turn_queue.current_batch() {
+        // The enemy might have been removed since the beginning of the turn.
+        let Ok((_, position, npc_state, speed, leash)) = chaser_query.get_mut(entity) else {
+            continue;
+        };
+
+        let target = match (npc_state.last_known_player_pos, leash) {
+            (Some(target), _) => target,
+            (None, Some(leash)) if leash.is_exceeded(position, gameplay_config.leash_radius) => {
+                leash.home
+            }
+            _ => continue,
+        };
+
+        if let Some(mut speed) = speed {
+            if !speed.tick() {
+                continue;
+            }
+        }
+
+        if npc_state.last_known_player_pos.is_some()
+            && position.chebyshev_distance(player_position) == 1
+        {
+            action_queue.push(Action::Attack {
+                attacker: entity,
+                target: player,
+            });
+            continue;
+        }
+
+        let dijkstra_map = dijkstra_maps
+            .entry(target)
+            .or_insert_with(|| DijkstraMap::new(map, &[target]));
+
+        if let Some(step) = step_towards(position, &target, dijkstra_map, &occupied_positions) {
+            occupied_positions.retain(|occupied| occupied != position);
+            occupied_positions.push(step);
+
+            action_queue.push(Action::Move {
+                actor: entity,
+                delta: step.delta(position),
+            });
+        }
+    }
+}
+
+/// The components of an enemy queried by the [enemy_chase_system].
+type ChaserComponents<'a> = (
+    Entity,
+    &'a Coord2d,
+    &'a NpcState,
+    Option<&'a mut Speed>,
+    Option<&'a Leash>,
+);
+
+/// (Private) Determines the position of a single orthogonal step from the passed `position` towards the `target`,
+/// following the downhill neighbors of the `target's` [DijkstraMap]. Among equally short routes, the step along the
+/// axis with the larger distance is tried first. Positions occupied by another `entity` are avoided, returning
+/// [None] if no step brings the enemy closer.
+fn step_towards(
+    position: &Coord2d,
+    target: &[i32; 2],
+    dijkstra_map: &DijkstraMap,
+    occupied_positions: &[Coord2d],
+) -> Option<Coord2d> {
+    let [x_delta, y_delta] = target.delta(position);
+    let mut steps = dijkstra_map.downhill_neighbors(position);
+
+    steps.sort_by_key(|step| {
+        let [dx, dy] = step.delta(position);
+        -(dx * x_delta + dy * y_delta)
+    });
+
+    steps
+        .into_iter()
+        .map(|step| Coord2d::from_position(&step))
+        .find(|step| !occupied_positions.contains(step))
+}
+
 #[cfg(test)]
 mod tests {
+    use bevy::app::{App, PostUpdate, Update};
+    use bevy::prelude::IntoSystemConfigs;
+
+    use crate::components::combat_stats::CombatStats;
+    use crate::components::health::Health;
+    use crate::events::attack::Attack;
+    use crate::plugins::game_state_systems::action::action_system;
+    use crate::plugins::game_state_systems::combat::melee_system;
+    use crate::plugins::game_state_systems::lifecycle::npc_turn_end_system;
+    use crate::res::turn_counter::TurnCounter;
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map::TileMap;
+    use crate::ui::tile_map_layout_generator::test::{split_room, TestTileMapGenerator};
+
+    use super::*;
+
+    fn spawn_enemy(app: &mut App, position: [i32; 2], sees_player: bool) -> Entity {
+        let mut fov = Fov::new(8);
+
+        if sees_player {
+            fov.replace_positions(&[(0, 0)]);
+        }
+
+        app.world
+            .spawn((
+                Coord2d::from_position(&position),
+                fov,
+                NameTag::new("Mended"),
+                EnemyType::Mended,
+                NpcState::default(),
+            ))
+            .id()
+    }
+
+    fn setup_app() -> (App, Entity, Entity, Entity) {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Npc);
+        app.insert_resource(GameplayConfig {
+            alert_radius: 5,
+            ..Default::default()
+        });
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_systems(Update, enemy_line_of_sight_system);
+
+        app.world
+            .spawn(GameMap::new(&[20, 20], &TestTileMapGenerator, None));
+        app.world.spawn((Coord2d::new(0, 0), Player));
+
+        let spotter = spawn_enemy(&mut app, [3, 0], true);
+        let nearby = spawn_enemy(&mut app, [6, 4], false);
+        let distant = spawn_enemy(&mut app, [9, 0], false);
+
+        (app, spotter, nearby, distant)
+    }
+
     #[test]
-    fn test_enemy_line_of_sight_system() {}
+    fn test_enemy_line_of_sight_system() {
+        let (mut app, spotter, _, _) = setup_app();
+
+        app.update();
+
+        let npc_state = app.world.get::<NpcState>(spotter).unwrap();
+
+        assert!(npc_state.is_seeing_player);
+        assert_eq!(Some([0, 0]), npc_state.last_known_player_pos);
+        assert_eq!(
+            "Mended gurgles and shifts at your presence.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_line_of_sight_leaves_movement_to_chase_system() {
+        let (mut app, ..) = setup_app();
+
+        app.update();
+
+        assert!(app.world.resource::<ActionQueue>().is_empty());
+
+        let (mut app, spotter, ..) = setup_app();
+        let mut map = app.world.query::<&mut GameMap>().single_mut(&mut app.world);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        app.add_systems(Update, enemy_chase_system.after(enemy_line_of_sight_system));
+        app.update();
+
+        let actions = app.world.resource_mut::<ActionQueue>().drain();
+
+        assert!(actions
+            .iter()
+            .any(|action| matches!(action, Action::Move { actor, .. } if *actor == spotter)));
+    }
+
+    #[test]
+    fn test_losing_sight_retains_last_known_player_position() {
+        let (mut app, spotter, _, _) = setup_app();
+
+        app.update();
+
+        app.world
+            .get_mut::<Fov>(spotter)
+            .unwrap()
+            .replace_positions(&[]);
+        *app.world
+            .query_filtered::<&mut Coord2d, With<Player>>()
+            .single_mut(&mut app.world) = Coord2d::new(0, 5);
+        app.insert_resource(NpcTurnQueue::default());
+        app.update();
+
+        let npc_state = app.world.get::<NpcState>(spotter).unwrap();
+
+        assert!(!npc_state.is_seeing_player);
+        assert_eq!(Some([0, 0]), npc_state.last_known_player_pos);
+    }
+
+    #[test]
+    fn test_alert_wakes_nearby_enemies_only() {
+        let (mut app, _, nearby, distant) = setup_app();
+
+        app.update();
+
+        let nearby_state = app.world.get::<NpcState>(nearby).unwrap();
+
+        assert!(!nearby_state.is_seeing_player);
+        assert_eq!(Some([0, 0]), nearby_state.last_known_player_pos);
+        assert_eq!(
+            None,
+            app.world
+                .get::<NpcState>(distant)
+                .unwrap()
+                .last_known_player_pos
+        );
+    }
+
+    #[test]
+    fn test_alert_disabled_by_zero_radius() {
+        let (mut app, _, nearby, _) = setup_app();
+
+        app.world.resource_mut::<GameplayConfig>().alert_radius = 0;
+        app.update();
+
+        assert_eq!(
+            None,
+            app.world
+                .get::<NpcState>(nearby)
+                .unwrap()
+                .last_known_player_pos
+        );
+    }
+
+    #[test]
+    fn test_ai_budget_spreads_enemies_across_frames() {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Npc);
+        app.insert_resource(GameplayConfig {
+            alert_radius: 0,
+            ai_budget: 3,
+            ..Default::default()
+        });
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_systems(Update, enemy_line_of_sight_system);
+        app.add_systems(PostUpdate, npc_turn_end_system);
+
+        app.world
+            .spawn(GameMap::new(&[20, 20], &TestTileMapGenerator, None));
+        app.world.spawn((Coord2d::new(0, 0), Player));
+
+        for x in 1..=10 {
+            spawn_enemy(&mut app, [x, 0], true);
+        }
+
+        for expected in [3, 6, 9, 10] {
+            assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+
+            app.update();
+
+            let seeing_count = app
+                .world
+                .query::<&NpcState>()
+                .iter(&app.world)
+                .filter(|npc_state| npc_state.is_seeing_player)
+                .count();
+
+            assert_eq!(expected, seeing_count);
+        }
+
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+        assert!(!app.world.resource::<NpcTurnQueue>().is_active());
+    }
+
+    #[test]
+    fn test_speed_delays_enemy_movement() {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Player);
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_event::<Attack>();
+        app.add_systems(
+            Update,
+            (
+                enemy_line_of_sight_system,
+                enemy_chase_system,
+                action_system,
+            )
+                .chain(),
+        );
+        app.add_systems(PostUpdate, npc_turn_end_system);
+
+        let mut map = GameMap::new(&[20, 20], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        app.world.spawn(map);
+        app.world.spawn((Coord2d::new(2, 2), Player));
+
+        let normal = spawn_enemy(&mut app, [12, 2], false);
+        let slow = spawn_enemy(&mut app, [12, 6], false);
+
+        app.world.entity_mut(normal).insert(Speed::new(1));
+        app.world.entity_mut(slow).insert(Speed::new(2));
+
+        for entity in [normal, slow] {
+            app.world
+                .get_mut::<NpcState>(entity)
+                .unwrap()
+                .last_known_player_pos = Some([2, 2]);
+        }
+
+        for (normal_x, slow_x) in [(11, 12), (10, 11), (9, 11), (8, 10)] {
+            *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+            app.update();
+
+            assert_eq!(
+                GameTurnState::Player,
+                *app.world.resource::<GameTurnState>()
+            );
+            assert_eq!(
+                &Coord2d::new(normal_x, 2),
+                app.world.get::<Coord2d>(normal).unwrap()
+            );
+            assert_eq!(
+                &Coord2d::new(slow_x, 6),
+                app.world.get::<Coord2d>(slow).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_adjacent_enemy_attacks_player() {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Npc);
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_event::<Attack>();
+        app.add_systems(
+            Update,
+            (
+                enemy_line_of_sight_system,
+                enemy_chase_system,
+                action_system,
+                melee_system,
+            )
+                .chain(),
+        );
+        app.add_systems(PostUpdate, npc_turn_end_system);
+
+        let mut map = GameMap::new(&[20, 20], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        app.world.spawn(map);
+
+        let player = app
+            .world
+            .spawn((Coord2d::new(2, 2), Player, Health::new(10)))
+            .id();
+        let attacker = spawn_enemy(&mut app, [3, 2], false);
+
+        app.world
+            .entity_mut(attacker)
+            .insert(CombatStats::new(3, 0));
+        app.world
+            .get_mut::<NpcState>(attacker)
+            .unwrap()
+            .last_known_player_pos = Some([2, 2]);
+        app.update();
+
+        assert_eq!(7, app.world.get::<Health>(player).unwrap().current);
+        assert_eq!(
+            &Coord2d::new(3, 2),
+            app.world.get::<Coord2d>(attacker).unwrap()
+        );
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+    }
+
+    #[test]
+    fn test_enemy_routes_around_wall() {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Player);
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_event::<Attack>();
+        app.add_systems(
+            Update,
+            (
+                enemy_line_of_sight_system,
+                enemy_chase_system,
+                action_system,
+            )
+                .chain(),
+        );
+        app.add_systems(PostUpdate, npc_turn_end_system);
+
+        app.world.spawn(split_room());
+        app.world.spawn((Coord2d::new(1, 5), Player));
+
+        let chaser = spawn_enemy(&mut app, [7, 2], false);
+
+        app.world
+            .get_mut::<NpcState>(chaser)
+            .unwrap()
+            .last_known_player_pos = Some([3, 2]);
+
+        for _ in 0..10 {
+            *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+            app.update();
+        }
+
+        assert_eq!(
+            &Coord2d::new(3, 2),
+            app.world.get::<Coord2d>(chaser).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_idle_enemy_stays_within_leash() {
+        let mut app = App::new();
+
+        app.insert_resource(GameTurnState::Player);
+        app.insert_resource(GameplayConfig {
+            leash_radius: 3,
+            ..Default::default()
+        });
+        app.insert_resource(NpcTurnQueue::default());
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MessageLog::default());
+        app.insert_resource(ActionQueue::default());
+        app.add_event::<Attack>();
+        app.add_systems(
+            Update,
+            (
+                enemy_line_of_sight_system,
+                enemy_chase_system,
+                action_system,
+            )
+                .chain(),
+        );
+        app.add_systems(PostUpdate, npc_turn_end_system);
+
+        let mut map = GameMap::new(&[20, 20], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
+            }
+        }
+
+        app.world.spawn(map);
+        app.world.spawn((Coord2d::new(0, 19), Player));
+
+        let wanderer = spawn_enemy(&mut app, [4, 4], false);
+        let stray = spawn_enemy(&mut app, [16, 12], false);
+
+        app.world.entity_mut(wanderer).insert(Leash::new(&[4, 4]));
+        app.world.entity_mut(stray).insert(Leash::new(&[10, 10]));
+        app.world
+            .get_mut::<NpcState>(wanderer)
+            .unwrap()
+            .last_known_player_pos = Some([18, 4]);
+
+        for _ in 0..30 {
+            *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+            app.update();
+
+            let wanderer_position = app.world.get::<Coord2d>(wanderer).unwrap();
+
+            assert!(wanderer_position.chebyshev_distance(&[4, 4]) <= 4);
+        }
+
+        for (entity, home) in [(wanderer, [4, 4]), (stray, [10, 10])] {
+            let position = app.world.get::<Coord2d>(entity).unwrap();
+
+            assert!(position.chebyshev_distance(&home) <= 3);
+            assert_eq!(
+                None,
+                app.world
+                    .get::<NpcState>(entity)
+                    .unwrap()
+                    .last_known_player_pos
+            );
+        }
+    }
 }
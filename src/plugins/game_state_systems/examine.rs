@@ -0,0 +1,298 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{Entity, EventReader, Query, Res, ResMut, Without};
+
+use crate::components::coord_2d::Coord2d;
+use crate::components::name_tag::NameTag;
+use crate::components::player::Player;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::log_view::LogView;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::targeting_mode::TargetingMode;
+use crate::ui::game_map::GameMap;
+use crate::ui::tile_map::TileMap;
+
+/// System handling the [ExamineMode], in which the player cycles a selection cursor through all
+/// currently visible, named `entities`, e.g., monsters and items.
+///
+/// The [InputType::Examine] key toggles the mode. On activation, all `entities` with a [NameTag] on a
+/// visible tile of the [GameMap] are collected in a deterministic order, from the top row of the map to
+/// the bottom and from left to right within a row. While active, the movement keys cycle the selection
+/// ([InputType::Right] and [InputType::Up] select the next, [InputType::Left] and [InputType::Down] the
+/// previous `entity`), and [InputType::Cancel] exits the mode. The mode can't be activated while the
+/// [LogView] is open.
+///
+/// # Arguments
+///
+/// * `examine_keys`: The [ExamineKeys] providing the keys pressed by the user.
+/// * `examine_mode`: The [ExamineMode] resource to update.
+/// * `message_log`: [MessageLog] to inform the player if there is nothing to examine.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], which is used to check the visibility of the
+///   `entities`.
+/// * `target_query`: [Query] to retrieve the positions of all named `entities` except the `player`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the [GameMap] can't be retrieved when the mode is activated.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn examine_system(
+    mut examine_keys: ExamineKeys,
+    mut examine_mode: ResMut<ExamineMode>,
+    mut message_log: ResMut<MessageLog>,
+    game_map_query: Query<&GameMap>,
+    target_query: Query<(Entity, &Coord2d, &NameTag), Without<Player>>,
+) {
+    for input in examine_keys.read() {
+        if !examine_mode.is_active() {
+            if input != InputType::Examine {
+                continue;
+            }
+
+            let game_map = game_map_query.get_single().expect(
+                "ECS -> Systems -> examine_system -> Unable to retrieve {GameMap} component!",
+            );
+
+            let mut targets: Vec<(Entity, &Coord2d)> = target_query
+                .iter()
+                .filter(|(_, position, _)| game_map.is_tile_visible(*position))
+                .map(|(entity, position, _)| (entity, position))
+                .collect();
+
+            targets.sort_by_key(|(entity, position)| (-position.y, position.x, *entity));
+
+            if !examine_mode.activate(targets.into_iter().map(|(entity, _)| entity).collect()) {
+                message_log.push("There is nothing to examine.", LogCategory::Warning);
+            }
+
+            continue;
+        }
+
+        match input {
+            InputType::Right | InputType::Up => examine_mode.next(),
+            InputType::Left | InputType::Down => examine_mode.previous(),
+            InputType::Examine | InputType::Cancel => examine_mode.deactivate(),
+            _ => {}
+        }
+    }
+}
+
+/// The keys pressed by the user, which are meant for the [ExamineMode], i.e., those not captured by the [LogView] or
+/// the [TargetingMode], as they reuse the movement keys.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct ExamineKeys<'w, 's> {
+    /// [InputConfig] required to recognize the user's input.
+    input_config: Res<'w, InputConfig>,
+    /// [LogView] to check, as the log view reuses the movement keys.
+    log_view: Res<'w, LogView>,
+    /// [TargetingMode] to check, as the targeting mode reuses the movement keys.
+    targeting_mode: Res<'w, TargetingMode>,
+    /// [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+    key_events: EventReader<'w, 's, KeyboardInput>,
+}
+
+impl ExamineKeys<'_, '_> {
+    /// Reads all [KeyboardInput] events received since the last call, parsing the pressed keys into the respective
+    /// [InputType]s. Unknown keys are skipped, while all keys are dropped as long as the [LogView] is open or the
+    /// [TargetingMode] is active.
+    ///
+    /// returns: [Vec]<[InputType]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn read(&mut self) -> Vec<InputType> {
+        let is_captured = self.log_view.is_open() || self.targeting_mode.is_active();
+
+        self.key_events
+            .read()
+            .filter(|event| event.state == ButtonState::Pressed && !is_captured)
+            .filter_map(|event| event.key_code)
+            .filter_map(|key_code| self.input_config.parse_input(key_code))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode};
+
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> (App, Vec<Entity>, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::default());
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(MessageLog::default());
+        app.add_systems(Update, examine_system);
+
+        let mut game_map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        for position in [[5, 5], [1, 8], [7, 5]] {
+            game_map.mark_tile_as_visible(&position);
+        }
+
+        app.world.spawn(game_map);
+
+        let visible = [[5, 5], [1, 8], [7, 5]].map(|position| {
+            app.world
+                .spawn((Coord2d::from_position(&position), NameTag::new("Mended")))
+                .id()
+        });
+
+        let hidden = app
+            .world
+            .spawn((Coord2d::new(2, 2), NameTag::new("Mended")))
+            .id();
+
+        app.world
+            .spawn((Coord2d::new(5, 5), NameTag::new("Player"), Player));
+
+        // Sorted from the top row of the map to the bottom, left to right.
+        (app, vec![visible[1], visible[0], visible[2]], hidden)
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    #[test]
+    fn test_examine_system_skips_hidden_entities() {
+        let (mut app, visible, hidden) = setup_app();
+
+        press(&mut app, KeyCode::L);
+
+        let examine_mode = app.world.resource::<ExamineMode>();
+
+        assert!(examine_mode.is_active());
+        assert_eq!(&visible, examine_mode.targets());
+        assert!(!examine_mode.targets().contains(&hidden));
+    }
+
+    #[test]
+    fn test_examine_system_cycles_and_wraps() {
+        let (mut app, visible, _) = setup_app();
+
+        press(&mut app, KeyCode::L);
+
+        let mut visited = Vec::new();
+
+        for _ in 0..visible.len() {
+            visited.push(
+                app.world
+                    .resource::<ExamineMode>()
+                    .selected_entity()
+                    .unwrap(),
+            );
+            press(&mut app, KeyCode::D);
+        }
+
+        assert_eq!(visible, visited);
+        assert_eq!(
+            Some(visible[0]),
+            app.world.resource::<ExamineMode>().selected_entity()
+        );
+
+        press(&mut app, KeyCode::A);
+
+        assert_eq!(
+            Some(visible[2]),
+            app.world.resource::<ExamineMode>().selected_entity()
+        );
+
+        press(&mut app, KeyCode::Escape);
+
+        assert!(!app.world.resource::<ExamineMode>().is_active());
+    }
+
+    #[test]
+    fn test_examine_system_without_visible_entities() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::default());
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(MessageLog::default());
+        app.add_systems(Update, examine_system);
+
+        app.world
+            .spawn(GameMap::new(&[10, 10], &TestTileMapGenerator, None));
+        app.world
+            .spawn((Coord2d::new(2, 2), NameTag::new("Mended")));
+
+        press(&mut app, KeyCode::L);
+
+        assert!(!app.world.resource::<ExamineMode>().is_active());
+        assert_eq!(
+            "There is nothing to examine.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+}
@@ -19,33 +19,170 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
-use bevy::prelude::{Mut, Query, With, Without};
+use bevy::ecs::system::SystemParam;
+#[cfg(feature = "debug_tools")]
+use bevy::input::keyboard::KeyboardInput;
+#[cfg(feature = "debug_tools")]
+use bevy::input::ButtonState;
+use bevy::log::debug;
+use bevy::prelude::{EventReader, Mut, Query, Res, ResMut, With, Without};
 
 use crate::components::coord_2d::Coord2d;
+use crate::components::facing::Facing;
 use crate::components::fov::Fov;
-use crate::ui::game_map::GameMap;
 use crate::components::player::Player;
 use crate::core::algorithm::field_of_view;
+use crate::events::map_changed::MapChanged;
+use crate::res::fov_algorithm::FovAlgorithm;
+use crate::res::fov_cache::FovCache;
+use crate::res::fov_shape::FovShape;
+use crate::res::gameplay_config::GameplayConfig;
+#[cfg(feature = "debug_tools")]
+use crate::res::input_config::{InputConfig, InputType};
+#[cfg(feature = "debug_tools")]
+use crate::res::message_log::MessageLog;
+use crate::res::turn_counter::TurnCounter;
+use crate::ui::game_map::GameMap;
 use crate::ui::tile_map::TileMap;
 
+/// System invalidating all cached `field of view` data once the layout of the [GameMap] changed,
+/// as signaled by a [MapChanged] event.
+///
+/// Refreshes the fingerprint of the [GameMap], clears the [FovCache] and marks every [Fov] as dirty,
+/// so the following [fov_system] run recalculates them.
+///
+/// # Arguments
+///
+/// * `map_changed_reader`: [EventReader] receiving the [MapChanged] events.
+/// * `fov_cache`: The [FovCache] resource to clear.
+/// * `game_map_query`: [Query] required to retrieve the game map for refreshing its fingerprint.
+/// * `fov_query`: [Query] required to mark all `field of view` components as dirty.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [FovCache] resource can't be retrieved from the ECS.
+/// * If the [GameMap] can't be retrieved from the ECS after a [MapChanged] event.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn fov_cache_invalidation_system(
+    mut map_changed_reader: EventReader<MapChanged>,
+    mut fov_cache: ResMut<FovCache>,
+    mut game_map_query: Query<&mut GameMap>,
+    mut fov_query: Query<&mut Fov>,
+) {
+    if map_changed_reader.is_empty() {
+        return;
+    }
+
+    map_changed_reader.clear();
+
+    game_map_query
+        .get_single_mut()
+        .expect("ECS -> Systems -> fov_cache_invalidation_system -> Unable to retrieve {GameMap} component!")
+        .refresh_fingerprint();
+
+    debug!(
+        "ECS -> Systems -> fov_cache_invalidation_system -> Map changed, discarding {} cached fov results.",
+        fov_cache.len()
+    );
+
+    fov_cache.clear();
+
+    for mut fov in fov_query.iter_mut() {
+        fov.is_dirty = true;
+    }
+}
+
+/// Debug system toggling the [FovAlgorithm] resource, once the [InputType::ToggleFov] key is pressed,
+/// so the visible sets of both algorithms can be compared live.
+///
+/// The choice is also stored in the in-memory [GameplayConfig], so it carries over to the next run of the same
+/// session, but it isn't written back to the `gameplay.json`. The [FovCache] keys its results by the [FovAlgorithm],
+/// so it is kept as is, while every [Fov] is marked as dirty to force a recalculation with the new algorithm.
+///
+/// Only available with the `debug_tools` feature enabled.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] resource required to parse the user's input.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+/// * `fov_algorithm`: The [FovAlgorithm] resource to toggle.
+/// * `gameplay_config`: The [GameplayConfig] resource keeping the choice for the next run.
+/// * `message_log`: The [MessageLog] resource informing the player about the active algorithm.
+/// * `fov_query`: [Query] required to mark all `field of view` components as dirty.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[cfg(feature = "debug_tools")]
+pub fn fov_algorithm_toggle_system(
+    input_config: Res<InputConfig>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut fov_algorithm: ResMut<FovAlgorithm>,
+    mut gameplay_config: ResMut<GameplayConfig>,
+    mut message_log: ResMut<MessageLog>,
+    mut fov_query: Query<&mut Fov>,
+) {
+    let toggle_count = key_events
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .filter_map(|event| event.key_code)
+        .filter(|key_code| input_config.parse_input(*key_code) == Some(InputType::ToggleFov))
+        .count();
+
+    if toggle_count % 2 == 0 {
+        return;
+    }
+
+    *fov_algorithm = fov_algorithm.toggled();
+    gameplay_config.fov_algorithm = *fov_algorithm;
+
+    for mut fov in fov_query.iter_mut() {
+        fov.is_dirty = true;
+    }
+
+    message_log.push_info(format!("Field of view algorithm: {}.", *fov_algorithm));
+}
+
 /// System to calculate and update the [Fov] of `actor` `entities` such as the `player`,
 /// `monsters`, `NPC`s, etc., while the `player` traverses the game's world.
 ///
 /// # Arguments
 ///
+/// * `fov_calculation`: The [FovCalculation] calculating the `field of view` of each `entity`.
+/// * `turn_counter`: The [TurnCounter] resource, whose current turn is recorded for all tiles the `player` sees.
+/// * `gameplay_config`: The [GameplayConfig] resource determining if the room the `player` steps into is revealed
+///   entirely, see [GameplayConfig::reveal_rooms].
 /// * `game_map_query`: [Query] required to retrieve the game map for the
 /// `field of view` calculation.
 /// * `fov_query`: [Query] required to retrieve and update the `field of view`
-/// of all `non-player entities`.
+/// of all `non-player entities`, considering their [Facing] for directional [FovShape]s.
 /// * `player_fov_query`: [Query] required to retrieve and update the `field of view`
-/// of the `player entity`.
+/// of the `player entity`, considering their [Facing] for directional [FovShape]s.
 ///
 /// returns: ()
 ///
 /// # Panics
 ///
 /// * If any of the [Query] calls fail.
-/// * If any of the required components can't be retrieved from the ECS.
+/// * If any of the required components, the [FovCache], the [FovAlgorithm], the [FovShape], the [TurnCounter] or
+///   the [GameplayConfig] can't be retrieved from the ECS.
 ///
 /// # About
 ///
@@ -54,31 +191,266 @@ use crate::ui::tile_map::TileMap;
 /// Since: `0.1.7`
 ///
 pub fn fov_system(
+    mut fov_calculation: FovCalculation,
+    turn_counter: Res<TurnCounter>,
+    gameplay_config: Res<GameplayConfig>,
     mut game_map_query: Query<&mut GameMap>,
-    mut fov_query: Query<(&mut Fov, &Coord2d), Without<Player>>,
-    mut player_fov_query: Query<(&mut Fov, &Coord2d), With<Player>>,
+    mut fov_query: Query<(&mut Fov, &Coord2d, Option<&Facing>), Without<Player>>,
+    mut player_fov_query: Query<(&mut Fov, &Coord2d, Option<&Facing>), With<Player>>,
 ) {
     let map = game_map_query
         .get_single_mut()
         .expect("ECS -> Systems -> fov_system -> Unable to retrieve {GameMap} component!")
         .into_inner();
 
-    for (mut fov, position) in fov_query.iter_mut() {
-        field_of_view(&mut fov, position, map);
+    for (mut fov, position, facing) in fov_query.iter_mut() {
+        fov_calculation.calculate(&mut fov, position, map, facing.copied());
     }
 
     // Calculate `field of view` for the `player entity`.
-    let (mut player_fov, player_position): (Mut<Fov>, &Coord2d) = player_fov_query.get_single_mut().expect(
+    let (mut player_fov, player_position, player_facing): (Mut<Fov>, &Coord2d, Option<&Facing>) = player_fov_query.get_single_mut().expect(
         "ECS -> Systems -> fov_system -> Unable to retrieve the player's {Fov} and/or {Coord2d} components!"
     );
 
-    field_of_view(&mut player_fov, player_position, map);
+    // The `field of view` of the player is only dirty if they moved since the last calculation.
+    let player_moved = player_fov.is_dirty;
+
+    fov_calculation.calculate(
+        &mut player_fov,
+        player_position,
+        map,
+        player_facing.copied(),
+    );
 
     // Update the `GameMap` with the `field of view` calculation result of the `player entity`.
     map.reset_visible_tiles();
 
-    for position in player_fov.positions() {
-        map.mark_tile_as_seen(position);
-        map.mark_tile_as_visible(position);
+    for position in player_fov.iter_positions() {
+        map.mark_tile_as_seen(&position);
+        map.mark_tile_as_visible(&position);
+        map.remember_tile(&position, turn_counter.current());
+    }
+
+    if gameplay_config.reveal_rooms && player_moved {
+        map.reveal_room_at(player_position, turn_counter.current());
+    }
+}
+
+/// The resources determining how the [fov_system] calculates the `field of view` of the `entities`.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct FovCalculation<'w> {
+    /// The [FovCache] resource consulted before recalculating a `field of view`.
+    fov_cache: ResMut<'w, FovCache>,
+    /// The [FovAlgorithm] resource selecting the algorithm used for the calculation.
+    fov_algorithm: Res<'w, FovAlgorithm>,
+    /// The [FovShape] resource selecting the shape of the calculated `field of view`.
+    fov_shape: Res<'w, FovShape>,
+}
+
+impl FovCalculation<'_> {
+    /// Calculates the passed `fov` from the `position` on the `map`, unless it isn't dirty, see [field_of_view].
+    ///
+    /// # Arguments
+    ///
+    /// * `fov`: The [Fov] component to update.
+    /// * `position`: The position of the `entity`, from which the `field of view` is calculated.
+    /// * `map`: The [GameMap] on which the `field of view` is calculated.
+    /// * `facing`: The optional [Facing] of the `entity`, considered by directional [FovShape]s only.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn calculate(
+        &mut self,
+        fov: &mut Fov,
+        position: &Coord2d,
+        map: &mut GameMap,
+        facing: Option<Facing>,
+    ) {
+        field_of_view(
+            fov,
+            position,
+            map,
+            &mut self.fov_cache,
+            *self.fov_algorithm,
+            *self.fov_shape,
+            facing,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::plugins::game_state_systems::lifecycle::startup_system;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::palette::Palette;
+    use crate::res::terminal_layout::TerminalLayout;
+    use crate::ui::tile::MapTile;
+
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.add_event::<MapChanged>();
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, (fov_cache_invalidation_system, fov_system).chain());
+
+        app.update();
+
+        app
+    }
+
+    #[test]
+    fn test_entered_room_is_revealed() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            reveal_rooms: true,
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, fov_system);
+
+        app.update();
+
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+        let room = game_map.rooms()[0].bounds;
+
+        for x in room.left..=room.right {
+            for y in room.bottom..=room.top {
+                assert!(game_map.is_tile_seen(&[x, y]), "[{}, {}] is unseen", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fov_system_fills_cache() {
+        let mut app = setup_app();
+
+        let fov_count = app.world.query::<&Fov>().iter(&app.world).len();
+        let fov_cache = app.world.resource::<FovCache>();
+
+        assert_eq!(fov_count, fov_cache.misses() + fov_cache.hits());
+        assert_ne!(0, fov_cache.len());
+    }
+
+    #[test]
+    fn test_map_changed_invalidates_cache() {
+        let mut app = setup_app();
+
+        let fingerprint = app
+            .world
+            .query::<&GameMap>()
+            .single(&app.world)
+            .fingerprint();
+        let misses = app.world.resource::<FovCache>().misses();
+
+        let mut game_map = app.world.query::<&mut GameMap>().single_mut(&mut app.world);
+        game_map.set_tile_at(&[0, 0], MapTile::floor('.'));
+
+        app.world.send_event(MapChanged);
+        app.update();
+
+        let fov_count = app.world.query::<&Fov>().iter(&app.world).len();
+        let fov_cache = app.world.resource::<FovCache>();
+
+        assert_eq!(misses + fov_count, fov_cache.misses());
+        assert_ne!(
+            fingerprint,
+            app.world
+                .query::<&GameMap>()
+                .single(&app.world)
+                .fingerprint()
+        );
+    }
+
+    fn player_fov_algorithm(app: &mut App) -> Option<FovAlgorithm> {
+        app.world
+            .query_filtered::<&Fov, With<Player>>()
+            .single(&app.world)
+            .algorithm
+    }
+
+    #[test]
+    fn test_fov_system_dispatches_to_selected_algorithm() {
+        let mut app = setup_app();
+
+        assert_eq!(
+            Some(FovAlgorithm::Bresenham),
+            player_fov_algorithm(&mut app)
+        );
+
+        *app.world.resource_mut::<FovAlgorithm>() = FovAlgorithm::Shadowcast;
+        app.world.resource_mut::<FovCache>().clear();
+
+        for mut fov in app.world.query::<&mut Fov>().iter_mut(&mut app.world) {
+            fov.is_dirty = true;
+        }
+
+        app.update();
+
+        assert_eq!(
+            Some(FovAlgorithm::Shadowcast),
+            player_fov_algorithm(&mut app)
+        );
+    }
+
+    #[cfg(feature = "debug_tools")]
+    #[test]
+    fn test_fov_algorithm_toggle_system_forces_recalculation() {
+        let mut app = setup_app();
+
+        app.insert_resource(InputConfig::default());
+        app.add_event::<KeyboardInput>();
+        app.add_systems(
+            Update,
+            fov_algorithm_toggle_system.before(fov_cache_invalidation_system),
+        );
+
+        let window = app.world.spawn_empty().id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::F1),
+            state: ButtonState::Pressed,
+            window,
+        });
+        app.update();
+
+        assert_eq!(
+            FovAlgorithm::Shadowcast,
+            *app.world.resource::<FovAlgorithm>()
+        );
+        assert_eq!(
+            FovAlgorithm::Shadowcast,
+            app.world.resource::<GameplayConfig>().fov_algorithm
+        );
+        assert_eq!(
+            Some(FovAlgorithm::Shadowcast),
+            player_fov_algorithm(&mut app)
+        );
     }
 }
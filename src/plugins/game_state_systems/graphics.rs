@@ -19,15 +19,38 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
-use bevy::prelude::{Query, With, Without};
-use bevy_ascii_terminal::Terminal;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Query, Res, Time, With, Without};
+use bevy_ascii_terminal::{StringFormatter, Terminal, TileFormatter};
 
 use crate::components::ascii_sprite::AsciiSprite;
+use crate::components::combat_stats::CombatStats;
 use crate::components::coord_2d::Coord2d;
 use crate::ui::game_map::GameMap;
 use crate::components::game_terminal::GameTerminal;
+use crate::components::health::Health;
+use crate::components::name_tag::NameTag;
 use crate::components::player::Player;
-use crate::core::position_2d::Position2d;
+use crate::core::constants;
+use crate::core::dimension_2d::Dimension2d;
+use crate::plugins::game_state_systems::targeting::line_of_fire;
+#[cfg(feature = "debug_tools")]
+use crate::plugins::states::GameTurnState;
+use crate::res::animation_queue::{AnimationKind, AnimationQueue};
+use crate::res::cursor_hud::CursorHud;
+#[cfg(feature = "debug_tools")]
+use crate::res::debug_footer::DebugFooter;
+use crate::res::dungeon_depth::DungeonDepth;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::log_view::LogView;
+use crate::res::map_camera::MapCamera;
+use crate::res::message_log::MessageLog;
+use crate::res::palette::Palette;
+use crate::res::targeting_mode::TargetingMode;
+use crate::res::terminal_layout::TerminalLayout;
+use crate::res::turn_counter::TurnCounter;
+use crate::ui::colors;
 use crate::ui::tile::Tile;
 use crate::ui::tile_map::TileMap;
 
@@ -37,9 +60,16 @@ use crate::ui::tile_map::TileMap;
 /// # Arguments
 ///
 /// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the next frame.
+/// * `map_camera`: The [MapCamera] resource, whose [crate::ui::viewport::Viewport] selects the section of the
+///   [GameMap] shown in the map panel.
+/// * `gameplay_config`: The [GameplayConfig] resource determining if seen but no longer visible tiles and
+///   `entities` stay on screen, see [GameplayConfig::tile_memory], and if they fade over time, see
+///   [GameplayConfig::sight_memory_fade].
+/// * `turn_counter`: The [TurnCounter] resource, against which the fading of remembered tiles is calculated.
+/// * `time`: The [Time] resource, from which the tick of animated tile backgrounds is derived, see
+///   [constants::TILE_ANIMATION_TICK_MS].
 /// * `game_map_query`: [Query] to retrieve the [GameMap] for rendering.
-/// * `player_query`: [Query] to retrieve the render data for the `player entity`.
-/// * `actors_query`: [Query] to retrieve the render data for all other renderable `entities`.
+/// * `sprites`: The [Sprites] of the `player entity` and all other renderable `entities`.
 ///
 /// # Panics
 ///
@@ -61,9 +91,12 @@ use crate::ui::tile_map::TileMap;
 ///
 pub fn render_system(
     mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    map_camera: Res<MapCamera>,
+    gameplay_config: Res<GameplayConfig>,
+    turn_counter: Res<TurnCounter>,
+    time: Res<Time>,
     game_map_query: Query<&GameMap>,
-    player_query: Query<(&Coord2d, &AsciiSprite), With<Player>>,
-    actors_query: Query<(&Coord2d, &AsciiSprite), Without<Player>>,
+    sprites: Sprites,
 ) {
     let mut terminal = terminal_query
         .get_single_mut()
@@ -75,23 +108,575 @@ pub fn render_system(
         .get_single()
         .expect("ECS -> Systems -> render_system -> Unable to retrieve {GameMap} component!");
 
-    game_map.render(&mut terminal);
+    game_map.render(
+        &mut terminal,
+        &map_camera.viewport,
+        gameplay_config.tile_memory,
+    );
+
+    if gameplay_config.tile_memory && gameplay_config.sight_memory_fade {
+        game_map.render_memory_fade(&mut terminal, &map_camera.viewport, turn_counter.current());
+    }
 
-    for (coord, sprite) in actors_query.iter() {
-        sprite.render(
-            &coord.as_array(),
+    game_map.render_animated_backgrounds(
+        &mut terminal,
+        &map_camera.viewport,
+        time.elapsed().as_millis() as u64 / constants::TILE_ANIMATION_TICK_MS,
+    );
+
+    for (coord, sprite) in sprites.actors_query.iter() {
+        game_map.render_entity_at(
+            coord,
+            sprite,
             &mut terminal,
-            game_map.is_tile_seen(coord),
-            game_map.is_tile_visible(coord),
+            &map_camera.viewport,
+            gameplay_config.tile_memory,
         );
     }
 
-    let (player_position, player_sprite) = player_query.get_single().expect(
+    let (player_position, player_sprite) = sprites.player_query.get_single().expect(
         "ECS -> Systems -> render_system -> Unable to retrieve {Coord2d} and/or {AsciiSprite} component \
         for the player entity!"
     );
 
-    player_sprite.render(player_position, &mut terminal, true, true);
+    if let Some(screen_position) = map_camera.viewport.world_to_screen(player_position) {
+        player_sprite.render(&screen_position, &mut terminal, true, true);
+    }
+}
+
+/// The [AsciiSprite]s rendered by the [render_system] on top of the [GameMap], split into the one of the
+/// `player entity` and those of all other renderable `entities`.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct Sprites<'w, 's> {
+    /// [Query] to retrieve the render data for the `player entity`.
+    player_query: Query<'w, 's, (&'static Coord2d, &'static AsciiSprite), With<Player>>,
+    /// [Query] to retrieve the render data for all other renderable `entities`.
+    actors_query: Query<'w, 's, (&'static Coord2d, &'static AsciiSprite), Without<Player>>,
+}
+
+/// Renders the most recent messages of the [MessageLog] in the rows reserved above the [GameMap],
+/// see [constants::MESSAGE_LOG_HEIGHT]. The newest message is placed directly above the map, with
+/// each message colored according to the [Palette] color of its [crate::res::message_log::LogCategory].
+/// Older messages fade towards [colors::INACTIVE], with the oldest reserved row using it entirely.
+///
+/// Has to run after the [render_system], which clears the [Terminal].
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the messages.
+/// * `message_log`: The [MessageLog] resource holding the messages to render.
+/// * `palette`: The [Palette] resource defining the colors of the messages.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If the [MessageLog] or [Palette] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn message_log_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    message_log: Res<MessageLog>,
+    palette: Res<Palette>,
+) {
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> message_log_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    let first_row = terminal.height() as i32 - constants::MESSAGE_LOG_HEIGHT;
+
+    for (row, entry) in message_log
+        .recent(constants::MESSAGE_LOG_HEIGHT as usize)
+        .enumerate()
+    {
+        let age = row as f32 / (constants::MESSAGE_LOG_HEIGHT - 1).max(1) as f32;
+
+        terminal.put_string(
+            [0, first_row + row as i32],
+            entry.text.as_str().fg(colors::blend(
+                palette.log_color(entry.category),
+                colors::INACTIVE,
+                age,
+            )),
+        );
+    }
+}
+
+/// Renders the sidebar next to the [GameMap], see [TerminalLayout::sidebar], listing the [Health] and
+/// [CombatStats] of the `player` as well as the current [DungeonDepth] and turn. Lines longer than the sidebar
+/// are cut off, so they never reach into the map panel.
+///
+/// Has to run after the [render_system], which clears the [Terminal].
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the sidebar.
+/// * `terminal_layout`: The [TerminalLayout] resource holding the sidebar panel.
+/// * `dungeon_depth`: The [DungeonDepth] resource holding the current floor.
+/// * `turn_counter`: The [TurnCounter] resource holding the current turn.
+/// * `player_query`: [Query] to retrieve the [Health] and [CombatStats] of the `player entity`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn sidebar_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    terminal_layout: Res<TerminalLayout>,
+    dungeon_depth: Res<DungeonDepth>,
+    turn_counter: Res<TurnCounter>,
+    player_query: Query<(&Health, &CombatStats), With<Player>>,
+) {
+    let Ok((health, combat_stats)) = player_query.get_single() else {
+        return;
+    };
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> sidebar_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    let sidebar = terminal_layout.sidebar;
+    let column = sidebar.left + 1;
+    let max_len = (sidebar.width() - 1).max(0) as usize;
+
+    let lines = [
+        format!("HP: {}/{}", health.current, health.max),
+        format!("Power: {}", combat_stats.power),
+        format!("Defense: {}", combat_stats.defense),
+        format!("Depth: {}", dungeon_depth.current()),
+        format!("Turn: {}", turn_counter.current()),
+    ];
+
+    for (row, line) in lines.iter().enumerate() {
+        let row = sidebar.top - 1 - row as i32;
+
+        if row < sidebar.bottom {
+            break;
+        }
+
+        let text: String = line.chars().take(max_len).collect();
+        terminal.put_string([column, row], text.as_str().fg(colors::HUD));
+    }
+}
+
+/// Renders the [TargetingMode], if active, by highlighting the tiles along the [line_of_fire] from the `player` to
+/// the cursor. Tiles the line reaches are highlighted in [colors::TARGET_VALID], the ones out of sight or behind an
+/// obstacle in [colors::TARGET_INVALID].
+///
+/// Has to run after the [render_system], which clears the [Terminal].
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the line.
+/// * `map_camera`: The [MapCamera] resource translating the line into the map panel.
+/// * `targeting_mode`: The [TargetingMode] resource holding the cursor.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], which is used to trace the [line_of_fire].
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] or the [GameMap] can't be retrieved while the [TargetingMode] is active.
+/// * If the [TargetingMode] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn targeting_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    map_camera: Res<MapCamera>,
+    targeting_mode: Res<TargetingMode>,
+    game_map_query: Query<&GameMap>,
+) {
+    let Some(cursor) = targeting_mode.cursor() else {
+        return;
+    };
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> targeting_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    let game_map = game_map_query.get_single().expect(
+        "ECS -> Systems -> targeting_render_system -> Unable to retrieve {GameMap} component!",
+    );
+
+    for (tile, is_clear) in line_of_fire(game_map, &targeting_mode.origin(), &cursor) {
+        let Some(tile) = map_camera.viewport.world_to_screen(&tile) else {
+            continue;
+        };
+
+        let color = if is_clear {
+            colors::TARGET_VALID
+        } else {
+            colors::TARGET_INVALID
+        };
+
+        let glyph = terminal.get_char(tile);
+        terminal.put_char(tile, glyph.bg(color));
+    }
+}
+
+/// Renders the [ExamineMode], if active, by highlighting the tile of the selected `entity` and
+/// replacing the [MessageLog] with a detail panel showing the `entity's` [NameTag], [Health] and
+/// [CombatStats], if present. The name is colored by its [colors::name_color].
+///
+/// Has to run after the [message_log_render_system], whose rows the detail panel occupies.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the selection.
+/// * `map_camera`: The [MapCamera] resource translating the selected tile into the map panel.
+/// * `examine_mode`: The [ExamineMode] resource holding the selected `entity`.
+/// * `target_query`: [Query] to retrieve the position and [NameTag] of the selected `entity`.
+/// * `stats_query`: [Query] to retrieve the optional [Health] and [CombatStats] of the selected `entity`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If the [ExamineMode] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn examine_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    map_camera: Res<MapCamera>,
+    examine_mode: Res<ExamineMode>,
+    target_query: Query<(&Coord2d, &NameTag)>,
+    stats_query: Query<(Option<&Health>, Option<&CombatStats>)>,
+) {
+    let Some(entity) = examine_mode.selected_entity() else {
+        return;
+    };
+
+    let Ok((position, name_tag)) = target_query.get(entity) else {
+        return;
+    };
+
+    let (health, combat_stats) = stats_query.get(entity).unwrap_or_default();
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> examine_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    if let Some(screen_position) = map_camera.viewport.world_to_screen(position) {
+        let glyph = terminal.get_char(screen_position);
+        terminal.put_char(screen_position, glyph.bg(colors::EXAMINE_CURSOR));
+    }
+
+    let targets = examine_mode.targets();
+    let index = targets
+        .iter()
+        .position(|target| *target == entity)
+        .unwrap_or_default();
+
+    let mut lines = vec![format!(
+        "{} ({}/{})",
+        name_tag.text,
+        index + 1,
+        targets.len()
+    )];
+
+    if let Some(health) = health {
+        lines.push(format!("HP: {}/{}", health.current, health.max));
+    }
+
+    if let Some(combat_stats) = combat_stats {
+        lines.push(format!(
+            "Power: {}  Defense: {}",
+            combat_stats.power, combat_stats.defense
+        ));
+    }
+
+    let blank_row = " ".repeat(terminal.width());
+    let top_row = terminal.height() as i32 - 1;
+
+    for row in 0..constants::MESSAGE_LOG_HEIGHT {
+        terminal.put_string([0, top_row - row], blank_row.as_str());
+    }
+
+    for (row, line) in lines.iter().enumerate() {
+        if row == 0 {
+            terminal.put_string(
+                [0, top_row],
+                line.as_str().fg(colors::name_color(&name_tag.text)),
+            );
+        } else {
+            terminal.put_string([0, top_row - row as i32], line);
+        }
+    }
+}
+
+/// Renders the currently playing [crate::res::animation_queue::Animation] of the [AnimationQueue], if any.
+/// A [AnimationKind::Flash] blinks the background of its tile, being visible on every other frame. A
+/// [AnimationKind::DamageNumber] is shown in the tile above its position.
+///
+/// Has to run after the [render_system], which clears the [Terminal].
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the animation.
+/// * `map_camera`: The [MapCamera] resource translating the animated tile into the map panel.
+/// * `animation_queue`: The [AnimationQueue] resource holding the currently playing animation.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If the [AnimationQueue] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn animation_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    map_camera: Res<MapCamera>,
+    animation_queue: Res<AnimationQueue>,
+) {
+    let Some(animation) = animation_queue.current() else {
+        return;
+    };
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> animation_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    match animation.kind {
+        AnimationKind::Flash(color) => {
+            if animation_queue.elapsed_frames() % 2 == 1 {
+                return;
+            }
+
+            if let Some(position) = map_camera.viewport.world_to_screen(&animation.position) {
+                let glyph = terminal.get_char(position);
+                terminal.put_char(position, glyph.bg(color));
+            }
+        }
+        AnimationKind::DamageNumber(damage, color) => {
+            let position = [animation.position.x, animation.position.y + 1];
+
+            if let Some(position) = map_camera.viewport.world_to_screen(&position) {
+                terminal.put_string(position, damage.to_string().as_str().fg(color));
+            }
+        }
+    }
+}
+
+/// Renders the [CursorHud], if shown, right-aligned in the top row of the [Terminal], describing the
+/// [GameMap] tile under the mouse cursor.
+///
+/// Has to run after the [message_log_render_system], whose rows the HUD shares.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the HUD.
+/// * `cursor_hud`: The [CursorHud] resource holding the tile under the mouse cursor.
+/// * `game_map_query`: [Query] to retrieve the [GameMap] the tile belongs to.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] or [GameMap] can't be retrieved.
+/// * If the [CursorHud] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn cursor_hud_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    cursor_hud: Res<CursorHud>,
+    game_map_query: Query<&GameMap>,
+) {
+    if !cursor_hud.is_visible() {
+        return;
+    }
+
+    let game_map = game_map_query.get_single().expect(
+        "ECS -> Systems -> cursor_hud_render_system -> Unable to retrieve {GameMap} component!",
+    );
+
+    let Some(text) = cursor_hud.text(game_map) else {
+        return;
+    };
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> cursor_hud_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    let column = terminal.width().saturating_sub(text.len()) as i32;
+    let row = terminal.height() as i32 - 1;
+
+    terminal.put_string([column, row], text.as_str().fg(colors::HUD));
+}
+
+/// Renders the [DebugFooter], if shown, right-aligned in the lowest row of the [MessageLog], directly above the
+/// [GameMap], so the map itself stays untouched. The seed of the [GameMap] is shown as `-` as long as no map
+/// exists.
+///
+/// Has to run after the [message_log_render_system], whose rows the footer shares.
+///
+/// Only available with the `debug_tools` feature enabled.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the footer.
+/// * `debug_footer`: The [DebugFooter] resource determining if the footer is shown.
+/// * `dungeon_depth`: The [DungeonDepth] resource holding the current floor.
+/// * `turn_counter`: The [TurnCounter] resource holding the current turn.
+/// * `game_turn_state`: The [GameTurnState] resource holding whose turn it is.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], whose seed is shown.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[cfg(feature = "debug_tools")]
+pub fn debug_footer_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    debug_footer: Res<DebugFooter>,
+    dungeon_depth: Res<DungeonDepth>,
+    turn_counter: Res<TurnCounter>,
+    game_turn_state: Res<GameTurnState>,
+    game_map_query: Query<&GameMap>,
+) {
+    if !debug_footer.is_visible() {
+        return;
+    }
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> debug_footer_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    let seed = game_map_query.get_single().ok().map(GameMap::seed);
+    let text = DebugFooter::text(seed, &dungeon_depth, &turn_counter, &game_turn_state);
+    let column = terminal.width().saturating_sub(text.len()) as i32;
+    let row = terminal.height() as i32 - constants::MESSAGE_LOG_HEIGHT;
+
+    terminal.put_string([column, row], text.as_str().fg(colors::HUD));
+}
+
+/// Renders the [LogView] overlay, if open, which replaces the whole [Terminal] with a page of the
+/// [MessageLog] history. The newest message of the page is rendered in the bottom row, older ones above
+/// it, and the top row shows which part of the history is currently visible.
+///
+/// Has to run after all other render systems, as the overlay covers their output.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the overlay.
+/// * `log_view`: The [LogView] resource holding the current scroll offset.
+/// * `message_log`: The [MessageLog] whose history is shown.
+/// * `palette`: The [Palette] providing the colors of the messages.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn log_view_render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    log_view: Res<LogView>,
+    message_log: Res<MessageLog>,
+    palette: Res<Palette>,
+) {
+    if !log_view.is_open() {
+        return;
+    }
+
+    let mut terminal = terminal_query.get_single_mut().expect(
+        "ECS -> Systems -> log_view_render_system -> Unable to retrieve {Terminal} component!",
+    );
+
+    terminal.clear();
+
+    let top_row = terminal.height() as i32 - 1;
+
+    if message_log.is_empty() {
+        terminal.put_string([0, top_row], "Message log (empty)");
+        return;
+    }
+
+    let mut visible_count = 0;
+
+    for (row, entry) in log_view
+        .visible_entries(&message_log)
+        .take(top_row as usize)
+        .enumerate()
+    {
+        terminal.put_string(
+            [0, row as i32],
+            entry.text.as_str().fg(palette.log_color(entry.category)),
+        );
+        visible_count += 1;
+    }
+
+    let newest = message_log.len() - log_view.scroll_offset();
+
+    terminal.put_string(
+        [0, top_row],
+        format!(
+            "Message log ({}-{}/{})",
+            newest + 1 - visible_count,
+            newest,
+            message_log.len()
+        )
+        .as_str(),
+    );
 }
 
 #[cfg(test)]
@@ -99,9 +684,14 @@ mod tests {
     use bevy::app::{App, Startup, Update};
     use bevy_ascii_terminal::TerminalBundle;
 
-    use crate::core::dimension_2d::Dimension2d;
     use crate::plugins::game_state_systems::lifecycle::startup_system;
-    use crate::res::window_config::WindowConfig;
+    use crate::res::animation_queue::Animation;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::message_log::LogCategory;
+    use crate::ui::colors;
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
 
     use super::*;
 
@@ -109,8 +699,12 @@ mod tests {
     fn test_render_system() {
         let mut app = App::new();
 
-        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
         app.add_systems(Startup, startup_system);
+        app.init_resource::<Time>();
         app.add_systems(Update, render_system);
 
         app.world
@@ -130,4 +724,383 @@ mod tests {
                 .get_char(center_coord)
         )
     }
+
+    fn render_seen_tile(tile_memory: bool) -> bevy_ascii_terminal::Tile {
+        let mut app = App::new();
+
+        let mut game_map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        game_map.mark_tile_as_seen(&[2, 2]);
+
+        app.insert_resource(GameplayConfig {
+            tile_memory,
+            ..Default::default()
+        });
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(MapCamera::new(&TerminalLayout::new(&[10, 10]).map));
+        app.init_resource::<Time>();
+        app.add_systems(Update, render_system);
+
+        app.world.spawn(game_map);
+        app.world
+            .spawn((Coord2d::new(5, 5), crate::ascii_sprite!('@'), Player));
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([10, 10])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        *app.world
+            .query::<&Terminal>()
+            .single(&app.world)
+            .get_tile([2, 2])
+    }
+
+    #[test]
+    fn test_render_system_tile_memory() {
+        let remembered_tile = render_seen_tile(true);
+        let forgotten_tile = render_seen_tile(false);
+
+        assert_eq!('#', remembered_tile.glyph);
+        assert_eq!(colors::INACTIVE, remembered_tile.fg_color);
+        assert_eq!(' ', forgotten_tile.glyph);
+    }
+
+    fn render_remembered_tiles(app: &mut App) -> [bevy_ascii_terminal::Tile; 2] {
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        [*terminal.get_tile([2, 2]), *terminal.get_tile([3, 3])]
+    }
+
+    #[test]
+    fn test_render_system_sight_memory_fade() {
+        let mut app = App::new();
+
+        let mut game_map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        let mut turn_counter = TurnCounter::default();
+
+        game_map.mark_tile_as_seen(&[2, 2]);
+        game_map.mark_tile_as_seen(&[3, 3]);
+        game_map.remember_tile(&[2, 2], turn_counter.current());
+
+        for _ in 0..8 {
+            turn_counter.advance();
+        }
+
+        game_map.remember_tile(&[3, 3], turn_counter.current());
+
+        for _ in 0..2 {
+            turn_counter.advance();
+        }
+
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(turn_counter);
+        app.insert_resource(MapCamera::new(&TerminalLayout::new(&[10, 10]).map));
+        app.init_resource::<Time>();
+        app.add_systems(Update, render_system);
+
+        app.world.spawn(game_map);
+        app.world
+            .spawn((Coord2d::new(5, 5), crate::ascii_sprite!('@'), Player));
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([10, 10])))
+            .insert(GameTerminal);
+
+        let [old_tile, recent_tile] = render_remembered_tiles(&mut app);
+
+        assert_eq!('#', old_tile.glyph);
+        assert_eq!('#', recent_tile.glyph);
+        assert!(old_tile.fg_color.r() < recent_tile.fg_color.r());
+        assert!(recent_tile.fg_color.r() < colors::INACTIVE.r());
+
+        // Revisiting the tile resets its freshness.
+        let current_turn = app.world.resource::<TurnCounter>().current();
+
+        app.world
+            .query::<&mut GameMap>()
+            .single_mut(&mut app.world)
+            .remember_tile(&[2, 2], current_turn);
+
+        let [revisited_tile, _] = render_remembered_tiles(&mut app);
+
+        assert_eq!(colors::INACTIVE, revisited_tile.fg_color);
+    }
+
+    #[test]
+    fn test_sidebar_render_system() {
+        let mut app = App::new();
+        let terminal_layout = TerminalLayout::new(&[100, 80]);
+
+        app.insert_resource(terminal_layout);
+        app.insert_resource(DungeonDepth::default());
+        app.insert_resource(TurnCounter::default());
+        app.add_systems(Update, sidebar_render_system);
+
+        app.world
+            .spawn((Health::new(30), CombatStats::new(5, 2), Player));
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+        let column = terminal_layout.sidebar.left + 1;
+        let row_text = |row: i32| {
+            (column..column + 9)
+                .map(|x| terminal.get_char([x, row]))
+                .collect::<String>()
+        };
+
+        assert_eq!("HP: 30/30", row_text(terminal_layout.sidebar.top - 1));
+        assert_eq!("Depth: 1 ", row_text(terminal_layout.sidebar.top - 4));
+        assert_eq!(' ', terminal.get_char([terminal_layout.map.right - 1, 74]));
+    }
+
+    #[test]
+    fn test_examine_render_system() {
+        let mut app = App::new();
+
+        let monster = app
+            .world
+            .spawn((
+                Coord2d::new(3, 4),
+                NameTag::new("Mended"),
+                Health::new(8),
+                CombatStats::new(4, 1),
+            ))
+            .id();
+
+        let mut examine_mode = ExamineMode::default();
+        examine_mode.activate(vec![monster]);
+
+        app.insert_resource(examine_mode);
+        app.insert_resource(MapCamera::new(&TerminalLayout::new(&[100, 80]).map));
+        app.add_systems(Update, examine_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!(colors::EXAMINE_CURSOR, terminal.get_tile([3, 4]).bg_color);
+        assert_eq!('M', terminal.get_char([0, 79]));
+        assert_eq!(
+            colors::name_color("Mended"),
+            terminal.get_tile([0, 79]).fg_color
+        );
+        assert_eq!('1', terminal.get_char([8, 79]));
+        assert_eq!('8', terminal.get_char([4, 78]));
+        assert_eq!('4', terminal.get_char([7, 77]));
+    }
+
+    #[test]
+    fn test_animation_render_system() {
+        let mut app = App::new();
+        let mut animation_queue = AnimationQueue::default();
+
+        animation_queue.enqueue(Animation {
+            position: Coord2d::new(2, 3),
+            kind: AnimationKind::Flash(colors::HIT_FLASH),
+            frames: 2,
+        });
+
+        app.insert_resource(animation_queue);
+        app.insert_resource(MapCamera::new(&TerminalLayout::new(&[100, 80]).map));
+        app.add_systems(Update, animation_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!(colors::HIT_FLASH, terminal.get_tile([2, 3]).bg_color);
+        assert_eq!(colors::BACKGROUND, terminal.get_tile([3, 3]).bg_color);
+    }
+
+    #[test]
+    fn test_damage_number_render_system() {
+        let mut app = App::new();
+        let mut animation_queue = AnimationQueue::default();
+
+        animation_queue.enqueue(Animation {
+            position: Coord2d::new(2, 3),
+            kind: AnimationKind::DamageNumber(12, colors::FACTION_HOSTILE),
+            frames: 2,
+        });
+
+        app.insert_resource(animation_queue);
+        app.insert_resource(MapCamera::new(&TerminalLayout::new(&[100, 80]).map));
+        app.add_systems(Update, animation_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!('1', terminal.get_char([2, 4]));
+        assert_eq!('2', terminal.get_char([3, 4]));
+        assert_eq!(colors::FACTION_HOSTILE, terminal.get_tile([2, 4]).fg_color);
+        assert_eq!(' ', terminal.get_char([2, 3]));
+    }
+
+    #[test]
+    fn test_message_log_render_system() {
+        let mut app = App::new();
+
+        let mut message_log = MessageLog::default();
+        message_log.push_info("You see a potion.");
+        message_log.push("You hit the Mended.", LogCategory::Combat);
+
+        app.insert_resource(message_log);
+        app.insert_resource(Palette::default());
+        app.add_systems(Update, message_log_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+        let first_row = 80 - constants::MESSAGE_LOG_HEIGHT;
+
+        assert_eq!('Y', terminal.get_char([0, first_row]));
+        assert_eq!('h', terminal.get_char([4, first_row]));
+        assert_eq!(
+            colors::LOG_COMBAT,
+            terminal.get_tile([0, first_row]).fg_color
+        );
+        assert_eq!('s', terminal.get_char([4, first_row + 1]));
+        assert_eq!(
+            colors::blend(
+                colors::LOG_INFO,
+                colors::INACTIVE,
+                1.0 / (constants::MESSAGE_LOG_HEIGHT - 1) as f32
+            ),
+            terminal.get_tile([0, first_row + 1]).fg_color
+        );
+    }
+
+    #[test]
+    fn test_message_log_render_system_fades_oldest_message() {
+        let mut app = App::new();
+
+        let mut message_log = MessageLog::default();
+
+        for index in 0..constants::MESSAGE_LOG_HEIGHT + 2 {
+            message_log.push_info(format!("Message {}", index));
+        }
+
+        app.insert_resource(message_log);
+        app.insert_resource(Palette::default());
+        app.add_systems(Update, message_log_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([100, 80])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+        let first_row = 80 - constants::MESSAGE_LOG_HEIGHT;
+        let last_row = 79;
+
+        let row_text = |row: i32| {
+            (0..9)
+                .map(|x| terminal.get_char([x, row]))
+                .collect::<String>()
+        };
+
+        assert_eq!(
+            format!("Message {}", constants::MESSAGE_LOG_HEIGHT + 1),
+            row_text(first_row)
+        );
+        assert_eq!(colors::LOG_INFO, terminal.get_tile([0, first_row]).fg_color);
+        assert_eq!("Message 2", row_text(last_row));
+        assert_eq!(colors::INACTIVE, terminal.get_tile([0, last_row]).fg_color);
+    }
+
+    #[test]
+    fn test_log_view_render_system() {
+        let mut app = App::new();
+
+        let mut message_log = MessageLog::default();
+
+        for index in 0..5 {
+            message_log.push_info(format!("Message {}", index));
+        }
+
+        let mut log_view = LogView::new(3);
+        log_view.open();
+        log_view.scroll_older(message_log.len());
+
+        app.insert_resource(message_log);
+        app.insert_resource(log_view);
+        app.insert_resource(Palette::default());
+        app.add_systems(Update, log_view_render_system);
+
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([40, 10])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!('3', terminal.get_char([8, 0]));
+        assert_eq!('2', terminal.get_char([8, 1]));
+        assert_eq!('1', terminal.get_char([8, 2]));
+        assert_eq!(' ', terminal.get_char([0, 3]));
+        assert_eq!(
+            "Message log (2-4/5)",
+            (0..19)
+                .map(|x| terminal.get_char([x, 9]))
+                .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_cursor_hud_render_system() {
+        let mut app = App::new();
+
+        let mut game_map = GameMap::new(&[20, 10], &TestTileMapGenerator, None);
+        game_map.set_tile_at(&[4, 2], MapTile::floor('.'));
+        game_map.mark_tile_as_visible(&[4, 2]);
+
+        let mut cursor_hud = CursorHud::default();
+        cursor_hud.toggle();
+        cursor_hud.set_tile(Some([4, 2]));
+
+        app.insert_resource(cursor_hud);
+        app.add_systems(Update, cursor_hud_render_system);
+
+        app.world.spawn(game_map);
+        app.world
+            .spawn(TerminalBundle::from(Terminal::new([60, 20])))
+            .insert(GameTerminal);
+
+        app.update();
+
+        let expected = "(4, 2) in bounds: true, visible: true, walkable: true";
+        let first_column = 60 - expected.len() as i32;
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+        let rendered: String = (first_column..60)
+            .map(|column| terminal.get_char([column, 19]))
+            .collect();
+
+        assert_eq!(expected, rendered);
+        assert_eq!(colors::HUD, terminal.get_tile([first_column, 19]).fg_color);
+    }
 }
@@ -0,0 +1,241 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{Camera, EventReader, GlobalTransform, Query, Res, ResMut, Window, With};
+use bevy::window::PrimaryWindow;
+use bevy_ascii_terminal::ToWorld;
+
+use crate::components::game_terminal::GameTerminal;
+use crate::res::cursor_hud::CursorHud;
+#[cfg(feature = "debug_tools")]
+use crate::res::debug_footer::DebugFooter;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::map_camera::MapCamera;
+
+/// System handling the [CursorHud], which describes the map tile under the mouse cursor.
+///
+/// The [InputType::CursorHud] key toggles the HUD. While shown, the cursor position of the [PrimaryWindow] is
+/// converted into world space through the [Camera] and from there into the tile of the [GameTerminal] through
+/// its [ToWorld] component. The terminal tile is then translated into the tile of the
+/// [crate::ui::game_map::GameMap] through the [crate::ui::viewport::Viewport], leaving the HUD empty while the cursor
+/// is outside of the map panel.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+/// * `cursor_hud`: The [CursorHud] resource to update.
+/// * `cursor_tile`: The [CursorTile] converting the cursor position into the map tile below it.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn cursor_hud_system(
+    input_config: Res<InputConfig>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut cursor_hud: ResMut<CursorHud>,
+    cursor_tile: CursorTile,
+) {
+    let toggle_count = key_events
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .filter_map(|event| event.key_code)
+        .filter(|key_code| input_config.parse_input(*key_code) == Some(InputType::CursorHud))
+        .count();
+
+    if toggle_count % 2 == 1 {
+        cursor_hud.toggle();
+    }
+
+    if !cursor_hud.is_visible() {
+        return;
+    }
+
+    cursor_hud.set_tile(cursor_tile.get());
+}
+
+/// The cursor position of the [PrimaryWindow] along with everything required to convert it into the tile of the
+/// [crate::ui::game_map::GameMap] below it, as described by the [cursor_hud_system].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct CursorTile<'w, 's> {
+    /// [Query] to retrieve the cursor position from the [PrimaryWindow].
+    window_query: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    /// [Query] to retrieve the [Camera] converting the cursor position into world space.
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    /// [Query] to retrieve the [ToWorld] component of the [GameTerminal].
+    terminal_query: Query<'w, 's, &'static ToWorld, With<GameTerminal>>,
+    /// The [MapCamera] resource, whose [crate::ui::viewport::Viewport] translates the terminal tile into the map
+    /// tile.
+    map_camera: Res<'w, MapCamera>,
+}
+
+impl CursorTile<'_, '_> {
+    /// Converts the cursor position of the [PrimaryWindow] into the tile of the [crate::ui::game_map::GameMap]
+    /// below it.
+    ///
+    /// returns: [Option]<[i32; 2]> - [None] if the cursor is outside of the window or the map panel, or any of the
+    /// queries fail.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn get(&self) -> Option<[i32; 2]> {
+        self.window_query
+            .get_single()
+            .ok()
+            .and_then(|window| window.cursor_position())
+            .zip(self.camera_query.get_single().ok())
+            .and_then(|(cursor, (camera, transform))| {
+                camera.viewport_to_world_2d(transform, cursor)
+            })
+            .zip(self.terminal_query.get_single().ok())
+            .and_then(|(world, to_world)| {
+                self.map_camera
+                    .viewport
+                    .screen_to_world(&to_world.world_to_tile(world).to_array())
+            })
+    }
+}
+
+/// System toggling the [DebugFooter] once the [InputType::DebugFooter] key is pressed.
+///
+/// Only available with the `debug_tools` feature enabled.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+/// * `debug_footer`: The [DebugFooter] resource to toggle.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[cfg(feature = "debug_tools")]
+pub fn debug_footer_toggle_system(
+    input_config: Res<InputConfig>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut debug_footer: ResMut<DebugFooter>,
+) {
+    let toggle_count = key_events
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .filter_map(|event| event.key_code)
+        .filter(|key_code| input_config.parse_input(*key_code) == Some(InputType::DebugFooter))
+        .count();
+
+    if toggle_count % 2 == 1 {
+        debug_footer.toggle();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode};
+
+    use crate::ui::rectangle::Rectangle;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    #[test]
+    fn test_cursor_hud_toggles() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(CursorHud::default());
+        app.insert_resource(MapCamera::new(&Rectangle::new([0, 0], [80, 75])));
+        app.add_systems(Update, cursor_hud_system);
+
+        press(&mut app, KeyCode::H);
+        assert!(app.world.resource::<CursorHud>().is_visible());
+
+        press(&mut app, KeyCode::W);
+        assert!(app.world.resource::<CursorHud>().is_visible());
+
+        press(&mut app, KeyCode::H);
+        assert!(!app.world.resource::<CursorHud>().is_visible());
+    }
+
+    #[cfg(feature = "debug_tools")]
+    #[test]
+    fn test_debug_footer_toggles() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(DebugFooter::default());
+        app.add_systems(Update, debug_footer_toggle_system);
+
+        press(&mut app, KeyCode::F2);
+        assert!(app.world.resource::<DebugFooter>().is_visible());
+
+        press(&mut app, KeyCode::F2);
+        assert!(!app.world.resource::<DebugFooter>().is_visible());
+    }
+}
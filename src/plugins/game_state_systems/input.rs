@@ -20,36 +20,49 @@
  */
 
 use bevy::app::AppExit;
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::GamepadButtonInput;
 use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButton, MouseButtonInput};
 use bevy::input::ButtonState;
 use bevy::log::debug;
-use bevy::prelude::{
-    DetectChangesMut, EventReader, EventWriter, Mut, Query, Res, ResMut, With, Without,
-};
+use bevy::prelude::{DetectChangesMut, Entity, EventReader, EventWriter, Query, Res, ResMut, With};
 
 use crate::components::collision::Collision;
 use crate::components::coord_2d::Coord2d;
-use crate::components::fov::Fov;
-use crate::ui::game_map::GameMap;
 use crate::components::player::Player;
+use crate::core::action::Action;
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::pathfinding::a_star;
+use crate::core::position_2d::Position2d;
+use crate::core::spatial_query::SpatialQuery;
+use crate::plugins::game_state_systems::hud::CursorTile;
 use crate::plugins::states::GameTurnState;
+use crate::res::action_queue::ActionQueue;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::gameplay_config::GameplayConfig;
 use crate::res::input_config::{InputConfig, InputType};
-use crate::ui::tile::Tile;
+use crate::res::log_view::LogView;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::targeting_mode::TargetingMode;
+use crate::res::travel_path::TravelPath;
+use crate::ui::game_map::GameMap;
 use crate::ui::tile_map::TileMap;
 
 /// System to handle user's input through the keyboard.
 ///
+/// Movement input is turned into an [Action::Move] of the `player entity`, which is resolved by the
+/// [crate::plugins::game_state_systems::action::action_system], and passes the turn to the `NPC entities`.
+/// Moving into a cell occupied by an `entity` with [Collision], e.g., a monster, pushes an [Action::Attack] against it
+/// instead. It is only handled during the [GameTurnState::Player] turn, movement events received while the
+/// `NPC entities` act are dropped. Bumping into a wall only passes the turn, if
+/// [GameplayConfig::wall_bump_wastes_turn] is enabled. Waiting pushes an [Action::Wait] instead, which passes the turn
+/// without moving the `player`.
+///
 /// # Arguments
 ///
-/// * `input_config`: [InputConfig] required to recognize the user's input.
-/// * `game_map_query`: [Query] required to retrieve the [GameMap], which is used to check for collision.
-/// * `exit_event`: [EventWriter] to send the [AppExit] event to the game's engine in order to close the game.
-/// * `in_game_state`: [GameTurnState] to update, when the player makes a valid movement, in order to pass the
-/// turn to the `NPC entities`.
-/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
-/// * `player_query`: [Query] to retrieve the position of the `player entity`, required to move him according
-/// to the user's input (if applicable).
-/// * `collision_entity_query`: [Query] to retrieve the positions of the `entities` which have collision.
+/// * `player_keys`: The [PlayerKeys] providing the keys pressed by the user.
+/// * `player_actions`: The [PlayerActions] turning the user's input into an [Action] of the `player`.
 ///
 /// returns: ()
 ///
@@ -64,130 +77,457 @@ use crate::ui::tile_map::TileMap;
 ///
 /// Since: `0.1.5`
 ///
-pub fn keyboard_input_system(
+pub fn keyboard_input_system(mut player_keys: PlayerKeys, mut player_actions: PlayerActions) {
+    for input in player_keys.read() {
+        debug!(
+            "ECS -> Systems -> keyboard_input_system -> Received keyboard input event: {:?}",
+            input
+        );
+
+        player_actions.handle(input);
+    }
+}
+
+/// System to handle user's input through a gamepad.
+///
+/// Button presses are translated by the [crate::res::input_config::GamepadBindings] of the [InputConfig] and handled
+/// exactly like their keyboard counterparts in the [keyboard_input_system], i.e., moving, attacking, waiting and
+/// cancelling.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `overlays`: The [Overlays] to check, as the player can't act while any of them is open.
+/// * `button_events`: [EventReader] stream of [GamepadButtonInput] events required to parse the user's input.
+/// * `player_actions`: The [PlayerActions] turning the user's input into an [Action] of the `player`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If any of the [Query] calls fail, i.e., the components required by the system can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn gamepad_input_system(
     input_config: Res<InputConfig>,
+    overlays: Overlays,
+    mut button_events: EventReader<GamepadButtonInput>,
+    mut player_actions: PlayerActions,
+) {
+    for event in button_events.read() {
+        if event.state == ButtonState::Released || overlays.is_open() {
+            continue;
+        }
+
+        if let Some(input) = input_config.gamepad.parse_input(event.button.button_type) {
+            debug!(
+                "ECS -> Systems -> gamepad_input_system -> Received gamepad input event: {:?}",
+                input
+            );
+
+            player_actions.handle(input);
+        }
+    }
+}
+
+/// System planning the travel of the `player` to the map tile clicked with the left mouse button.
+///
+/// The cursor is converted into the clicked tile just like for the [crate::res::cursor_hud::CursorHud]. The shortest
+/// path to it is searched with [a_star] and stored in the [TravelPath], which the [travel_system] follows one step
+/// per turn. Clicks on walls, outside of the map or on unreachable tiles are ignored and reported in the
+/// [MessageLog].
+///
+/// # Arguments
+///
+/// * `overlays`: The [Overlays] to check, as the player can't act while any of them is open.
+/// * `message_log`: [MessageLog] to inform the player about ignored clicks.
+/// * `travel_path`: [TravelPath] to store the planned path in.
+/// * `mouse_events`: [EventReader] stream of [MouseButtonInput] events required to parse the user's input.
+/// * `cursor_tile`: The [CursorTile] converting the cursor position into the clicked map tile.
+/// * `player_query`: [Query] to retrieve the position of the `player entity`, where the path starts.
+/// * `game_map_query`: [Query] to retrieve the [GameMap] the path is searched on.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the position of the `player entity` or the [GameMap] can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn mouse_move_system(
+    overlays: Overlays,
+    mut message_log: ResMut<MessageLog>,
+    mut travel_path: ResMut<TravelPath>,
+    mut mouse_events: EventReader<MouseButtonInput>,
+    cursor_tile: CursorTile,
+    player_query: Query<&Coord2d, With<Player>>,
     game_map_query: Query<&GameMap>,
-    mut exit_event: EventWriter<AppExit>,
-    mut turn_state: ResMut<GameTurnState>,
-    mut key_events: EventReader<KeyboardInput>,
-    mut player_query: Query<(&mut Fov, &mut Coord2d), With<Player>>,
-    collision_entity_query: Query<&Coord2d, (With<Collision>, Without<Player>)>,
 ) {
-    for event in key_events.read() {
-        if event.state == ButtonState::Released || event.key_code.is_none() {
-            return;
+    for event in mouse_events.read() {
+        if event.button != MouseButton::Left
+            || event.state == ButtonState::Released
+            || overlays.is_open()
+        {
+            continue;
         }
 
-        if let Some(key_code) = event.key_code {
-            if let Some(input) = input_config.parse_input(key_code) {
-                debug!("ECS -> Systems -> keyboard_input_system -> Received keyboard input event: {:?}", input);
+        let Some(tile) = cursor_tile.get() else {
+            continue;
+        };
 
-                let (mut fov, mut position) = player_query.get_single_mut().expect(
-                    "ECS -> Systems -> keyboard_input_system -> \
-                    Unable to retrieve player {Fov} and {Coord2d} components!",
-                );
+        let player_position = player_query.get_single().expect(
+            "ECS -> Systems -> mouse_move_system -> Unable to retrieve player {Coord2d} component!",
+        );
 
-                let map = game_map_query.get_single().expect(
-                    "ECS -> Systems -> keyboard_input_system -> Unable to retrieve {GameMap} component!"
-                );
+        let map = game_map_query.get_single().expect(
+            "ECS -> Systems -> mouse_move_system -> Unable to retrieve the {GameMap} component!",
+        );
 
-                if input.is_movement_event() {
-                    turn_state.set_if_neq(handle_player_movement(
-                        &input,
-                        &mut fov,
-                        map,
-                        &mut position,
-                        &collision_entity_query.iter().collect(),
-                    ));
-                }
-
-                if input == InputType::Cancel {
-                    exit_event.send(AppExit)
-                }
-            }
-        }
+        plan_travel(
+            &tile,
+            player_position,
+            map,
+            &mut travel_path,
+            &mut message_log,
+        );
     }
 }
 
-/// Internal function to update the `player entities` positional component according to the passed `input_type`
-/// within the set `player_area` and the given `entity_collision_positions`.
+/// System moving the `player` one step along the [TravelPath] per turn.
 ///
-/// If the `player entity` is moved, the passed associated `fov` is also marked as dirty to trigger a recalculation.
+/// The travel stops once the path is finished, the next step is blocked by a wall or another `entity`, or the user
+/// presses any key, which is then handled as usual by the other input systems.
 ///
 /// # Arguments
 ///
-/// * `input_type`: The movement [InputType] according to which the `player_position` will be manipulated.
-/// * `player_fov`: The `field of view` of the `player entity`.
-/// * `tile_map`: The [TileMap] on which the `player` moves, required for bounds and collision checking.
-/// * `player_position`: The [Coord2d] ecs [bevy::prelude::Component] of the `player` `entity`.
-/// * `entity_collision_positions`: List of all positions on the current map, which are occupied by an `entity`
-/// with collision.
-///
-/// returns: [GameTurnState]
+/// * `action_queue`: [ActionQueue] to push the [Action::Move] of the `player` into.
+/// * `turn_state`: [GameTurnState] to check and update, in order to pass the turn to the `NPC entities`.
+/// * `travel_path`: [TravelPath] to take the next step from.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events interrupting the travel.
+/// * `player_query`: [Query] to retrieve the `player entity` and its position.
+/// * `blocker_query`: [SpatialQuery] to look up the other `entities` with [Collision] on the next step.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], which is used to check for walls.
 ///
-/// # Examples
+/// returns: ()
 ///
-/// ```
-/// let mut player_fov = Fov::new(8);
-/// let tile_map = TileMapImpl::new(...);
-/// let mut player_position = Coord2d::new(40, 25);
-/// handle_player_movement(InputType::Up, &player_fov, &map, &player_position, &Vec::new());
+/// # Panics
 ///
-/// assert_eq!([40, 26], player_position.to_array());
-/// ```
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the `player entity` can't be retrieved from the ECS while travelling.
 ///
 /// # About
 ///
 /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
 ///
-/// Since: `0.1.5`
+/// Since: `0.1.10`
 ///
-/// # See also
-///
-/// * [InputType]
-/// * [Dimension2d]
-/// * [Coord2d]
-///
-fn handle_player_movement<T: Tile>(
-    input_type: &InputType,
-    player_fov: &mut Mut<Fov>,
-    tile_map: &impl TileMap<T>,
-    player_position: &mut Mut<Coord2d>,
-    entity_collision_positions: &Vec<&Coord2d>,
-) -> GameTurnState {
-    let new_position = match input_type {
-        InputType::Up => player_position.up(tile_map.height() - 1),
-        InputType::Left => player_position.left(0),
-        InputType::Right => player_position.right(tile_map.width() - 1),
-        InputType::Down => player_position.down(0),
-        _ => Coord2d::from_position(&[player_position.x, player_position.y]),
+pub fn travel_system(
+    mut action_queue: ResMut<ActionQueue>,
+    mut turn_state: ResMut<GameTurnState>,
+    mut travel_path: ResMut<TravelPath>,
+    mut key_events: EventReader<KeyboardInput>,
+    player_query: Query<(Entity, &Coord2d), With<Player>>,
+    blocker_query: Query<(Entity, &Coord2d), With<Collision>>,
+    game_map_query: Query<&GameMap>,
+) {
+    if key_events
+        .read()
+        .any(|event| event.state == ButtonState::Pressed)
+    {
+        travel_path.clear();
+        return;
+    }
+
+    if *turn_state != GameTurnState::Player {
+        return;
+    }
+
+    let Some(step) = travel_path.next_step() else {
+        return;
     };
 
-    let player_collides_with_entity = entity_collision_positions
-        .iter()
-        .find(|coord2d: &&&Coord2d| ***coord2d == new_position)
-        .is_some();
+    let (player, position) = player_query
+        .get_single()
+        .expect("ECS -> Systems -> travel_system -> Unable to retrieve the player entity!");
+
+    let is_blocked = position.chebyshev_distance(&step) != 1
+        || game_map_query.get_single().map_or(true, |map| {
+            !map.is_in_bounds(&step) || map.tile_has_collision(&step)
+        })
+        || !blocker_query.entities_at(&step).is_empty();
+
+    if is_blocked {
+        travel_path.clear();
+        return;
+    }
+
+    action_queue.push(Action::Move {
+        actor: player,
+        delta: step.delta(position),
+    });
+    turn_state.set_if_neq(GameTurnState::Npc);
+}
 
-    if tile_map.tile_has_collision(&new_position) || player_collides_with_entity {
-        return GameTurnState::Npc;
+/// (Private) Searches the path from the `start` to the clicked `tile` on the `map` and stores it in the
+/// `travel_path`, replacing the previous one. Unreachable tiles are reported through the `message_log`.
+fn plan_travel(
+    tile: &[i32; 2],
+    start: &Coord2d,
+    map: &GameMap,
+    travel_path: &mut TravelPath,
+    message_log: &mut MessageLog,
+) {
+    if *tile == start.as_array() {
+        return;
     }
 
-    if new_position != **player_position {
-        player_fov.is_dirty = true;
-        player_position.x = new_position.x;
-        player_position.y = new_position.y;
+    if !map.is_in_bounds(tile) || map.tile_has_collision(tile) {
+        message_log.push("You can't walk there.", LogCategory::Warning);
+        return;
     }
 
-    GameTurnState::Npc
+    match a_star(start, tile, map, true) {
+        Some(path) => travel_path.set(path),
+        None => message_log.push("There is no way to get there.", LogCategory::Warning),
+    }
+}
+
+/// The modes, which capture the user's input while they're open, i.e., the [ExamineMode], the [LogView] and the
+/// [TargetingMode], so the `player` can't act in the meantime.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct Overlays<'w> {
+    /// The [ExamineMode], which is open while examining the surroundings.
+    examine_mode: Res<'w, ExamineMode>,
+    /// The [LogView], which is open while scrolling through the message history.
+    log_view: Res<'w, LogView>,
+    /// The [TargetingMode], which is open while aiming.
+    targeting_mode: Res<'w, TargetingMode>,
+}
+
+impl Overlays<'_> {
+    /// Checks if any of the modes is open and captures the user's input.
+    ///
+    /// returns: bool - `true` if the `player` can't act at the moment and `false` otherwise.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_open(&self) -> bool {
+        self.examine_mode.is_active() || self.log_view.is_open() || self.targeting_mode.is_active()
+    }
+}
+
+/// The keys pressed by the user, which are meant for the `player`, i.e., those not captured by any of the
+/// [Overlays].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct PlayerKeys<'w, 's> {
+    /// [InputConfig] required to recognize the user's input.
+    input_config: Res<'w, InputConfig>,
+    /// The [Overlays] to check, as the player can't act while any of them is open.
+    overlays: Overlays<'w>,
+    /// [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+    key_events: EventReader<'w, 's, KeyboardInput>,
+}
+
+impl PlayerKeys<'_, '_> {
+    /// Reads all [KeyboardInput] events received since the last call, parsing the pressed keys into the respective
+    /// [InputType]s. Unknown keys are skipped, while all keys are dropped as long as any of the [Overlays] is open.
+    ///
+    /// returns: [Vec]<[InputType]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn read(&mut self) -> Vec<InputType> {
+        let is_open = self.overlays.is_open();
+
+        self.key_events
+            .read()
+            .filter(|event| event.state == ButtonState::Pressed && !is_open)
+            .filter_map(|event| event.key_code)
+            .filter_map(|key_code| self.input_config.parse_input(key_code))
+            .collect()
+    }
+}
+
+/// Everything required to turn the user's input into an [Action] of the `player`, independent of the device it
+/// was made with, see [PlayerActions::handle].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct PlayerActions<'w, 's> {
+    /// [GameplayConfig] to check if bumping into a wall passes the turn.
+    gameplay_config: Res<'w, GameplayConfig>,
+    /// [ActionQueue] to push the [Action::Move], [Action::Attack] or [Action::Wait] of the `player` into.
+    action_queue: ResMut<'w, ActionQueue>,
+    /// [MessageLog] to inform the player about bumping into a wall.
+    message_log: ResMut<'w, MessageLog>,
+    /// [EventWriter] to send the [AppExit] event to the game's engine in order to close the game.
+    exit_event: EventWriter<'w, AppExit>,
+    /// [GameTurnState] to check and update, when the player makes a valid movement, in order to pass the turn to
+    /// the `NPC entities`.
+    turn_state: ResMut<'w, GameTurnState>,
+    /// [Query] to retrieve the `player entity` and its position, which is moved according to the user's input
+    /// (if applicable).
+    player_query: Query<'w, 's, (Entity, &'static Coord2d), With<Player>>,
+    /// [Query] to retrieve all `entities` with [Collision] and their positions, which are attacked when moving into
+    /// them.
+    target_query: Query<'w, 's, (Entity, &'static Coord2d), With<Collision>>,
+    /// [Query] to retrieve the [GameMap], which is used to check for walls.
+    game_map_query: Query<'w, 's, &'static GameMap>,
+}
+
+impl PlayerActions<'_, '_> {
+    /// Handles the passed `input` of the player. Movement is turned into an [Action::Move], or an [Action::Attack] if
+    /// the target cell is occupied, waiting into an [Action::Wait] and cancelling closes the game, as described by
+    /// the [keyboard_input_system].
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The [InputType] to handle.
+    ///
+    /// returns: ()
+    ///
+    /// # Panics
+    ///
+    /// * If the `player entity` can't be retrieved from the ECS, while it's the [GameTurnState::Player] turn.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn handle(&mut self, input: InputType) {
+        if let Some(delta) = input.movement_delta() {
+            if *self.turn_state != GameTurnState::Player {
+                debug!(
+                    "ECS -> Systems -> PlayerActions -> Dropping movement input during the {} turn",
+                    *self.turn_state
+                );
+            } else {
+                self.handle_movement(delta);
+            }
+        }
+
+        if input == InputType::Wait {
+            if *self.turn_state != GameTurnState::Player {
+                debug!(
+                    "ECS -> Systems -> PlayerActions -> Dropping wait input during the {} turn",
+                    *self.turn_state
+                );
+            } else {
+                let (player, _) = self.player_query.get_single().expect(
+                    "ECS -> Systems -> PlayerActions -> Unable to retrieve the player entity!",
+                );
+
+                self.action_queue.push(Action::Wait { actor: player });
+                self.turn_state.set_if_neq(GameTurnState::Npc);
+            }
+        }
+
+        if input == InputType::Cancel {
+            self.exit_event.send(AppExit)
+        }
+    }
+
+    /// (Private) Turns the movement of the player by the passed `delta` into an [Action::Attack] against the
+    /// `entity` occupying the target cell, or into an [Action::Move] otherwise, unless a wall blocks the way.
+    fn handle_movement(&mut self, delta: [i32; 2]) {
+        let (player, position) = self
+            .player_query
+            .get_single()
+            .expect("ECS -> Systems -> PlayerActions -> Unable to retrieve the player entity!");
+
+        let target = Coord2d::new(position.x + delta[0], position.y + delta[1]);
+
+        if let Some(&occupant) = self.target_query.entities_at(&target).first() {
+            self.action_queue.push(Action::Attack {
+                attacker: player,
+                target: occupant,
+            });
+            self.turn_state.set_if_neq(GameTurnState::Npc);
+            return;
+        }
+
+        let is_wall = self
+            .game_map_query
+            .get_single()
+            .is_ok_and(|map| !map.is_in_bounds(&target) || map.tile_has_collision(&target));
+
+        if is_wall {
+            self.message_log
+                .push("You bump into a wall.", LogCategory::Warning);
+
+            if !self.gameplay_config.wall_bump_wastes_turn {
+                return;
+            }
+        }
+
+        self.action_queue.push(Action::Move {
+            actor: player,
+            delta,
+        });
+        self.turn_state.set_if_neq(GameTurnState::Npc);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use bevy::app::{App, Startup, Update};
-    use bevy::prelude::{Component, KeyCode};
+    use bevy::app::{App, PostUpdate, Startup, Update};
+    use bevy::input::gamepad::{Gamepad, GamepadButton, GamepadButtonType};
+    use bevy::prelude::{Component, Events, IntoSystemConfigs, KeyCode, Or};
 
-    use crate::plugins::game_state_systems::lifecycle::startup_system;
-    use crate::res::window_config::WindowConfig;
+    use crate::components::coord_2d::Coord2d;
+    use crate::components::enemy_type::EnemyType;
+    use crate::components::fov::Fov;
+    use crate::events::attack::Attack;
+    use crate::plugins::game_state_systems::action::action_system;
+    use crate::plugins::game_state_systems::enemy_ai::enemy_line_of_sight_system;
+    use crate::plugins::game_state_systems::lifecycle::{npc_turn_end_system, startup_system};
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::palette::Palette;
+    use crate::res::terminal_layout::TerminalLayout;
+    use crate::ui::rectangle::Rectangle;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
 
     use super::*;
 
@@ -199,16 +539,48 @@ mod tests {
         let mut app = App::new();
 
         app.add_event::<KeyboardInput>();
-        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.add_event::<Attack>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
         app.insert_resource(InputConfig {
             up: KeyCode::W,
             left: KeyCode::A,
             down: KeyCode::S,
             right: KeyCode::D,
+            up_left: KeyCode::Numpad7,
+            up_right: KeyCode::Numpad9,
+            down_left: KeyCode::Numpad1,
+            down_right: KeyCode::Numpad3,
             cancel: KeyCode::Escape,
+            confirm: KeyCode::Return,
+            wait: KeyCode::Period,
+            drop: KeyCode::R,
+            examine: KeyCode::L,
+            pickup: KeyCode::G,
+            use_item: KeyCode::U,
+            log_view: KeyCode::M,
+            cursor_hud: KeyCode::H,
+            spyglass: KeyCode::Z,
+            interact: KeyCode::E,
+            target: KeyCode::F,
+            toggle_fullscreen: KeyCode::F11,
+            debug_footer: KeyCode::F2,
+            toggle_fov: KeyCode::F1,
+            gamepad: Default::default(),
         });
         app.add_systems(Startup, startup_system);
-        app.add_systems(Update, keyboard_input_system);
+        app.add_systems(
+            Update,
+            (
+                enemy_line_of_sight_system,
+                keyboard_input_system,
+                action_system,
+            )
+                .chain(),
+        );
+        app.add_systems(PostUpdate, npc_turn_end_system);
 
         // Test keyboard up press and resulting player movement
 
@@ -245,6 +617,9 @@ mod tests {
                 .0
         );
 
+        // The NPC entities act in the frame after the player, which hands the turn back.
+        app.update();
+
         // Test keyboard left press and resulting player movement
 
         app.world.send_event(KeyboardInput {
@@ -266,6 +641,9 @@ mod tests {
                 .0
         );
 
+        // The NPC entities act in the frame after the player, which hands the turn back.
+        app.update();
+
         // Test keyboard down press and resulting player movement
 
         app.world.send_event(KeyboardInput {
@@ -287,6 +665,9 @@ mod tests {
                 .0
         );
 
+        // The NPC entities act in the frame after the player, which hands the turn back.
+        app.update();
+
         // Test keyboard right press and resulting player movement
 
         app.world.send_event(KeyboardInput {
@@ -308,6 +689,9 @@ mod tests {
                 .0
         );
 
+        // The NPC entities act in the frame after the player, which hands the turn back.
+        app.update();
+
         // Test unrecognized keyboard press and resulting player movement
 
         app.world.send_event(KeyboardInput {
@@ -327,4 +711,388 @@ mod tests {
                 .0
         );
     }
+
+    /// Runs a single move of the `player` to the left, into the wall of a fixed room.
+    fn bump_into_wall(wall_bump_wastes_turn: bool) -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<Attack>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            wall_bump_wastes_turn,
+            ..Default::default()
+        });
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, (keyboard_input_system, action_system).chain());
+
+        app.update();
+
+        let generated_map = app
+            .world
+            .query_filtered::<Entity, With<GameMap>>()
+            .single(&app.world);
+        app.world.despawn(generated_map);
+
+        let mut game_map = GameMap::new(&[20, 12], &TestTileMapGenerator, None);
+        Rectangle::new([1, 1], [8, 8]).add_to_map(&mut game_map);
+        app.world.spawn(game_map);
+
+        *app.world
+            .query_filtered::<&mut Coord2d, With<Player>>()
+            .single_mut(&mut app.world) = Coord2d::new(2, 4);
+
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 30,
+            key_code: Some(KeyCode::A),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        assert_eq!(
+            &Coord2d::new(2, 4),
+            app.world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world)
+        );
+        assert_eq!(
+            "You bump into a wall.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+
+        app
+    }
+
+    #[test]
+    fn test_wall_bump_keeps_turn() {
+        let app = bump_into_wall(false);
+
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+    }
+
+    #[test]
+    fn test_wall_bump_wastes_turn() {
+        let app = bump_into_wall(true);
+
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+    }
+
+    /// Places the `player` at the `start` position of a fixed room spanning from `[2, 2]` to `[8, 8]` without any
+    /// monsters, handling both keyboard and gamepad input. The `player's` [Fov] is marked as computed.
+    fn setup_fixed_room(start: Coord2d) -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<GamepadButtonInput>();
+        app.add_event::<Attack>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(
+            Update,
+            (keyboard_input_system, gamepad_input_system, action_system).chain(),
+        );
+
+        app.update();
+
+        let generated_entities: Vec<Entity> = app
+            .world
+            .query_filtered::<Entity, Or<(With<GameMap>, With<EnemyType>)>>()
+            .iter(&app.world)
+            .collect();
+
+        for entity in generated_entities {
+            app.world.despawn(entity);
+        }
+
+        let mut game_map = GameMap::new(&[20, 12], &TestTileMapGenerator, None);
+        Rectangle::new([1, 1], [8, 8]).add_to_map(&mut game_map);
+        app.world.spawn(game_map);
+
+        *app.world
+            .query_filtered::<&mut Coord2d, With<Player>>()
+            .single_mut(&mut app.world) = start;
+        app.world
+            .query_filtered::<&mut Fov, With<Player>>()
+            .single_mut(&mut app.world)
+            .is_dirty = false;
+
+        app
+    }
+
+    /// Runs a single move of the `player` from the `start` position of the [setup_fixed_room], according to the
+    /// pressed `key_code`.
+    fn move_in_fixed_room(start: Coord2d, key_code: KeyCode) -> App {
+        let mut app = setup_fixed_room(start);
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        app
+    }
+
+    fn player_position(app: &mut App) -> Coord2d {
+        *app.world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world)
+    }
+
+    #[test]
+    fn test_diagonal_movement() {
+        for (key_code, expected) in [
+            (KeyCode::Numpad7, Coord2d::new(4, 6)),
+            (KeyCode::Numpad9, Coord2d::new(6, 6)),
+            (KeyCode::Numpad1, Coord2d::new(4, 4)),
+            (KeyCode::Numpad3, Coord2d::new(6, 4)),
+        ] {
+            let mut app = move_in_fixed_room(Coord2d::new(5, 5), key_code);
+
+            assert_eq!(expected, player_position(&mut app));
+            assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+        }
+    }
+
+    #[test]
+    fn test_diagonal_movement_respects_collision() {
+        // The target lies in the left wall of the room, although the upwards movement alone would be free.
+        let mut app = move_in_fixed_room(Coord2d::new(2, 4), KeyCode::Numpad7);
+
+        assert_eq!(Coord2d::new(2, 4), player_position(&mut app));
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+
+        // The target lies in the lower right corner of the room's wall.
+        let mut app = move_in_fixed_room(Coord2d::new(8, 2), KeyCode::Numpad3);
+
+        assert_eq!(Coord2d::new(8, 2), player_position(&mut app));
+    }
+
+    #[test]
+    fn test_wait_passes_turn() {
+        let mut app = move_in_fixed_room(Coord2d::new(5, 5), KeyCode::Period);
+
+        assert_eq!(Coord2d::new(5, 5), player_position(&mut app));
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+        assert!(
+            !app.world
+                .query_filtered::<&Fov, With<Player>>()
+                .single(&app.world)
+                .is_dirty
+        );
+    }
+
+    #[test]
+    fn test_movement_is_dropped_during_npc_turn() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<Attack>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, (keyboard_input_system, action_system).chain());
+
+        app.update();
+
+        let player_coord = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 32,
+            key_code: Some(KeyCode::W),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        assert_eq!(
+            &player_coord,
+            app.world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world)
+        );
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_gamepad_input_system() {
+        let mut app = setup_fixed_room(Coord2d::new(5, 5));
+        let gamepad = Gamepad::new(0);
+
+        app.world.send_event(GamepadButtonInput {
+            button: GamepadButton::new(gamepad, GamepadButtonType::DPadRight),
+            state: ButtonState::Released,
+        });
+
+        app.update();
+
+        assert_eq!(Coord2d::new(5, 5), player_position(&mut app));
+        assert_eq!(
+            GameTurnState::Player,
+            *app.world.resource::<GameTurnState>()
+        );
+
+        app.world.send_event(GamepadButtonInput {
+            button: GamepadButton::new(gamepad, GamepadButtonType::DPadRight),
+            state: ButtonState::Pressed,
+        });
+
+        app.update();
+
+        assert_eq!(Coord2d::new(6, 5), player_position(&mut app));
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+
+        *app.world.resource_mut::<GameTurnState>() = GameTurnState::Player;
+
+        app.world.send_event(GamepadButtonInput {
+            button: GamepadButton::new(gamepad, GamepadButtonType::West),
+            state: ButtonState::Pressed,
+        });
+
+        app.update();
+
+        assert_eq!(Coord2d::new(6, 5), player_position(&mut app));
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_gamepad_movement_into_monster_attacks() {
+        let mut app = setup_fixed_room(Coord2d::new(5, 5));
+        let player = app
+            .world
+            .query_filtered::<Entity, With<Player>>()
+            .single(&app.world);
+        let monster = app.world.spawn((Coord2d::new(6, 5), Collision)).id();
+
+        app.world.send_event(GamepadButtonInput {
+            button: GamepadButton::new(Gamepad::new(0), GamepadButtonType::DPadRight),
+            state: ButtonState::Pressed,
+        });
+
+        app.update();
+
+        let events = app.world.resource::<Events<Attack>>();
+        let attacks: Vec<Attack> = events.get_reader().read(events).copied().collect();
+
+        assert_eq!(vec![Attack::new(player, monster)], attacks);
+        assert_eq!(Coord2d::new(5, 5), player_position(&mut app));
+        assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_click_on_floor_plans_travel() {
+        let mut game_map = GameMap::new(&[20, 12], &TestTileMapGenerator, None);
+        Rectangle::new([1, 1], [8, 8]).add_to_map(&mut game_map);
+
+        let mut travel_path = TravelPath::default();
+        let mut message_log = MessageLog::new(10);
+
+        plan_travel(
+            &[7, 6],
+            &Coord2d::new(3, 3),
+            &game_map,
+            &mut travel_path,
+            &mut message_log,
+        );
+
+        assert_ne!(TravelPath::default(), travel_path);
+        assert!(message_log.is_empty());
+
+        let mut last_step = [3, 3];
+
+        while let Some(step) = travel_path.next_step() {
+            assert_eq!(1, step.chebyshev_distance(&last_step));
+            last_step = step;
+        }
+
+        assert_eq!([7, 6], last_step);
+
+        for tile in [[1, 5], [30, 5]] {
+            plan_travel(
+                &tile,
+                &Coord2d::new(3, 3),
+                &game_map,
+                &mut travel_path,
+                &mut message_log,
+            );
+
+            assert_eq!(TravelPath::default(), travel_path);
+            assert_eq!(
+                "You can't walk there.",
+                message_log.recent(1).next().unwrap().text
+            );
+        }
+    }
+
+    #[test]
+    fn test_travel_system_takes_one_step_per_turn() {
+        let mut app = setup_fixed_room(Coord2d::new(3, 3));
+
+        app.add_systems(Update, travel_system.before(action_system));
+
+        app.world
+            .resource_mut::<TravelPath>()
+            .set(vec![[3, 3], [4, 3], [5, 3], [6, 3]]);
+
+        for expected in [Coord2d::new(4, 3), Coord2d::new(5, 3)] {
+            app.update();
+
+            assert_eq!(expected, player_position(&mut app));
+            assert_eq!(GameTurnState::Npc, *app.world.resource::<GameTurnState>());
+
+            *app.world.resource_mut::<GameTurnState>() = GameTurnState::Player;
+        }
+
+        // Any key press interrupts the travel.
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::H),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        assert_eq!(Coord2d::new(5, 3), player_position(&mut app));
+        assert_eq!(TravelPath::default(), *app.world.resource::<TravelPath>());
+    }
 }
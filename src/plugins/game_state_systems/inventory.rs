@@ -0,0 +1,821 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{
+    Commands, DetectChanges, DetectChangesMut, Entity, Query, Ref, Res, ResMut, With,
+};
+
+use crate::components::carryable::Carryable;
+use crate::components::consumable::Consumable;
+use crate::components::coord_2d::Coord2d;
+use crate::components::health::Health;
+use crate::components::inventory::Inventory;
+use crate::components::item::Item;
+use crate::components::name_tag::NameTag;
+use crate::components::player::Player;
+use crate::plugins::game_state_systems::input::PlayerKeys;
+use crate::plugins::states::GameTurnState;
+use crate::res::gameplay_config::GameplayConfig;
+use crate::res::input_config::InputType;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::turn_counter::TurnCounter;
+use crate::ui::game_map::GameMap;
+
+/// System dropping the selected item of the player's [Inventory] onto the floor when the user presses
+/// the [InputType::Drop] key.
+///
+/// The dropped item is re-materialized in the game's world at the position of the `player entity`,
+/// by adding a [Coord2d] to it. As items don't stack, nothing is dropped if the player stands on a cell
+/// already holding an item. A successful drop passes the turn to the `NPC entities`.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to place the dropped item in the game's world.
+/// * `player_keys`: The [PlayerKeys] providing the keys pressed by the user.
+/// * `turn_state`: [GameTurnState] to update after a successful drop.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the drop.
+/// * `player_query`: [Query] to retrieve the position and [Inventory] of the `player entity`.
+/// * `item_query`: [Query] to retrieve the positions of all items lying on the floor and the names of
+///   items for logging.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the position or [Inventory] of the `player entity` can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn drop_item_system(
+    mut commands: Commands,
+    mut player_keys: PlayerKeys,
+    mut turn_state: ResMut<GameTurnState>,
+    mut message_log: ResMut<MessageLog>,
+    mut player_query: Query<(&Coord2d, &mut Inventory), With<Player>>,
+    item_query: Query<(Option<&Coord2d>, Option<&NameTag>), With<Item>>,
+) {
+    for input in player_keys.read() {
+        if input != InputType::Drop {
+            continue;
+        }
+
+        let (player_position, mut inventory) = player_query.get_single_mut().expect(
+            "ECS -> Systems -> drop_item_system -> \
+            Unable to retrieve player {Coord2d} and {Inventory} components!",
+        );
+
+        let Some(item) = inventory.selected_item() else {
+            message_log.push("You have nothing to drop.", LogCategory::Warning);
+            continue;
+        };
+
+        let name = item_query
+            .get(item)
+            .ok()
+            .and_then(|(_, name_tag)| name_tag)
+            .map_or(String::from("item"), |name_tag| name_tag.text.clone());
+
+        if item_query
+            .iter()
+            .any(|(position, _)| position == Some(player_position))
+        {
+            message_log.push(
+                format!("There is no room to drop the {} here.", name),
+                LogCategory::Warning,
+            );
+            continue;
+        }
+
+        inventory.take_selected();
+        commands.entity(item).insert(*player_position);
+
+        message_log.push_info(format!("You drop the {}.", name));
+
+        turn_state.set_if_neq(GameTurnState::Npc);
+    }
+}
+
+/// System picking up the item lying on the cell of the `player entity` when the user presses the
+/// [InputType::Pickup] key. The item is removed from the game's world, by removing its [Coord2d], and
+/// added to the player's [Inventory], if there is room left. A successful pickup passes the turn to the
+/// `NPC entities`.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the picked up item from the game's world.
+/// * `player_keys`: The [PlayerKeys] providing the keys pressed by the user.
+/// * `turn_state`: [GameTurnState] to update after a successful pickup.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the pickup.
+/// * `player_query`: [Query] to retrieve the position and [Inventory] of the `player entity`.
+/// * `item_query`: [Query] to retrieve all [Carryable] items lying on the floor.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the position or [Inventory] of the `player entity` can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [auto_pickup_system]
+///
+pub fn pickup_item_system(
+    mut commands: Commands,
+    mut player_keys: PlayerKeys,
+    mut turn_state: ResMut<GameTurnState>,
+    mut message_log: ResMut<MessageLog>,
+    mut player_query: Query<(&Coord2d, &mut Inventory), With<Player>>,
+    item_query: Query<(Entity, &Coord2d, Option<&NameTag>), CarryableItem>,
+) {
+    for input in player_keys.read() {
+        if input != InputType::Pickup {
+            continue;
+        }
+
+        let (player_position, mut inventory) = player_query.get_single_mut().expect(
+            "ECS -> Systems -> pickup_item_system -> \
+            Unable to retrieve player {Coord2d} and {Inventory} components!",
+        );
+
+        let Some((item, _, name_tag)) = item_query
+            .iter()
+            .find(|(_, position, _)| *position == player_position)
+        else {
+            message_log.push("There is nothing to pick up here.", LogCategory::Warning);
+            continue;
+        };
+
+        if pick_up_item(
+            &mut commands,
+            &mut inventory,
+            &mut message_log,
+            item,
+            name_tag,
+        ) {
+            turn_state.set_if_neq(GameTurnState::Npc);
+        }
+    }
+}
+
+/// System automatically picking up the item the `player entity` steps onto, if enabled through
+/// [GameplayConfig::auto_pickup]. As the step itself already passed the turn, the pickup is free.
+///
+/// Only runs for a changed position of the `player entity`, so that an item dropped by the player
+/// isn't picked up again right away.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the picked up item from the game's world.
+/// * `gameplay_config`: [GameplayConfig] to check if the auto-pickup is enabled.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the pickup.
+/// * `player_query`: [Query] to retrieve the position and [Inventory] of the `player entity`.
+/// * `item_query`: [Query] to retrieve all [Carryable] items lying on the floor.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [pickup_item_system]
+///
+pub fn auto_pickup_system(
+    mut commands: Commands,
+    gameplay_config: Res<GameplayConfig>,
+    mut message_log: ResMut<MessageLog>,
+    mut player_query: Query<(Ref<Coord2d>, &mut Inventory), With<Player>>,
+    item_query: Query<(Entity, &Coord2d, Option<&NameTag>), CarryableItem>,
+) {
+    if !gameplay_config.auto_pickup {
+        return;
+    }
+
+    let Ok((player_position, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    if !player_position.is_changed() {
+        return;
+    }
+
+    if let Some((item, _, name_tag)) = item_query
+        .iter()
+        .find(|(_, position, _)| **position == *player_position)
+    {
+        pick_up_item(
+            &mut commands,
+            &mut inventory,
+            &mut message_log,
+            item,
+            name_tag,
+        );
+    }
+}
+
+/// System using the selected item of the player's [Inventory] when the user presses the [InputType::UseItem]
+/// key. Only items with a [Consumable] can be used, which are removed from the [Inventory] and despawned after
+/// their effect has been applied. A successful use passes the turn to the `NPC entities`, while a
+/// [Consumable::Healing] item is kept if the player is already at full [Health].
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to despawn the used item.
+/// * `player_keys`: The [PlayerKeys] providing the keys pressed by the user.
+/// * `turn_state`: [GameTurnState] to update after a successful use.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the use.
+/// * `player_query`: [Query] to retrieve the [Inventory] and [Health] of the `player entity`.
+/// * `item_query`: [Query] to retrieve the names and [Consumable]s of the carried items.
+/// * `mapped_floor`: The [MappedFloor] revealed by a [Consumable::Mapping] item.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the [Inventory] or [Health] of the `player entity` can't be retrieved from the ECS.
+/// * If a [Consumable::Mapping] is used, but the [GameMap] can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn use_item_system(
+    mut commands: Commands,
+    mut player_keys: PlayerKeys,
+    mut turn_state: ResMut<GameTurnState>,
+    mut message_log: ResMut<MessageLog>,
+    mut player_query: Query<(&mut Inventory, &mut Health), With<Player>>,
+    item_query: Query<(Option<&NameTag>, Option<&Consumable>), With<Item>>,
+    mut mapped_floor: MappedFloor,
+) {
+    for input in player_keys.read() {
+        if input != InputType::UseItem {
+            continue;
+        }
+
+        let (mut inventory, mut health) = player_query.get_single_mut().expect(
+            "ECS -> Systems -> use_item_system -> Unable to retrieve player {Inventory} or {Health} component!",
+        );
+
+        let Some(item) = inventory.selected_item() else {
+            message_log.push("You have nothing to use.", LogCategory::Warning);
+            continue;
+        };
+
+        let (name_tag, consumable) = item_query.get(item).unwrap_or((None, None));
+        let name = name_tag.map_or(String::from("item"), |name_tag| name_tag.text.clone());
+
+        let Some(consumable) = consumable else {
+            message_log.push(format!("You can't use the {}.", name), LogCategory::Warning);
+            continue;
+        };
+
+        match consumable {
+            Consumable::Mapping => {
+                mapped_floor.reveal();
+                message_log.push_info(format!(
+                    "You read the {}. The layout of the floor is revealed to you.",
+                    name
+                ));
+            }
+            Consumable::Healing(amount) => {
+                if health.current >= health.max {
+                    message_log.push("You are already at full health.", LogCategory::Warning);
+                    continue;
+                }
+
+                let previous = health.current;
+                health.heal(*amount);
+
+                message_log.push_info(format!(
+                    "You drink the {}. You recover {} hit points.",
+                    name,
+                    health.current - previous
+                ));
+            }
+        }
+
+        inventory.take_selected();
+        commands.entity(item).despawn();
+
+        turn_state.set_if_neq(GameTurnState::Npc);
+    }
+}
+
+/// The [GameMap] of the current floor, which is revealed entirely by a [Consumable::Mapping] item, along with the
+/// [TurnCounter] providing the turn in which the revealed tiles are remembered.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct MappedFloor<'w, 's> {
+    /// [TurnCounter] providing the turn in which revealed tiles are remembered.
+    turn_counter: Res<'w, TurnCounter>,
+    /// [Query] to retrieve the [GameMap] to reveal.
+    game_map_query: Query<'w, 's, &'static mut GameMap>,
+}
+
+impl MappedFloor<'_, '_> {
+    /// Reveals all tiles of the [GameMap], remembering them in the current turn.
+    ///
+    /// returns: ()
+    ///
+    /// # Panics
+    ///
+    /// * If the [GameMap] can't be retrieved from the ECS.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn reveal(&mut self) {
+        let mut map = self
+            .game_map_query
+            .get_single_mut()
+            .expect("ECS -> Systems -> use_item_system -> Unable to retrieve {GameMap} component!");
+
+        map.reveal_all(self.turn_counter.current());
+    }
+}
+
+/// The filter matching the items, which can be picked up by the [pickup_item_system] and [auto_pickup_system].
+type CarryableItem = (With<Item>, With<Carryable>);
+
+/// Internal function to move the passed `item` from the floor into the passed `inventory`, respecting
+/// its capacity. The outcome is reported through the `message_log`.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the `item` from the game's world.
+/// * `inventory`: The [Inventory] to add the `item` to.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the pickup.
+/// * `item`: The item `entity` to pick up.
+/// * `name_tag`: The optional [NameTag] of the `item`, used for the message.
+///
+/// returns: bool - `true` if the `item` was picked up, `false` if the `inventory` is full.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn pick_up_item(
+    commands: &mut Commands,
+    inventory: &mut Inventory,
+    message_log: &mut MessageLog,
+    item: Entity,
+    name_tag: Option<&NameTag>,
+) -> bool {
+    let name = name_tag.map_or("item", |name_tag| name_tag.text.as_str());
+
+    if !inventory.push(item) {
+        message_log.push(
+            format!("Your inventory is full, you can't pick up the {}.", name),
+            LogCategory::Warning,
+        );
+        return false;
+    }
+
+    commands.entity(item).remove::<Coord2d>();
+    message_log.push_info(format!("You pick up the {}.", name));
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Startup, Update};
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::ButtonState;
+    use bevy::prelude::{Color, Component, Entity, IntoSystemConfigs, KeyCode};
+
+    use crate::ascii_sprite;
+    use crate::components::ascii_sprite::AsciiSprite;
+    use crate::core::constants;
+    use crate::plugins::game_state_systems::lifecycle::startup_system;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::input_config::InputConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::palette::Palette;
+    use crate::res::terminal_layout::TerminalLayout;
+    use crate::ui::tile_map::TileMap;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> (App, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, drop_item_system);
+
+        app.update();
+
+        let item = app
+            .world
+            .spawn((
+                ascii_sprite!('!', Color::PURPLE),
+                NameTag::new("Health Potion"),
+                Item,
+            ))
+            .id();
+
+        app.world
+            .query_filtered::<&mut Inventory, With<Player>>()
+            .single_mut(&mut app.world)
+            .items
+            .push(item);
+
+        (app, item)
+    }
+
+    fn setup_pickup_app(auto_pickup: bool) -> (App, Entity) {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            auto_pickup,
+            ..Default::default()
+        });
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(Update, (pickup_item_system, auto_pickup_system).chain());
+
+        app.update();
+
+        let player_position = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        let item = app
+            .world
+            .spawn((
+                Coord2d::new(player_position.x + 1, player_position.y),
+                ascii_sprite!('!', Color::PURPLE),
+                NameTag::new("Health Potion"),
+                Item,
+                Carryable,
+            ))
+            .id();
+
+        (app, item)
+    }
+
+    fn setup_use_app() -> (App, Entity) {
+        let (mut app, _) = setup_app();
+
+        app.add_systems(Update, use_item_system);
+
+        let scroll = app
+            .world
+            .spawn((NameTag::new("Scroll of Mapping"), Item, Consumable::Mapping))
+            .id();
+
+        let mut inventory = app
+            .world
+            .query_filtered::<&mut Inventory, With<Player>>()
+            .single_mut(&mut app.world);
+
+        inventory.items.clear();
+        inventory.items.push(scroll);
+
+        (app, scroll)
+    }
+
+    fn step_onto_item(app: &mut App, item: Entity) {
+        let item_position = *app.world.get::<Coord2d>(item).unwrap();
+
+        *app.world
+            .query_filtered::<&mut Coord2d, With<Player>>()
+            .single_mut(&mut app.world) = item_position;
+
+        app.update();
+    }
+
+    fn player_items(app: &mut App) -> Vec<Entity> {
+        app.world
+            .query_filtered::<&Inventory, With<Player>>()
+            .single(&app.world)
+            .items
+            .clone()
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 19,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    #[test]
+    fn test_drop_item_system() {
+        let (mut app, item) = setup_app();
+
+        press(&mut app, KeyCode::R);
+
+        let player_position = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        assert_eq!(Some(&player_position), app.world.get::<Coord2d>(item));
+        assert!(app.world.get::<AsciiSprite>(item).is_some());
+        assert!(app
+            .world
+            .query_filtered::<&Inventory, With<Player>>()
+            .single(&app.world)
+            .items
+            .is_empty());
+        assert_eq!(&GameTurnState::Npc, app.world.resource::<GameTurnState>());
+        assert_eq!(
+            "You drop the Health Potion.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_drop_item_system_on_occupied_cell() {
+        let (mut app, item) = setup_app();
+
+        let player_position = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        app.world.spawn((player_position, Item));
+
+        press(&mut app, KeyCode::R);
+
+        assert!(app.world.get::<Coord2d>(item).is_none());
+        assert_eq!(
+            vec![item],
+            app.world
+                .query_filtered::<&Inventory, With<Player>>()
+                .single(&app.world)
+                .items
+        );
+        assert_eq!(
+            &GameTurnState::Player,
+            app.world.resource::<GameTurnState>()
+        );
+    }
+
+    #[test]
+    fn test_use_scroll_of_mapping() {
+        let (mut app, scroll) = setup_use_app();
+
+        let mut game_map_query = app.world.query::<&mut GameMap>();
+        let mut map = game_map_query.single_mut(&mut app.world);
+
+        map.reset_visible_tiles();
+        map.mark_tile_as_visible(&[1, 1]);
+
+        press(&mut app, KeyCode::U);
+
+        let map = game_map_query.single(&app.world);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                assert!(map.is_tile_seen(&[x, y]), "[{}, {}] is unseen", x, y);
+                assert_eq!([x, y] == [1, 1], map.is_tile_visible(&[x, y]));
+            }
+        }
+
+        assert!(app.world.get_entity(scroll).is_none());
+        assert!(player_items(&mut app).is_empty());
+        assert_eq!(&GameTurnState::Npc, app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_use_item_without_effect() {
+        let (mut app, item) = setup_app();
+
+        app.add_systems(Update, use_item_system);
+
+        press(&mut app, KeyCode::U);
+
+        assert_eq!(vec![item], player_items(&mut app));
+        assert!(app.world.get_entity(item).is_some());
+        assert_eq!(
+            &GameTurnState::Player,
+            app.world.resource::<GameTurnState>()
+        );
+        assert_eq!(
+            "You can't use the Health Potion.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_use_health_potion() {
+        let (mut app, scroll) = setup_use_app();
+
+        let potion = app
+            .world
+            .spawn((
+                NameTag::new("Health Potion"),
+                Item,
+                Consumable::Healing(constants::HEALTH_POTION_HEALING),
+            ))
+            .id();
+
+        let mut player_query = app
+            .world
+            .query_filtered::<(&mut Inventory, &mut Health), With<Player>>();
+        let (mut inventory, mut health) = player_query.single_mut(&mut app.world);
+
+        inventory.items = vec![potion, scroll];
+        health.current = health.max;
+
+        press(&mut app, KeyCode::U);
+
+        assert_eq!(vec![potion, scroll], player_items(&mut app));
+        assert_eq!(
+            &GameTurnState::Player,
+            app.world.resource::<GameTurnState>()
+        );
+
+        let (_, mut health) = player_query.single_mut(&mut app.world);
+
+        health.current = 5;
+
+        press(&mut app, KeyCode::U);
+
+        let (_, health) = player_query.single(&app.world);
+
+        assert_eq!(5 + constants::HEALTH_POTION_HEALING, health.current);
+        assert!(app.world.get_entity(potion).is_none());
+        assert_eq!(vec![scroll], player_items(&mut app));
+        assert_eq!(&GameTurnState::Npc, app.world.resource::<GameTurnState>());
+        assert_eq!(
+            format!(
+                "You drink the Health Potion. You recover {} hit points.",
+                constants::HEALTH_POTION_HEALING
+            ),
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_auto_pickup_grabs_item_on_step() {
+        let (mut app, item) = setup_pickup_app(true);
+
+        step_onto_item(&mut app, item);
+
+        assert_eq!(vec![item], player_items(&mut app));
+        assert!(app.world.get::<Coord2d>(item).is_none());
+        assert_eq!(
+            "You pick up the Health Potion.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_manual_pickup_requires_action() {
+        let (mut app, item) = setup_pickup_app(false);
+
+        step_onto_item(&mut app, item);
+
+        assert!(player_items(&mut app).is_empty());
+        assert!(app.world.get::<Coord2d>(item).is_some());
+
+        press(&mut app, KeyCode::G);
+
+        assert_eq!(vec![item], player_items(&mut app));
+        assert!(app.world.get::<Coord2d>(item).is_none());
+        assert_eq!(&GameTurnState::Npc, app.world.resource::<GameTurnState>());
+    }
+
+    #[test]
+    fn test_pickup_ignores_items_which_cant_be_carried() {
+        let (mut app, item) = setup_pickup_app(true);
+
+        app.world.entity_mut(item).remove::<Carryable>();
+
+        step_onto_item(&mut app, item);
+        press(&mut app, KeyCode::G);
+
+        assert!(player_items(&mut app).is_empty());
+        assert!(app.world.get::<Coord2d>(item).is_some());
+        assert_eq!(
+            "There is nothing to pick up here.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+
+    #[test]
+    fn test_auto_pickup_respects_capacity() {
+        let (mut app, item) = setup_pickup_app(true);
+
+        let mut inventory = app
+            .world
+            .query_filtered::<&mut Inventory, With<Player>>()
+            .single_mut(&mut app.world);
+        inventory.capacity = 0;
+
+        step_onto_item(&mut app, item);
+
+        assert!(player_items(&mut app).is_empty());
+        assert!(app.world.get::<Coord2d>(item).is_some());
+        assert_eq!(
+            "Your inventory is full, you can't pick up the Health Potion.",
+            app.world
+                .resource::<MessageLog>()
+                .recent(1)
+                .next()
+                .unwrap()
+                .text
+        );
+    }
+}
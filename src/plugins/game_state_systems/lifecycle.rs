@@ -19,32 +19,84 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
-use bevy::prelude::{Commands, DetectChangesMut, Entity, Query, Res, ResMut, With};
-use log::debug;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{
+    Commands, DetectChanges, DetectChangesMut, Entity, EventWriter, Query, Res, ResMut, Resource,
+    Time, With, Without, World,
+};
+use log::{debug, warn};
 
+use crate::components::coord_2d::Coord2d;
+use crate::components::health::Health;
+use crate::components::player::Player;
 use crate::components::state_label::GameStateLabel;
+use crate::core::constants;
+use crate::core::dijkstra_map::DijkstraMap;
 use crate::core::dimension_2d::Dimension2d;
+use crate::core::monster_spawn_table::MonsterSpawnTable;
+use crate::core::position_2d::Position2d;
+use crate::core::rng::RandomNumberGenerator;
+use crate::core::save_game::SaveGame;
+use crate::entities::game_state_commands::GameStateCommands;
+use crate::entities::item_factory::ItemFactory;
 use crate::entities::monster_factory::MonsterFactory;
 use crate::entities::player_factory::PlayerFactory;
+use crate::events::map_changed::MapChanged;
+use crate::plugins::game_state_systems::input::PlayerKeys;
 use crate::plugins::states::GameTurnState;
-use crate::res::window_config::WindowConfig;
+use crate::res::action_queue::ActionQueue;
+use crate::res::animation_queue::AnimationQueue;
+use crate::res::cursor_hud::CursorHud;
+use crate::res::debug_footer::DebugFooter;
+use crate::res::dungeon_depth::DungeonDepth;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::fov_algorithm::FovAlgorithm;
+use crate::res::fov_cache::FovCache;
+use crate::res::fov_shape::FovShape;
+use crate::res::gameplay_config::{GameplayConfig, SpawnStrategy};
+use crate::res::input_config::InputType;
+use crate::res::log_config::LogConfig;
+use crate::res::log_view::LogView;
+use crate::res::map_camera::MapCamera;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::npc_turn_queue::NpcTurnQueue;
+use crate::res::palette::Palette;
+use crate::res::targeting_mode::TargetingMode;
+use crate::res::terminal_layout::TerminalLayout;
+use crate::res::travel_path::TravelPath;
+use crate::res::turn_counter::TurnCounter;
+use crate::res::turn_delay::TurnDelay;
 use crate::ui::game_map::GameMap;
-use crate::ui::tile_map_layout_generator::BaseTileMapGenerator;
+use crate::ui::rectangle::Rectangle;
+use crate::ui::room::Room;
+use crate::ui::tile::{MapTile, MapTileType};
+use crate::ui::tile_map::TileMap;
+use crate::ui::tile_map_layout_generator::TileMapGeneratorRegistry;
 
 /// System which is run when the game's state machine changes into the
 /// [AppState::Game] state to spawn all required  `entities`.
 ///
+/// If a [SaveGame] was loaded to continue, the saved floor is regenerated from its seed and the `player entity`
+/// restored with its position and [Health]. A saved position, which doesn't fit the regenerated floor anymore,
+/// e.g., after resizing the terminal, falls back to the starting position of the floor.
+///
 /// # Arguments
 ///
 /// * `commands`: [Commands] queue required to spawn the necessary `entities`.
-/// * `window_config`: [WindowConfig] resource required to check the bounds of the game's
-/// window during the `entity` creation.
+/// * `terminal_layout`: [TerminalLayout] resource providing the map panel, which the [MapCamera] renders into and
+///   the floor fills, unless the [GameplayConfig::map_size] is set.
+/// * `gameplay_config`: [GameplayConfig] resource providing the difficulty, which scales the number
+///   of spawned monsters and their stats, the [SpawnStrategy] of the `player entity` and the initial
+///   [FovAlgorithm] and [FovShape].
+/// * `log_config`: [LogConfig] resource providing the history capacity of the [MessageLog].
+/// * `palette`: [Palette] resource providing the colors of the spawned `entities`.
+/// * `save_game`: The [SaveGame] to continue, if any, which is removed once it has been restored.
 ///
 /// returns: ()
 ///
 /// # Panics
 ///
-/// * If the [WindowConfig] resource can't be retrieved from the ECS.
+/// * If the [TerminalLayout], [GameplayConfig], [LogConfig] or [Palette] resource can't be retrieved from the ECS.
 /// * If no starting position for the `player entity` can be determined.
 ///
 /// # About
@@ -53,37 +105,722 @@ use crate::ui::tile_map_layout_generator::BaseTileMapGenerator;
 ///
 /// Since: `0.1.5`
 ///
-pub fn startup_system(mut commands: Commands, window_config: Res<WindowConfig>) {
+pub fn startup_system(
+    mut commands: Commands,
+    terminal_layout: Res<TerminalLayout>,
+    gameplay_config: Res<GameplayConfig>,
+    log_config: Res<LogConfig>,
+    palette: Res<Palette>,
+    save_game: Option<Res<SaveGame>>,
+) {
     commands.insert_resource(GameTurnState::Player);
+    commands.insert_resource(FovCache::default());
+    commands.insert_resource(MessageLog::new(log_config.history_capacity()));
+    commands.insert_resource(ExamineMode::default());
+    commands.insert_resource(TargetingMode::default());
+    commands.insert_resource(LogView::default());
+    commands.insert_resource(CursorHud::default());
+    commands.insert_resource(AnimationQueue::default());
+    commands.insert_resource(ActionQueue::default());
+    commands.insert_resource(TravelPath::default());
+    commands.insert_resource(NpcTurnQueue::default());
+    commands.insert_resource(TurnCounter::default());
+    commands.insert_resource(DebugFooter::default());
+    commands.insert_resource(MapCamera::new(&terminal_layout.map));
+    commands.insert_resource(TurnDelay::new(gameplay_config.turn_delay_ms));
+    commands.insert_resource(gameplay_config.fov_algorithm);
+    commands.insert_resource(gameplay_config.fov_shape);
+
+    let dungeon_depth = save_game
+        .as_ref()
+        .map_or_else(DungeonDepth::default, |save_game| save_game.dungeon_depth);
+    let seed = save_game.as_ref().map_or_else(
+        || floor_seed(&gameplay_config, &dungeon_depth),
+        |save_game| Some(save_game.seed),
+    );
+
+    let mut game_map = generate_map(&terminal_layout, &gameplay_config, seed);
+    let mut starting_position = populate_level(
+        &mut commands,
+        &mut game_map,
+        &gameplay_config,
+        &palette,
+        &dungeon_depth,
+    );
+
+    if let Some(save_game) = save_game.as_ref() {
+        if game_map.is_in_bounds(&save_game.player_position)
+            && !game_map.tile_has_collision(&save_game.player_position)
+        {
+            starting_position = save_game.player_position;
+        } else {
+            warn!(
+                "ECS -> Systems -> startup_system -> Saved position {:?} doesn't fit the restored map, \
+                falling back to {:?}!",
+                save_game.player_position, starting_position
+            );
+        }
+    }
+
+    let player = PlayerFactory::spawn(
+        &mut commands,
+        &starting_position,
+        &gameplay_config,
+        &palette,
+    );
+
+    if let Some(save_game) = save_game {
+        commands.entity(player).insert(save_game.health);
+        commands.remove_resource::<SaveGame>();
+    }
+
+    commands.insert_resource(dungeon_depth);
+    commands.spawn_in_game_state(game_map);
+}
+
+/// System descending to the next floor of the dungeon, once the user presses the [InputType::Interact] key
+/// while the `player entity` stands on a [MapTileType::DownStairs] tile.
+///
+/// All monsters and items lying on the floor are despawned and the [GameMap] is replaced by a freshly generated
+/// one for the next [DungeonDepth], which is populated like the first floor, see [populate_level]. The
+/// `player entity` itself, including its [crate::components::health::Health] and carried items, is kept and
+/// placed at the starting position of the new floor. Descending doesn't pass the turn, so the player gets to
+/// act first on the new floor. With [GameplayConfig::autosave] enabled, the run is saved on arrival, see
+/// [SaveGame::save].
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to despawn the old and spawn the new `entities`.
+/// * `player_keys`: The [PlayerKeys] providing the keys pressed by the user.
+/// * `next_floor`: The [NextFloor] providing everything required to generate and populate the next floor.
+/// * `current_floor`: The [CurrentFloor] to replace.
+/// * `message_log`: [MessageLog] to inform the player about the outcome of the interaction.
+/// * `player_query`: [Query] to retrieve the position and [Health] of the `player entity`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the position of the `player entity` or the [GameMap] can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn descend_system(
+    mut commands: Commands,
+    mut player_keys: PlayerKeys,
+    mut next_floor: NextFloor,
+    mut current_floor: CurrentFloor,
+    mut message_log: ResMut<MessageLog>,
+    mut player_query: Query<(&mut Coord2d, &Health), With<Player>>,
+) {
+    for input in player_keys.read() {
+        if input != InputType::Interact {
+            continue;
+        }
+
+        let (mut player_position, health) = player_query.get_single_mut().expect(
+            "ECS -> Systems -> descend_system -> Unable to retrieve player {Coord2d} and {Health} components!",
+        );
+
+        let mut game_map = current_floor.game_map_query.get_single_mut().expect(
+            "ECS -> Systems -> descend_system -> Unable to retrieve the {GameMap} component!",
+        );
+
+        if game_map.get_tile_at(&*player_position).kind != MapTileType::DownStairs {
+            message_log.push("There are no stairs here.", LogCategory::Warning);
+            continue;
+        }
+
+        for entity in current_floor.level_query.iter() {
+            commands.entity(entity).despawn();
+        }
+
+        next_floor.dungeon_depth.descend();
+
+        let NextFloor {
+            terminal_layout,
+            gameplay_config,
+            palette,
+            dungeon_depth,
+        } = &next_floor;
+
+        let seed = floor_seed(gameplay_config, dungeon_depth);
+        let mut next_map = generate_map(terminal_layout, gameplay_config, seed);
+        let starting_position = populate_level(
+            &mut commands,
+            &mut next_map,
+            gameplay_config,
+            palette,
+            dungeon_depth,
+        );
+
+        *game_map = next_map;
+        *player_position = Coord2d::from_position(&starting_position);
+        current_floor.map_changed_writer.send(MapChanged);
+
+        message_log.push_info(format!("You descend to depth {}.", dungeon_depth.current()));
+
+        if gameplay_config.autosave {
+            let save_game =
+                SaveGame::new(&*player_position, health, dungeon_depth, game_map.seed());
+
+            if !save_game.save() {
+                message_log.push("The game couldn't be saved.", LogCategory::Warning);
+            }
+        }
+    }
+}
+
+/// The resources the [descend_system] requires to generate and populate the next floor of the dungeon.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct NextFloor<'w> {
+    /// [TerminalLayout] resource providing the size of the next floor.
+    terminal_layout: Res<'w, TerminalLayout>,
+    /// [GameplayConfig] resource required to generate and populate the next floor.
+    gameplay_config: Res<'w, GameplayConfig>,
+    /// [Palette] resource providing the colors of the spawned `entities`.
+    palette: Res<'w, Palette>,
+    /// The [DungeonDepth] to advance.
+    dungeon_depth: ResMut<'w, DungeonDepth>,
+}
+
+/// The current floor of the dungeon, which the [descend_system] replaces by the next one.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct CurrentFloor<'w, 's> {
+    /// [Query] to retrieve the [GameMap] to replace.
+    game_map_query: Query<'w, 's, &'static mut GameMap>,
+    /// [Query] to retrieve all other `entities` placed on the [GameMap].
+    level_query: Query<'w, 's, Entity, (With<Coord2d>, Without<Player>)>,
+    /// [EventWriter] signaling the new layout through a [MapChanged] event.
+    map_changed_writer: EventWriter<'w, MapChanged>,
+}
 
-    let game_map = GameMap::new(&window_config.terminal_size(), &BaseTileMapGenerator);
-    let starting_position = game_map
+/// Internal function to generate a [GameMap] from the passed `seed` through the
+/// [crate::ui::tile_map_layout_generator::TileMapLayoutGenerator] configured in the `gameplay_config`.
+///
+/// The map fills the terminal, except for the topmost rows, which are reserved for the message log.
+/// # Arguments
+///
+/// * `terminal_layout`: [TerminalLayout] providing the map panel, which the floor fills, unless the
+///   [GameplayConfig::map_size] is set.
+/// * `gameplay_config`: [GameplayConfig] providing the generator and the optional map size.
+/// * `seed`: The seed to generate the layout from, see [floor_seed]. Without a seed, a random one is picked.
+///
+/// returns: [GameMap]
+///
+/// # Panics
+///
+/// * If the default generator isn't registered in the [TileMapGeneratorRegistry].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn generate_map(
+    terminal_layout: &TerminalLayout,
+    gameplay_config: &GameplayConfig,
+    seed: Option<u64>,
+) -> GameMap {
+    // Without a configured size, the map fills the map panel, leaving the message log and the sidebar untouched.
+    let map_size = gameplay_config
+        .map_size
+        .unwrap_or_else(|| [terminal_layout.map.width(), terminal_layout.map.height()]);
+
+    let generator_registry = TileMapGeneratorRegistry::default();
+
+    let generator = generator_registry
+        .get(&gameplay_config.generator)
+        .unwrap_or_else(|| {
+            warn!(
+                "ECS -> Systems -> startup_system -> Unknown map generator {:?}, expected one of {:?}, \
+                falling back to {:?}!",
+                gameplay_config.generator,
+                generator_registry.names(),
+                TileMapGeneratorRegistry::DEFAULT_GENERATOR
+            );
+
+            generator_registry
+                .get(TileMapGeneratorRegistry::DEFAULT_GENERATOR)
+                .expect("ECS -> Systems -> startup_system -> Default map generator isn't registered!")
+        });
+
+    GameMap::new(&map_size, generator, seed)
+}
+
+/// Internal function to derive the seed of the floor of the passed `dungeon_depth` from the seed configured in
+/// the `gameplay_config`. The configured seed is offset by the depth, so every floor of a replayed dungeon has its
+/// own layout, while the first floor still matches the seed.
+///
+/// # Arguments
+///
+/// * `gameplay_config`: [GameplayConfig] providing the optional seed.
+/// * `dungeon_depth`: The [DungeonDepth] of the floor.
+///
+/// returns: [Option]<u64> - The seed of the floor, or [None] if no seed is configured.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn floor_seed(gameplay_config: &GameplayConfig, dungeon_depth: &DungeonDepth) -> Option<u64> {
+    gameplay_config
+        .seed
+        .map(|seed| seed.wrapping_add(u64::from(dungeon_depth.current() - 1)))
+}
+
+/// Internal function to populate the freshly generated `game_map` of the passed `dungeon_depth`, returning
+/// the starting position of the `player entity`.
+///
+/// The difficulty configured in the `gameplay_config` scales the number of monsters, of which every floor
+/// further down adds another one per room. The type of each monster is rolled from the [MonsterSpawnTable], whose
+/// variety grows with the `dungeon_depth`. A single scroll of mapping waits in the room furthest down the
+/// generation order and the configured number of health potions is scattered across the other rooms, see
+/// [item_spawn_positions]. The stairs leading further down are placed on the floor tile furthest away from
+/// the starting position. Below the first floor, the `player entity` arrives on the stairs leading back up.
+/// Finally, the rooms are decorated around them.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to spawn the monsters and items.
+/// * `game_map`: The [GameMap] to populate.
+/// * `gameplay_config`: [GameplayConfig] providing the difficulty and the [SpawnStrategy].
+/// * `palette`: [Palette] providing the colors of the spawned `entities`.
+/// * `dungeon_depth`: The [DungeonDepth] of the `game_map`.
+///
+/// returns: [i32; 2]
+///
+/// # Panics
+///
+/// * If no starting position for the `player entity` can be determined.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn populate_level(
+    commands: &mut Commands,
+    game_map: &mut GameMap,
+    gameplay_config: &GameplayConfig,
+    palette: &Palette,
+    dungeon_depth: &DungeonDepth,
+) -> [i32; 2] {
+    let starting_position = player_spawn_position(game_map, gameplay_config.spawn_strategy);
+
+    let monster_rooms = monster_rooms(game_map.rooms(), &starting_position);
+
+    let monster_count = gameplay_config
+        .scale_by_difficulty(monster_rooms.len() as i32 * dungeon_depth.current() as i32)
+        as usize;
+    let monster_positions = monster_spawn_positions(
+        &monster_rooms,
+        monster_count,
+        gameplay_config.monster_spacing,
+    );
+
+    // A single scroll of mapping waits in the room furthest down the generation order, away from the player.
+    let scroll_position = game_map
+        .rooms()
+        .iter()
+        .rev()
+        .find(|room| !room.bounds.contains(&starting_position))
+        .map(|room| room.center());
+
+    let mut reserved_positions = monster_positions.clone();
+    reserved_positions.push(starting_position);
+    reserved_positions.extend(scroll_position);
+
+    let item_rooms: Vec<&Room> = game_map
         .rooms()
-        .first()
-        .expect("ECS -> Systems -> startup_system -> Unable to find a starting position for the player entity!")
-        .center();
+        .iter()
+        .filter(|room| !room.bounds.contains(&starting_position))
+        .collect();
+    let mut rng = RandomNumberGenerator::seeded(game_map.seed());
+    let item_positions = item_spawn_positions(
+        &item_rooms,
+        gameplay_config.item_count,
+        &reserved_positions,
+        &mut rng,
+    );
+    reserved_positions.extend(item_positions.iter().copied());
+
+    let stairs_position = stairs_position(game_map, &starting_position, &reserved_positions);
+    reserved_positions.push(stairs_position);
+
+    game_map.decorate_rooms(gameplay_config.decoration_density, &reserved_positions);
+    game_map.set_tile_at(&stairs_position, MapTile::stairs_down());
+
+    if dungeon_depth.current() > 1 {
+        game_map.set_tile_at(&starting_position, MapTile::stairs_up());
+    }
+
+    game_map.refresh_fingerprint();
+
+    let spawn_table = MonsterSpawnTable::default();
+
+    for position in monster_positions {
+        let enemy_type = spawn_table.roll(dungeon_depth.current(), &mut rng);
+        MonsterFactory::spawn(commands, enemy_type, &position, gameplay_config, palette);
+    }
+
+    if let Some(position) = scroll_position {
+        ItemFactory::spawn_scroll_of_mapping(commands, &position, palette);
+    }
 
-    PlayerFactory::spawn(&mut commands, &starting_position);
+    for position in item_positions {
+        ItemFactory::spawn_health_potion(commands, &position, palette);
+    }
+
+    starting_position
+}
+
+/// Internal function to scatter `count` items randomly across the passed `rooms`. Each item is placed on a random
+/// floor position of a random room, skipping the `reserved` positions and those of already placed items. Items
+/// for which the chosen room has no free floor position left are skipped.
+///
+/// # Arguments
+///
+/// * `rooms`: The [Room]s in which the items should be placed.
+/// * `count`: The number of items to place.
+/// * `reserved`: The positions already taken by other `entities`.
+/// * `rng`: The [RandomNumberGenerator] choosing the rooms and positions.
+///
+/// returns: [Vec]<[i32; 2]>
+///
+/// # Examples
+///
+/// ```
+/// let room = Room::new(Rectangle::new([0, 0], [8, 8]), RoomShape::Rect);
+/// let positions = item_spawn_positions(&[&room], 3, &[[4, 4]], &mut RandomNumberGenerator::seeded(42));
+///
+/// assert_eq!(3, positions.len());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn item_spawn_positions(
+    rooms: &[&Room],
+    count: usize,
+    reserved: &[[i32; 2]],
+    rng: &mut RandomNumberGenerator,
+) -> Vec<[i32; 2]> {
+    let mut positions: Vec<[i32; 2]> = Vec::with_capacity(count);
+
+    if rooms.is_empty() {
+        return positions;
+    }
+
+    for _ in 0..count {
+        let room = rooms[rng.range(0..rooms.len())];
+        let candidates: Vec<[i32; 2]> = room
+            .floor_positions()
+            .into_iter()
+            .filter(|position| !reserved.contains(position) && !positions.contains(position))
+            .collect();
+
+        if candidates.is_empty() {
+            debug!(
+                "ECS -> Systems -> startup_system -> No free position left for an item in {:?}",
+                room
+            );
+            continue;
+        }
+
+        positions.push(candidates[rng.range(0..candidates.len())]);
+    }
+
+    positions
+}
+
+/// Internal function to determine the position of the stairs leading further down, which is the walkable tile
+/// of the `game_map` furthest away from the `starting_position` of the `player entity`, skipping the `reserved`
+/// positions. If no such tile is left, the stairs are placed at the `starting_position` itself.
+///
+/// # Arguments
+///
+/// * `game_map`: The [GameMap] to place the stairs on.
+/// * `starting_position`: The spawn position of the `player entity`.
+/// * `reserved`: The positions already taken by other `entities`.
+///
+/// returns: [i32; 2]
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn stairs_position(
+    game_map: &GameMap,
+    starting_position: &[i32; 2],
+    reserved: &[[i32; 2]],
+) -> [i32; 2] {
+    let distances = DijkstraMap::new(game_map, &[*starting_position]);
+
+    game_map
+        .walkable_positions()
+        .into_iter()
+        .filter(|position| !reserved.contains(position))
+        .max_by_key(|position| distances.distance_at(position))
+        .unwrap_or(*starting_position)
+}
+
+/// Internal function to determine the spawn position of the `player entity` on the passed `game_map`
+/// according to the configured [SpawnStrategy].
+///
+/// # Arguments
+///
+/// * `game_map`: The [GameMap] on which the `player entity` is spawned.
+/// * `spawn_strategy`: The [SpawnStrategy] to apply.
+///
+/// returns: [i32; 2]
+///
+/// # Panics
+///
+/// * If the `game_map` has no room or walkable tile to spawn the `player entity` on.
+///
+/// # Examples
+///
+/// ```
+/// let game_map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+///
+/// assert_eq!(
+///     game_map.rooms()[0].center(),
+///     player_spawn_position(&game_map, SpawnStrategy::FirstRoom)
+/// );
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn player_spawn_position(game_map: &GameMap, spawn_strategy: SpawnStrategy) -> [i32; 2] {
+    let position = match spawn_strategy {
+        SpawnStrategy::FirstRoom => Some(game_map.starting_position()),
+        SpawnStrategy::Center => {
+            let center = game_map.center();
+
+            game_map
+                .walkable_positions()
+                .into_iter()
+                .min_by_key(|position| {
+                    let [x_delta, y_delta] = position.delta(&center);
+                    (x_delta * x_delta) + (y_delta * y_delta)
+                })
+        }
+        SpawnStrategy::RandomWalkable => {
+            game_map.random_floor_position(&mut RandomNumberGenerator::seeded(game_map.seed()))
+        }
+    };
+
+    position.expect(
+        "ECS -> Systems -> startup_system -> Unable to find a starting position for the player entity!",
+    )
+}
+
+/// Internal function to select the [Room]s in which monsters may be spawned.
+///
+/// The room containing the `starting_position` of the `player entity` is always excluded, independent
+/// of its position in the generation order of the `rooms`. Rooms closer than
+/// [constants::MONSTER_SPAWN_DISTANCE] to the `starting_position` are excluded as well.
+///
+/// # Arguments
+///
+/// * `rooms`: The [Room]s of the [GameMap].
+/// * `starting_position`: The spawn position of the `player entity`.
+///
+/// returns: [Vec]<&[Room]>
+///
+/// # Examples
+///
+/// ```
+/// let game_map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+/// let starting_position = game_map.rooms()[0].center();
+///
+/// assert!(monster_rooms(game_map.rooms(), &starting_position)
+///     .iter()
+///     .all(|room| !room.bounds.contains(&starting_position)));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn monster_rooms<'a>(rooms: &'a [Room], starting_position: &[i32; 2]) -> Vec<&'a Room> {
+    rooms
+        .iter()
+        .filter(|room| {
+            !room.bounds.contains(starting_position)
+                && room.bounds.distance_to(starting_position) >= constants::MONSTER_SPAWN_DISTANCE
+        })
+        .collect()
+}
+
+/// Internal function to determine the spawn positions of `count` monsters, which are distributed evenly
+/// across the passed `rooms`.
+///
+/// Each monster is placed at its preferred position in the room, see [monster_spawn_position]. If that
+/// position lies outside of the room's [crate::ui::room::RoomShape] or is closer than `spacing` to an
+/// already placed monster, measured as the [Position2d::chebyshev_distance], the monster is relocated to
+/// the free floor position of the room closest to its center. Monsters never share a tile, even with a `spacing`
+/// of `0`, and those for which no room is left are skipped.
+///
+/// # Arguments
+///
+/// * `rooms`: The [Room]s in which the monsters should be spawned.
+/// * `count`: The number of monsters to spawn.
+/// * `spacing`: The minimum distance between two monsters.
+///
+/// returns: [Vec]<[i32; 2]>
+///
+/// # Examples
+///
+/// ```
+/// let room = Room::new(Rectangle::new([0, 0], [8, 8]), RoomShape::Rect);
+///
+/// assert_eq!(vec![[4, 4], [2, 2]], monster_spawn_positions(&[&room], 2, 2));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn monster_spawn_positions(rooms: &[&Room], count: usize, spacing: i32) -> Vec<[i32; 2]> {
+    let mut positions: Vec<[i32; 2]> = Vec::with_capacity(count);
+
+    if rooms.is_empty() {
+        return positions;
+    }
+
+    for index in 0..count {
+        let room = rooms[index % rooms.len()];
+
+        // Even without any spacing, two monsters must never share the same tile.
+        let is_spaced = |position: &[i32; 2]| {
+            positions
+                .iter()
+                .all(|other| other.chebyshev_distance(position) >= spacing.max(1))
+        };
 
-    for room in game_map.rooms().iter().skip(1) {
-        MonsterFactory::spawn_mended(&mut commands, &room.center());
+        let preferred = monster_spawn_position(&room.bounds, index / rooms.len());
+
+        let position = if room.contains(&preferred) && is_spaced(&preferred) {
+            Some(preferred)
+        } else {
+            let center = room.center();
+            let mut candidates = room.floor_positions();
+
+            candidates.sort_by_key(|candidate| {
+                (
+                    candidate.chebyshev_distance(&center),
+                    candidate[1],
+                    candidate[0],
+                )
+            });
+            candidates
+                .into_iter()
+                .find(|candidate| is_spaced(candidate))
+        };
+
+        match position {
+            Some(position) => positions.push(position),
+            None => debug!(
+                "ECS -> Systems -> startup_system -> No spaced position left for a monster in {:?}",
+                room
+            ),
+        }
     }
 
-    commands.spawn(game_map).insert(GameStateLabel);
+    positions
+}
+
+/// Internal function to determine the spawn position of a monster inside the passed `room`.
+///
+/// The first monster of a room is placed at its center, every additional one (if the difficulty
+/// spawns more monsters than there are rooms) alternates to the right and left of it, staying inside
+/// the room's walls.
+///
+/// # Arguments
+///
+/// * `room`: The [Rectangle] in which the monster should be spawned.
+/// * `occupants`: The number of monsters already spawned in the `room`.
+///
+/// returns: [i32; 2]
+///
+/// # Examples
+///
+/// ```
+/// let room = Rectangle::new([0, 0], [8, 8]);
+///
+/// assert_eq!([4, 4], monster_spawn_position(&room, 0));
+/// assert_eq!([5, 4], monster_spawn_position(&room, 1));
+/// assert_eq!([3, 4], monster_spawn_position(&room, 2));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn monster_spawn_position(room: &Rectangle, occupants: usize) -> [i32; 2] {
+    let [x, y] = room.center();
+    let distance = occupants as i32 / 2 + occupants as i32 % 2;
+    let offset = if occupants % 2 == 1 { distance } else { -distance };
+
+    [(x + offset).clamp(room.left + 1, room.right - 1), y]
 }
 
 /// Resets the [GameTurnState] back to [GameTurnState::Player] after the `NPC entity systems` have run, giving
-/// control back to the player.
+/// control back to the player. Only a turn begun by the [NpcTurnQueue] is ended, so the `NPC entities` get to act in
+/// the frame after the player, and it only ends once all queued `NPC entities` have acted, otherwise it is carried
+/// over to the next frame. Once the turn ended, the [TurnCounter] is advanced.
 ///
 /// # Arguments
 ///
 /// * `in_game_state`: The [InGameTurnState] [bevy::ecs::prelude::Resource] to update.
+/// * `npc_turn_queue`: The [NpcTurnQueue] to check for `NPC entities`, which still have to act.
+/// * `turn_counter`: The [TurnCounter] to advance once the turn ended.
 ///
 /// returns: ()
 ///
 /// # Panics
 ///
-/// If the [GameTurnState] resource can't be retrieved from the ECS.
+/// If the [GameTurnState], [NpcTurnQueue] or [TurnCounter] resource can't be retrieved from the ECS.
 ///
 /// # About
 ///
@@ -91,9 +828,22 @@ pub fn startup_system(mut commands: Commands, window_config: Res<WindowConfig>)
 ///
 /// Since: `0.1.9`
 ///
-pub fn npc_turn_end_system(mut in_game_state: ResMut<GameTurnState>) {
+pub fn npc_turn_end_system(
+    mut in_game_state: ResMut<GameTurnState>,
+    mut npc_turn_queue: ResMut<NpcTurnQueue>,
+    mut turn_counter: ResMut<TurnCounter>,
+) {
+    // The NPC entities haven't begun, or not yet finished, acting in the current turn.
+    if !npc_turn_queue.is_active() || npc_turn_queue.has_pending() {
+        return;
+    }
+
+    npc_turn_queue.end();
+
     // Only reset the resource if necessary for performance.
     if in_game_state.set_if_neq(GameTurnState::Player) {
+        turn_counter.advance();
+
         debug!(
             "ECS -> Systems -> npc_turn_end_system -> Setting GameTurnState back to {}",
             GameTurnState::Player
@@ -101,17 +851,143 @@ pub fn npc_turn_end_system(mut in_game_state: ResMut<GameTurnState>) {
     }
 }
 
+/// Advances the [TurnDelay] by the time of the last frame and restarts it once a turn passed, i.e., the
+/// [TurnCounter] was advanced since the system ran last.
+///
+/// # Arguments
+///
+/// * `time`: The [Time] resource providing the duration of the last frame.
+/// * `turn_counter`: The [TurnCounter] to check for a passed turn.
+/// * `turn_delay`: The [TurnDelay] to advance.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// If the [Time], [TurnCounter] or [TurnDelay] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn turn_delay_system(
+    time: Res<Time>,
+    turn_counter: Res<TurnCounter>,
+    mut turn_delay: ResMut<TurnDelay>,
+) {
+    if turn_counter.is_changed() && !turn_counter.is_added() {
+        turn_delay.restart();
+    }
+
+    turn_delay.tick(time.delta());
+}
+
+/// Run condition for the systems handling the player's actions, which only holds once the configured
+/// [TurnDelay] since the last turn has elapsed.
+///
+/// # Arguments
+///
+/// * `turn_delay`: The [TurnDelay] resource to check.
+///
+/// returns: bool
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn turn_delay_elapsed(turn_delay: Res<TurnDelay>) -> bool {
+    turn_delay.is_elapsed()
+}
+
+/// A [Resource] scoped to a single run of the [AppState::Game] state, which is inserted by the [startup_system]
+/// and removed by the [shutdown_system]. See [RUN_RESOURCES] for all of them.
+///
+/// # Properties
+///
+/// * `name`: Returns the type name of the [Resource].
+/// * `remove`: Queues the removal of the [Resource] from the ECS.
+/// * `is_present`: Checks if the [Resource] exists in the passed [World].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone)]
+pub struct RunResource {
+    /// Returns the type name of the [Resource].
+    pub name: fn() -> &'static str,
+    /// Queues the removal of the [Resource] from the ECS.
+    pub remove: fn(&mut Commands),
+    /// Checks if the [Resource] exists in the passed [World].
+    pub is_present: fn(&World) -> bool,
+}
+
+impl RunResource {
+    /// Creates the [RunResource] entry for the [Resource] `R`.
+    ///
+    /// returns: [RunResource]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    const fn of<R: Resource>() -> Self {
+        Self {
+            name: std::any::type_name::<R>,
+            remove: remove_run_resource::<R>,
+            is_present: World::contains_resource::<R>,
+        }
+    }
+}
+
+/// (Private) Queues the removal of the [Resource] `R`, see [RunResource::remove].
+fn remove_run_resource<R: Resource>(commands: &mut Commands) {
+    commands.remove_resource::<R>();
+}
+
+/// All [Resource]s scoped to a single run of the [AppState::Game] state. Every [Resource] inserted by the
+/// [startup_system] has to be listed here, so the [shutdown_system] removes it and it doesn't leak into the
+/// next run.
+pub const RUN_RESOURCES: [RunResource; 18] = [
+    RunResource::of::<GameTurnState>(),
+    RunResource::of::<FovCache>(),
+    RunResource::of::<MessageLog>(),
+    RunResource::of::<ExamineMode>(),
+    RunResource::of::<TargetingMode>(),
+    RunResource::of::<LogView>(),
+    RunResource::of::<CursorHud>(),
+    RunResource::of::<AnimationQueue>(),
+    RunResource::of::<ActionQueue>(),
+    RunResource::of::<TravelPath>(),
+    RunResource::of::<NpcTurnQueue>(),
+    RunResource::of::<TurnCounter>(),
+    RunResource::of::<DungeonDepth>(),
+    RunResource::of::<DebugFooter>(),
+    RunResource::of::<MapCamera>(),
+    RunResource::of::<TurnDelay>(),
+    RunResource::of::<FovAlgorithm>(),
+    RunResource::of::<FovShape>(),
+];
+
 /// Clean up system, which is run when the game's state machine is leaving the
 /// [AppState::Game] state.
 ///
 /// Removes all no longer required state specific `entities`, [bevy::prelude::Component]s
-/// and [bevy::prelude::Resource]s.
+/// and [RUN_RESOURCES].
 ///
 /// # Arguments
 ///
 /// * `commands`: [Commands] queue required to execute the associated operations in the ecs.
-/// * `player_query`: [Query] to fetch the [Player] entity.
-/// * `game_map_query`: [Query] to fetch the [GameMap] component.
+/// * `world`: The [World] to check which of the [RUN_RESOURCES] are present.
+/// * `entities_query`: [Query] to fetch all `entities` labeled with the [GameStateLabel].
 ///
 /// returns: ()
 ///
@@ -129,23 +1005,42 @@ pub fn npc_turn_end_system(mut in_game_state: ResMut<GameTurnState>) {
 ///
 pub fn shutdown_system(
     mut commands: Commands,
+    world: &World,
     entities_query: Query<Entity, With<GameStateLabel>>,
 ) {
     for entity in entities_query.iter() {
         commands.get_entity(entity).unwrap().despawn();
     }
 
-    commands.remove_resource::<GameTurnState>();
+    for run_resource in RUN_RESOURCES
+        .into_iter()
+        .filter(|run_resource| (run_resource.is_present)(world))
+    {
+        debug!(
+            "ECS -> Systems -> shutdown_system -> Removing {}",
+            (run_resource.name)()
+        );
+        (run_resource.remove)(&mut commands);
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+    use std::time::Duration;
+
     use bevy::prelude::*;
 
     use crate::components::ascii_sprite::AsciiSprite;
+    use crate::components::carryable::Carryable;
+    use crate::components::consumable::Consumable;
     use crate::components::coord_2d::Coord2d;
-    use crate::components::player::Player;
-    use crate::core::position_2d::Position2d;
+    use crate::components::enemy_type::EnemyType;
+    use crate::components::item::Item;
+    use crate::ui::room::RoomShape;
+    use crate::ui::tile_map::TileMap;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+    use crate::ui::tile_map_layout_generator::BaseTileMapGenerator;
 
     use super::*;
 
@@ -153,7 +1048,10 @@ mod tests {
     fn test_startup_system() {
         let mut app = App::new();
 
-        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
         app.add_systems(Startup, startup_system);
 
         app.update();
@@ -182,16 +1080,459 @@ mod tests {
         );
 
         assert_eq!(
-            [100, 80],
+            [
+                100 - constants::SIDEBAR_WIDTH,
+                80 - constants::MESSAGE_LOG_HEIGHT
+            ],
             app.world.query::<&GameMap>().single(&app.world).as_array()
         );
+
+        assert_eq!(
+            1,
+            app.world
+                .query::<&Consumable>()
+                .iter(&app.world)
+                .filter(|consumable| **consumable == Consumable::Mapping)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_difficulty_scales_monster_count() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            difficulty: 2.0,
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let room_count = app
+            .world
+            .query::<&GameMap>()
+            .single(&app.world)
+            .rooms()
+            .len();
+
+        assert_eq!(
+            (room_count - 1) * 2,
+            app.world.query::<&EnemyType>().iter(&app.world).len()
+        );
+    }
+
+    #[test]
+    fn test_spawn_strategies_spawn_player_on_walkable_tile() {
+        for spawn_strategy in [
+            SpawnStrategy::FirstRoom,
+            SpawnStrategy::Center,
+            SpawnStrategy::RandomWalkable,
+        ] {
+            let mut app = App::new();
+
+            app.insert_resource(TerminalLayout::new(&[100, 80]));
+            app.insert_resource(LogConfig::default());
+            app.insert_resource(Palette::default());
+            app.insert_resource(GameplayConfig {
+                spawn_strategy,
+                ..Default::default()
+            });
+            app.add_systems(Startup, startup_system);
+
+            app.update();
+
+            let player_position = *app
+                .world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world);
+
+            let game_map = app.world.query::<&GameMap>().single(&app.world);
+
+            assert!(game_map.is_in_bounds(&player_position));
+            assert!(!game_map.tile_has_collision(&player_position));
+        }
+    }
+
+    #[test]
+    fn test_each_generator_spawns_player_on_walkable_tile_and_monsters() {
+        for generator in TileMapGeneratorRegistry::default().names() {
+            let mut app = App::new();
+
+            app.insert_resource(TerminalLayout::new(&[100, 80]));
+            app.insert_resource(LogConfig::default());
+            app.insert_resource(Palette::default());
+            app.insert_resource(GameplayConfig {
+                generator: String::from(generator),
+                ..Default::default()
+            });
+            app.add_systems(Startup, startup_system);
+
+            app.update();
+
+            let player_position = *app
+                .world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world);
+
+            let monster_count = app
+                .world
+                .query_filtered::<Entity, With<EnemyType>>()
+                .iter(&app.world)
+                .count();
+
+            let game_map = app.world.query::<&GameMap>().single(&app.world);
+
+            assert_eq!(Ok(()), game_map.validate());
+            assert!(!game_map.tile_has_collision(&player_position));
+            assert!(monster_count > 0, "{} spawned no monsters", generator);
+        }
+    }
+
+    #[test]
+    fn test_configured_seed_is_used_for_the_map() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            seed: Some(42),
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+
+        assert_eq!(42, game_map.seed());
+    }
+
+    #[test]
+    fn test_startup_system_restores_save_game() {
+        let mut health = Health::new(constants::PLAYER_MAX_HEALTH);
+        health.apply_damage(5);
+
+        let mut dungeon_depth = DungeonDepth::default();
+        dungeon_depth.descend();
+
+        let mut restored_position = [-1, -1];
+
+        for _ in 0..2 {
+            let mut app = App::new();
+
+            app.insert_resource(TerminalLayout::new(&[100, 80]));
+            app.insert_resource(LogConfig::default());
+            app.insert_resource(Palette::default());
+            app.insert_resource(GameplayConfig::default());
+            app.insert_resource(SaveGame::new(
+                &restored_position,
+                &health,
+                &dungeon_depth,
+                42,
+            ));
+            app.add_systems(Startup, startup_system);
+
+            app.update();
+
+            let game_map = app.world.query::<&GameMap>().single(&app.world);
+            let walkable_position = *game_map.walkable_positions().last().unwrap();
+            let start_position = game_map.start_position();
+
+            assert_eq!(42, game_map.seed());
+
+            let (position, restored_health) = app
+                .world
+                .query_filtered::<(&Coord2d, &Health), With<Player>>()
+                .single(&app.world);
+
+            // An invalid saved position falls back to the starting position of the floor.
+            if restored_position == [-1, -1] {
+                assert_eq!(start_position, position.as_array());
+            } else {
+                assert_eq!(restored_position, position.as_array());
+            }
+
+            assert_eq!(health, *restored_health);
+            assert_eq!(dungeon_depth, *app.world.resource::<DungeonDepth>());
+            assert!(!app.world.contains_resource::<SaveGame>());
+
+            restored_position = walkable_position;
+        }
+    }
+
+    #[test]
+    fn test_configured_map_size_exceeds_the_terminal() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            map_size: Some([160, 90]),
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+        let viewport = app.world.resource::<MapCamera>().viewport;
+
+        assert_eq!([160, 90], game_map.as_array());
+        assert_eq!(
+            app.world.resource::<TerminalLayout>().map.width(),
+            viewport.size[0]
+        );
+    }
+
+    #[test]
+    fn test_center_spawn_strategy_avoids_wall_at_map_center() {
+        let mut game_map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        let [x, y] = game_map.center();
+
+        game_map.set_tile_at(&[x, y], MapTile::default());
+        game_map.set_tile_at(&[x, y + 1], MapTile::floor('.'));
+        game_map.set_tile_at(&[x + 3, y], MapTile::floor('.'));
+
+        assert!(game_map.tile_has_collision(&game_map.center()));
+        assert_eq!(
+            [x, y + 1],
+            player_spawn_position(&game_map, SpawnStrategy::Center)
+        );
+    }
+
+    #[test]
+    fn test_random_spawn_strategy_is_seeded_by_the_map() {
+        let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(1337));
+        let copy = GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(1337));
+        let position = player_spawn_position(&map, SpawnStrategy::RandomWalkable);
+
+        assert!(!map.tile_has_collision(&position));
+        assert_eq!(
+            position,
+            player_spawn_position(&copy, SpawnStrategy::RandomWalkable)
+        );
+    }
+
+    #[test]
+    fn test_monster_rooms_exclude_starting_room() {
+        let first_room = Room::new(Rectangle::new([0, 0], [6, 6]), RoomShape::Rect);
+        let starting_room = Room::new(Rectangle::new([20, 0], [6, 6]), RoomShape::Rect);
+        let last_room = Room::new(Rectangle::new([40, 0], [6, 6]), RoomShape::Rect);
+        let rooms = vec![first_room, starting_room, last_room];
+
+        // The starting room is neither the first nor the last one in the generation order.
+        let selected = monster_rooms(&rooms, &[22, 2]);
+
+        assert_eq!(vec![&rooms[0], &rooms[2]], selected);
+
+        // A start on the edge of a room still excludes it.
+        let selected = monster_rooms(&rooms, &[46, 6]);
+
+        assert_eq!(vec![&rooms[0], &rooms[1]], selected);
+    }
+
+    #[test]
+    fn test_startup_system_spawns_no_monster_in_starting_room() {
+        for spawn_strategy in [
+            SpawnStrategy::FirstRoom,
+            SpawnStrategy::Center,
+            SpawnStrategy::RandomWalkable,
+        ] {
+            let mut app = App::new();
+
+            app.insert_resource(TerminalLayout::new(&[100, 80]));
+            app.insert_resource(LogConfig::default());
+            app.insert_resource(Palette::default());
+            app.insert_resource(GameplayConfig {
+                spawn_strategy,
+                ..Default::default()
+            });
+            app.add_systems(Startup, startup_system);
+
+            app.update();
+
+            let player_position = *app
+                .world
+                .query_filtered::<&Coord2d, With<Player>>()
+                .single(&app.world);
+
+            let game_map = app.world.query::<&GameMap>().single(&app.world);
+            let starting_rooms: Vec<Rectangle> = game_map
+                .rooms()
+                .iter()
+                .map(|room| room.bounds)
+                .filter(|bounds| bounds.contains(&player_position))
+                .collect();
+
+            for monster_position in app
+                .world
+                .query_filtered::<&Coord2d, With<EnemyType>>()
+                .iter(&app.world)
+            {
+                assert!(starting_rooms
+                    .iter()
+                    .all(|bounds| !bounds.contains(monster_position)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_startup_system_scatters_items() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            item_count: 4,
+            seed: Some(42),
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let player_position = *app
+            .world
+            .query_filtered::<&Coord2d, With<Player>>()
+            .single(&app.world);
+
+        let item_positions: Vec<[i32; 2]> = app
+            .world
+            .query_filtered::<&Coord2d, (With<Item>, With<Carryable>)>()
+            .iter(&app.world)
+            .map(|position| position.as_array())
+            .collect();
+
+        // The scroll of mapping is placed in addition to the configured items.
+        assert_eq!(5, item_positions.len());
+        assert_eq!(5, item_positions.iter().collect::<HashSet<_>>().len());
+        assert!(!item_positions.contains(&player_position.as_array()));
+    }
+
+    #[test]
+    fn test_item_spawn_positions() {
+        let room = Room::new(Rectangle::new([0, 0], [4, 4]), RoomShape::Rect);
+        let reserved = [[2, 2]];
+
+        let positions = item_spawn_positions(
+            &[&room],
+            10,
+            &reserved,
+            &mut RandomNumberGenerator::seeded(7),
+        );
+
+        // Only the eight floor positions around the reserved center are free.
+        assert_eq!(8, positions.len());
+        assert!(positions.iter().all(|position| room.contains(position)));
+        assert!(!positions.contains(&[2, 2]));
+        assert_eq!(8, positions.iter().collect::<HashSet<_>>().len());
+        assert!(
+            item_spawn_positions(&[], 3, &[], &mut RandomNumberGenerator::seeded(7)).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_monster_spawn_position() {
+        let room = Rectangle::new([0, 0], [8, 8]);
+
+        assert_eq!([4, 4], monster_spawn_position(&room, 0));
+        assert_eq!([5, 4], monster_spawn_position(&room, 1));
+        assert_eq!([3, 4], monster_spawn_position(&room, 2));
+        assert_eq!([6, 4], monster_spawn_position(&room, 3));
+        assert_eq!([7, 4], monster_spawn_position(&room, 7));
+    }
+
+    #[test]
+    fn test_monster_spawn_positions_keep_spacing() {
+        // The rooms share a wall, so their centers are only two tiles apart.
+        let left_room = Room::new(Rectangle::new([0, 0], [4, 4]), RoomShape::Rect);
+        let right_room = Room::new(Rectangle::new([2, 0], [4, 4]), RoomShape::Rect);
+        let rooms = [&left_room, &right_room];
+
+        let positions = monster_spawn_positions(&rooms, 4, 3);
+
+        // The second monster is relocated away from the first one, the rooms are too small for the others.
+        assert_eq!(vec![[2, 2], [5, 1]], positions);
+
+        for (index, position) in positions.iter().enumerate() {
+            for other in positions.iter().skip(index + 1) {
+                assert!(position.chebyshev_distance(other) >= 3);
+            }
+        }
+
+        assert_eq!(
+            vec![[2, 2], [4, 2], [3, 2], [5, 2]],
+            monster_spawn_positions(&rooms, 4, 0)
+        );
+    }
+
+    #[test]
+    fn test_monster_spawn_positions_never_share_a_tile() {
+        let room = Room::new(Rectangle::new([0, 0], [3, 2]), RoomShape::Rect);
+
+        let positions = monster_spawn_positions(&[&room], 3, 0);
+
+        assert_eq!(2, positions.len());
+        assert_ne!(positions[0], positions[1]);
+    }
+
+    #[test]
+    fn test_monster_spawn_positions_stay_inside_room_shape() {
+        let room = Room::new(Rectangle::new([0, 0], [10, 10]), RoomShape::Cross);
+
+        let positions = monster_spawn_positions(&[&room], 6, 2);
+
+        assert_eq!(6, positions.len());
+        assert!(positions.iter().all(|position| room.contains(position)));
+    }
+
+    #[test]
+    fn test_startup_system_keeps_monster_spacing() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig {
+            difficulty: 3.0,
+            monster_spacing: 3,
+            ..Default::default()
+        });
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let positions: Vec<[i32; 2]> = app
+            .world
+            .query_filtered::<&Coord2d, With<EnemyType>>()
+            .iter(&app.world)
+            .map(|position| position.as_array())
+            .collect();
+
+        assert!(!positions.is_empty());
+
+        for (index, position) in positions.iter().enumerate() {
+            for other in positions.iter().skip(index + 1) {
+                assert!(position.chebyshev_distance(other) >= 3);
+            }
+        }
     }
 
     #[test]
     fn test_npc_turn_end_system() {
         let mut app = App::new();
 
-        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
         app.add_systems(Startup, startup_system);
         app.add_systems(Update, npc_turn_end_system);
 
@@ -206,24 +1547,68 @@ mod tests {
             .resource_mut::<GameTurnState>()
             .set_if_neq(GameTurnState::Npc);
 
+        app.update();
+
+        // The turn isn't ended as long as it hasn't been begun by the NpcTurnQueue.
         assert_eq!(
             Some(&GameTurnState::Npc),
             app.world.get_resource::<GameTurnState>()
         );
+        assert_eq!(0, app.world.resource::<TurnCounter>().current());
 
+        app.world.resource_mut::<NpcTurnQueue>().begin(vec![]);
         app.update();
 
         assert_eq!(
             Some(&GameTurnState::Player),
             app.world.get_resource::<GameTurnState>()
         );
+        assert_eq!(1, app.world.resource::<TurnCounter>().current());
+    }
+
+    fn count_turns(turn_delay_ms: u64) -> Vec<u32> {
+        let mut app = App::new();
+
+        app.init_resource::<Time>();
+        app.insert_resource(TurnCounter::default());
+        app.insert_resource(TurnDelay::new(turn_delay_ms));
+        app.add_systems(
+            Update,
+            (
+                turn_delay_system,
+                (|mut turn_counter: ResMut<TurnCounter>| turn_counter.advance())
+                    .run_if(turn_delay_elapsed),
+            )
+                .chain(),
+        );
+
+        (0..10)
+            .map(|_| {
+                app.world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(40));
+                app.update();
+
+                app.world.resource::<TurnCounter>().current()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_turn_delay_limits_turn_rate() {
+        // Every frame takes 40ms, so after the first turn another one may pass only every third frame.
+        assert_eq!(vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4], count_turns(100));
+        assert_eq!((1..=10).collect::<Vec<u32>>(), count_turns(0));
     }
 
     #[test]
     fn test_shutdown_system() {
         let mut app = App::new();
 
-        app.insert_resource(WindowConfig::new([800, 640], true, 1));
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
         app.add_systems(Startup, startup_system);
         app.add_systems(Update, shutdown_system);
 
@@ -237,6 +1622,80 @@ mod tests {
                 .len()
         );
 
-        assert_eq!(None, app.world.get_resource::<GameTurnState>())
+        for run_resource in RUN_RESOURCES {
+            assert!(
+                !(run_resource.is_present)(&app.world),
+                "{} wasn't removed",
+                (run_resource.name)()
+            );
+        }
+    }
+
+    /// Returns the type names of all [Resource]s currently present in the `world`.
+    fn present_resources(world: &World) -> HashSet<String> {
+        world
+            .storages()
+            .resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .filter_map(|(id, _)| world.components().get_info(id))
+            .map(|info| String::from(info.name()))
+            .collect()
+    }
+
+    #[test]
+    fn test_run_resources_cover_startup_resources() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.add_systems(Startup, startup_system);
+
+        let resources_before = present_resources(&app.world);
+
+        app.update();
+
+        let inserted_resources: HashSet<String> = present_resources(&app.world)
+            .difference(&resources_before)
+            .cloned()
+            .collect();
+
+        let run_resources: HashSet<String> = RUN_RESOURCES
+            .iter()
+            .map(|run_resource| String::from((run_resource.name)()))
+            .collect();
+
+        assert_eq!(run_resources, inserted_resources);
+    }
+
+    #[test]
+    fn test_spawned_entities_are_cleaned_up() {
+        let mut app = App::new();
+
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.add_systems(Startup, startup_system);
+
+        app.update();
+
+        let entity_count = app.world.iter_entities().count();
+
+        assert!(entity_count > 1);
+        assert_eq!(
+            entity_count,
+            app.world
+                .query_filtered::<Entity, With<GameStateLabel>>()
+                .iter(&app.world)
+                .len()
+        );
+
+        app.add_systems(Update, shutdown_system);
+        app.update();
+
+        assert_eq!(0, app.world.iter_entities().count());
     }
 }
@@ -0,0 +1,204 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{EventReader, Res, ResMut};
+
+use crate::res::examine_mode::ExamineMode;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::log_view::LogView;
+use crate::res::message_log::MessageLog;
+use crate::res::targeting_mode::TargetingMode;
+
+/// System handling the [LogView], an overlay in which the player scrolls through the full history of
+/// the [MessageLog].
+///
+/// The [InputType::LogView] key toggles the overlay. While open, [InputType::Up] scrolls towards older
+/// and [InputType::Down] towards newer messages, and [InputType::Cancel] closes the overlay. The log view
+/// can't be opened while the [ExamineMode] is active.
+///
+/// Has to run after all other input handling systems, so the key closing the overlay isn't handled by
+/// them as well.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `examine_mode`: [ExamineMode] to check, as the examine mode reuses the movement keys.
+/// * `targeting_mode`: [TargetingMode] to check, as the targeting mode reuses the movement keys.
+/// * `log_view`: The [LogView] resource to update.
+/// * `message_log`: The [MessageLog] whose history is scrolled through.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn log_view_system(
+    input_config: Res<InputConfig>,
+    examine_mode: Res<ExamineMode>,
+    targeting_mode: Res<TargetingMode>,
+    mut log_view: ResMut<LogView>,
+    message_log: Res<MessageLog>,
+    mut key_events: EventReader<KeyboardInput>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released
+            || examine_mode.is_active()
+            || targeting_mode.is_active()
+        {
+            continue;
+        }
+
+        let Some(input) = event
+            .key_code
+            .and_then(|key_code| input_config.parse_input(key_code))
+        else {
+            continue;
+        };
+
+        if !log_view.is_open() {
+            if input == InputType::LogView {
+                log_view.open();
+            }
+
+            continue;
+        }
+
+        match input {
+            InputType::Up => {
+                log_view.scroll_older(message_log.len());
+            }
+            InputType::Down => {
+                log_view.scroll_newer();
+            }
+            InputType::LogView | InputType::Cancel => log_view.close(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app(message_count: usize) -> App {
+        let mut app = App::new();
+
+        let mut message_log = MessageLog::new(message_count);
+
+        for index in 0..message_count {
+            message_log.push_info(index.to_string());
+        }
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::new(3));
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(message_log);
+        app.add_systems(Update, log_view_system);
+
+        app
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    fn visible_texts(app: &App) -> Vec<String> {
+        app.world
+            .resource::<LogView>()
+            .visible_entries(app.world.resource::<MessageLog>())
+            .map(|entry| entry.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_log_view_toggles() {
+        let mut app = setup_app(5);
+
+        press(&mut app, KeyCode::M);
+        assert!(app.world.resource::<LogView>().is_open());
+
+        press(&mut app, KeyCode::M);
+        assert!(!app.world.resource::<LogView>().is_open());
+
+        press(&mut app, KeyCode::M);
+        press(&mut app, KeyCode::Escape);
+        assert!(!app.world.resource::<LogView>().is_open());
+    }
+
+    #[test]
+    fn test_scrolling_stops_at_oldest_and_newest_message() {
+        let mut app = setup_app(5);
+
+        press(&mut app, KeyCode::M);
+        assert_eq!(vec!["4", "3", "2"], visible_texts(&app));
+
+        for _ in 0..4 {
+            press(&mut app, KeyCode::W);
+        }
+
+        assert_eq!(2, app.world.resource::<LogView>().scroll_offset());
+        assert_eq!(vec!["2", "1", "0"], visible_texts(&app));
+
+        for _ in 0..4 {
+            press(&mut app, KeyCode::S);
+        }
+
+        assert_eq!(0, app.world.resource::<LogView>().scroll_offset());
+        assert_eq!(vec!["4", "3", "2"], visible_texts(&app));
+    }
+
+    #[test]
+    fn test_movement_keys_are_ignored_while_closed() {
+        let mut app = setup_app(5);
+
+        press(&mut app, KeyCode::W);
+
+        assert!(!app.world.resource::<LogView>().is_open());
+        assert_eq!(0, app.world.resource::<LogView>().scroll_offset());
+    }
+}
@@ -28,8 +28,18 @@
 //! Since: `0.1.9`
 //!
 
+pub(super) mod action;
+pub(super) mod animation;
+pub(super) mod camera;
+pub(super) mod combat;
 pub(super) mod enemy_ai;
+pub(super) mod examine;
 pub(super) mod fov;
 pub(super) mod graphics;
+pub(super) mod hud;
 pub(super) mod input;
+pub(super) mod inventory;
 pub(super) mod lifecycle;
+pub(super) mod log_view;
+pub(super) mod spyglass;
+pub(super) mod targeting;
@@ -0,0 +1,223 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{DetectChanges, EventReader, Query, Res, ResMut, With};
+
+use crate::components::fov::Fov;
+use crate::components::player::Player;
+use crate::components::spyglass::Spyglass;
+use crate::plugins::states::GameTurnState;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::log_view::LogView;
+use crate::res::message_log::MessageLog;
+use crate::res::targeting_mode::TargetingMode;
+
+/// System putting the [Spyglass] of the `player` to use once the [InputType::Spyglass] key is pressed,
+/// temporarily extending the radius of the `player's` [Fov]. Using the spyglass doesn't end the turn of the
+/// `player`. The key is ignored while the [ExamineMode] is active or the [LogView] is open.
+///
+/// # Arguments
+///
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `examine_mode`: [ExamineMode] to check, as its input takes precedence.
+/// * `log_view`: [LogView] to check, as its input takes precedence.
+/// * `targeting_mode`: [TargetingMode] to check, as its input takes precedence.
+/// * `message_log`: [MessageLog] to inform the player about the use of the spyglass.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+/// * `player_query`: [Query] to retrieve the [Fov] and [Spyglass] of the `player entity`.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn spyglass_system(
+    input_config: Res<InputConfig>,
+    examine_mode: Res<ExamineMode>,
+    log_view: Res<LogView>,
+    targeting_mode: Res<TargetingMode>,
+    mut message_log: ResMut<MessageLog>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut player_query: Query<(&mut Fov, &mut Spyglass), With<Player>>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released
+            || examine_mode.is_active()
+            || log_view.is_open()
+            || targeting_mode.is_active()
+        {
+            continue;
+        }
+
+        let is_spyglass_input = event
+            .key_code
+            .and_then(|key_code| input_config.parse_input(key_code))
+            == Some(InputType::Spyglass);
+
+        if !is_spyglass_input {
+            continue;
+        }
+
+        let Ok((mut fov, mut spyglass)) = player_query.get_single_mut() else {
+            continue;
+        };
+
+        spyglass.activate(&mut fov);
+        message_log.push_info(format!(
+            "You raise your spyglass for {} turns.",
+            spyglass.remaining_turns()
+        ));
+    }
+}
+
+/// System advancing the [Spyglass] of every `entity` by one turn, once the `player` ended their turn, and
+/// reverting the radius of the respective [Fov] once the spyglass expired.
+///
+/// # Arguments
+///
+/// * `game_turn_state`: The [GameTurnState] resource, whose change to [GameTurnState::Npc] marks the end of a turn.
+/// * `spyglass_query`: [Query] to retrieve the [Fov] and [Spyglass] components to update.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [GameTurnState] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn spyglass_expiry_system(
+    game_turn_state: Res<GameTurnState>,
+    mut spyglass_query: Query<(&mut Fov, &mut Spyglass)>,
+) {
+    if !game_turn_state.is_changed() || *game_turn_state != GameTurnState::Npc {
+        return;
+    }
+
+    for (mut fov, mut spyglass) in spyglass_query.iter_mut() {
+        spyglass.tick(&mut fov);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Component, IntoSystemConfigs, KeyCode};
+
+    use crate::core::constants;
+    use crate::plugins::game_state_systems::fov::fov_system;
+    use crate::plugins::game_state_systems::lifecycle::startup_system;
+    use crate::res::fov_cache::FovCache;
+    use crate::res::gameplay_config::GameplayConfig;
+    use crate::res::log_config::LogConfig;
+    use crate::res::palette::Palette;
+    use crate::res::terminal_layout::TerminalLayout;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn player_fov(app: &mut App) -> Fov {
+        app.world
+            .query_filtered::<&Fov, With<Player>>()
+            .single(&app.world)
+            .clone()
+    }
+
+    fn fov_calculations(app: &App) -> usize {
+        let fov_cache = app.world.resource::<FovCache>();
+
+        fov_cache.hits() + fov_cache.misses()
+    }
+
+    #[test]
+    fn test_spyglass_extends_fov_for_duration() {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.insert_resource(TerminalLayout::new(&[100, 80]));
+        app.insert_resource(LogConfig::default());
+        app.insert_resource(Palette::default());
+        app.insert_resource(GameplayConfig::default());
+        app.insert_resource(InputConfig::default());
+        app.add_systems(Startup, startup_system);
+        app.add_systems(
+            Update,
+            (spyglass_system, spyglass_expiry_system, fov_system).chain(),
+        );
+
+        app.update();
+
+        let base_radius = player_fov(&mut app).radius;
+        let calculations = fov_calculations(&app);
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::Z),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+
+        assert_eq!(
+            base_radius + constants::SPYGLASS_RADIUS_BONUS,
+            player_fov(&mut app).radius
+        );
+        assert_eq!(calculations + 1, fov_calculations(&app));
+
+        for _ in 0..constants::SPYGLASS_DURATION - 1 {
+            *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+            app.update();
+        }
+
+        assert_eq!(
+            base_radius + constants::SPYGLASS_RADIUS_BONUS,
+            player_fov(&mut app).radius
+        );
+        assert_eq!(calculations + 1, fov_calculations(&app));
+
+        *app.world.resource_mut::<GameTurnState>() = GameTurnState::Npc;
+        app.update();
+
+        let fov = player_fov(&mut app);
+
+        assert_eq!(base_radius, fov.radius);
+        assert!(!fov.is_dirty);
+        assert_eq!(calculations + 2, fov_calculations(&app));
+    }
+}
@@ -0,0 +1,420 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{EventReader, EventWriter, Query, Res, ResMut, With};
+
+use crate::components::coord_2d::Coord2d;
+use crate::components::name_tag::NameTag;
+use crate::components::player::Player;
+use crate::core::constants;
+use crate::core::position_2d::Position2d;
+use crate::events::target_selected::TargetSelected;
+use crate::res::examine_mode::ExamineMode;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::log_view::LogView;
+use crate::res::message_log::{LogCategory, MessageLog};
+use crate::res::targeting_mode::TargetingMode;
+use crate::ui::game_map::GameMap;
+use crate::ui::tile_map::TileMap;
+
+/// System handling the [TargetingMode], in which the player aims a cursor at a tile within the
+/// [constants::TARGETING_RANGE], e.g., for a ranged attack.
+///
+/// The [InputType::Target] key activates the mode with the cursor on the `player`. While active, the movement
+/// keys move the cursor and [InputType::Confirm] sends a [TargetSelected] event for the cursor's position, if the
+/// [line_of_fire] reaches it. [InputType::Target] and [InputType::Cancel] exit the mode. The mode can't be
+/// activated while the [ExamineMode] is active or the [LogView] is open.
+///
+/// Has to run after the systems handling the player's actions, which ignore the user's input while the
+/// [TargetingMode] is active, so the key exiting the mode isn't handled by them as well.
+///
+/// # Arguments
+///
+/// * `targeting_keys`: The [TargetingKeys] providing the keys pressed by the user.
+/// * `targeting_mode`: The [TargetingMode] resource to update.
+/// * `message_log`: [MessageLog] to inform the player about an unreachable target.
+/// * `target_writer`: [EventWriter] to send the [TargetSelected] event.
+/// * `game_map_query`: [Query] to retrieve the [GameMap], which is used to check the [line_of_fire].
+/// * `player_query`: [Query] to retrieve the position of the `player`, where the cursor starts.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+/// * If the [GameMap] can't be retrieved when a target is confirmed.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn targeting_system(
+    mut targeting_keys: TargetingKeys,
+    mut targeting_mode: ResMut<TargetingMode>,
+    mut message_log: ResMut<MessageLog>,
+    mut target_writer: EventWriter<TargetSelected>,
+    game_map_query: Query<&GameMap>,
+    player_query: Query<&Coord2d, With<Player>>,
+) {
+    for input in targeting_keys.read() {
+        let Some(cursor) = targeting_mode.cursor() else {
+            if input == InputType::Target {
+                if let Ok(position) = player_query.get_single() {
+                    targeting_mode.activate(position, constants::TARGETING_RANGE);
+                }
+            }
+
+            continue;
+        };
+
+        if let Some(delta) = input.movement_delta() {
+            targeting_mode.move_cursor(delta);
+            continue;
+        }
+
+        match input {
+            InputType::Confirm => {
+                let game_map = game_map_query.get_single().expect(
+                    "ECS -> Systems -> targeting_system -> Unable to retrieve {GameMap} component!",
+                );
+
+                let is_reachable = line_of_fire(game_map, &targeting_mode.origin(), &cursor)
+                    .last()
+                    .is_some_and(|(_, is_clear)| *is_clear);
+
+                if is_reachable {
+                    target_writer.send(TargetSelected::new(cursor));
+                    targeting_mode.deactivate();
+                } else {
+                    message_log.push("You can't target that.", LogCategory::Warning);
+                }
+            }
+            InputType::Target | InputType::Cancel => targeting_mode.deactivate(),
+            _ => {}
+        }
+    }
+}
+
+/// The keys pressed by the user, which are meant for the [TargetingMode], i.e., those not captured by the
+/// [ExamineMode] or the [LogView], as they reuse the movement keys.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(SystemParam)]
+pub struct TargetingKeys<'w, 's> {
+    /// [InputConfig] required to recognize the user's input.
+    input_config: Res<'w, InputConfig>,
+    /// [ExamineMode] to check, as it reuses the movement keys.
+    examine_mode: Res<'w, ExamineMode>,
+    /// [LogView] to check, as it reuses the movement keys.
+    log_view: Res<'w, LogView>,
+    /// [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+    key_events: EventReader<'w, 's, KeyboardInput>,
+}
+
+impl TargetingKeys<'_, '_> {
+    /// Reads all [KeyboardInput] events received since the last call, parsing the pressed keys into the respective
+    /// [InputType]s. Unknown keys are skipped, while all keys are dropped as long as the [ExamineMode] is active or
+    /// the [LogView] is open.
+    ///
+    /// returns: [Vec]<[InputType]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn read(&mut self) -> Vec<InputType> {
+        let is_captured = self.examine_mode.is_active() || self.log_view.is_open();
+
+        self.key_events
+            .read()
+            .filter(|event| event.state == ButtonState::Pressed && !is_captured)
+            .filter_map(|event| event.key_code)
+            .filter_map(|key_code| self.input_config.parse_input(key_code))
+            .collect()
+    }
+}
+
+/// System reporting the target confirmed in the [TargetingMode] in the [MessageLog], naming the `entity` standing
+/// on the targeted tile, if there is one.
+///
+/// # Arguments
+///
+/// * `message_log`: [MessageLog] to inform the player about the selected target.
+/// * `target_events`: [EventReader] stream of [TargetSelected] events sent by the [targeting_system].
+/// * `target_query`: [Query] to retrieve the named `entities` and their positions.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [MessageLog] resource can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn target_selected_system(
+    mut message_log: ResMut<MessageLog>,
+    mut target_events: EventReader<TargetSelected>,
+    target_query: Query<(&Coord2d, &NameTag)>,
+) {
+    for event in target_events.read() {
+        let target_name = target_query
+            .iter()
+            .find(|(position, _)| position.as_array() == event.target)
+            .map(|(_, name_tag)| name_tag.text.as_str());
+
+        match target_name {
+            Some(name) => message_log.push_info(format!("You take aim at the {}.", name)),
+            None => message_log.push_info("You take aim at the empty floor."),
+        }
+    }
+}
+
+/// Traces the line from the `origin` to the `cursor` on the `game_map`, see [TileMap::tiles_along_line], and
+/// marks every tile on it as clear, as long as it and all tiles before it are visible and free of collision. The
+/// `origin` itself isn't part of the line.
+///
+/// # Arguments
+///
+/// * `game_map`: The [GameMap] to trace the line on.
+/// * `origin`: The position the line starts at, i.e., the position of the `player`.
+/// * `cursor`: The aimed at position.
+///
+/// returns: [Vec]<([i32; 2], bool)> - Every tile of the line and whether it's clear.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn line_of_fire(
+    game_map: &GameMap,
+    origin: &impl Position2d,
+    cursor: &impl Position2d,
+) -> Vec<([i32; 2], bool)> {
+    let mut is_clear = true;
+
+    game_map
+        .tiles_along_line(origin, cursor)
+        .into_iter()
+        .skip(1)
+        .map(|tile| {
+            is_clear =
+                is_clear && game_map.is_tile_visible(&tile) && !game_map.tile_has_collision(&tile);
+
+            (tile, is_clear)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, Events, IntoSystemConfigs, KeyCode};
+
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<TargetSelected>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(TargetingMode::default());
+        app.insert_resource(ExamineMode::default());
+        app.insert_resource(LogView::default());
+        app.insert_resource(MessageLog::default());
+        app.add_systems(Update, targeting_system);
+
+        let mut game_map = GameMap::new(&[20, 20], &TestTileMapGenerator, None);
+
+        for x in 0..20 {
+            for y in 0..20 {
+                game_map.set_tile_at(&[x, y], MapTile::floor('.'));
+                game_map.mark_tile_as_visible(&[x, y]);
+            }
+        }
+
+        game_map.set_tile_at(&[8, 7], MapTile::default());
+
+        app.world.spawn(game_map);
+        app.world.spawn((Coord2d::new(5, 5), Player));
+
+        app
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    fn cursor(app: &App) -> Option<[i32; 2]> {
+        app.world.resource::<TargetingMode>().cursor()
+    }
+
+    #[test]
+    fn test_cursor_moves_and_clamps_to_range() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::W);
+        assert_eq!(None, cursor(&app));
+
+        press(&mut app, KeyCode::F);
+        assert_eq!(Some([5, 5]), cursor(&app));
+
+        press(&mut app, KeyCode::W);
+        press(&mut app, KeyCode::D);
+        assert_eq!(Some([6, 6]), cursor(&app));
+
+        for _ in 0..constants::TARGETING_RANGE + 2 {
+            press(&mut app, KeyCode::D);
+        }
+
+        assert_eq!(Some([5 + constants::TARGETING_RANGE, 6]), cursor(&app));
+
+        press(&mut app, KeyCode::Escape);
+        assert_eq!(None, cursor(&app));
+    }
+
+    #[test]
+    fn test_confirm_sends_reachable_target() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::F);
+        press(&mut app, KeyCode::W);
+        press(&mut app, KeyCode::W);
+        press(&mut app, KeyCode::Return);
+
+        let events = app.world.resource::<Events<TargetSelected>>();
+
+        assert_eq!(
+            vec![[5, 7]],
+            events
+                .iter_current_update_events()
+                .map(|event| event.target)
+                .collect::<Vec<[i32; 2]>>()
+        );
+        assert_eq!(None, cursor(&app));
+    }
+
+    #[test]
+    fn test_selected_target_is_reported() {
+        let mut app = setup_app();
+
+        app.add_systems(Update, target_selected_system.after(targeting_system));
+        app.world.spawn((Coord2d::new(5, 7), NameTag::new("Ghoul")));
+
+        for target in [[5, 7], [6, 5]] {
+            press(&mut app, KeyCode::F);
+
+            if target == [5, 7] {
+                press(&mut app, KeyCode::W);
+                press(&mut app, KeyCode::W);
+            } else {
+                press(&mut app, KeyCode::D);
+            }
+
+            press(&mut app, KeyCode::Return);
+        }
+
+        let messages: Vec<String> = app
+            .world
+            .resource::<MessageLog>()
+            .recent(2)
+            .map(|message| message.text.clone())
+            .collect();
+
+        assert_eq!(
+            vec![
+                String::from("You take aim at the empty floor."),
+                String::from("You take aim at the Ghoul."),
+            ],
+            messages
+        );
+    }
+
+    #[test]
+    fn test_confirm_rejects_target_behind_wall() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::F);
+
+        for key_code in [
+            KeyCode::W,
+            KeyCode::W,
+            KeyCode::D,
+            KeyCode::D,
+            KeyCode::D,
+            KeyCode::D,
+        ] {
+            press(&mut app, key_code);
+        }
+
+        assert_eq!(Some([9, 7]), cursor(&app));
+
+        press(&mut app, KeyCode::Return);
+
+        assert!(app.world.resource::<Events<TargetSelected>>().is_empty());
+        assert_eq!(Some([9, 7]), cursor(&app));
+
+        let game_map = app.world.query::<&GameMap>().single(&app.world);
+
+        assert_eq!(
+            vec![
+                ([6, 6], true),
+                ([7, 6], true),
+                ([8, 7], false),
+                ([9, 7], false)
+            ],
+            line_of_fire(game_map, &[5, 5], &[9, 7])
+        );
+    }
+}
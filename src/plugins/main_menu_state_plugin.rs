@@ -0,0 +1,63 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::app::{App, Plugin};
+use bevy::prelude::{in_state, IntoSystemConfigs, OnEnter, OnExit, Update};
+
+use crate::plugins::main_menu_state_systems::menu;
+use crate::plugins::states::AppState;
+
+/// Plugin coupled with the [AppState::MainMenu] state, which shows the title screen the game boots into.
+/// Its main menu starts a new game in the [AppState::Game], opens the [AppState::Settings] or quits the game.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [Plugin]
+/// * [AppState::MainMenu]
+///
+pub struct MainMenuStatePlugin;
+
+impl Plugin for MainMenuStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::MainMenu), menu::startup_system)
+            .add_systems(
+                Update,
+                (menu::main_menu_input_system, menu::render_system)
+                    .chain()
+                    .run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(OnExit(AppState::MainMenu), menu::shutdown_system);
+    }
+
+    fn name(&self) -> &str {
+        "ECS -> Plugins -> MainMenuState"
+    }
+
+    fn is_unique(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,339 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::app::AppExit;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::log::warn;
+use bevy::prelude::{Commands, EventReader, EventWriter, Mut, NextState, Query, Res, ResMut, With};
+use bevy_ascii_terminal::{StringFormatter, Terminal};
+
+use crate::components::game_terminal::GameTerminal;
+use crate::core::constants;
+use crate::core::save_game::{SaveError, SaveGame};
+use crate::plugins::states::AppState;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::main_menu::{MainMenu, MainMenuOption};
+use crate::ui::colors;
+use crate::ui::rectangle::Rectangle;
+use crate::ui::tile::{SimpleTile, Tile};
+
+/// Startup system of the [AppState::MainMenu], which inserts a fresh [MainMenu] with
+/// [MainMenuOption::Start] selected.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to insert the [MainMenu].
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn startup_system(mut commands: Commands) {
+    commands.insert_resource(MainMenu::default());
+}
+
+/// System handling the input of the [MainMenu].
+///
+/// [InputType::Up] and [InputType::Down] move the selection between the options and [InputType::Confirm]
+/// picks the selected [MainMenuOption]. [MainMenuOption::Start] enters the [AppState::Game],
+/// [MainMenuOption::Settings] the [AppState::Settings] and [MainMenuOption::Quit] closes the game.
+/// [MainMenuOption::Continue] loads the [SaveGame] and enters the [AppState::Game] to restore it, but stays on
+/// the title screen if there is no save to continue.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to insert the loaded [SaveGame].
+/// * `input_config`: [InputConfig] required to recognize the user's input.
+/// * `main_menu`: The [MainMenu] holding the selection.
+/// * `next_state`: [NextState] required to leave the menu.
+/// * `exit_event`: [EventWriter] to send the [AppExit] event to the game's engine in order to close the game.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn main_menu_input_system(
+    mut commands: Commands,
+    input_config: Res<InputConfig>,
+    mut main_menu: ResMut<MainMenu>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut exit_event: EventWriter<AppExit>,
+    mut key_events: EventReader<KeyboardInput>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released {
+            continue;
+        }
+
+        let Some(input) = event
+            .key_code
+            .and_then(|key_code| input_config.parse_input(key_code))
+        else {
+            continue;
+        };
+
+        match input {
+            InputType::Up => main_menu.previous(),
+            InputType::Down => main_menu.next(),
+            InputType::Confirm => match main_menu.selected_option() {
+                MainMenuOption::Start => next_state.set(AppState::Game),
+                MainMenuOption::Continue => match SaveGame::load() {
+                    Ok(save_game) => {
+                        commands.insert_resource(save_game);
+                        next_state.set(AppState::Game);
+                    }
+                    Err(SaveError::Missing) => {
+                        warn!("ECS -> Systems -> main_menu_input_system -> No saved game to continue!")
+                    }
+                    Err(error) => warn!(
+                        "ECS -> Systems -> main_menu_input_system -> Unable to continue the saved game: {}",
+                        error
+                    ),
+                },
+                MainMenuOption::Settings => next_state.set(AppState::Settings),
+                MainMenuOption::Quit => exit_event.send(AppExit),
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Renders the title screen onto the [Terminal], with the title and the [MainMenuOption]s centered, framed by a
+/// border, and the selected option highlighted. The bottom row shows the keys operating the menu.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the menu.
+/// * `input_config`: The [InputConfig] providing the keys shown in the bottom row.
+/// * `main_menu`: The [MainMenu] holding the selection.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    input_config: Res<InputConfig>,
+    main_menu: Res<MainMenu>,
+) {
+    let mut terminal = terminal_query
+        .get_single_mut()
+        .expect("ECS -> Systems -> render_system -> Unable to retrieve {Terminal} component!");
+
+    terminal.clear();
+
+    let center = [terminal.width() as i32 / 2, terminal.height() as i32 / 2];
+    let title_row = center[1] + MainMenuOption::ALL.len() as i32;
+
+    let title_position = [center[0] - constants::TITLE.len() as i32 / 2, title_row];
+    let mut menu_area = Rectangle::new(title_position, [constants::TITLE.len() as i32 - 1, 0]);
+
+    terminal.put_string(title_position, constants::TITLE);
+
+    for (index, option) in MainMenuOption::ALL.into_iter().enumerate() {
+        let text = option.to_string();
+        let position = [center[0] - text.len() as i32 / 2, center[1] - index as i32];
+
+        menu_area = menu_area.union(&Rectangle::new(position, [text.len() as i32 - 1, 0]));
+
+        if option == main_menu.selected_option() {
+            terminal.put_string(position, text.as_str().fg(colors::MENU_SELECTION));
+        } else {
+            terminal.put_string(position, text.as_str());
+        }
+    }
+
+    // Leave a margin of two columns and one row between the border and the enclosed texts.
+    render_border(
+        &mut terminal,
+        &Rectangle {
+            left: menu_area.left - 2,
+            bottom: menu_area.bottom - 1,
+            right: menu_area.right + 2,
+            top: menu_area.top + 1,
+        },
+    );
+
+    terminal.put_string(
+        [0, 0],
+        format!(
+            "{:?}/{:?}: Select  {:?}: Confirm",
+            input_config.up, input_config.down, input_config.confirm
+        )
+        .as_str(),
+    );
+}
+
+/// (Private) Frames the passed `area` of the `terminal` with a border of [SimpleTile]s.
+fn render_border(terminal: &mut Mut<Terminal>, area: &Rectangle) {
+    let corner = SimpleTile::new('+', colors::MENU_BORDER);
+    let horizontal = SimpleTile::new('-', colors::MENU_BORDER);
+    let vertical = SimpleTile::new('|', colors::MENU_BORDER);
+
+    for x in area.left..=area.right {
+        for y in area.bottom..=area.top {
+            let is_horizontal_edge = y == area.bottom || y == area.top;
+            let is_vertical_edge = x == area.left || x == area.right;
+
+            let tile = match (is_horizontal_edge, is_vertical_edge) {
+                (true, true) => corner,
+                (true, false) => horizontal,
+                (false, true) => vertical,
+                (false, false) => continue,
+            };
+
+            tile.render(&[x, y], terminal, true, true);
+        }
+    }
+}
+
+/// Shutdown system of the [AppState::MainMenu], which removes the [MainMenu].
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the [MainMenu].
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn shutdown_system(mut commands: Commands) {
+    commands.remove_resource::<MainMenu>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode, State};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_event::<AppExit>();
+        app.add_state::<AppState>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(MainMenu::default());
+        app.add_systems(Update, main_menu_input_system);
+
+        app
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    fn current_state(app: &App) -> AppState {
+        *app.world.resource::<State<AppState>>().get()
+    }
+
+    #[test]
+    fn test_selecting_start_enters_game() {
+        let mut app = setup_app();
+
+        app.update();
+        assert_eq!(AppState::MainMenu, current_state(&app));
+
+        press(&mut app, KeyCode::Return);
+        app.update();
+
+        assert_eq!(AppState::Game, current_state(&app));
+    }
+
+    #[test]
+    fn test_menu_is_framed_by_border() {
+        let mut app = setup_app();
+        let terminal = app
+            .world
+            .spawn((Terminal::new([40, 20]), GameTerminal))
+            .id();
+
+        app.add_systems(Update, render_system);
+        app.update();
+
+        let terminal = app.world.get::<Terminal>(terminal).unwrap();
+
+        // The title "BRouge" is centered at [20, 14], followed by four options below it.
+        assert_eq!('+', terminal.get_char([14, 6]));
+        assert_eq!('+', terminal.get_char([25, 15]));
+        assert_eq!('-', terminal.get_char([20, 15]));
+        assert_eq!('|', terminal.get_char([14, 10]));
+        assert_eq!(colors::MENU_BORDER, terminal.get_tile([14, 10]).fg_color);
+    }
+
+    #[test]
+    fn test_selecting_settings_enters_settings() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::S);
+        press(&mut app, KeyCode::S);
+        assert_eq!(
+            MainMenuOption::Settings,
+            app.world.resource::<MainMenu>().selected_option()
+        );
+
+        press(&mut app, KeyCode::Return);
+        app.update();
+
+        assert_eq!(AppState::Settings, current_state(&app));
+    }
+}
@@ -38,7 +38,13 @@
 //!
 
 pub mod bootstrap_plugin;
+pub mod game_over_state_plugin;
+pub mod game_over_state_systems;
 pub mod game_state_plugin;
 pub mod game_state_systems;
+pub mod main_menu_state_plugin;
+pub mod main_menu_state_systems;
 pub mod plugin_provider;
+pub mod settings_state_plugin;
+pub mod settings_state_systems;
 pub mod states;
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::app::{App, Plugin};
+use bevy::prelude::{in_state, IntoSystemConfigs, OnEnter, OnExit, Update};
+
+use crate::plugins::settings_state_systems::menu;
+use crate::plugins::states::AppState;
+
+/// Plugin coupled with the [AppState::Settings] state, in which the player rebinds the keys of the
+/// [crate::res::input_config::InputConfig]. Changed bindings are written back to the config file when
+/// leaving the menu.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [Plugin]
+/// * [AppState::Settings]
+///
+pub struct SettingsStatePlugin;
+
+impl Plugin for SettingsStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Settings), menu::startup_system)
+            .add_systems(
+                Update,
+                (menu::settings_input_system, menu::render_system)
+                    .chain()
+                    .run_if(in_state(AppState::Settings)),
+            )
+            .add_systems(OnExit(AppState::Settings), menu::shutdown_system);
+    }
+
+    fn name(&self) -> &str {
+        "ECS -> Plugins -> SettingsState"
+    }
+
+    fn is_unique(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,275 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::{Commands, EventReader, NextState, Query, Res, ResMut, With};
+use bevy_ascii_terminal::{StringFormatter, Terminal};
+
+use crate::components::game_terminal::GameTerminal;
+use crate::plugins::states::AppState;
+use crate::res::config_file::ConfigFile;
+use crate::res::input_config::{InputConfig, InputType};
+use crate::res::settings_menu::SettingsMenu;
+use crate::ui::colors;
+
+/// Startup system of the [AppState::Settings], which inserts a fresh [SettingsMenu] with the first binding
+/// row selected.
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to insert the [SettingsMenu].
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn startup_system(mut commands: Commands) {
+    commands.insert_resource(SettingsMenu::default());
+}
+
+/// System handling the input of the [SettingsMenu].
+///
+/// [InputType::Up] and [InputType::Down] move the selection between the binding rows and
+/// [InputType::Confirm] starts rebinding the selected [InputType]. The next pressed key is then bound to
+/// it, see [InputConfig::rebind], unless it's the [InputType::Cancel] key, which aborts the rebinding.
+/// Otherwise, [InputType::Cancel] saves the [InputConfig] and returns to the [AppState::MainMenu].
+///
+/// # Arguments
+///
+/// * `input_config`: The [InputConfig] to rebind.
+/// * `settings_menu`: The [SettingsMenu] holding the selection.
+/// * `next_state`: [NextState] required to leave the menu.
+/// * `key_events`: [EventReader] stream of [KeyboardInput] events required to parse the user's input.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If any of the resources required by the system aren't available through the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn settings_input_system(
+    mut input_config: ResMut<InputConfig>,
+    mut settings_menu: ResMut<SettingsMenu>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut key_events: EventReader<KeyboardInput>,
+) {
+    for event in key_events.read() {
+        if event.state == ButtonState::Released {
+            continue;
+        }
+
+        let Some(key_code) = event.key_code else {
+            continue;
+        };
+
+        let input = input_config.parse_input(key_code);
+
+        if settings_menu.is_rebinding() {
+            if input != Some(InputType::Cancel) {
+                input_config.rebind(settings_menu.selected_input(), key_code);
+            }
+
+            settings_menu.finish_rebinding();
+            continue;
+        }
+
+        match input {
+            Some(InputType::Up) => settings_menu.previous(),
+            Some(InputType::Down) => settings_menu.next(),
+            Some(InputType::Confirm) => settings_menu.start_rebinding(),
+            Some(InputType::Cancel) => {
+                input_config.save();
+                next_state.set(AppState::MainMenu);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Renders the [SettingsMenu] onto the [Terminal], listing every [InputType] next to the key it's bound to.
+/// The selected row is highlighted and the bottom row shows the keys operating the menu.
+///
+/// # Arguments
+///
+/// * `terminal_query`: [Query] to retrieve the [Terminal], in order to render the menu.
+/// * `input_config`: The [InputConfig] providing the bindings.
+/// * `settings_menu`: The [SettingsMenu] holding the selection.
+///
+/// returns: ()
+///
+/// # Panics
+///
+/// * If the [Terminal] can't be retrieved.
+/// * If any of the required resources can't be retrieved from the ECS.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn render_system(
+    mut terminal_query: Query<&mut Terminal, With<GameTerminal>>,
+    input_config: Res<InputConfig>,
+    settings_menu: Res<SettingsMenu>,
+) {
+    let mut terminal = terminal_query
+        .get_single_mut()
+        .expect("ECS -> Systems -> render_system -> Unable to retrieve {Terminal} component!");
+
+    terminal.clear();
+
+    let top_row = terminal.height() as i32 - 1;
+
+    terminal.put_string([0, top_row], "Settings - Key bindings");
+
+    for (index, input) in InputType::ALL.into_iter().enumerate() {
+        let mut text = format!(
+            "{:<16} {:?}",
+            input.to_string(),
+            input_config.binding(input)
+        );
+
+        if input == settings_menu.selected_input() {
+            if settings_menu.is_rebinding() {
+                text = format!("{:<16} Press a key...", input.to_string());
+            }
+
+            terminal.put_string(
+                [2, top_row - 2 - index as i32],
+                text.as_str().fg(colors::MENU_SELECTION),
+            );
+        } else {
+            terminal.put_string([2, top_row - 2 - index as i32], text.as_str());
+        }
+    }
+
+    terminal.put_string(
+        [0, 0],
+        format!(
+            "{:?}/{:?}: Select  {:?}: Rebind  {:?}: Back",
+            input_config.up, input_config.down, input_config.confirm, input_config.cancel
+        )
+        .as_str(),
+    );
+}
+
+/// Shutdown system of the [AppState::Settings], which removes the [SettingsMenu].
+///
+/// # Arguments
+///
+/// * `commands`: [Commands] queue required to remove the [SettingsMenu].
+///
+/// returns: ()
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn shutdown_system(mut commands: Commands) {
+    commands.remove_resource::<SettingsMenu>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Component, KeyCode};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+
+        app.add_event::<KeyboardInput>();
+        app.add_state::<AppState>();
+        app.insert_resource(InputConfig::default());
+        app.insert_resource(SettingsMenu::default());
+        app.add_systems(Update, settings_input_system);
+
+        app
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        let window = app.world.spawn(DummyComponent).id();
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            window,
+        });
+
+        app.update();
+    }
+
+    #[test]
+    fn test_rebinding_selected_input() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::S);
+        assert_eq!(
+            InputType::Left,
+            app.world.resource::<SettingsMenu>().selected_input()
+        );
+
+        press(&mut app, KeyCode::Return);
+        assert!(app.world.resource::<SettingsMenu>().is_rebinding());
+
+        press(&mut app, KeyCode::Left);
+        assert!(!app.world.resource::<SettingsMenu>().is_rebinding());
+
+        let input_config = app.world.resource::<InputConfig>();
+
+        assert_eq!(KeyCode::Left, input_config.left);
+        assert_eq!(
+            Some(InputType::Left),
+            input_config.parse_input(KeyCode::Left)
+        );
+        assert_eq!(None, input_config.parse_input(KeyCode::A));
+    }
+
+    #[test]
+    fn test_cancel_aborts_rebinding() {
+        let mut app = setup_app();
+
+        press(&mut app, KeyCode::Return);
+        press(&mut app, KeyCode::Escape);
+
+        assert!(!app.world.resource::<SettingsMenu>().is_rebinding());
+        assert_eq!(InputConfig::default(), *app.world.resource::<InputConfig>());
+    }
+}
@@ -20,9 +20,13 @@
  */
 
 use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
+use bevy::log::warn;
 use bevy::prelude::{Resource, States};
 
+use crate::core::constants;
+
 /// Defines all states the game can be in, with every state representing an isolated and distinct logic section
 /// in the game's state machine.
 ///
@@ -46,6 +50,20 @@ use bevy::prelude::{Resource, States};
 ///
 #[derive(Copy, Clone, Default, Eq, PartialEq, Hash, States)]
 pub enum AppState {
+    /// The title screen, which the game boots into. From its main menu the player starts a new game, opens the
+    /// settings or quits.
+    ///
+    /// See the [crate::plugins::main_menu_state_plugin::MainMenuStatePlugin] for the corresponding
+    /// [bevy::prelude::Plugin].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    #[default]
+    MainMenu,
     /// The main gameplay state, in which the player actively travers the world and interacts with the game.
     ///
     /// See the [crate::plugins::game_state_plugin::GameStatePlugin] for the corresponding [bevy::prelude::Plugin].
@@ -56,8 +74,106 @@ pub enum AppState {
     ///
     /// Since: `0.1.5`
     ///
-    #[default]
     Game,
+    /// The settings menu, in which the player rebinds the keys of the [crate::res::input_config::InputConfig].
+    ///
+    /// See the [crate::plugins::settings_state_plugin::SettingsStatePlugin] for the corresponding
+    /// [bevy::prelude::Plugin].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    Settings,
+    /// The game over screen, shown once the player died, which summarizes the finished run.
+    ///
+    /// See the [crate::plugins::game_over_state_plugin::GameOverStatePlugin] for the corresponding
+    /// [bevy::prelude::Plugin].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    GameOver,
+}
+
+impl AppState {
+    /// Determines the [AppState] the game boots into. With the `debug_tools` feature enabled, the state can be
+    /// overridden through the [constants::INITIAL_STATE_ENV_VAR] environment variable, otherwise the default
+    /// [AppState] is used.
+    ///
+    /// returns: [AppState]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn initial() -> Self {
+        #[cfg(feature = "debug_tools")]
+        let value = std::env::var(constants::INITIAL_STATE_ENV_VAR).ok();
+
+        #[cfg(not(feature = "debug_tools"))]
+        let value: Option<String> = None;
+
+        Self::initial_from(value.as_deref())
+    }
+
+    /// Parses the passed `value` into the [AppState] the game boots into. A missing or unknown `value` falls back
+    /// to the default [AppState].
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The name of the [AppState], e.g., `Game`.
+    ///
+    /// returns: [AppState]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(AppState::Game, AppState::initial_from(Some("Game")));
+    /// assert_eq!(AppState::default(), AppState::initial_from(None));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn initial_from(value: Option<&str>) -> Self {
+        let Some(value) = value else {
+            return Self::default();
+        };
+
+        value.parse().unwrap_or_else(|error| {
+            warn!(
+                "{} (set through {}), falling back to the default state.",
+                error,
+                constants::INITIAL_STATE_ENV_VAR
+            );
+            Self::default()
+        })
+    }
+}
+
+impl FromStr for AppState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "MainMenu" => Ok(AppState::MainMenu),
+            "Game" => Ok(AppState::Game),
+            "Settings" => Ok(AppState::Settings),
+            "GameOver" => Ok(AppState::GameOver),
+            _ => Err(format!("Unknown app state {:?}", s)),
+        }
+    }
 }
 
 impl Debug for AppState {
@@ -69,7 +185,10 @@ impl Debug for AppState {
 impl Display for AppState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            AppState::MainMenu => write!(f, "MainMenu"),
             AppState::Game => write!(f, "Game"),
+            AppState::Settings => write!(f, "Settings"),
+            AppState::GameOver => write!(f, "GameOver"),
         }
     }
 }
@@ -108,3 +227,20 @@ impl Display for GameTurnState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initial_from() {
+        assert_eq!(AppState::MainMenu, AppState::default());
+        assert_eq!(AppState::Game, AppState::initial_from(Some("Game")));
+        assert_eq!(AppState::Game, AppState::initial_from(Some(" Game\n")));
+        assert_eq!(AppState::Settings, AppState::initial_from(Some("Settings")));
+        assert_eq!(AppState::GameOver, AppState::initial_from(Some("GameOver")));
+        assert_eq!(AppState::default(), AppState::initial_from(Some("Menu")));
+        assert_eq!(AppState::default(), AppState::initial_from(None));
+        assert!("Victory".parse::<AppState>().is_err());
+    }
+}
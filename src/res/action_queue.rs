@@ -0,0 +1,147 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::core::action::Action;
+
+/// [Resource] queueing the [Action]s pushed by the `player's` input and the AI of the `NPC entities`. The
+/// [Action]s are resolved in the order they were pushed by the
+/// [crate::plugins::game_state_systems::action::action_system].
+///
+/// # Examples
+///
+/// ```
+/// let mut action_queue = ActionQueue::default();
+///
+/// action_queue.push(Action::Wait { actor: player });
+///
+/// assert_eq!(vec![Action::Wait { actor: player }], action_queue.drain());
+/// assert!(action_queue.is_empty());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Eq, PartialEq, Resource)]
+pub struct ActionQueue {
+    /// (Private) The [Action]s still to resolve.
+    actions: VecDeque<Action>,
+}
+
+impl ActionQueue {
+    /// Adds the passed `action` to the end of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `action`: The [Action] to resolve.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn push(&mut self, action: Action) {
+        self.actions.push_back(action);
+    }
+
+    /// Takes all queued [Action]s in the order they were pushed, leaving the queue empty.
+    ///
+    /// returns: [Vec]<[Action]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn drain(&mut self) -> Vec<Action> {
+        self.actions.drain(..).collect()
+    }
+
+    /// Checks if no [Action]s are queued.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl Debug for ActionQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> ActionQueue {{ actions: {:?} }}",
+            self.actions
+        )
+    }
+}
+
+impl Display for ActionQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.actions.len())
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use bevy::prelude::Entity;
+
+    use super::*;
+
+    #[test]
+    fn test_drain_keeps_push_order() {
+        let mut action_queue = ActionQueue::default();
+        let actions = vec![
+            Action::Wait {
+                actor: Entity::from_raw(1),
+            },
+            Action::Move {
+                actor: Entity::from_raw(2),
+                delta: [0, 1],
+            },
+        ];
+
+        for action in actions.iter() {
+            action_queue.push(*action);
+        }
+
+        assert!(!action_queue.is_empty());
+        assert_eq!(actions, action_queue.drain());
+        assert!(action_queue.is_empty());
+    }
+}
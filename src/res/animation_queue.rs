@@ -0,0 +1,292 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Color, Resource};
+
+use crate::components::coord_2d::Coord2d;
+
+/// A short-lived visual effect, played for a fixed number of frames at a position of the map.
+///
+/// # Properties
+///
+/// * `position`: The position of the map the [Animation] is played at.
+/// * `kind`: The [AnimationKind] defining how the [Animation] is rendered.
+/// * `frames`: The number of frames the [Animation] lasts.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, PartialEq)]
+pub struct Animation {
+    /// The position of the map the [Animation] is played at.
+    pub position: Coord2d,
+    /// The [AnimationKind] defining how the [Animation] is rendered.
+    pub kind: AnimationKind,
+    /// The number of frames the [Animation] lasts.
+    pub frames: u32,
+}
+
+/// Defines how an [Animation] is rendered.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, PartialEq)]
+pub enum AnimationKind {
+    /// Flashes the background of the tile in the given [Color], e.g., on a hit.
+    Flash(Color),
+    /// Shows the dealt damage as a number in the given [Color] above the tile, e.g., on a hit.
+    DamageNumber(i32, Color),
+}
+
+/// [Resource] queueing the [Animation]s to play, e.g., after an attack. The [Animation]s are played
+/// one after another in the order they were enqueued, each one for its configured number of frames.
+///
+/// The turn doesn't advance, i.e., the player can't act, until the queue is drained.
+///
+/// # Examples
+///
+/// ```
+/// let mut animation_queue = AnimationQueue::default();
+///
+/// animation_queue.enqueue(Animation {
+///     position: Coord2d::new(4, 2),
+///     kind: AnimationKind::Flash(colors::HIT_FLASH),
+///     frames: 2,
+/// });
+///
+/// animation_queue.advance();
+/// assert!(!animation_queue.is_finished());
+///
+/// animation_queue.advance();
+/// assert!(animation_queue.is_finished());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Resource)]
+pub struct AnimationQueue {
+    /// (Private) The queued [Animation]s, the first one being the currently playing one.
+    animations: VecDeque<Animation>,
+    /// (Private) The number of frames the current [Animation] has been played for.
+    elapsed_frames: u32,
+}
+
+impl AnimationQueue {
+    /// Adds the passed `animation` to the end of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation`: The [Animation] to play.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn enqueue(&mut self, animation: Animation) {
+        self.animations.push_back(animation);
+    }
+
+    /// Returns the currently playing [Animation], if any.
+    ///
+    /// returns: [Option]<&[Animation]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn current(&self) -> Option<&Animation> {
+        self.animations.front()
+    }
+
+    /// Returns the number of frames the current [Animation] has been played for.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn elapsed_frames(&self) -> u32 {
+        self.elapsed_frames
+    }
+
+    /// Checks if all queued [Animation]s have been played.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.animations.is_empty()
+    }
+
+    /// Advances the current [Animation] by one frame, removing it from the queue once it has been
+    /// played for its configured number of frames.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn advance(&mut self) {
+        let Some(animation) = self.animations.front() else {
+            return;
+        };
+
+        self.elapsed_frames += 1;
+
+        if self.elapsed_frames >= animation.frames {
+            self.animations.pop_front();
+            self.elapsed_frames = 0;
+        }
+    }
+}
+
+impl Debug for Animation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> Animation {{ \
+        position: {:?}, \
+        kind: {:?}, \
+        frames: {:?} \
+        }}",
+            self.position, self.kind, self.frames
+        )
+    }
+}
+
+impl Display for Animation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.position, self.kind, self.frames)
+    }
+}
+
+impl Debug for AnimationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Resources -> AnimationKind::{}", self)
+    }
+}
+
+impl Display for AnimationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimationKind::Flash(color) => write!(f, "Flash({:?})", color),
+            AnimationKind::DamageNumber(damage, color) => {
+                write!(f, "DamageNumber({}, {:?})", damage, color)
+            }
+        }
+    }
+}
+
+impl Debug for AnimationQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> AnimationQueue {{ \
+        animations: {:?}, \
+        elapsed_frames: {:?} \
+        }}",
+            self.animations, self.elapsed_frames
+        )
+    }
+}
+
+impl Display for AnimationQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.animations.len(), self.elapsed_frames)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn flash(x: i32, frames: u32) -> Animation {
+        Animation {
+            position: Coord2d::new(x, 0),
+            kind: AnimationKind::Flash(Color::RED),
+            frames,
+        }
+    }
+
+    #[test]
+    fn test_queue_drains_in_order_after_configured_frames() {
+        let mut animation_queue = AnimationQueue::default();
+
+        animation_queue.enqueue(flash(1, 3));
+        animation_queue.enqueue(flash(2, 2));
+
+        for frame in 1..=3 {
+            assert_eq!(Some(&flash(1, 3)), animation_queue.current());
+            animation_queue.advance();
+            assert_eq!(frame % 3, animation_queue.elapsed_frames());
+        }
+
+        for _ in 0..2 {
+            assert!(!animation_queue.is_finished());
+            assert_eq!(Some(&flash(2, 2)), animation_queue.current());
+            animation_queue.advance();
+        }
+
+        assert!(animation_queue.is_finished());
+        assert_eq!(None, animation_queue.current());
+    }
+
+    #[test]
+    fn test_advance_on_empty_queue() {
+        let mut animation_queue = AnimationQueue::default();
+
+        animation_queue.advance();
+
+        assert!(animation_queue.is_finished());
+        assert_eq!(0, animation_queue.elapsed_frames());
+    }
+}
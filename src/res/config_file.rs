@@ -22,8 +22,9 @@
 use std::env;
 use std::path::PathBuf;
 
-use bevy::log::debug;
+use bevy::log::{debug, error};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::os::file_system;
 
@@ -44,6 +45,7 @@ use crate::os::file_system;
 /// # See also
 ///
 /// * [file_system::load_file]
+/// * [file_system::write_file]
 ///
 pub trait ConfigFile: DeserializeOwned {
     /// Specifies the actual name of the respective [ConfigFile] in the file system, so it can
@@ -114,6 +116,52 @@ pub trait ConfigFile: DeserializeOwned {
             panic!("Unable to load config file!");
         })
     }
+
+    /// Serializes the [ConfigFile] implementor and writes it to the file with the set [ConfigFile::file_name],
+    /// replacing the loaded configuration, so changes made during the game persist into the next session.
+    ///
+    /// returns: bool - `true` if the configuration was written successfully and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut input_config = InputConfig::load();
+    ///
+    /// input_config.rebind(InputType::Up, KeyCode::Up);
+    ///
+    /// assert!(input_config.save());
+    /// assert_eq!(KeyCode::Up, InputConfig::load().up);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [serde_json::to_string_pretty]
+    /// * [file_system::write_file]
+    ///
+    fn save(&self) -> bool
+    where
+        Self: Serialize,
+    {
+        let path = resolve_config_file_path(Self::file_name());
+
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => file_system::write_file(&path, &json),
+            Err(error) => {
+                error!(
+                    "Unable to serialize config file {}: {}",
+                    Self::file_name(),
+                    error
+                );
+                false
+            }
+        }
+    }
 }
 
 /// Internal function to resolves the complete file path for the passed `file_name`
@@ -0,0 +1,212 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::core::position_2d::Position2d;
+use crate::ui::tile::Tile;
+use crate::ui::tile_map::TileMap;
+
+/// [Resource] tracking the state of the cursor HUD, which describes the map tile under the mouse cursor,
+/// e.g., for mapping or bug reports.
+///
+/// # Examples
+///
+/// ```
+/// let mut cursor_hud = CursorHud::default();
+///
+/// cursor_hud.toggle();
+/// cursor_hud.set_tile(Some([12, 5]));
+///
+/// assert_eq!(
+///     Some(String::from("(12, 5) in bounds: true, visible: false, walkable: true")),
+///     cursor_hud.text(&game_map)
+/// );
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Eq, PartialEq, Resource)]
+pub struct CursorHud {
+    /// (Private) Flag indicating if the HUD is shown.
+    is_visible: bool,
+    /// (Private) The map tile under the mouse cursor, [None] if the cursor is outside of the window.
+    tile: Option<[i32; 2]>,
+}
+
+impl CursorHud {
+    /// Checks if the HUD is currently shown.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Shows the HUD if it's hidden and hides it otherwise.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn toggle(&mut self) {
+        self.is_visible = !self.is_visible;
+    }
+
+    /// Updates the map tile under the mouse cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile`: The tile under the mouse cursor, [None] if the cursor is outside of the window.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn set_tile(&mut self, tile: Option<[i32; 2]>) {
+        self.tile = tile;
+    }
+
+    /// Describes the map tile under the mouse cursor, i.e., its coordinate and whether it's in the bounds of
+    /// the passed `map`, visible and walkable.
+    ///
+    /// # Arguments
+    ///
+    /// * `map`: The [TileMap] the tile belongs to.
+    ///
+    /// returns: [Option]<[String]> - The description of the tile, [None] if no tile is under the cursor.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn text<T: Tile>(&self, map: &impl TileMap<T>) -> Option<String> {
+        let tile = self.tile?;
+        let coordinate = format!("({}, {})", tile.x_coordinate(), tile.y_coordinate());
+
+        if !map.is_in_bounds(&tile) {
+            return Some(format!("{} in bounds: false", coordinate));
+        }
+
+        Some(format!(
+            "{} in bounds: true, visible: {}, walkable: {}",
+            coordinate,
+            map.is_tile_visible(&tile),
+            !map.tile_has_collision(&tile)
+        ))
+    }
+}
+
+impl Debug for CursorHud {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> CursorHud {{ \
+        is_visible: {:?}, \
+        tile: {:?} \
+        }}",
+            self.is_visible, self.tile
+        )
+    }
+}
+
+impl Display for CursorHud {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?})", self.is_visible, self.tile)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::ui::game_map::GameMap;
+    use crate::ui::tile::MapTile;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let mut cursor_hud = CursorHud::default();
+
+        assert!(!cursor_hud.is_visible());
+
+        cursor_hud.toggle();
+        assert!(cursor_hud.is_visible());
+
+        cursor_hud.toggle();
+        assert!(!cursor_hud.is_visible());
+    }
+
+    #[test]
+    fn test_text_reports_coordinate_and_flags() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        map.set_tile_at(&[3, 4], MapTile::floor('.'));
+        map.mark_tile_as_visible(&[3, 4]);
+
+        let mut cursor_hud = CursorHud::default();
+
+        assert_eq!(None, cursor_hud.text(&map));
+
+        cursor_hud.set_tile(Some([3, 4]));
+        assert_eq!(
+            Some(String::from(
+                "(3, 4) in bounds: true, visible: true, walkable: true"
+            )),
+            cursor_hud.text(&map)
+        );
+
+        cursor_hud.set_tile(Some([2, 4]));
+        assert_eq!(
+            Some(String::from(
+                "(2, 4) in bounds: true, visible: false, walkable: false"
+            )),
+            cursor_hud.text(&map)
+        );
+
+        cursor_hud.set_tile(Some([-1, 4]));
+        assert_eq!(
+            Some(String::from("(-1, 4) in bounds: false")),
+            cursor_hud.text(&map)
+        );
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+#[cfg(any(test, feature = "debug_tools"))]
+use crate::plugins::states::GameTurnState;
+#[cfg(any(test, feature = "debug_tools"))]
+use crate::res::dungeon_depth::DungeonDepth;
+#[cfg(any(test, feature = "debug_tools"))]
+use crate::res::turn_counter::TurnCounter;
+
+/// [Resource] tracking the state of the debug footer, which summarizes the current run for bug reports, i.e.,
+/// the seed, the [crate::res::dungeon_depth::DungeonDepth], the [crate::res::turn_counter::TurnCounter] and the
+/// [crate::plugins::states::GameTurnState].
+///
+/// Only toggled and rendered with the `debug_tools` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// let footer_text = DebugFooter::text(Some(42), &DungeonDepth::default(), &TurnCounter::default(), &GameTurnState::Player);
+///
+/// assert_eq!("seed: 42 | depth: 1 | turn: 0 | Player", footer_text);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Resource)]
+pub struct DebugFooter {
+    /// (Private) Flag indicating if the footer is shown.
+    is_visible: bool,
+}
+
+#[cfg(any(test, feature = "debug_tools"))]
+impl DebugFooter {
+    /// Checks if the footer is currently shown.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Shows the footer if it's hidden and hides it otherwise.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn toggle(&mut self) {
+        self.is_visible = !self.is_visible;
+    }
+
+    /// Creates the text of the footer. A missing seed, e.g., because no map has been generated yet, is marked
+    /// with a `-`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed`: The seed of the current map, see [crate::ui::game_map::GameMap::seed], if any.
+    /// * `dungeon_depth`: The [DungeonDepth] the `player` is on.
+    /// * `turn_counter`: The [TurnCounter] of the current run.
+    /// * `game_turn_state`: The [GameTurnState] of the current turn.
+    ///
+    /// returns: [String]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn text(
+        seed: Option<u64>,
+        dungeon_depth: &DungeonDepth,
+        turn_counter: &TurnCounter,
+        game_turn_state: &GameTurnState,
+    ) -> String {
+        format!(
+            "seed: {} | depth: {} | turn: {} | {}",
+            seed.map_or(String::from("-"), |seed| seed.to_string()),
+            dungeon_depth.current(),
+            turn_counter.current(),
+            game_turn_state
+        )
+    }
+}
+
+impl Debug for DebugFooter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> DebugFooter {{ is_visible: {:?} }}",
+            self.is_visible
+        )
+    }
+}
+
+impl Display for DebugFooter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.is_visible)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_text_contains_run_values() {
+        let mut turn_counter = TurnCounter::default();
+
+        for _ in 0..7 {
+            turn_counter.advance();
+        }
+
+        let text = DebugFooter::text(
+            Some(1337),
+            &DungeonDepth::default(),
+            &turn_counter,
+            &GameTurnState::Npc,
+        );
+
+        assert!(text.contains("seed: 1337"), "{}", text);
+        assert!(text.contains("depth: 1"), "{}", text);
+        assert!(text.contains("turn: 7"), "{}", text);
+        assert!(text.contains("NPC"), "{}", text);
+        assert!(DebugFooter::text(
+            None,
+            &DungeonDepth::default(),
+            &turn_counter,
+            &GameTurnState::Player
+        )
+        .starts_with("seed: - |"));
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut debug_footer = DebugFooter::default();
+
+        assert!(!debug_footer.is_visible());
+
+        debug_footer.toggle();
+
+        assert!(debug_footer.is_visible());
+    }
+}
@@ -0,0 +1,124 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+/// [Resource] holding the floor of the dungeon the `player` is currently on, starting at `1` for the first floor.
+///
+/// # Examples
+///
+/// ```
+/// let dungeon_depth = DungeonDepth::default();
+///
+/// assert_eq!(1, dungeon_depth.current());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Resource)]
+pub struct DungeonDepth {
+    /// (Private) The current floor, starting at `1`.
+    depth: u32,
+}
+
+impl DungeonDepth {
+    /// Returns the current floor, starting at `1`.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn current(&self) -> u32 {
+        self.depth
+    }
+
+    /// Moves one floor further down into the dungeon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut dungeon_depth = DungeonDepth::default();
+    /// dungeon_depth.descend();
+    ///
+    /// assert_eq!(2, dungeon_depth.current());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn descend(&mut self) {
+        self.depth += 1;
+    }
+}
+
+impl Default for DungeonDepth {
+    fn default() -> Self {
+        Self { depth: 1 }
+    }
+}
+
+impl Debug for DungeonDepth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> DungeonDepth {{ depth: {:?} }}",
+            self.depth
+        )
+    }
+}
+
+impl Display for DungeonDepth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.depth)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_descend() {
+        let mut dungeon_depth = DungeonDepth::default();
+
+        assert_eq!(1, dungeon_depth.current());
+
+        dungeon_depth.descend();
+        dungeon_depth.descend();
+
+        assert_eq!(3, dungeon_depth.current());
+        assert_eq!("(3)", dungeon_depth.to_string());
+    }
+}
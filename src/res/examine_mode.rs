@@ -0,0 +1,230 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Entity, Resource};
+
+/// [Resource] tracking the state of the examine mode, in which the player cycles a selection cursor
+/// through all currently visible `entities` to inspect their names and stats.
+///
+/// While the examine mode is active, the player can't act, i.e., the turn doesn't advance.
+///
+/// # Examples
+///
+/// ```
+/// let mut examine_mode = ExamineMode::default();
+///
+/// examine_mode.activate(vec![monster, potion]);
+/// assert_eq!(Some(monster), examine_mode.selected_entity());
+///
+/// examine_mode.next();
+/// assert_eq!(Some(potion), examine_mode.selected_entity());
+///
+/// examine_mode.next(); // Wraps around to the first target.
+/// assert_eq!(Some(monster), examine_mode.selected_entity());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Eq, PartialEq, Resource)]
+pub struct ExamineMode {
+    /// (Private) The examinable `entities` in the order they are cycled through.
+    targets: Vec<Entity>,
+    /// (Private) Index of the currently selected target, [None] if the examine mode is inactive.
+    selected: Option<usize>,
+}
+
+impl ExamineMode {
+    /// Checks if the examine mode is currently active.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_active(&self) -> bool {
+        self.selected.is_some()
+    }
+
+    /// Activates the examine mode for the passed `targets`, selecting the first one. The examine mode
+    /// stays inactive if there are no `targets`.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets`: The examinable `entities` in the order they should be cycled through.
+    ///
+    /// returns: bool - `true` if the examine mode was activated.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn activate(&mut self, targets: Vec<Entity>) -> bool {
+        self.selected = if targets.is_empty() { None } else { Some(0) };
+        self.targets = targets;
+
+        self.is_active()
+    }
+
+    /// Deactivates the examine mode, clearing all targets.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn deactivate(&mut self) {
+        self.targets.clear();
+        self.selected = None;
+    }
+
+    /// Moves the selection to the next target, wrapping around to the first one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn next(&mut self) {
+        if let Some(selected) = self.selected {
+            self.selected = Some((selected + 1) % self.targets.len());
+        }
+    }
+
+    /// Moves the selection to the previous target, wrapping around to the last one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn previous(&mut self) {
+        if let Some(selected) = self.selected {
+            self.selected = Some((selected + self.targets.len() - 1) % self.targets.len());
+        }
+    }
+
+    /// Returns the currently selected target, [None] if the examine mode is inactive.
+    ///
+    /// returns: [Option]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn selected_entity(&self) -> Option<Entity> {
+        self.selected.map(|selected| self.targets[selected])
+    }
+
+    /// Returns all examinable targets in the order they are cycled through.
+    ///
+    /// returns: &[Vec]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn targets(&self) -> &Vec<Entity> {
+        &self.targets
+    }
+}
+
+impl Debug for ExamineMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> ExamineMode {{ \
+        targets: {:?}, \
+        selected: {:?} \
+        }}",
+            self.targets, self.selected
+        )
+    }
+}
+
+impl Display for ExamineMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {:?})", self.targets.len(), self.selected)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_cycling_visits_each_target_once_and_wraps() {
+        let targets: Vec<Entity> = (1..=3).map(Entity::from_raw).collect();
+        let mut examine_mode = ExamineMode::default();
+
+        assert!(examine_mode.activate(targets.clone()));
+
+        let mut visited = Vec::new();
+
+        for _ in 0..targets.len() {
+            visited.push(examine_mode.selected_entity().unwrap());
+            examine_mode.next();
+        }
+
+        assert_eq!(targets, visited);
+        assert_eq!(Some(targets[0]), examine_mode.selected_entity());
+
+        examine_mode.previous();
+        assert_eq!(Some(targets[2]), examine_mode.selected_entity());
+    }
+
+    #[test]
+    fn test_activation_without_targets() {
+        let mut examine_mode = ExamineMode::default();
+
+        assert!(!examine_mode.activate(Vec::new()));
+        assert!(!examine_mode.is_active());
+        assert_eq!(None, examine_mode.selected_entity());
+    }
+
+    #[test]
+    fn test_deactivation() {
+        let mut examine_mode = ExamineMode::default();
+
+        examine_mode.activate(vec![Entity::from_raw(1)]);
+        examine_mode.deactivate();
+
+        assert!(!examine_mode.is_active());
+        assert!(examine_mode.targets().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+/// [Resource] selecting the algorithm [crate::core::algorithm::field_of_view] uses to calculate the
+/// `field of view` of the `entities`.
+///
+/// The initial value is taken from the [crate::res::gameplay_config::GameplayConfig]. With the
+/// `debug_tools` feature enabled, it can be toggled at runtime to compare the visible sets of both
+/// algorithms.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Resource)]
+pub enum FovAlgorithm {
+    /// Checks the line of sight to every position within the radius through slopes.
+    #[default]
+    Bresenham,
+    /// Recursive shadowcasting, scanning the eight octants around the origin row by row.
+    Shadowcast,
+}
+
+impl FovAlgorithm {
+    /// Returns the respective other [FovAlgorithm].
+    ///
+    /// returns: [FovAlgorithm]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(FovAlgorithm::Shadowcast, FovAlgorithm::Bresenham.toggled());
+    /// assert_eq!(FovAlgorithm::Bresenham, FovAlgorithm::Shadowcast.toggled());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    #[cfg(any(test, feature = "debug_tools"))]
+    pub fn toggled(&self) -> Self {
+        match self {
+            FovAlgorithm::Bresenham => FovAlgorithm::Shadowcast,
+            FovAlgorithm::Shadowcast => FovAlgorithm::Bresenham,
+        }
+    }
+}
+
+impl Debug for FovAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Resources -> FovAlgorithm::{}", self)
+    }
+}
+
+impl Display for FovAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FovAlgorithm::Bresenham => write!(f, "Bresenham"),
+            FovAlgorithm::Shadowcast => write!(f, "Shadowcast"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_toggled() {
+        assert_eq!(FovAlgorithm::Shadowcast, FovAlgorithm::Bresenham.toggled());
+        assert_eq!(FovAlgorithm::Bresenham, FovAlgorithm::Shadowcast.toggled());
+    }
+
+    #[test]
+    fn test_deserialization() {
+        assert_eq!(
+            FovAlgorithm::Shadowcast,
+            serde_json::from_str::<FovAlgorithm>("\"Shadowcast\"").unwrap()
+        );
+    }
+}
@@ -0,0 +1,353 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::core::constants;
+use crate::core::position_2d::Position2d;
+use crate::res::fov_algorithm::FovAlgorithm;
+use crate::res::fov_shape::FovShape;
+
+/// Key identifying a cached `field of view` result, consisting of the origin, the radius, the
+/// fingerprint of the map the result was calculated on and the [FovAlgorithm] and [FovShape] used.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+type FovCacheKey = ((i32, i32), i32, u64, FovAlgorithm, FovShape);
+
+/// Small least-recently-used cache for `field of view` calculation results, which allows stationary
+/// `entities` to skip the recalculation of their [crate::components::fov::Fov] as long as the
+/// map stays the same.
+///
+/// Results are keyed by the origin of the calculation, the fingerprint of the map, see
+/// [crate::ui::tile_map::TileMap::fingerprint], and the [FovAlgorithm] and [FovShape] used. The cache is cleared whenever a
+/// [crate::events::map_changed::MapChanged] event is received.
+///
+/// # Examples
+///
+/// ```
+/// let mut cache = FovCache::default();
+///
+/// field_of_view(&mut fov, &position, &mut map, &mut cache, algorithm, shape); // Miss, calculates the fov.
+///
+/// fov.is_dirty = true;
+/// field_of_view(&mut fov, &position, &mut map, &mut cache, algorithm, shape); // Hit, reuses the previous result.
+///
+/// assert_eq!(1, cache.hits());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Resource)]
+pub struct FovCache {
+    /// (Private) The maximum number of entries kept in the cache.
+    capacity: usize,
+    /// (Private) The cached entries, ordered from least to most recently used.
+    entries: VecDeque<(FovCacheKey, Vec<(i32, i32)>)>,
+    /// (Private) The number of lookups which found a cached result.
+    hits: usize,
+    /// (Private) The number of lookups which didn't find a cached result.
+    misses: usize,
+}
+
+impl FovCache {
+    /// Creates a new, empty [FovCache] holding at most `capacity` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: The maximum number of entries, after which the least recently used entry is evicted.
+    ///
+    /// returns: [FovCache]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up the cached `field of view` for the passed `origin`, `radius`, map `fingerprint`, `algorithm`
+    /// and `shape`, marking the entry as most recently used.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The [Position2d] from which the `field of view` was calculated.
+    /// * `radius`: The radius of the `field of view`.
+    /// * `fingerprint`: The fingerprint of the map the `field of view` was calculated on.
+    /// * `algorithm`: The [FovAlgorithm] the `field of view` was calculated with.
+    /// * `shape`: The [FovShape] the `field of view` was calculated with.
+    ///
+    /// returns: [Option]<&[Vec]<(i32, i32)>> - The cached positions or [None] if there is no entry.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn get(
+        &mut self,
+        origin: &impl Position2d,
+        radius: i32,
+        fingerprint: u64,
+        algorithm: FovAlgorithm,
+        shape: FovShape,
+    ) -> Option<&Vec<(i32, i32)>> {
+        let key = (origin.as_tuple(), radius, fingerprint, algorithm, shape);
+
+        match self
+            .entries
+            .iter()
+            .position(|(entry_key, _)| *entry_key == key)
+        {
+            Some(index) => {
+                self.hits += 1;
+
+                let entry = self.entries.remove(index)?;
+                self.entries.push_back(entry);
+                self.entries.back().map(|(_, positions)| positions)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Stores the passed `positions` as the `field of view` for the given `origin`, `radius`, map `fingerprint`,
+    /// `algorithm` and `shape`, evicting the least recently used entry if the cache is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The [Position2d] from which the `field of view` was calculated.
+    /// * `radius`: The radius of the `field of view`.
+    /// * `fingerprint`: The fingerprint of the map the `field of view` was calculated on.
+    /// * `algorithm`: The [FovAlgorithm] the `field of view` was calculated with.
+    /// * `shape`: The [FovShape] the `field of view` was calculated with.
+    /// * `positions`: The positions in the `field of view`.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn insert(
+        &mut self,
+        origin: &impl Position2d,
+        radius: i32,
+        fingerprint: u64,
+        algorithm: FovAlgorithm,
+        shape: FovShape,
+        positions: Vec<(i32, i32)>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = (origin.as_tuple(), radius, fingerprint, algorithm, shape);
+
+        self.entries.retain(|(entry_key, _)| *entry_key != key);
+
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back((key, positions));
+    }
+
+    /// Removes all entries from the cache, e.g., after the map has changed.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of entries currently in the cache.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the number of lookups which were answered from the cache.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of lookups which required a recalculation.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+impl Default for FovCache {
+    fn default() -> Self {
+        Self::new(constants::FOV_CACHE_CAPACITY)
+    }
+}
+
+impl Debug for FovCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> FovCache {{ \
+        capacity: {:?}, \
+        entries: {:?}, \
+        hits: {:?}, \
+        misses: {:?} \
+        }}",
+            self.capacity,
+            self.len(),
+            self.hits(),
+            self.misses()
+        )
+    }
+}
+
+impl Display for FovCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}, {}, {})",
+            self.capacity,
+            self.len(),
+            self.hits(),
+            self.misses()
+        )
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const ALGORITHM: FovAlgorithm = FovAlgorithm::Shadowcast;
+    const SHAPE: FovShape = FovShape::Circle;
+
+    #[test]
+    fn test_lookup_counts_hits_and_misses() {
+        let mut cache = FovCache::default();
+
+        assert!(cache.get(&(1, 1), 8, 42, ALGORITHM, SHAPE).is_none());
+
+        cache.insert(&(1, 1), 8, 42, ALGORITHM, SHAPE, vec![(1, 1), (1, 2)]);
+
+        assert_eq!(
+            Some(&vec![(1, 1), (1, 2)]),
+            cache.get(&(1, 1), 8, 42, ALGORITHM, SHAPE)
+        );
+        assert!(cache.get(&(1, 1), 8, 43, ALGORITHM, SHAPE).is_none());
+        assert!(cache.get(&(1, 1), 4, 42, ALGORITHM, SHAPE).is_none());
+
+        assert_eq!(1, cache.hits());
+        assert_eq!(3, cache.misses());
+    }
+
+    #[test]
+    fn test_lookup_respects_algorithm_and_shape() {
+        let mut cache = FovCache::default();
+
+        cache.insert(&(1, 1), 8, 42, ALGORITHM, SHAPE, vec![(1, 1)]);
+
+        assert!(cache
+            .get(&(1, 1), 8, 42, FovAlgorithm::Bresenham, SHAPE)
+            .is_none());
+        assert!(cache
+            .get(&(1, 1), 8, 42, ALGORITHM, FovShape::Square)
+            .is_none());
+        assert!(cache.get(&(1, 1), 8, 42, ALGORITHM, SHAPE).is_some());
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let mut cache = FovCache::new(2);
+
+        cache.insert(&(0, 0), 8, 1, ALGORITHM, SHAPE, vec![(0, 0)]);
+        cache.insert(&(1, 1), 8, 1, ALGORITHM, SHAPE, vec![(1, 1)]);
+
+        // Touch the first entry, making the second one the least recently used.
+        assert!(cache.get(&(0, 0), 8, 1, ALGORITHM, SHAPE).is_some());
+
+        cache.insert(&(2, 2), 8, 1, ALGORITHM, SHAPE, vec![(2, 2)]);
+
+        assert_eq!(2, cache.len());
+        assert!(cache.get(&(0, 0), 8, 1, ALGORITHM, SHAPE).is_some());
+        assert!(cache.get(&(1, 1), 8, 1, ALGORITHM, SHAPE).is_none());
+        assert!(cache.get(&(2, 2), 8, 1, ALGORITHM, SHAPE).is_some());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cache = FovCache::default();
+
+        cache.insert(&(0, 0), 8, 1, ALGORITHM, SHAPE, vec![(0, 0)]);
+        cache.clear();
+
+        assert_eq!(0, cache.len());
+        assert!(cache.get(&(0, 0), 8, 1, ALGORITHM, SHAPE).is_none());
+    }
+}
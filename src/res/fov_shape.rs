@@ -0,0 +1,251 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+use crate::components::facing::Facing;
+use crate::core::position_2d::Position2d;
+
+/// [Resource] selecting the shape of the `field of view` calculated by
+/// [crate::core::algorithm::field_of_view], by determining the distance metric used to check whether a position
+/// lies within the radius.
+///
+/// The value is taken from the [crate::res::gameplay_config::GameplayConfig], e.g., `"Circle"` or
+/// `{ "Cone": { "angle": 90, "awareness": 2 } }`.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Resource)]
+pub enum FovShape {
+    /// Measures the euclidean distance, resulting in a round `field of view`.
+    #[default]
+    Circle,
+    /// Measures the chebyshev distance, resulting in a square `field of view`.
+    Square,
+    /// Measures the manhattan distance, resulting in a diamond shaped `field of view`.
+    Diamond,
+    /// Measures the euclidean distance like [FovShape::Circle], but restricts the `field of view` of `entities`
+    /// with a [Facing] to a cone in the direction they face, e.g., for stealth gameplay.
+    Cone {
+        /// The opening angle of the cone in degrees, centered on the [Facing] direction.
+        angle: u32,
+        /// The radius around the `entity` which is seen regardless of the [Facing].
+        awareness: i32,
+    },
+}
+
+impl FovShape {
+    /// Calculates the distance between the passed `start` and `end` [Position2d] in steps, using the distance metric
+    /// of the [FovShape].
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: The [Position2d] from which the distance should be calculated.
+    /// * `end`: The [Position2d] to which the distance should be calculated.
+    ///
+    /// returns: i32 - The distance between `start` and `end` [Position2d] in steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let start_position = (2, 2);
+    /// let end_position = (5, 6);
+    ///
+    /// assert_eq!(5, FovShape::Circle.distance(&start_position, &end_position));
+    /// assert_eq!(4, FovShape::Square.distance(&start_position, &end_position));
+    /// assert_eq!(7, FovShape::Diamond.distance(&start_position, &end_position));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn distance(&self, start: &impl Position2d, end: &impl Position2d) -> i32 {
+        let [x_delta, y_delta] = end.delta(start);
+
+        match self {
+            FovShape::Circle | FovShape::Cone { .. } => {
+                (((x_delta * x_delta) + (y_delta * y_delta)) as f64)
+                    .sqrt()
+                    .floor() as i32
+            }
+            FovShape::Square => start.chebyshev_distance(end),
+            FovShape::Diamond => x_delta.abs() + y_delta.abs(),
+        }
+    }
+
+    /// Checks if the passed `target` lies within the `field of view` of the [FovShape] with the passed `radius`
+    /// around the `origin`. For a [FovShape::Cone] and a `facing`, the `target` has to lie within the awareness
+    /// radius or the cone in the `facing` direction as well. Without a `facing`, a cone covers the full circle.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The center [Position2d] of the `field of view`.
+    /// * `target`: The [Position2d] to check.
+    /// * `radius`: The radius of the `field of view`, which the distance has to stay below.
+    /// * `facing`: The [Facing] of the `entity` the `field of view` belongs to, if any.
+    ///
+    /// returns: bool - `true` if the `target` lies within the shape and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cone = FovShape::Cone { angle: 90, awareness: 1 };
+    ///
+    /// assert!(cone.contains(&(5, 5), &(8, 6), 8, Some(Facing::East)));
+    /// assert!(!cone.contains(&(5, 5), &(2, 5), 8, Some(Facing::East)));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn contains(
+        &self,
+        origin: &impl Position2d,
+        target: &impl Position2d,
+        radius: i32,
+        facing: Option<Facing>,
+    ) -> bool {
+        let distance = self.distance(origin, target);
+
+        if distance >= radius {
+            return false;
+        }
+
+        let (FovShape::Cone { angle, awareness }, Some(facing)) = (self, facing) else {
+            return true;
+        };
+
+        if distance <= (*awareness).max(0) {
+            return true;
+        }
+
+        let [facing_x, facing_y] = facing.delta();
+        let [x_delta, y_delta] = target.delta(origin);
+        let cosine = (facing_x * x_delta + facing_y * y_delta) as f64
+            / (((facing_x * facing_x + facing_y * facing_y)
+                * (x_delta * x_delta + y_delta * y_delta)) as f64)
+                .sqrt();
+
+        cosine.clamp(-1.0, 1.0).acos().to_degrees() <= *angle as f64 / 2.0
+    }
+
+    /// Checks if the [FovShape] depends on the [Facing] of an `entity`.
+    ///
+    /// returns: bool - `true` for a [FovShape::Cone] and `false` otherwise.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_directional(&self) -> bool {
+        matches!(self, FovShape::Cone { .. })
+    }
+}
+
+impl Debug for FovShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Resources -> FovShape::{}", self)
+    }
+}
+
+impl Display for FovShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FovShape::Circle => write!(f, "Circle"),
+            FovShape::Square => write!(f, "Square"),
+            FovShape::Diamond => write!(f, "Diamond"),
+            FovShape::Cone { angle, awareness } => write!(f, "Cone({}°, {})", angle, awareness),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let start_position = (2, 2);
+        let end_position = (5, 6);
+
+        assert_eq!(5, FovShape::Circle.distance(&start_position, &end_position));
+        assert_eq!(4, FovShape::Square.distance(&start_position, &end_position));
+        assert_eq!(
+            7,
+            FovShape::Diamond.distance(&start_position, &end_position)
+        );
+    }
+
+    #[test]
+    fn test_cone_contains() {
+        let cone = FovShape::Cone {
+            angle: 90,
+            awareness: 1,
+        };
+        let origin = (5, 5);
+
+        for target in [(9, 5), (8, 7), (7, 4), (6, 5)] {
+            assert!(cone.contains(&origin, &target, 8, Some(Facing::East)));
+        }
+
+        // Behind, beside and beyond the radius.
+        for target in [(1, 5), (3, 6), (5, 9), (5, 1), (14, 5)] {
+            assert!(!cone.contains(&origin, &target, 8, Some(Facing::East)));
+        }
+
+        // Within the awareness radius, even behind.
+        assert!(cone.contains(&origin, &(4, 5), 8, Some(Facing::East)));
+        assert!(cone.contains(&origin, &origin, 8, Some(Facing::East)));
+        assert!(cone.contains(&origin, &(1, 5), 8, None));
+        assert!(FovShape::Circle.contains(&origin, &(1, 5), 8, Some(Facing::East)));
+    }
+
+    #[test]
+    fn test_deserialization() {
+        assert_eq!(
+            FovShape::Diamond,
+            serde_json::from_str::<FovShape>("\"Diamond\"").unwrap()
+        );
+        assert_eq!(
+            FovShape::Cone {
+                angle: 90,
+                awareness: 2
+            },
+            serde_json::from_str::<FovShape>(r#"{ "Cone": { "angle": 90, "awareness": 2 } }"#)
+                .unwrap()
+        );
+    }
+}
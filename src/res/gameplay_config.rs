@@ -0,0 +1,559 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::cmp::max;
+use std::fmt::{Display, Formatter};
+
+use bevy::log::warn;
+use bevy::prelude::Resource;
+use serde::{Deserialize, Deserializer};
+
+use crate::core::constants;
+use crate::res::config_file::ConfigFile;
+use crate::res::fov_algorithm::FovAlgorithm;
+use crate::res::fov_shape::FovShape;
+use crate::ui::tile_map_layout_generator::TileMapGeneratorRegistry;
+
+/// A [Resource] containing the tunable gameplay rules of the game, e.g., how difficult the
+/// spawned monsters are.
+///
+/// It is usually not instantiated directly, but deserialized from the `gameplay.json` configuration
+/// file shipped with the game. This allows the user to tweak the game to their liking without
+/// recompiling it. Properties missing from the file fall back to their [Default] values.
+///
+/// # Properties
+///
+/// * `difficulty`: Multiplier scaling the stats and spawn counts of monsters. The effective value
+///   is clamped between [constants::MIN_DIFFICULTY] and [constants::MAX_DIFFICULTY].
+/// * `spawn_strategy`: The [SpawnStrategy] determining where the `player entity` is placed on the map.
+/// * `auto_pickup`: Whether items are picked up automatically when the `player entity` steps onto them,
+///   instead of requiring the explicit pickup action.
+/// * `animation_frames`: The number of frames an animation, e.g., the flash of a hit, is played for.
+/// * `alert_radius`: The radius within which a monster spotting the `player` alerts other monsters.
+///   A radius of `0` disables the propagation.
+/// * `fov_algorithm`: The [FovAlgorithm] used to calculate the `field of view` of the `entities`.
+/// * `ai_budget`: The maximum number of monsters whose AI is processed per frame, the remaining ones act in
+///   the following frames of the same turn. A budget of `0` processes all monsters at once.
+/// * `monster_spacing`: The minimum distance, measured as the chebyshev distance, between two spawned monsters.
+///   A spacing of `0` or `1` allows monsters to spawn right next to each other.
+/// * `fov_shape`: The [FovShape] determining the distance metric, and thereby the shape, of the `field of view`.
+/// * `tile_memory`: Whether tiles seen before stay on screen, dimmed, once they leave the `field of view`. When disabled,
+///   only the tiles currently in the `field of view` are rendered.
+/// * `sight_memory_fade`: If remembered tiles gradually darken the longer they are out of the `player's` sight. Only
+///   takes effect with `tile_memory` enabled.
+/// * `reveal_rooms`: If stepping into a room immediately marks all of its tiles, including the bordering walls, as seen.
+/// * `leash_radius`: The maximum chebyshev distance a monster, which isn't chasing the `player`, may wander off from its
+///   spawn position before returning home. A radius of `0` disables the leash.
+/// * `turn_delay_ms`: The minimum number of milliseconds between two processed turns of the `player`, slowing down
+///   held or rapid input. A delay of `0` processes every turn right away.
+/// * `decoration_density`: The share of the free floor of large rooms, between `0.0` and `1.0`, which is decorated
+///   with pillars and rubble. A density of `0.0` disables the decorations.
+/// * `wall_bump_wastes_turn`: If bumping into a wall passes the turn to the `NPC entities`, like a successful move.
+///   Otherwise, the impossible move is ignored and it stays the `player's` turn.
+/// * `show_damage_numbers`: Whether the damage of an attack is shown as a floating number above its target.
+/// * `damage_number_frames`: The number of frames a floating damage number is shown for.
+/// * `generator`: The name of the generator creating the dungeon layout, i.e., `rooms`, `cave`, `bsp` or
+///   `maze`. Unknown names fall back to `rooms`, see [TileMapGeneratorRegistry].
+/// * `seed`: The seed to generate the dungeon layout from, e.g., to replay a shared map. Without a seed, a random
+///   one is picked.
+/// * `player_fov_radius`: Radius of the player's `field of view`.
+/// * `monster_fov_radius`: Radius of the `field of view` of every monster, overriding their stat table, if set.
+/// * `item_count`: The number of items scattered across the rooms the player doesn't start in.
+/// * `map_size`: The size of every floor in tiles. Floors larger than the map panel scroll along with the `player`.
+///   Without a size, floors fill the map panel of the terminal. Each dimension is clamped to at least
+///   [constants::MAP_MIN_SIZE] when the config is loaded.
+///
+/// # Examples
+///
+/// ```
+/// Json config file content:
+///
+/// {
+///   "difficulty": 1.5,
+///   "spawn_strategy": "FirstRoom",
+///   "auto_pickup": false,
+///   "animation_frames": 6,
+///   "alert_radius": 6,
+///   "fov_algorithm": "Bresenham",
+///   "ai_budget": 32,
+///   "monster_spacing": 2,
+///   "fov_shape": "Circle",
+///   "tile_memory": true,
+///   "sight_memory_fade": true,
+///   "reveal_rooms": false,
+///   "leash_radius": 12,
+///   "turn_delay_ms": 0,
+///   "decoration_density": 0.0,
+///   "wall_bump_wastes_turn": false,
+///   "show_damage_numbers": true,
+///   "damage_number_frames": 8,
+///   "generator": "rooms",
+///   "seed": null,
+///   "player_fov_radius": 8,
+///   "monster_fov_radius": null,
+///   "item_count": 3,
+///   "map_size": [160, 90],
+///   "autosave": true
+/// }
+///
+/// ...
+///
+/// let gameplay_config = GameplayConfig::load();
+///
+/// assert_eq!(24, gameplay_config.scale_by_difficulty(16));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [ConfigFile]
+///
+#[derive(Debug, Clone, Deserialize, Resource)]
+#[serde(default)]
+pub struct GameplayConfig {
+    /// Multiplier scaling the stats and spawn counts of monsters. The effective value
+    /// is clamped between [constants::MIN_DIFFICULTY] and [constants::MAX_DIFFICULTY].
+    pub difficulty: f32,
+    /// The [SpawnStrategy] determining where the `player entity` is placed on the map.
+    pub spawn_strategy: SpawnStrategy,
+    /// Whether items are picked up automatically when the `player entity` steps onto them,
+    /// instead of requiring the explicit pickup action.
+    pub auto_pickup: bool,
+    /// The number of frames an animation, e.g., the flash of a hit, is played for.
+    pub animation_frames: u32,
+    /// The radius within which a monster spotting the `player` alerts other monsters.
+    /// A radius of `0` disables the propagation.
+    pub alert_radius: i32,
+    /// The [FovAlgorithm] used to calculate the `field of view` of the `entities`.
+    pub fov_algorithm: FovAlgorithm,
+    /// The maximum number of monsters whose AI is processed per frame, the remaining ones act in
+    /// the following frames of the same turn. A budget of `0` processes all monsters at once.
+    pub ai_budget: usize,
+    /// The minimum distance, measured as the chebyshev distance, between two spawned monsters.
+    /// A spacing of `0` or `1` allows monsters to spawn right next to each other.
+    pub monster_spacing: i32,
+    /// The [FovShape] determining the distance metric, and thereby the shape, of the `field of view`.
+    pub fov_shape: FovShape,
+    /// Whether tiles seen before stay on screen, dimmed, once they leave the `field of view`. When disabled,
+    /// only the tiles currently in the `field of view` are rendered.
+    pub tile_memory: bool,
+    /// If remembered tiles gradually darken the longer they are out of the `player's` sight. Only
+    /// takes effect with `tile_memory` enabled.
+    pub sight_memory_fade: bool,
+    /// If stepping into a room immediately marks all of its tiles, including the bordering walls, as seen.
+    pub reveal_rooms: bool,
+    /// The maximum chebyshev distance a monster, which isn't chasing the `player`, may wander off from its
+    /// spawn position before returning home. A radius of `0` disables the leash.
+    pub leash_radius: i32,
+    /// The minimum number of milliseconds between two processed turns of the `player`, slowing down held or rapid
+    /// input. A delay of `0` processes every turn right away.
+    pub turn_delay_ms: u64,
+    /// The share of the free floor of large rooms, between `0.0` and `1.0`, which is decorated with pillars and
+    /// rubble. A density of `0.0` disables the decorations.
+    pub decoration_density: f32,
+    /// If bumping into a wall passes the turn to the `NPC entities`, like a successful move. Otherwise, the
+    /// impossible move is ignored and it stays the `player's` turn.
+    pub wall_bump_wastes_turn: bool,
+    /// Whether the damage of an attack is shown as a floating number above its target.
+    pub show_damage_numbers: bool,
+    /// The number of frames a floating damage number is shown for.
+    pub damage_number_frames: u32,
+    /// The name of the generator creating the dungeon layout, i.e., `rooms`, `cave`, `bsp` or `maze`. Unknown
+    /// names fall back to `rooms`, see [TileMapGeneratorRegistry].
+    pub generator: String,
+    /// The seed to generate the dungeon layout from, e.g., to replay a shared map. Without a seed, a random one is
+    /// picked.
+    pub seed: Option<u64>,
+    /// Radius of the player's `field of view`.
+    pub player_fov_radius: i32,
+    /// Radius of the `field of view` of every monster, overriding their stat table, if set.
+    pub monster_fov_radius: Option<i32>,
+    /// The number of items scattered across the rooms the player doesn't start in.
+    pub item_count: usize,
+    /// The size of every floor in tiles. Floors larger than the map panel scroll along with the `player`. Without a
+    /// size, floors fill the map panel of the terminal. Each dimension is at least [constants::MAP_MIN_SIZE].
+    #[serde(deserialize_with = "deserialize_map_size")]
+    pub map_size: Option<[i32; 2]>,
+    /// Whether the run is saved whenever the `player` reaches a new floor, so it can be continued from the title
+    /// screen, see [crate::core::save_game::SaveGame].
+    pub autosave: bool,
+}
+
+/// Determines where the `player entity` is placed when a new map is entered.
+///
+/// # Examples
+///
+/// ```
+/// let gameplay_config = GameplayConfig {
+///     spawn_strategy: SpawnStrategy::Center,
+///     ..Default::default()
+/// };
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [GameplayConfig]
+///
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Deserialize)]
+pub enum SpawnStrategy {
+    /// Spawns the player in the center of the first room of the map, or on the walkable tile closest to the center
+    /// of the map, if it has no rooms, see [crate::ui::game_map::GameMap::starting_position].
+    #[default]
+    FirstRoom,
+    /// Spawns the player on the walkable tile closest to the center of the map.
+    Center,
+    /// Spawns the player on a random walkable tile of the map.
+    RandomWalkable,
+}
+
+impl GameplayConfig {
+    /// Returns the configured `difficulty` clamped to the range supported by the game.
+    ///
+    /// # Arguments
+    ///
+    /// returns: f32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gameplay_config = GameplayConfig {
+    ///     difficulty: 100.0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(constants::MAX_DIFFICULTY, gameplay_config.difficulty_multiplier());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn difficulty_multiplier(&self) -> f32 {
+        if self.difficulty.is_nan() {
+            return 1.0;
+        }
+
+        self.difficulty
+            .clamp(constants::MIN_DIFFICULTY, constants::MAX_DIFFICULTY)
+    }
+
+    /// Scales the passed `value` by the [GameplayConfig::difficulty_multiplier], rounding to the nearest
+    /// whole number. The result is never smaller than `1`, so a scaled stat or count never vanishes.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The base value to scale, e.g., the maximum hit points of a monster.
+    ///
+    /// returns: i32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gameplay_config = GameplayConfig {
+    ///     difficulty: 2.0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(32, gameplay_config.scale_by_difficulty(16));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn scale_by_difficulty(&self, value: i32) -> i32 {
+        max(
+            1,
+            (value as f32 * self.difficulty_multiplier()).round() as i32,
+        )
+    }
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        Self {
+            difficulty: 1.0,
+            spawn_strategy: SpawnStrategy::default(),
+            auto_pickup: false,
+            animation_frames: 6,
+            alert_radius: 6,
+            fov_algorithm: FovAlgorithm::default(),
+            ai_budget: 32,
+            monster_spacing: 2,
+            fov_shape: FovShape::default(),
+            tile_memory: true,
+            sight_memory_fade: true,
+            reveal_rooms: false,
+            leash_radius: 12,
+            turn_delay_ms: 0,
+            decoration_density: 0.0,
+            wall_bump_wastes_turn: false,
+            show_damage_numbers: true,
+            damage_number_frames: 8,
+            generator: String::from(TileMapGeneratorRegistry::DEFAULT_GENERATOR),
+            seed: None,
+            player_fov_radius: 8,
+            monster_fov_radius: None,
+            item_count: 3,
+            map_size: None,
+            autosave: true,
+        }
+    }
+}
+
+impl Display for GameplayConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {:?}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {:?}, {}, \
+            {:?}, {}, {:?}, {})",
+            self.difficulty,
+            self.spawn_strategy,
+            self.auto_pickup,
+            self.animation_frames,
+            self.alert_radius,
+            self.fov_algorithm,
+            self.ai_budget,
+            self.monster_spacing,
+            self.fov_shape,
+            self.tile_memory,
+            self.sight_memory_fade,
+            self.reveal_rooms,
+            self.leash_radius,
+            self.turn_delay_ms,
+            self.decoration_density,
+            self.wall_bump_wastes_turn,
+            self.show_damage_numbers,
+            self.damage_number_frames,
+            self.generator,
+            self.seed,
+            self.player_fov_radius,
+            self.monster_fov_radius,
+            self.item_count,
+            self.map_size,
+            self.autosave
+        )
+    }
+}
+
+impl Display for SpawnStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ConfigFile for GameplayConfig {
+    fn file_name() -> String {
+        String::from("gameplay.json")
+    }
+}
+
+/// Internal function to deserialize the optional `map_size`, clamping each dimension to at least
+/// [constants::MAP_MIN_SIZE], so an empty or negative size can't break the map generation. A warning is logged if
+/// the size had to be clamped.
+///
+/// # Arguments
+///
+/// * `deserializer`: The [Deserializer] providing the size.
+///
+/// returns: [Result]<[Option]<[i32; 2]>, D::Error>
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn deserialize_map_size<'de, D>(deserializer: D) -> Result<Option<[i32; 2]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(map_size) = Option::<[i32; 2]>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let clamped_size = map_size.map(|dimension| max(dimension, constants::MAP_MIN_SIZE));
+
+    if clamped_size != map_size {
+        warn!(
+            "Configured map size {:?} is too small, clamping it to {:?}.",
+            map_size, clamped_size
+        );
+    }
+
+    Ok(Some(clamped_size))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_difficulty_clamping() {
+        assert_eq!(
+            constants::MIN_DIFFICULTY,
+            GameplayConfig {
+                difficulty: -3.0,
+                ..Default::default()
+            }
+            .difficulty_multiplier()
+        );
+        assert_eq!(
+            constants::MAX_DIFFICULTY,
+            GameplayConfig {
+                difficulty: 50.0,
+                ..Default::default()
+            }
+            .difficulty_multiplier()
+        );
+        assert_eq!(
+            1.5,
+            GameplayConfig {
+                difficulty: 1.5,
+                ..Default::default()
+            }
+            .difficulty_multiplier()
+        );
+        assert_eq!(
+            1.0,
+            GameplayConfig {
+                difficulty: f32::NAN,
+                ..Default::default()
+            }
+            .difficulty_multiplier()
+        );
+    }
+
+    #[test]
+    fn test_scaling_by_difficulty() {
+        assert_eq!(16, GameplayConfig::default().scale_by_difficulty(16));
+        assert_eq!(
+            32,
+            GameplayConfig {
+                difficulty: 2.0,
+                ..Default::default()
+            }
+            .scale_by_difficulty(16)
+        );
+        assert_eq!(
+            1,
+            GameplayConfig {
+                difficulty: 0.25,
+                ..Default::default()
+            }
+            .scale_by_difficulty(1)
+        );
+    }
+
+    #[test]
+    fn test_deserialization() {
+        let gameplay_config: GameplayConfig =
+            serde_json::from_str(
+                "{ \"difficulty\": 2.5, \"spawn_strategy\": \"RandomWalkable\", \"auto_pickup\": true }",
+            )
+            .unwrap();
+
+        assert_eq!(2.5, gameplay_config.difficulty);
+        assert_eq!(
+            SpawnStrategy::RandomWalkable,
+            gameplay_config.spawn_strategy
+        );
+        assert!(gameplay_config.auto_pickup);
+
+        let defaulted_config: GameplayConfig = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(1.0, defaulted_config.difficulty);
+        assert_eq!(SpawnStrategy::FirstRoom, defaulted_config.spawn_strategy);
+        assert!(!defaulted_config.auto_pickup);
+        assert_eq!(6, defaulted_config.animation_frames);
+        assert_eq!(6, defaulted_config.alert_radius);
+        assert_eq!(FovAlgorithm::Bresenham, defaulted_config.fov_algorithm);
+        assert_eq!(32, defaulted_config.ai_budget);
+        assert_eq!(2, defaulted_config.monster_spacing);
+        assert_eq!(FovShape::Circle, defaulted_config.fov_shape);
+        assert!(defaulted_config.tile_memory);
+        assert!(defaulted_config.sight_memory_fade);
+        assert!(!defaulted_config.reveal_rooms);
+        assert_eq!(12, defaulted_config.leash_radius);
+        assert_eq!(0, defaulted_config.turn_delay_ms);
+        assert_eq!(0.0, defaulted_config.decoration_density);
+        assert!(!defaulted_config.wall_bump_wastes_turn);
+        assert!(defaulted_config.show_damage_numbers);
+        assert_eq!(8, defaulted_config.damage_number_frames);
+        assert_eq!("rooms", defaulted_config.generator);
+        assert_eq!(None, defaulted_config.seed);
+        assert_eq!(8, defaulted_config.player_fov_radius);
+        assert_eq!(None, defaulted_config.monster_fov_radius);
+        assert_eq!(3, defaulted_config.item_count);
+        assert_eq!(None, defaulted_config.map_size);
+        assert!(defaulted_config.autosave);
+    }
+
+    #[test]
+    fn test_fov_radius_deserialization() {
+        let gameplay_config: GameplayConfig =
+            serde_json::from_str("{ \"player_fov_radius\": 12, \"monster_fov_radius\": 5 }")
+                .unwrap();
+
+        assert_eq!(12, gameplay_config.player_fov_radius);
+        assert_eq!(Some(5), gameplay_config.monster_fov_radius);
+    }
+
+    #[test]
+    fn test_map_size_deserialization_clamps_small_sizes() {
+        let parse = |json: &str| {
+            serde_json::from_str::<GameplayConfig>(json)
+                .unwrap()
+                .map_size
+        };
+
+        assert_eq!(Some([160, 90]), parse("{ \"map_size\": [160, 90] }"));
+        assert_eq!(
+            Some([constants::MAP_MIN_SIZE, constants::MAP_MIN_SIZE]),
+            parse("{ \"map_size\": [0, 0] }")
+        );
+        assert_eq!(
+            Some([constants::MAP_MIN_SIZE, 40]),
+            parse("{ \"map_size\": [-20, 40] }")
+        );
+        assert_eq!(None, parse("{ \"map_size\": null }"));
+    }
+
+    #[test]
+    fn test_config_file_name() {
+        assert_eq!("gameplay.json", GameplayConfig::file_name());
+    }
+}
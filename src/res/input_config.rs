@@ -21,8 +21,9 @@
 
 use std::fmt::{Display, Formatter};
 
+use bevy::input::gamepad::GamepadButtonType;
 use bevy::prelude::{KeyCode, Resource};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::res::config_file::ConfigFile;
 
@@ -48,7 +49,29 @@ use crate::res::config_file::ConfigFile;
 /// moving the player down, moving the cursor down, moving a selection down.
 /// * `right`: A rightwards directed movement, e.g.,
 /// moving the player right, moving the cursor to the right, moving a selection to the right.
+/// * `up_left`: An up- and leftwards directed diagonal movement, e.g., moving the player up and to the left.
+/// * `up_right`: An up- and rightwards directed diagonal movement, e.g., moving the player up and to the right.
+/// * `down_left`: A down- and leftwards directed diagonal movement, e.g., moving the player down and to the left.
+/// * `down_right`: A down- and rightwards directed diagonal movement, e.g., moving the player down and to the right.
 /// * `cancel`: Cancelling a given action, e.g. closing a dialog, cancelling a choice, etc.
+/// * `confirm`: Confirming a choice, e.g. selecting a menu option.
+/// * `wait`: Waiting in place, passing the turn to the NPCs without moving the player.
+/// * `drop`: Dropping the selected item of the player's inventory onto the floor.
+/// * `examine`: Toggling the examine mode, which inspects the currently visible entities.
+/// * `pickup`: Picking up the item lying on the player's cell.
+/// * `use_item`: Using the selected item of the player's inventory, consuming it.
+/// * `log_view`: Toggling the log view, which scrolls through the full message history.
+/// * `cursor_hud`: Toggling the cursor HUD, which describes the map tile under the mouse cursor.
+/// * `spyglass`: Using the spyglass, which temporarily extends the player's `field of view`.
+/// * `interact`: Interacting with the player's cell, e.g., descending the stairs.
+/// * `target`: Entering the targeting mode, in which the player aims at a tile.
+/// * `toggle_fullscreen`: Toggling the game's window between windowed and borderless fullscreen mode.
+/// * `debug_footer`: Toggling the debug footer, only handled with the `debug_tools` feature enabled.
+/// * `toggle_fov`: Toggling the [crate::res::fov_algorithm::FovAlgorithm], only handled with the
+///   `debug_tools` feature enabled.
+/// * `gamepad`: The [GamepadBindings] translating the buttons of a gamepad.
+///
+/// Bindings missing from the config file fall back to their [Default] values.
 ///
 /// # Examples
 ///
@@ -60,7 +83,33 @@ use crate::res::config_file::ConfigFile;
 ///   "left": "A",
 ///   "down": "S",
 ///   "right": "D",
-///   "cancel": "Escape"
+///   "up_left": "Numpad7",
+///   "up_right": "Numpad9",
+///   "down_left": "Numpad1",
+///   "down_right": "Numpad3",
+///   "cancel": "Escape",
+///   "confirm": "Return",
+///   "wait": "Period",
+///   "drop": "R",
+///   "examine": "L",
+///   "pickup": "G",
+///   "use_item": "U",
+///   "log_view": "M",
+///   "cursor_hud": "H",
+///   "spyglass": "Z",
+///   "interact": "E",
+///   "target": "F",
+///   "toggle_fullscreen": "F11",
+///   "debug_footer": "F2",
+///   "toggle_fov": "F1",
+///   "gamepad": {
+///     "up": "DPadUp",
+///     "left": "DPadLeft",
+///     "down": "DPadDown",
+///     "right": "DPadRight",
+///     "cancel": "Start",
+///     "wait": "West"
+///   }
 /// }
 ///
 /// ...
@@ -81,7 +130,8 @@ use crate::res::config_file::ConfigFile;
 /// * [KeyCode]
 /// * [InputType]
 ///
-#[derive(Debug, Copy, Clone, Deserialize, Resource)]
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize, Resource)]
+#[serde(default)]
 pub struct InputConfig {
     /// An upwards directed movement, e.g.,
     /// moving the player up, moving the cursor up, moving a selection up.
@@ -95,8 +145,89 @@ pub struct InputConfig {
     /// A rightwards directed movement, e.g.,
     /// moving the player right, moving the cursor to the right, moving a selection to the right.
     pub right: KeyCode,
+    /// An up- and leftwards directed diagonal movement, e.g., moving the player up and to the left.
+    pub up_left: KeyCode,
+    /// An up- and rightwards directed diagonal movement, e.g., moving the player up and to the right.
+    pub up_right: KeyCode,
+    /// A down- and leftwards directed diagonal movement, e.g., moving the player down and to the left.
+    pub down_left: KeyCode,
+    /// A down- and rightwards directed diagonal movement, e.g., moving the player down and to the right.
+    pub down_right: KeyCode,
     /// Cancelling a given action, e.g. closing a dialog, cancelling a choice, etc.
     pub cancel: KeyCode,
+    /// Confirming a choice, e.g. selecting a menu option.
+    pub confirm: KeyCode,
+    /// Waiting in place, passing the turn to the NPCs without moving the player.
+    pub wait: KeyCode,
+    /// Dropping the selected item of the player's inventory onto the floor.
+    pub drop: KeyCode,
+    /// Toggling the examine mode, which inspects the currently visible entities.
+    pub examine: KeyCode,
+    /// Picking up the item lying on the player's cell.
+    pub pickup: KeyCode,
+    /// Using the selected item of the player's inventory, consuming it.
+    pub use_item: KeyCode,
+    /// Toggling the log view, which scrolls through the full message history.
+    pub log_view: KeyCode,
+    /// Toggling the cursor HUD, which describes the map tile under the mouse cursor.
+    pub cursor_hud: KeyCode,
+    /// Using the spyglass, which temporarily extends the player's `field of view`.
+    pub spyglass: KeyCode,
+    /// Interacting with the player's cell, e.g., descending the stairs.
+    pub interact: KeyCode,
+    /// Entering the targeting mode, in which the player aims at a tile.
+    pub target: KeyCode,
+    /// Toggling the game's window between windowed and borderless fullscreen mode.
+    pub toggle_fullscreen: KeyCode,
+    /// Toggling the debug footer, only handled with the `debug_tools` feature enabled.
+    pub debug_footer: KeyCode,
+    /// Toggling the [crate::res::fov_algorithm::FovAlgorithm], only handled with the `debug_tools` feature enabled.
+    pub toggle_fov: KeyCode,
+    /// The [GamepadBindings] translating the buttons of a gamepad.
+    pub gamepad: GamepadBindings,
+}
+
+/// The gamepad counterpart of the [InputConfig], translating [GamepadButtonType]s into the same [InputType]s as
+/// their keyboard bindings. Only moving, waiting and cancelling can be done with a gamepad, as the D-Pad has no
+/// diagonal buttons and the remaining actions are still bound to the keyboard only.
+///
+/// # Properties
+///
+/// * `up`: An upwards directed movement, e.g., moving the player up.
+/// * `left`: A leftwards directed movement, e.g., moving the player left.
+/// * `down`: A downwards directed movement, e.g., moving the player down.
+/// * `right`: A rightwards directed movement, e.g., moving the player right.
+/// * `cancel`: Cancelling a given action, e.g. closing a dialog, cancelling a choice, etc.
+/// * `wait`: Waiting in place, passing the turn to the NPCs without moving the player.
+///
+/// Bindings missing from the config file fall back to their [Default] values.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [GamepadButtonType]
+/// * [InputType]
+///
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GamepadBindings {
+    /// An upwards directed movement, e.g., moving the player up.
+    pub up: GamepadButtonType,
+    /// A leftwards directed movement, e.g., moving the player left.
+    pub left: GamepadButtonType,
+    /// A downwards directed movement, e.g., moving the player down.
+    pub down: GamepadButtonType,
+    /// A rightwards directed movement, e.g., moving the player right.
+    pub right: GamepadButtonType,
+    /// Cancelling a given action, e.g. closing a dialog, cancelling a choice, etc.
+    pub cancel: GamepadButtonType,
+    /// Waiting in place, passing the turn to the NPCs without moving the player.
+    pub wait: GamepadButtonType,
 }
 
 /// Serves as an abstraction layer between the raw user input in form of periphery events,
@@ -152,8 +283,44 @@ pub enum InputType {
     /// A rightwards directed movement, e.g., moving the player right, moving the cursor to
     /// the right, moving a selection to the right.
     Right,
+    /// An up- and leftwards directed diagonal movement, e.g., moving the player up and to the left.
+    UpLeft,
+    /// An up- and rightwards directed diagonal movement, e.g., moving the player up and to the right.
+    UpRight,
+    /// A down- and leftwards directed diagonal movement, e.g., moving the player down and to the left.
+    DownLeft,
+    /// A down- and rightwards directed diagonal movement, e.g., moving the player down and to the right.
+    DownRight,
     /// Cancelling a given action, e.g. closing a dialog, cancelling a choice, etc.
     Cancel,
+    /// Confirming a choice, e.g. selecting a menu option.
+    Confirm,
+    /// Waiting in place, passing the turn to the NPCs without moving the player.
+    Wait,
+    /// Dropping the selected item of the player's inventory onto the floor.
+    Drop,
+    /// Toggling the examine mode, which inspects the currently visible entities.
+    Examine,
+    /// Picking up the item lying on the player's cell.
+    Pickup,
+    /// Using the selected item of the player's inventory, consuming it.
+    UseItem,
+    /// Toggling the log view, which scrolls through the full message history.
+    LogView,
+    /// Toggling the cursor HUD, which describes the map tile under the mouse cursor.
+    CursorHud,
+    /// Using the spyglass, which temporarily extends the player's `field of view`.
+    Spyglass,
+    /// Interacting with the player's cell, e.g., descending the stairs.
+    Interact,
+    /// Entering the targeting mode, in which the player aims at a tile.
+    Target,
+    /// Toggling the game's window between windowed and borderless fullscreen mode.
+    ToggleFullscreen,
+    /// Toggling the debug footer, only handled with the `debug_tools` feature enabled.
+    DebugFooter,
+    /// Toggling the [crate::res::fov_algorithm::FovAlgorithm], only handled with the `debug_tools` feature enabled.
+    ToggleFov,
 }
 
 impl InputConfig {
@@ -174,14 +341,51 @@ impl InputConfig {
     ///     left: KeyCode::A,
     ///     down: KeyCode:S,
     ///     right: KeyCode::D,
+    ///     up_left: KeyCode::Numpad7,
+    ///     up_right: KeyCode::Numpad9,
+    ///     down_left: KeyCode::Numpad1,
+    ///     down_right: KeyCode::Numpad3,
     ///     cancel: KeyCode::Escape,
+    ///     confirm: KeyCode::Return,
+    ///     wait: KeyCode::Period,
+    ///     drop: KeyCode::R,
+    ///     examine: KeyCode::L,
+    ///     pickup: KeyCode::G,
+    ///     use_item: KeyCode::U,
+    ///     log_view: KeyCode::M,
+    ///     cursor_hud: KeyCode::H,
+    ///     spyglass: KeyCode::Z,
+    ///     interact: KeyCode::E,
+    ///     target: KeyCode::F,
+    ///     toggle_fullscreen: KeyCode::F11,
+    ///     debug_footer: KeyCode::F2,
+    ///     toggle_fov: KeyCode::F1,
+    ///     gamepad: GamepadBindings::default(),
     /// };
     ///
     /// assert_eq!(InputType::UP, input_config.parse_input(KeyCode::W).unwrap()); // true
     /// assert_eq!(InputType::Left, input_config.parse_input(KeyCode::A).unwrap()); // true
     /// assert_eq!(InputType::Down, input_config.parse_input(KeyCode::S).unwrap()); // true
     /// assert_eq!(InputType::Right, input_config.parse_input(KeyCode::D).unwrap()); // true
+    /// assert_eq!(InputType::UpLeft, input_config.parse_input(KeyCode::Numpad7).unwrap()); // true
+    /// assert_eq!(InputType::UpRight, input_config.parse_input(KeyCode::Numpad9).unwrap()); // true
+    /// assert_eq!(InputType::DownLeft, input_config.parse_input(KeyCode::Numpad1).unwrap()); // true
+    /// assert_eq!(InputType::DownRight, input_config.parse_input(KeyCode::Numpad3).unwrap()); // true
     /// assert_eq!(InputType::Cancel, input_config.parse_input(KeyCode::Escape).unwrap()); // true
+    /// assert_eq!(InputType::Confirm, input_config.parse_input(KeyCode::Return).unwrap()); // true
+    /// assert_eq!(InputType::Wait, input_config.parse_input(KeyCode::Period).unwrap()); // true
+    /// assert_eq!(InputType::Drop, input_config.parse_input(KeyCode::R).unwrap()); // true
+    /// assert_eq!(InputType::Examine, input_config.parse_input(KeyCode::L).unwrap()); // true
+    /// assert_eq!(InputType::Pickup, input_config.parse_input(KeyCode::G).unwrap()); // true
+    /// assert_eq!(InputType::UseItem, input_config.parse_input(KeyCode::U).unwrap()); // true
+    /// assert_eq!(InputType::LogView, input_config.parse_input(KeyCode::M).unwrap()); // true
+    /// assert_eq!(InputType::CursorHud, input_config.parse_input(KeyCode::H).unwrap()); // true
+    /// assert_eq!(InputType::Spyglass, input_config.parse_input(KeyCode::Z).unwrap()); // true
+    /// assert_eq!(InputType::Interact, input_config.parse_input(KeyCode::E).unwrap()); // true
+    /// assert_eq!(InputType::Target, input_config.parse_input(KeyCode::F).unwrap()); // true
+    /// assert_eq!(InputType::ToggleFullscreen, input_config.parse_input(KeyCode::F11).unwrap()); // true
+    /// assert_eq!(InputType::DebugFooter, input_config.parse_input(KeyCode::F2).unwrap()); // true
+    /// assert_eq!(InputType::ToggleFov, input_config.parse_input(KeyCode::F1).unwrap()); // true
     /// assert_eq!(true, input_config.parse_input(KeyCode::F).is_none()); // false
     /// ```
     ///
@@ -201,7 +405,156 @@ impl InputConfig {
             _ if self.left == key_code => Some(InputType::Left),
             _ if self.down == key_code => Some(InputType::Down),
             _ if self.right == key_code => Some(InputType::Right),
+            _ if self.up_left == key_code => Some(InputType::UpLeft),
+            _ if self.up_right == key_code => Some(InputType::UpRight),
+            _ if self.down_left == key_code => Some(InputType::DownLeft),
+            _ if self.down_right == key_code => Some(InputType::DownRight),
             _ if self.cancel == key_code => Some(InputType::Cancel),
+            _ if self.confirm == key_code => Some(InputType::Confirm),
+            _ if self.wait == key_code => Some(InputType::Wait),
+            _ if self.drop == key_code => Some(InputType::Drop),
+            _ if self.examine == key_code => Some(InputType::Examine),
+            _ if self.pickup == key_code => Some(InputType::Pickup),
+            _ if self.use_item == key_code => Some(InputType::UseItem),
+            _ if self.log_view == key_code => Some(InputType::LogView),
+            _ if self.cursor_hud == key_code => Some(InputType::CursorHud),
+            _ if self.spyglass == key_code => Some(InputType::Spyglass),
+            _ if self.interact == key_code => Some(InputType::Interact),
+            _ if self.target == key_code => Some(InputType::Target),
+            _ if self.toggle_fullscreen == key_code => Some(InputType::ToggleFullscreen),
+            _ if self.debug_footer == key_code => Some(InputType::DebugFooter),
+            _ if self.toggle_fov == key_code => Some(InputType::ToggleFov),
+            _ => None,
+        }
+    }
+
+    /// Returns the [KeyCode] the passed `input` is bound to, i.e., the reverse of [InputConfig::parse_input].
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The [InputType] whose binding is requested.
+    ///
+    /// returns: [KeyCode]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(KeyCode::W, InputConfig::default().binding(InputType::Up));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn binding(&self, input: InputType) -> KeyCode {
+        let mut input_config = *self;
+
+        *input_config.binding_mut(input)
+    }
+
+    /// Binds the passed `input` to the passed `key_code`. If the `key_code` is already bound to another
+    /// [InputType], that one takes over the previous binding of the `input`, so no [KeyCode] triggers two
+    /// [InputType]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The [InputType] to rebind.
+    /// * `key_code`: The new [KeyCode] of the `input`.
+    ///
+    /// returns: ()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut input_config = InputConfig::default();
+    ///
+    /// input_config.rebind(InputType::Up, KeyCode::S);
+    ///
+    /// assert_eq!(KeyCode::S, input_config.binding(InputType::Up));
+    /// assert_eq!(KeyCode::W, input_config.binding(InputType::Down));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn rebind(&mut self, input: InputType, key_code: KeyCode) {
+        let previous = self.binding(input);
+
+        if let Some(conflict) = self.parse_input(key_code) {
+            *self.binding_mut(conflict) = previous;
+        }
+
+        *self.binding_mut(input) = key_code;
+    }
+
+    /// (Private) Returns a mutable reference to the [KeyCode] the passed `input` is bound to.
+    fn binding_mut(&mut self, input: InputType) -> &mut KeyCode {
+        match input {
+            InputType::Up => &mut self.up,
+            InputType::Left => &mut self.left,
+            InputType::Down => &mut self.down,
+            InputType::Right => &mut self.right,
+            InputType::UpLeft => &mut self.up_left,
+            InputType::UpRight => &mut self.up_right,
+            InputType::DownLeft => &mut self.down_left,
+            InputType::DownRight => &mut self.down_right,
+            InputType::Cancel => &mut self.cancel,
+            InputType::Confirm => &mut self.confirm,
+            InputType::Wait => &mut self.wait,
+            InputType::Drop => &mut self.drop,
+            InputType::Examine => &mut self.examine,
+            InputType::Pickup => &mut self.pickup,
+            InputType::UseItem => &mut self.use_item,
+            InputType::LogView => &mut self.log_view,
+            InputType::CursorHud => &mut self.cursor_hud,
+            InputType::Spyglass => &mut self.spyglass,
+            InputType::Interact => &mut self.interact,
+            InputType::Target => &mut self.target,
+            InputType::ToggleFullscreen => &mut self.toggle_fullscreen,
+            InputType::DebugFooter => &mut self.debug_footer,
+            InputType::ToggleFov => &mut self.toggle_fov,
+        }
+    }
+}
+
+impl GamepadBindings {
+    /// Translates the passed [GamepadButtonType] to its corresponding [InputType], just like
+    /// [InputConfig::parse_input] does for the keyboard.
+    ///
+    /// # Arguments
+    ///
+    /// * `button`: The [GamepadButtonType] which needs to be parsed.
+    ///
+    /// returns: [Option]<[InputType]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gamepad = GamepadBindings::default();
+    ///
+    /// assert_eq!(Some(InputType::Up), gamepad.parse_input(GamepadButtonType::DPadUp));
+    /// assert_eq!(None, gamepad.parse_input(GamepadButtonType::Mode));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn parse_input(&self, button: GamepadButtonType) -> Option<InputType> {
+        match button {
+            _ if self.up == button => Some(InputType::Up),
+            _ if self.left == button => Some(InputType::Left),
+            _ if self.down == button => Some(InputType::Down),
+            _ if self.right == button => Some(InputType::Right),
+            _ if self.cancel == button => Some(InputType::Cancel),
+            _ if self.wait == button => Some(InputType::Wait),
             _ => None,
         }
     }
@@ -211,12 +564,120 @@ impl Display for InputConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "({:?}, {:?}, {:?}, {:?}, {:?})",
-            self.up, self.left, self.down, self.right, self.cancel
+            "({:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, \
+            {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {})",
+            self.up,
+            self.left,
+            self.down,
+            self.right,
+            self.up_left,
+            self.up_right,
+            self.down_left,
+            self.down_right,
+            self.cancel,
+            self.confirm,
+            self.wait,
+            self.drop,
+            self.examine,
+            self.pickup,
+            self.use_item,
+            self.log_view,
+            self.cursor_hud,
+            self.spyglass,
+            self.interact,
+            self.target,
+            self.toggle_fullscreen,
+            self.debug_footer,
+            self.toggle_fov,
+            self.gamepad
+        )
+    }
+}
+
+impl Display for GamepadBindings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
+            self.up, self.left, self.down, self.right, self.cancel, self.wait
         )
     }
 }
 
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            up: KeyCode::W,
+            left: KeyCode::A,
+            down: KeyCode::S,
+            right: KeyCode::D,
+            up_left: KeyCode::Numpad7,
+            up_right: KeyCode::Numpad9,
+            down_left: KeyCode::Numpad1,
+            down_right: KeyCode::Numpad3,
+            cancel: KeyCode::Escape,
+            confirm: KeyCode::Return,
+            wait: KeyCode::Period,
+            drop: KeyCode::R,
+            examine: KeyCode::L,
+            pickup: KeyCode::G,
+            use_item: KeyCode::U,
+            log_view: KeyCode::M,
+            cursor_hud: KeyCode::H,
+            spyglass: KeyCode::Z,
+            interact: KeyCode::E,
+            target: KeyCode::F,
+            toggle_fullscreen: KeyCode::F11,
+            debug_footer: KeyCode::F2,
+            toggle_fov: KeyCode::F1,
+            gamepad: GamepadBindings::default(),
+        }
+    }
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Self {
+            up: GamepadButtonType::DPadUp,
+            left: GamepadButtonType::DPadLeft,
+            down: GamepadButtonType::DPadDown,
+            right: GamepadButtonType::DPadRight,
+            cancel: GamepadButtonType::Start,
+            wait: GamepadButtonType::West,
+        }
+    }
+}
+
+impl Display for InputType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputType::Up => write!(f, "Move up"),
+            InputType::Left => write!(f, "Move left"),
+            InputType::Down => write!(f, "Move down"),
+            InputType::Right => write!(f, "Move right"),
+            InputType::UpLeft => write!(f, "Move up-left"),
+            InputType::UpRight => write!(f, "Move up-right"),
+            InputType::DownLeft => write!(f, "Move down-left"),
+            InputType::DownRight => write!(f, "Move down-right"),
+            InputType::Cancel => write!(f, "Cancel"),
+            InputType::Confirm => write!(f, "Confirm"),
+            InputType::Wait => write!(f, "Wait"),
+            InputType::Drop => write!(f, "Drop item"),
+            InputType::Examine => write!(f, "Examine"),
+            InputType::Pickup => write!(f, "Pick up item"),
+            InputType::UseItem => write!(f, "Use item"),
+            InputType::LogView => write!(f, "Message log"),
+            InputType::CursorHud => write!(f, "Cursor HUD"),
+            InputType::Spyglass => write!(f, "Spyglass"),
+            InputType::Interact => write!(f, "Interact"),
+            InputType::Target => write!(f, "Target"),
+            InputType::ToggleFullscreen => write!(f, "Toggle fullscreen"),
+            InputType::DebugFooter => write!(f, "Debug footer"),
+            InputType::ToggleFov => write!(f, "Toggle FOV"),
+        }
+    }
+}
+
 impl ConfigFile for InputConfig {
     fn file_name() -> String {
         String::from("input.json")
@@ -224,24 +685,69 @@ impl ConfigFile for InputConfig {
 }
 
 impl InputType {
-    /// `True` if the respective [InputType] is a movement event of any kind,
-    /// e.g. moving the player up or down, moving the cursor in a menu to the right, etc.
+    /// All [InputType]s in the order of the [InputConfig]'s bindings.
     ///
-    /// # Arguments
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub const ALL: [InputType; 23] = [
+        InputType::Up,
+        InputType::Left,
+        InputType::Down,
+        InputType::Right,
+        InputType::UpLeft,
+        InputType::UpRight,
+        InputType::DownLeft,
+        InputType::DownRight,
+        InputType::Cancel,
+        InputType::Confirm,
+        InputType::Wait,
+        InputType::Drop,
+        InputType::Examine,
+        InputType::Pickup,
+        InputType::UseItem,
+        InputType::LogView,
+        InputType::CursorHud,
+        InputType::Spyglass,
+        InputType::Interact,
+        InputType::Target,
+        InputType::ToggleFullscreen,
+        InputType::DebugFooter,
+        InputType::ToggleFov,
+    ];
+
+    /// Maps the respective movement [InputType] to the offset it moves by on the map.
     ///
-    /// returns: `false` if the calling [InputType] is not a movement event.
+    /// returns: [Option]<[i32; 2]> - [None] if the calling [InputType] is not a movement event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(Some([0, 1]), InputType::Up.movement_delta());
+    /// assert_eq!(None, InputType::Cancel.movement_delta());
+    /// ```
     ///
     /// # About
     ///
     /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
     ///
-    /// Since: `0.1.5`
+    /// Since: `0.1.10`
     ///
-    pub fn is_movement_event(&self) -> bool {
-        matches!(
-            self,
-            InputType::Up | InputType::Left | InputType::Down | InputType::Right
-        )
+    pub fn movement_delta(&self) -> Option<[i32; 2]> {
+        match self {
+            InputType::Up => Some([0, 1]),
+            InputType::Left => Some([-1, 0]),
+            InputType::Down => Some([0, -1]),
+            InputType::Right => Some([1, 0]),
+            InputType::UpLeft => Some([-1, 1]),
+            InputType::UpRight => Some([1, 1]),
+            InputType::DownLeft => Some([-1, -1]),
+            InputType::DownRight => Some([1, -1]),
+            _ => None,
+        }
     }
 }
 
@@ -254,7 +760,33 @@ mod unit_tests {
         left: KeyCode::A,
         down: KeyCode::S,
         right: KeyCode::D,
+        up_left: KeyCode::Numpad7,
+        up_right: KeyCode::Numpad9,
+        down_left: KeyCode::Numpad1,
+        down_right: KeyCode::Numpad3,
         cancel: KeyCode::Escape,
+        confirm: KeyCode::Return,
+        wait: KeyCode::Period,
+        drop: KeyCode::R,
+        examine: KeyCode::L,
+        pickup: KeyCode::G,
+        use_item: KeyCode::U,
+        log_view: KeyCode::M,
+        cursor_hud: KeyCode::H,
+        spyglass: KeyCode::Z,
+        interact: KeyCode::E,
+        target: KeyCode::F,
+        toggle_fullscreen: KeyCode::F11,
+        debug_footer: KeyCode::F2,
+        toggle_fov: KeyCode::F1,
+        gamepad: GamepadBindings {
+            up: GamepadButtonType::DPadUp,
+            left: GamepadButtonType::DPadLeft,
+            down: GamepadButtonType::DPadDown,
+            right: GamepadButtonType::DPadRight,
+            cancel: GamepadButtonType::Start,
+            wait: GamepadButtonType::West,
+        },
     };
 
     #[test]
@@ -268,6 +800,22 @@ mod unit_tests {
             InputType::Right,
             INPUT_CONFIG.parse_input(KeyCode::D).unwrap()
         );
+        assert_eq!(
+            InputType::UpLeft,
+            INPUT_CONFIG.parse_input(KeyCode::Numpad7).unwrap()
+        );
+        assert_eq!(
+            InputType::UpRight,
+            INPUT_CONFIG.parse_input(KeyCode::Numpad9).unwrap()
+        );
+        assert_eq!(
+            InputType::DownLeft,
+            INPUT_CONFIG.parse_input(KeyCode::Numpad1).unwrap()
+        );
+        assert_eq!(
+            InputType::DownRight,
+            INPUT_CONFIG.parse_input(KeyCode::Numpad3).unwrap()
+        );
         assert_eq!(
             InputType::Down,
             INPUT_CONFIG.parse_input(KeyCode::S).unwrap()
@@ -276,15 +824,159 @@ mod unit_tests {
             InputType::Cancel,
             INPUT_CONFIG.parse_input(KeyCode::Escape).unwrap()
         );
+        assert_eq!(
+            InputType::Confirm,
+            INPUT_CONFIG.parse_input(KeyCode::Return).unwrap()
+        );
+        assert_eq!(
+            InputType::Wait,
+            INPUT_CONFIG.parse_input(KeyCode::Period).unwrap()
+        );
+        assert_eq!(
+            InputType::Drop,
+            INPUT_CONFIG.parse_input(KeyCode::R).unwrap()
+        );
+        assert_eq!(
+            InputType::Examine,
+            INPUT_CONFIG.parse_input(KeyCode::L).unwrap()
+        );
+        assert_eq!(
+            InputType::Pickup,
+            INPUT_CONFIG.parse_input(KeyCode::G).unwrap()
+        );
+        assert_eq!(
+            InputType::UseItem,
+            INPUT_CONFIG.parse_input(KeyCode::U).unwrap()
+        );
+        assert_eq!(
+            InputType::LogView,
+            INPUT_CONFIG.parse_input(KeyCode::M).unwrap()
+        );
+        assert_eq!(
+            InputType::CursorHud,
+            INPUT_CONFIG.parse_input(KeyCode::H).unwrap()
+        );
+        assert_eq!(
+            InputType::Spyglass,
+            INPUT_CONFIG.parse_input(KeyCode::Z).unwrap()
+        );
+        assert_eq!(
+            InputType::Interact,
+            INPUT_CONFIG.parse_input(KeyCode::E).unwrap()
+        );
+        assert_eq!(
+            InputType::Target,
+            INPUT_CONFIG.parse_input(KeyCode::F).unwrap()
+        );
+        assert_eq!(
+            InputType::ToggleFullscreen,
+            INPUT_CONFIG.parse_input(KeyCode::F11).unwrap()
+        );
+        assert_eq!(
+            InputType::DebugFooter,
+            INPUT_CONFIG.parse_input(KeyCode::F2).unwrap()
+        );
+        assert_eq!(
+            InputType::ToggleFov,
+            INPUT_CONFIG.parse_input(KeyCode::F1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_gamepad_button_to_input_event_conversion() {
+        let gamepad = INPUT_CONFIG.gamepad;
+
+        assert_eq!(
+            Some(InputType::Up),
+            gamepad.parse_input(GamepadButtonType::DPadUp)
+        );
+        assert_eq!(
+            Some(InputType::Left),
+            gamepad.parse_input(GamepadButtonType::DPadLeft)
+        );
+        assert_eq!(
+            Some(InputType::Down),
+            gamepad.parse_input(GamepadButtonType::DPadDown)
+        );
+        assert_eq!(
+            Some(InputType::Right),
+            gamepad.parse_input(GamepadButtonType::DPadRight)
+        );
+        assert_eq!(
+            Some(InputType::Cancel),
+            gamepad.parse_input(GamepadButtonType::Start)
+        );
+        assert_eq!(
+            Some(InputType::Wait),
+            gamepad.parse_input(GamepadButtonType::West)
+        );
+        assert_eq!(None, gamepad.parse_input(GamepadButtonType::Mode));
+    }
+
+    #[test]
+    fn test_movement_delta() {
+        assert_eq!(Some([0, 1]), InputType::Up.movement_delta());
+        assert_eq!(Some([-1, 0]), InputType::Left.movement_delta());
+        assert_eq!(Some([0, -1]), InputType::Down.movement_delta());
+        assert_eq!(Some([1, 0]), InputType::Right.movement_delta());
+        assert_eq!(Some([-1, 1]), InputType::UpLeft.movement_delta());
+        assert_eq!(Some([1, 1]), InputType::UpRight.movement_delta());
+        assert_eq!(Some([-1, -1]), InputType::DownLeft.movement_delta());
+        assert_eq!(Some([1, -1]), InputType::DownRight.movement_delta());
+        assert_eq!(None, InputType::Cancel.movement_delta());
+    }
+
+    #[test]
+    fn test_missing_bindings_fall_back_to_default() {
+        let input_config: InputConfig = serde_json::from_str("{ \"up\": \"K\" }").unwrap();
+
+        assert_eq!(KeyCode::K, input_config.up);
+        assert_eq!(InputConfig::default().drop, input_config.drop);
+
+        let input_config: InputConfig =
+            serde_json::from_str("{ \"gamepad\": { \"wait\": \"South\" } }").unwrap();
+
+        assert_eq!(GamepadButtonType::South, input_config.gamepad.wait);
+        assert_eq!(GamepadButtonType::DPadUp, input_config.gamepad.up);
+        assert_eq!(KeyCode::W, input_config.up);
+    }
+
+    #[test]
+    fn test_rebind_swaps_conflicting_bindings() {
+        let mut input_config = InputConfig::default();
+
+        input_config.rebind(InputType::Wait, KeyCode::Space);
+
+        assert_eq!(KeyCode::Space, input_config.binding(InputType::Wait));
+        assert_eq!(
+            Some(InputType::Wait),
+            input_config.parse_input(KeyCode::Space)
+        );
+        assert_eq!(None, input_config.parse_input(KeyCode::Period));
+
+        input_config.rebind(InputType::Up, KeyCode::S);
+
+        assert_eq!(KeyCode::S, input_config.binding(InputType::Up));
+        assert_eq!(KeyCode::W, input_config.binding(InputType::Down));
+
+        for input in InputType::ALL {
+            assert_eq!(
+                Some(input),
+                input_config.parse_input(input_config.binding(input))
+            );
+        }
     }
 
     #[test]
-    fn test_movement_type_detection() {
-        assert_eq!(true, InputType::Up.is_movement_event());
-        assert_eq!(true, InputType::Left.is_movement_event());
-        assert_eq!(true, InputType::Down.is_movement_event());
-        assert_eq!(true, InputType::Right.is_movement_event());
-        assert_eq!(false, InputType::Cancel.is_movement_event());
+    fn test_rebound_config_deserializes_back_equal() {
+        let mut input_config = InputConfig::default();
+
+        input_config.rebind(InputType::Up, KeyCode::Up);
+        input_config.gamepad.wait = GamepadButtonType::South;
+
+        let json = serde_json::to_string(&input_config).unwrap();
+
+        assert_eq!(input_config, serde_json::from_str(&json).unwrap());
     }
 
     #[test]
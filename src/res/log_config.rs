@@ -0,0 +1,166 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::cmp::max;
+use std::fmt::{Display, Formatter};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+use crate::core::constants;
+use crate::res::config_file::ConfigFile;
+
+/// A [Resource] configuring the [crate::res::message_log::MessageLog], e.g., how many messages are
+/// kept in its history.
+///
+/// It is usually not instantiated directly, but deserialized from the `log.json` configuration file
+/// shipped with the game. Properties missing from the file fall back to their [Default] values.
+///
+/// # Properties
+///
+/// * `history_capacity`: The maximum number of messages kept in the history, which can be scrolled
+///   through in the log view. The effective value is never smaller than [constants::MESSAGE_LOG_HEIGHT].
+///
+/// # Examples
+///
+/// ```
+/// Json config file content:
+///
+/// {
+///   "history_capacity": 200
+/// }
+///
+/// ...
+///
+/// let log_config = LogConfig::load();
+///
+/// assert_eq!(200, log_config.history_capacity());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [ConfigFile]
+///
+#[derive(Debug, Clone, Deserialize, Resource)]
+#[serde(default)]
+pub struct LogConfig {
+    /// The maximum number of messages kept in the history, which can be scrolled through in the log
+    /// view. The effective value is never smaller than [constants::MESSAGE_LOG_HEIGHT].
+    pub history_capacity: usize,
+}
+
+impl LogConfig {
+    /// Returns the configured `history_capacity`, which is at least as large as the number of messages
+    /// visible in the log window.
+    ///
+    /// returns: usize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let log_config = LogConfig {
+    ///     history_capacity: 1,
+    /// };
+    ///
+    /// assert_eq!(constants::MESSAGE_LOG_HEIGHT as usize, log_config.history_capacity());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn history_capacity(&self) -> usize {
+        max(
+            self.history_capacity,
+            constants::MESSAGE_LOG_HEIGHT as usize,
+        )
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            history_capacity: constants::MESSAGE_LOG_CAPACITY,
+        }
+    }
+}
+
+impl Display for LogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.history_capacity)
+    }
+}
+
+impl ConfigFile for LogConfig {
+    fn file_name() -> String {
+        String::from("log.json")
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_history_capacity_is_at_least_visible_height() {
+        assert_eq!(
+            constants::MESSAGE_LOG_HEIGHT as usize,
+            LogConfig {
+                history_capacity: 0
+            }
+            .history_capacity()
+        );
+        assert_eq!(
+            200,
+            LogConfig {
+                history_capacity: 200
+            }
+            .history_capacity()
+        );
+    }
+
+    #[test]
+    fn test_deserialization() {
+        let log_config: LogConfig = serde_json::from_str("{ \"history_capacity\": 120 }").unwrap();
+
+        assert_eq!(120, log_config.history_capacity());
+
+        let defaulted_config: LogConfig = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(
+            constants::MESSAGE_LOG_CAPACITY,
+            defaulted_config.history_capacity()
+        );
+    }
+
+    #[test]
+    fn test_config_file_name() {
+        assert_eq!("log.json", LogConfig::file_name());
+    }
+}
@@ -0,0 +1,319 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::core::constants;
+use crate::res::message_log::{LogEntry, MessageLog};
+
+/// [Resource] tracking the state of the log view, an overlay in which the player scrolls through the
+/// full history of the [MessageLog].
+///
+/// The scroll offset counts the messages skipped from the newest one, i.e., an offset of `0` shows the
+/// most recent page. The offset is bounded, so the view can neither scroll past the newest nor past the
+/// oldest message. While the log view is open, the player can't act.
+///
+/// # Examples
+///
+/// ```
+/// let mut log_view = LogView::new(2);
+///
+/// log_view.open();
+///
+/// assert!(log_view.scroll_older(3)); // Shows the messages 2 and 3 from the newest.
+/// assert!(!log_view.scroll_older(3)); // The oldest message is already visible.
+/// assert!(log_view.scroll_newer());
+/// assert!(!log_view.scroll_newer()); // The newest message is already visible.
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Eq, PartialEq, Resource)]
+pub struct LogView {
+    /// (Private) The number of messages visible at once.
+    page_size: usize,
+    /// (Private) The number of messages skipped from the newest one.
+    scroll_offset: usize,
+    /// (Private) If the log view is currently open.
+    is_open: bool,
+}
+
+impl LogView {
+    /// Creates a new, closed [LogView] showing `page_size` messages at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size`: The number of messages visible at once.
+    ///
+    /// returns: [LogView]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page_size,
+            scroll_offset: 0,
+            is_open: false,
+        }
+    }
+
+    /// Checks if the log view is currently open.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the log view, scrolled to the most recent messages.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn open(&mut self) {
+        self.scroll_offset = 0;
+        self.is_open = true;
+    }
+
+    /// Closes the log view.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Returns the number of messages skipped from the newest one.
+    ///
+    /// returns: usize
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Scrolls one message towards the oldest one, unless it is already visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `total`: The number of messages in the [MessageLog].
+    ///
+    /// returns: bool - `true` if the view was scrolled.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn scroll_older(&mut self, total: usize) -> bool {
+        if self.scroll_offset >= total.saturating_sub(self.page_size) {
+            return false;
+        }
+
+        self.scroll_offset += 1;
+        true
+    }
+
+    /// Scrolls one message towards the newest one, unless it is already visible.
+    ///
+    /// returns: bool - `true` if the view was scrolled.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn scroll_newer(&mut self) -> bool {
+        if self.scroll_offset == 0 {
+            return false;
+        }
+
+        self.scroll_offset -= 1;
+        true
+    }
+
+    /// Returns the messages of the passed `message_log` visible at the current scroll offset, ordered
+    /// from newest to oldest.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_log`: The [MessageLog] to show.
+    ///
+    /// returns: impl [Iterator]<Item = &[LogEntry]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn visible_entries<'a>(
+        &self,
+        message_log: &'a MessageLog,
+    ) -> impl Iterator<Item = &'a LogEntry> {
+        message_log.page(self.scroll_offset, self.page_size)
+    }
+}
+
+impl Default for LogView {
+    fn default() -> Self {
+        Self::new(constants::LOG_VIEW_PAGE_SIZE)
+    }
+}
+
+impl Debug for LogView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> LogView {{ \
+        page_size: {:?}, \
+        scroll_offset: {:?}, \
+        is_open: {:?} \
+        }}",
+            self.page_size, self.scroll_offset, self.is_open
+        )
+    }
+}
+
+impl Display for LogView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}, {})",
+            self.page_size, self.scroll_offset, self.is_open
+        )
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn message_log(count: usize) -> MessageLog {
+        let mut message_log = MessageLog::new(count);
+
+        for index in 0..count {
+            message_log.push_info(index.to_string());
+        }
+
+        message_log
+    }
+
+    #[test]
+    fn test_scrolling_is_bounded_by_oldest_and_newest_message() {
+        let mut log_view = LogView::new(3);
+        log_view.open();
+
+        assert!(!log_view.scroll_newer());
+        assert_eq!(0, log_view.scroll_offset());
+
+        for _ in 0..2 {
+            assert!(log_view.scroll_older(5));
+        }
+
+        assert!(!log_view.scroll_older(5));
+        assert_eq!(2, log_view.scroll_offset());
+
+        for _ in 0..2 {
+            assert!(log_view.scroll_newer());
+        }
+
+        assert!(!log_view.scroll_newer());
+        assert_eq!(0, log_view.scroll_offset());
+    }
+
+    #[test]
+    fn test_scrolling_with_fewer_messages_than_page_size() {
+        let mut log_view = LogView::new(3);
+        log_view.open();
+
+        assert!(!log_view.scroll_older(2));
+        assert!(!log_view.scroll_older(0));
+        assert_eq!(0, log_view.scroll_offset());
+    }
+
+    #[test]
+    fn test_visible_entries_match_scroll_offset() {
+        let message_log = message_log(5);
+        let mut log_view = LogView::new(3);
+        log_view.open();
+
+        let texts = |log_view: &LogView| -> Vec<String> {
+            log_view
+                .visible_entries(&message_log)
+                .map(|entry| entry.text.clone())
+                .collect()
+        };
+
+        assert_eq!(vec!["4", "3", "2"], texts(&log_view));
+
+        log_view.scroll_older(message_log.len());
+        assert_eq!(vec!["3", "2", "1"], texts(&log_view));
+
+        log_view.scroll_older(message_log.len());
+        assert_eq!(vec!["2", "1", "0"], texts(&log_view));
+    }
+
+    #[test]
+    fn test_opening_resets_scroll_offset() {
+        let mut log_view = LogView::new(1);
+        log_view.open();
+        log_view.scroll_older(3);
+        log_view.close();
+
+        assert!(!log_view.is_open());
+
+        log_view.open();
+
+        assert!(log_view.is_open());
+        assert_eq!(0, log_view.scroll_offset());
+    }
+}
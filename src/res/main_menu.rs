@@ -0,0 +1,171 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+/// Defines the options listed in the [MainMenu].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MainMenuOption {
+    /// Starts a new game.
+    Start,
+    /// Continues the saved game, see [crate::core::save_game::SaveGame].
+    Continue,
+    /// Opens the settings menu.
+    Settings,
+    /// Closes the game.
+    Quit,
+}
+
+impl MainMenuOption {
+    /// All [MainMenuOption]s in the order they're listed in the [MainMenu].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub const ALL: [MainMenuOption; 4] = [
+        MainMenuOption::Start,
+        MainMenuOption::Continue,
+        MainMenuOption::Settings,
+        MainMenuOption::Quit,
+    ];
+}
+
+impl Display for MainMenuOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MainMenuOption::Start => write!(f, "Start"),
+            MainMenuOption::Continue => write!(f, "Continue"),
+            MainMenuOption::Settings => write!(f, "Settings"),
+            MainMenuOption::Quit => write!(f, "Quit"),
+        }
+    }
+}
+
+/// [Resource] tracking the selected [MainMenuOption] of the title screen.
+///
+/// # Examples
+///
+/// ```
+/// let mut main_menu = MainMenu::default();
+///
+/// assert_eq!(MainMenuOption::Start, main_menu.selected_option());
+///
+/// main_menu.next();
+/// assert_eq!(MainMenuOption::Continue, main_menu.selected_option());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Resource)]
+pub struct MainMenu {
+    /// (Private) Index of the selected option in [MainMenuOption::ALL].
+    selected: usize,
+}
+
+impl MainMenu {
+    /// Returns the selected [MainMenuOption].
+    ///
+    /// returns: [MainMenuOption]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn selected_option(&self) -> MainMenuOption {
+        MainMenuOption::ALL[self.selected]
+    }
+
+    /// Moves the selection to the next option, wrapping around to the first one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % MainMenuOption::ALL.len();
+    }
+
+    /// Moves the selection to the previous option, wrapping around to the last one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn previous(&mut self) {
+        self.selected = (self.selected + MainMenuOption::ALL.len() - 1) % MainMenuOption::ALL.len();
+    }
+}
+
+impl Debug for MainMenu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> MainMenu {{ \
+        selected: {} \
+        }}",
+            self.selected
+        )
+    }
+}
+
+impl Display for MainMenu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.selected_option())
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_wraps_around() {
+        let mut main_menu = MainMenu::default();
+
+        main_menu.previous();
+        assert_eq!(MainMenuOption::Quit, main_menu.selected_option());
+
+        main_menu.next();
+        assert_eq!(MainMenuOption::Start, main_menu.selected_option());
+    }
+}
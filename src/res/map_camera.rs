@@ -0,0 +1,90 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::ui::rectangle::Rectangle;
+use crate::ui::viewport::Viewport;
+
+/// [Resource] holding the [Viewport] through which the [crate::ui::game_map::GameMap] of the running game is
+/// rendered into the map panel of the [crate::res::terminal_layout::TerminalLayout].
+///
+/// # Examples
+///
+/// ```
+/// let terminal_layout = TerminalLayout::new(&[100, 80]);
+///
+/// commands.insert_resource(MapCamera::new(&terminal_layout.map));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, PartialEq, Resource)]
+pub struct MapCamera {
+    /// The [Viewport] selecting the section of the [crate::ui::game_map::GameMap] shown in the map panel.
+    pub viewport: Viewport,
+}
+
+impl MapCamera {
+    /// Creates a new [MapCamera] rendering into the passed `panel` of the [bevy_ascii_terminal::Terminal], see
+    /// [Viewport::new].
+    ///
+    /// # Arguments
+    ///
+    /// * `panel`: The [Rectangle] of the [bevy_ascii_terminal::Terminal] the map is rendered into, see
+    ///   [crate::res::terminal_layout::TerminalLayout::map].
+    ///
+    /// returns: [MapCamera]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(panel: &Rectangle) -> Self {
+        Self {
+            viewport: Viewport::new(panel),
+        }
+    }
+}
+
+impl Debug for MapCamera {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> MapCamera {{ viewport: {:?} }}",
+            self.viewport
+        )
+    }
+}
+
+impl Display for MapCamera {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.viewport)
+    }
+}
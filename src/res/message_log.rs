@@ -0,0 +1,382 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Color, Resource};
+use serde::Deserialize;
+
+use crate::core::constants;
+use crate::ui::colors;
+
+/// [Resource] collecting the human-readable messages shown to the player, e.g., combat results or
+/// the reactions of monsters.
+///
+/// Only the most recent `capacity` messages are kept, older ones are evicted as new ones are pushed. The
+/// capacity is configured through the [crate::res::log_config::LogConfig].
+///
+/// # Examples
+///
+/// ```
+/// fn combat_system(mut message_log: ResMut<MessageLog>) {
+///     message_log.push("The Mended hits you for 3 damage.", LogCategory::Combat);
+///     message_log.push_info("You feel rested.");
+/// }
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Resource)]
+pub struct MessageLog {
+    /// (Private) The maximum number of kept messages.
+    capacity: usize,
+    /// (Private) The kept messages, ordered from oldest to newest.
+    entries: VecDeque<LogEntry>,
+}
+
+/// A single message of the [MessageLog].
+///
+/// # Properties
+///
+/// * `text`: The human-readable text of the message.
+/// * `category`: The [LogCategory] of the message, which determines its color.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LogEntry {
+    /// The human-readable text of the message.
+    pub text: String,
+    /// The [LogCategory] of the message, which determines its color.
+    pub category: LogCategory,
+}
+
+/// The category of a [LogEntry], which is used to color code the messages of the [MessageLog].
+///
+/// The colors are configurable through the [crate::res::palette::Palette].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [crate::res::palette::Palette::log_color]
+///
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize)]
+pub enum LogCategory {
+    /// Attacks and damage dealt or received.
+    Combat,
+    /// General information, e.g., picking up an item.
+    #[default]
+    Info,
+    /// Notable dangers or failed actions, e.g., a monster noticing the player.
+    Warning,
+    /// The player gaining a level.
+    LevelUp,
+    /// The death of the player or a monster.
+    Death,
+}
+
+impl MessageLog {
+    /// Creates a new, empty [MessageLog] keeping at most `capacity` messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: The maximum number of kept messages.
+    ///
+    /// returns: [MessageLog]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a new message with the passed `text` and `category`, evicting the oldest message if the
+    /// [MessageLog] is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `text`: The human-readable text of the message.
+    /// * `category`: The [LogCategory] of the message.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn push(&mut self, text: impl Into<String>, category: LogCategory) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(LogEntry {
+            text: text.into(),
+            category,
+        });
+    }
+
+    /// Adds a new message with the passed `text` and the [LogCategory::Info] category.
+    ///
+    /// # Arguments
+    ///
+    /// * `text`: The human-readable text of the message.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn push_info(&mut self, text: impl Into<String>) {
+        self.push(text, LogCategory::Info);
+    }
+
+    /// Returns up to `count` of the most recent messages, ordered from newest to oldest.
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: The maximum number of returned messages.
+    ///
+    /// returns: impl [Iterator]<Item = &[LogEntry]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut message_log = MessageLog::default();
+    ///
+    /// message_log.push_info("First");
+    /// message_log.push_info("Second");
+    ///
+    /// assert_eq!("Second", message_log.recent(1).next().unwrap().text);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &LogEntry> {
+        self.page(0, count)
+    }
+
+    /// Returns up to `count` messages, skipping the `offset` most recent ones, ordered from newest to
+    /// oldest.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: The number of skipped messages, starting from the newest one.
+    /// * `count`: The maximum number of returned messages.
+    ///
+    /// returns: impl [Iterator]<Item = &[LogEntry]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut message_log = MessageLog::default();
+    ///
+    /// message_log.push_info("First");
+    /// message_log.push_info("Second");
+    /// message_log.push_info("Third");
+    ///
+    /// assert_eq!("Second", message_log.page(1, 1).next().unwrap().text);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn page(&self, offset: usize, count: usize) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().rev().skip(offset).take(count)
+    }
+
+    /// Returns the number of kept messages.
+    ///
+    /// returns: usize
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the [MessageLog] doesn't contain any messages.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl LogCategory {
+    /// Returns the default color messages of the [LogCategory] are rendered in, as defined by the
+    /// game's color scheme in [colors].
+    ///
+    /// # Arguments
+    ///
+    /// returns: [Color]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(colors::LOG_COMBAT, LogCategory::Combat.default_color());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn default_color(&self) -> Color {
+        match self {
+            LogCategory::Combat => colors::LOG_COMBAT,
+            LogCategory::Info => colors::LOG_INFO,
+            LogCategory::Warning => colors::LOG_WARNING,
+            LogCategory::LevelUp => colors::LOG_LEVEL_UP,
+            LogCategory::Death => colors::LOG_DEATH,
+        }
+    }
+}
+
+impl Default for MessageLog {
+    fn default() -> Self {
+        Self::new(constants::MESSAGE_LOG_CAPACITY)
+    }
+}
+
+impl Debug for MessageLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> MessageLog {{ \
+        capacity: {:?}, \
+        entries: {:?} \
+        }}",
+            self.capacity, self.entries
+        )
+    }
+}
+
+impl Display for MessageLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.capacity, self.entries.len())
+    }
+}
+
+impl Display for LogEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.category, self.text)
+    }
+}
+
+impl Display for LogCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_retain_category() {
+        let mut message_log = MessageLog::default();
+
+        message_log.push("You hit the Mended.", LogCategory::Combat);
+        message_log.push_info("You see a potion.");
+
+        let entries: Vec<&LogEntry> = message_log.recent(2).collect();
+
+        assert_eq!(LogCategory::Info, entries[0].category);
+        assert_eq!("You see a potion.", entries[0].text);
+        assert_eq!(LogCategory::Combat, entries[1].category);
+        assert_eq!("You hit the Mended.", entries[1].text);
+    }
+
+    #[test]
+    fn test_pushing_beyond_capacity_evicts_oldest_entry() {
+        let mut message_log = MessageLog::new(2);
+
+        message_log.push_info("First");
+        message_log.push_info("Second");
+        message_log.push_info("Third");
+
+        let texts: Vec<&str> = message_log
+            .recent(5)
+            .map(|entry| entry.text.as_str())
+            .collect();
+
+        assert_eq!(vec!["Third", "Second"], texts);
+    }
+
+    #[test]
+    fn test_category_colors() {
+        assert_eq!(colors::LOG_COMBAT, LogCategory::Combat.default_color());
+        assert_eq!(colors::LOG_INFO, LogCategory::Info.default_color());
+        assert_eq!(colors::LOG_WARNING, LogCategory::Warning.default_color());
+        assert_eq!(colors::LOG_LEVEL_UP, LogCategory::LevelUp.default_color());
+        assert_eq!(colors::LOG_DEATH, LogCategory::Death.default_color());
+    }
+}
@@ -28,6 +28,30 @@
 //! Since: `0.1.5`
 //!
 
+pub mod action_queue;
+pub mod animation_queue;
 pub mod config_file;
+pub mod cursor_hud;
+pub mod debug_footer;
+pub mod dungeon_depth;
+pub mod examine_mode;
+pub mod fov_algorithm;
+pub mod fov_cache;
+pub mod fov_shape;
+pub mod gameplay_config;
 pub mod input_config;
+pub mod log_config;
+pub mod log_view;
+pub mod main_menu;
+pub mod map_camera;
+pub mod message_log;
+pub mod npc_turn_queue;
+pub mod palette;
+pub mod run_summary;
+pub mod settings_menu;
+pub mod targeting_mode;
+pub mod terminal_layout;
+pub mod travel_path;
+pub mod turn_counter;
+pub mod turn_delay;
 pub mod window_config;
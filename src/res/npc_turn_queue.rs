@@ -0,0 +1,236 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::{Entity, Resource};
+
+/// [Resource] spreading the AI processing of the `NPC entities` over multiple frames, so a large number of
+/// monsters doesn't stall a single frame.
+///
+/// When a [crate::plugins::states::GameTurnState::Npc] turn begins, all acting `entities` are queued. Each
+/// frame, only a batch of at most [crate::res::gameplay_config::GameplayConfig::ai_budget] `entities` is
+/// taken from the queue, and the turn only ends once the queue is drained.
+///
+/// # Examples
+///
+/// ```
+/// let mut npc_turn_queue = NpcTurnQueue::default();
+///
+/// npc_turn_queue.begin(vec![first, second, third]);
+///
+/// assert_eq!(vec![first, second], npc_turn_queue.next_batch(2));
+/// assert!(npc_turn_queue.has_pending());
+///
+/// assert_eq!(vec![third], npc_turn_queue.next_batch(2));
+/// assert!(!npc_turn_queue.has_pending());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Eq, PartialEq, Resource)]
+pub struct NpcTurnQueue {
+    /// (Private) The `entities` which still have to act in the current turn.
+    pending: VecDeque<Entity>,
+    /// (Private) The `entities` of the batch taken last, which act in the current frame.
+    batch: Vec<Entity>,
+    /// (Private) If a turn of the `NPC entities` is currently in progress.
+    is_active: bool,
+}
+
+impl NpcTurnQueue {
+    /// Checks if a turn of the `NPC entities` is currently in progress.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    /// Begins a new turn of the `NPC entities`, queueing the passed `entities` in the order they act.
+    ///
+    /// # Arguments
+    ///
+    /// * `entities`: The `entities` acting in the turn.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn begin(&mut self, entities: Vec<Entity>) {
+        self.pending = entities.into();
+        self.batch.clear();
+        self.is_active = true;
+    }
+
+    /// Takes the next batch of at most `budget` `entities` from the queue. A `budget` of `0` takes all
+    /// remaining `entities`. The batch is kept as the [NpcTurnQueue::current_batch] until the next one is taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget`: The maximum number of `entities` to take.
+    ///
+    /// returns: [Vec]<[Entity]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn next_batch(&mut self, budget: usize) -> Vec<Entity> {
+        let count = if budget == 0 {
+            self.pending.len()
+        } else {
+            budget.min(self.pending.len())
+        };
+
+        self.batch = self.pending.drain(..count).collect();
+        self.batch.clone()
+    }
+
+    /// Returns the `entities` of the batch taken last through [NpcTurnQueue::next_batch], so all systems acting
+    /// for the `NPC entities` in a frame process the same `entities`.
+    ///
+    /// returns: &[Entity]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn current_batch(&self) -> &[Entity] {
+        &self.batch
+    }
+
+    /// Checks if any `entities` still have to act in the current turn.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Ends the current turn of the `NPC entities`, discarding all pending `entities` and the current batch.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn end(&mut self) {
+        self.pending.clear();
+        self.batch.clear();
+        self.is_active = false;
+    }
+}
+
+impl Debug for NpcTurnQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> NpcTurnQueue {{ \
+        pending: {:?}, \
+        batch: {:?}, \
+        is_active: {:?} \
+        }}",
+            self.pending, self.batch, self.is_active
+        )
+    }
+}
+
+impl Display for NpcTurnQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}, {})",
+            self.pending.len(),
+            self.batch.len(),
+            self.is_active
+        )
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_batches_are_bounded_by_budget() {
+        let entities: Vec<Entity> = (0..5).map(Entity::from_raw).collect();
+        let mut npc_turn_queue = NpcTurnQueue::default();
+
+        npc_turn_queue.begin(entities.clone());
+
+        assert!(npc_turn_queue.is_active());
+        assert_eq!(entities[0..2], npc_turn_queue.next_batch(2));
+        assert_eq!(entities[2..4], npc_turn_queue.next_batch(2));
+        assert_eq!(entities[2..4], *npc_turn_queue.current_batch());
+        assert_eq!(entities[4..5], npc_turn_queue.next_batch(2));
+        assert!(!npc_turn_queue.has_pending());
+        assert!(npc_turn_queue.next_batch(2).is_empty());
+    }
+
+    #[test]
+    fn test_zero_budget_takes_all_entities() {
+        let entities: Vec<Entity> = (0..5).map(Entity::from_raw).collect();
+        let mut npc_turn_queue = NpcTurnQueue::default();
+
+        npc_turn_queue.begin(entities.clone());
+
+        assert_eq!(entities, npc_turn_queue.next_batch(0));
+        assert!(!npc_turn_queue.has_pending());
+    }
+
+    #[test]
+    fn test_end_discards_pending_entities() {
+        let mut npc_turn_queue = NpcTurnQueue::default();
+
+        npc_turn_queue.begin(vec![Entity::from_raw(1)]);
+        npc_turn_queue.end();
+
+        assert!(!npc_turn_queue.is_active());
+        assert!(!npc_turn_queue.has_pending());
+    }
+}
@@ -0,0 +1,244 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+use bevy::prelude::{Color, Resource};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use crate::components::faction::Faction;
+use crate::res::config_file::ConfigFile;
+use crate::res::message_log::LogCategory;
+
+/// A [Resource] defining the configurable colors of the game, e.g., the colors of the messages in the
+/// [crate::res::message_log::MessageLog].
+///
+/// It is usually not instantiated directly, but deserialized from the `palette.json` configuration
+/// file shipped with the game. Colors are defined as hex strings, colors missing from the file fall
+/// back to the game's default scheme defined in [crate::ui::colors].
+///
+/// # Properties
+///
+/// * `log`: The colors of the [crate::res::message_log::MessageLog] messages by [LogCategory].
+/// * `factions`: The colors of the `entities` on the map by [Faction].
+///
+/// # Examples
+///
+/// ```
+/// Json config file content:
+///
+/// {
+///   "log": {
+///     "Combat": "FF0000",
+///     "LevelUp": "FFD700"
+///   },
+///   "factions": {
+///     "Hostile": "FFFF00"
+///   }
+/// }
+///
+/// ...
+///
+/// let palette = Palette::load();
+///
+/// assert_eq!(Color::hex("FF0000").unwrap(), palette.log_color(LogCategory::Combat));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [ConfigFile]
+///
+#[derive(Debug, Clone, Default, Deserialize, Resource)]
+#[serde(default)]
+pub struct Palette {
+    /// The colors of the [crate::res::message_log::MessageLog] messages by [LogCategory].
+    #[serde(deserialize_with = "deserialize_hex_colors")]
+    pub log: HashMap<LogCategory, Color>,
+    /// The colors of the `entities` on the map by [Faction].
+    #[serde(deserialize_with = "deserialize_hex_colors")]
+    pub factions: HashMap<Faction, Color>,
+}
+
+impl Palette {
+    /// Returns the color messages of the passed `category` are rendered in, falling back to
+    /// [LogCategory::default_color] if the [Palette] doesn't define one.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: The [LogCategory] whose color is returned.
+    ///
+    /// returns: [Color]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(colors::LOG_COMBAT, Palette::default().log_color(LogCategory::Combat));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn log_color(&self, category: LogCategory) -> Color {
+        self.log
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| category.default_color())
+    }
+
+    /// Returns the color `entities` of the passed `faction` are rendered in, falling back to
+    /// [Faction::default_color] if the [Palette] doesn't define one.
+    ///
+    /// # Arguments
+    ///
+    /// * `faction`: The [Faction] whose color is returned.
+    ///
+    /// returns: [Color]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(colors::FACTION_HOSTILE, Palette::default().faction_color(Faction::Hostile));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn faction_color(&self, faction: Faction) -> Color {
+        self.factions
+            .get(&faction)
+            .copied()
+            .unwrap_or_else(|| faction.default_color())
+    }
+}
+
+impl Display for Palette {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?})", self.log, self.factions)
+    }
+}
+
+impl ConfigFile for Palette {
+    fn file_name() -> String {
+        String::from("palette.json")
+    }
+}
+
+/// Internal function to deserialize a map of hex color strings, e.g., `"FF0000"` or `"#FF0000"`,
+/// into a map of [Color]s.
+///
+/// # Arguments
+///
+/// * `deserializer`: The [Deserializer] providing the map.
+///
+/// returns: [Result]<[HashMap]<K, [Color]>, D::Error> - An error if any of the colors isn't a valid
+/// hex string.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+fn deserialize_hex_colors<'de, D, K>(deserializer: D) -> Result<HashMap<K, Color>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+{
+    HashMap::<K, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, hex)| {
+            Color::hex(&hex)
+                .map(|color| (key, color))
+                .map_err(|error| D::Error::custom(format!("Invalid color {:?}: {:?}", hex, error)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::ui::colors;
+
+    use super::*;
+
+    #[test]
+    fn test_log_color_falls_back_to_default() {
+        let palette = Palette::default();
+
+        assert_eq!(colors::LOG_COMBAT, palette.log_color(LogCategory::Combat));
+        assert_eq!(
+            colors::LOG_LEVEL_UP,
+            palette.log_color(LogCategory::LevelUp)
+        );
+    }
+
+    #[test]
+    fn test_deserialization() {
+        let palette: Palette =
+            serde_json::from_str("{ \"log\": { \"Combat\": \"#00FF00\" } }").unwrap();
+
+        assert_eq!(
+            Color::rgb(0.0, 1.0, 0.0),
+            palette.log_color(LogCategory::Combat)
+        );
+        assert_eq!(colors::LOG_INFO, palette.log_color(LogCategory::Info));
+        assert!(serde_json::from_str::<Palette>("{ \"log\": { \"Info\": \"nope\" } }").is_err());
+    }
+
+    #[test]
+    fn test_faction_color() {
+        assert_eq!(
+            colors::FACTION_HOSTILE,
+            Palette::default().faction_color(Faction::Hostile)
+        );
+
+        let palette: Palette =
+            serde_json::from_str("{ \"factions\": { \"Hostile\": \"#0000FF\" } }").unwrap();
+
+        assert_eq!(
+            Color::rgb(0.0, 0.0, 1.0),
+            palette.faction_color(Faction::Hostile)
+        );
+        assert_eq!(
+            colors::FACTION_PLAYER,
+            palette.faction_color(Faction::Player)
+        );
+    }
+
+    #[test]
+    fn test_config_file_name() {
+        assert_eq!("palette.json", Palette::file_name());
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+/// [Resource] summarizing a finished run of the [crate::plugins::states::AppState::Game], which is shown on the
+/// game over screen. Unlike the run resources, it outlives the run it describes.
+///
+/// # Examples
+///
+/// ```
+/// let run_summary = RunSummary::new(3, 120);
+///
+/// assert_eq!(3, run_summary.depth());
+/// assert_eq!(120, run_summary.turns());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq, Resource)]
+pub struct RunSummary {
+    /// (Private) The floor of the dungeon the run ended on.
+    depth: u32,
+    /// (Private) The number of turns the run lasted.
+    turns: u32,
+}
+
+impl RunSummary {
+    /// Creates a new [RunSummary].
+    ///
+    /// # Arguments
+    ///
+    /// * `depth`: The floor of the dungeon the run ended on.
+    /// * `turns`: The number of turns the run lasted.
+    ///
+    /// returns: [RunSummary]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(depth: u32, turns: u32) -> Self {
+        Self { depth, turns }
+    }
+
+    /// Returns the floor of the dungeon the run ended on.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Returns the number of turns the run lasted.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn turns(&self) -> u32 {
+        self.turns
+    }
+}
+
+impl Debug for RunSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> RunSummary {{ \
+        depth: {}, \
+        turns: {} \
+        }}",
+            self.depth, self.turns
+        )
+    }
+}
+
+impl Display for RunSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.depth, self.turns)
+    }
+}
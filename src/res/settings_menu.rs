@@ -0,0 +1,174 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::res::input_config::InputType;
+
+/// [Resource] tracking the state of the settings menu, which lists the bindings of all [InputType]s. The player
+/// selects a binding row and then presses the key it should be bound to.
+///
+/// # Examples
+///
+/// ```
+/// let mut settings_menu = SettingsMenu::default();
+///
+/// settings_menu.next();
+/// assert_eq!(InputType::Left, settings_menu.selected_input());
+///
+/// settings_menu.start_rebinding();
+/// assert!(settings_menu.is_rebinding());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Resource)]
+pub struct SettingsMenu {
+    /// (Private) Index of the selected row in [InputType::ALL].
+    selected: usize,
+    /// (Private) Whether the next pressed key is bound to the selected [InputType].
+    is_rebinding: bool,
+}
+
+impl SettingsMenu {
+    /// Returns the [InputType] of the selected binding row.
+    ///
+    /// returns: [InputType]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn selected_input(&self) -> InputType {
+        InputType::ALL[self.selected]
+    }
+
+    /// Moves the selection to the next binding row, wrapping around to the first one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % InputType::ALL.len();
+    }
+
+    /// Moves the selection to the previous binding row, wrapping around to the last one.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn previous(&mut self) {
+        self.selected = (self.selected + InputType::ALL.len() - 1) % InputType::ALL.len();
+    }
+
+    /// Checks if the menu waits for the key the selected [InputType] should be bound to.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_rebinding(&self) -> bool {
+        self.is_rebinding
+    }
+
+    /// Waits for the key the selected [InputType] should be bound to.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn start_rebinding(&mut self) {
+        self.is_rebinding = true;
+    }
+
+    /// Stops waiting for a key, once the selected [InputType] has been rebound.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn finish_rebinding(&mut self) {
+        self.is_rebinding = false;
+    }
+}
+
+impl Debug for SettingsMenu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> SettingsMenu {{ \
+        selected: {}, \
+        is_rebinding: {} \
+        }}",
+            self.selected, self.is_rebinding
+        )
+    }
+}
+
+impl Display for SettingsMenu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.selected, self.is_rebinding)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_wraps_around() {
+        let mut settings_menu = SettingsMenu::default();
+
+        assert_eq!(InputType::ALL[0], settings_menu.selected_input());
+
+        settings_menu.previous();
+        assert_eq!(
+            InputType::ALL[InputType::ALL.len() - 1],
+            settings_menu.selected_input()
+        );
+
+        settings_menu.next();
+        settings_menu.next();
+        assert_eq!(InputType::ALL[1], settings_menu.selected_input());
+    }
+}
@@ -0,0 +1,233 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::components::coord_2d::Coord2d;
+use crate::core::position_2d::Position2d;
+
+/// [Resource] tracking the state of the targeting mode, in which the player moves a cursor away from the `player`
+/// to aim at a tile, e.g., for a ranged attack. The cursor can't leave the square of the `range` around the
+/// origin.
+///
+/// While the targeting mode is active, the player can't act, i.e., the turn doesn't advance.
+///
+/// # Examples
+///
+/// ```
+/// let mut targeting_mode = TargetingMode::default();
+///
+/// targeting_mode.activate(&[5, 5], 2);
+/// targeting_mode.move_cursor([1, 0]);
+/// targeting_mode.move_cursor([1, 0]);
+/// targeting_mode.move_cursor([1, 0]); // Clamped to the range.
+///
+/// assert_eq!(Some([7, 5]), targeting_mode.cursor());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Resource)]
+pub struct TargetingMode {
+    /// (Private) The position the targeting line starts at, i.e., the position of the `player`.
+    origin: [i32; 2],
+    /// (Private) The aimed at position, [None] if the targeting mode is inactive.
+    cursor: Option<[i32; 2]>,
+    /// (Private) The maximum distance of the cursor from the origin along each axis.
+    range: i32,
+}
+
+impl TargetingMode {
+    /// Checks if the targeting mode is currently active.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_active(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// Activates the targeting mode with the cursor placed on the passed `origin`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The position the targeting line starts at, i.e., the position of the `player`.
+    /// * `range`: The maximum distance of the cursor from the `origin` along each axis.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn activate(&mut self, origin: &impl Position2d, range: i32) {
+        self.origin = origin.as_array();
+        self.cursor = Some(self.origin);
+        self.range = range;
+    }
+
+    /// Deactivates the targeting mode, removing the cursor.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn deactivate(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Moves the cursor a single tile in the direction of the passed `delta`, clamped to the `range` around the
+    /// origin. Diagonal moves step along both axes, each clamped on its own. Does nothing, if the targeting mode is
+    /// inactive.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta`: The direction to move the cursor in, e.g.,
+    ///   [crate::res::input_config::InputType::movement_delta].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn move_cursor(&mut self, delta: [i32; 2]) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+
+        let cursor = Coord2d::from_position(&cursor);
+        let [left, bottom] = self.origin.map(|coordinate| coordinate - self.range);
+        let [right, top] = self.origin.map(|coordinate| coordinate + self.range);
+
+        let cursor = match delta[0].signum() {
+            -1 => cursor.left(left),
+            1 => cursor.right(right),
+            _ => cursor,
+        };
+        let cursor = match delta[1].signum() {
+            -1 => cursor.down(bottom),
+            1 => cursor.up(top),
+            _ => cursor,
+        };
+
+        self.cursor = Some(cursor.as_array());
+    }
+
+    /// Returns the position the targeting line starts at.
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn origin(&self) -> [i32; 2] {
+        self.origin
+    }
+
+    /// Returns the aimed at position, [None] if the targeting mode is inactive.
+    ///
+    /// returns: [Option]<[i32; 2]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn cursor(&self) -> Option<[i32; 2]> {
+        self.cursor
+    }
+}
+
+impl Debug for TargetingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> TargetingMode {{ \
+        origin: {:?}, \
+        cursor: {:?}, \
+        range: {} \
+        }}",
+            self.origin, self.cursor, self.range
+        )
+    }
+}
+
+impl Display for TargetingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?}, {})", self.origin, self.cursor, self.range)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_moves_within_range() {
+        let mut targeting_mode = TargetingMode::default();
+
+        targeting_mode.move_cursor([1, 0]);
+        assert_eq!(None, targeting_mode.cursor());
+
+        targeting_mode.activate(&[5, 5], 2);
+        assert_eq!(Some([5, 5]), targeting_mode.cursor());
+
+        targeting_mode.move_cursor([1, 1]);
+        assert_eq!(Some([6, 6]), targeting_mode.cursor());
+
+        for _ in 0..3 {
+            targeting_mode.move_cursor([1, 0]);
+        }
+
+        assert_eq!(Some([7, 6]), targeting_mode.cursor());
+
+        for _ in 0..6 {
+            targeting_mode.move_cursor([-1, -1]);
+        }
+
+        assert_eq!(Some([3, 3]), targeting_mode.cursor());
+
+        targeting_mode.deactivate();
+        assert!(!targeting_mode.is_active());
+    }
+}
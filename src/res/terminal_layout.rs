@@ -0,0 +1,155 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+use crate::core::constants;
+use crate::core::dimension_2d::Dimension2d;
+use crate::ui::rectangle::Rectangle;
+
+/// [Resource] dividing the [bevy_ascii_terminal::Terminal] into panels, so each part of the game screen renders
+/// into its own area without overlapping the others:
+///
+/// ```text
+///
+/// +-------------------------------+----------+
+/// | Message log                              |
+/// +-------------------------------+----------+
+/// |                               |          |
+/// | Map                           | Sidebar  |
+/// |                               |          |
+/// +-------------------------------+----------+
+/// ```
+///
+/// The message log keeps the top [constants::MESSAGE_LOG_HEIGHT] rows, the sidebar the right most
+/// [constants::SIDEBAR_WIDTH] columns below it and the [crate::ui::viewport::Viewport] of the
+/// [crate::ui::game_map::GameMap] the remaining area.
+///
+/// # Properties
+///
+/// * `map`: The [Rectangle] the [crate::ui::game_map::GameMap] is rendered into.
+/// * `sidebar`: The [Rectangle] reserved for the `player's` stats, right of the `map`.
+///
+/// # Examples
+///
+/// ```
+/// let terminal_layout = TerminalLayout::new(&[100, 80]);
+///
+/// assert_eq!(Rectangle::new([0, 0], [80, 75]), terminal_layout.map);
+/// assert_eq!(Rectangle::new([80, 0], [20, 75]), terminal_layout.sidebar);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, PartialEq, Resource)]
+pub struct TerminalLayout {
+    /// The [Rectangle] the [crate::ui::game_map::GameMap] is rendered into.
+    pub map: Rectangle,
+    /// The [Rectangle] reserved for the `player's` stats, right of the `map`.
+    pub sidebar: Rectangle,
+}
+
+impl TerminalLayout {
+    /// Creates a new [TerminalLayout] dividing a [bevy_ascii_terminal::Terminal] of the passed size. The sidebar
+    /// never takes more than half of the width, so small terminals still leave room for the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminal_size`: The size of the [bevy_ascii_terminal::Terminal] in tiles.
+    ///
+    /// returns: [TerminalLayout]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let window_config = WindowConfig::default();
+    ///
+    /// commands.insert_resource(TerminalLayout::new(&window_config.terminal_size()));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(terminal_size: &impl Dimension2d) -> Self {
+        let height = (terminal_size.height() - constants::MESSAGE_LOG_HEIGHT).max(0);
+        let sidebar_width = constants::SIDEBAR_WIDTH.min(terminal_size.width() / 2);
+        let map_width = terminal_size.width() - sidebar_width;
+
+        Self {
+            map: Rectangle::new([0, 0], [map_width, height]),
+            sidebar: Rectangle::new([map_width, 0], [sidebar_width, height]),
+        }
+    }
+}
+
+impl Debug for TerminalLayout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> TerminalLayout {{ map: {:?}, sidebar: {:?} }}",
+            self.map, self.sidebar
+        )
+    }
+}
+
+impl Display for TerminalLayout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.map, self.sidebar)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_new_divides_terminal_into_map_and_sidebar() {
+        let terminal_layout = TerminalLayout::new(&[100, 80]);
+
+        assert_eq!(
+            [
+                100 - constants::SIDEBAR_WIDTH,
+                80 - constants::MESSAGE_LOG_HEIGHT
+            ],
+            [terminal_layout.map.width(), terminal_layout.map.height()]
+        );
+        assert_eq!(terminal_layout.map.right, terminal_layout.sidebar.left);
+        assert_eq!(100, terminal_layout.sidebar.right);
+        assert_eq!(terminal_layout.map.top, terminal_layout.sidebar.top);
+    }
+
+    #[test]
+    fn test_sidebar_never_takes_more_than_half_of_the_terminal() {
+        let terminal_layout = TerminalLayout::new(&[30, 20]);
+
+        assert_eq!(15, terminal_layout.map.width());
+        assert_eq!(15, terminal_layout.sidebar.width());
+    }
+}
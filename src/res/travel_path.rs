@@ -0,0 +1,137 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+/// [Resource] holding the remaining steps of the path the `player` travels along after clicking on a map tile. One
+/// step is taken per turn by the [crate::plugins::game_state_systems::input::travel_system], until the path is
+/// finished, blocked or interrupted by the user's input.
+///
+/// # Examples
+///
+/// ```
+/// let mut travel_path = TravelPath::default();
+///
+/// travel_path.set(vec![[1, 1], [2, 1], [3, 1]]);
+///
+/// assert_eq!(Some([2, 1]), travel_path.next_step()); // The current position is skipped.
+/// assert_eq!(Some([3, 1]), travel_path.next_step());
+/// assert_eq!(None, travel_path.next_step());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Default, Eq, PartialEq, Resource)]
+pub struct TravelPath {
+    /// (Private) The positions still to step onto, in the order they're visited.
+    steps: VecDeque<[i32; 2]>,
+}
+
+impl TravelPath {
+    /// Replaces the remaining steps with the passed `path`, whose first position is the current position of the
+    /// `player` and therefore skipped, e.g., a path returned by [crate::core::pathfinding::a_star].
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: The positions of the path, including the start and the goal.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn set(&mut self, path: Vec<[i32; 2]>) {
+        self.steps = path.into_iter().skip(1).collect();
+    }
+
+    /// Takes the next position to step onto from the path.
+    ///
+    /// returns: [Option]<[i32; 2]> - [None] if the path is finished.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn next_step(&mut self) -> Option<[i32; 2]> {
+        self.steps.pop_front()
+    }
+
+    /// Removes all remaining steps, stopping the travel.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn clear(&mut self) {
+        self.steps.clear();
+    }
+}
+
+impl Debug for TravelPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> TravelPath {{ steps: {:?} }}",
+            self.steps
+        )
+    }
+}
+
+impl Display for TravelPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.steps.len())
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_skip_start_and_keep_order() {
+        let mut travel_path = TravelPath::default();
+
+        travel_path.set(vec![[1, 1], [2, 2], [3, 2]]);
+
+        assert_eq!(Some([2, 2]), travel_path.next_step());
+        assert_eq!(Some([3, 2]), travel_path.next_step());
+        assert_eq!(None, travel_path.next_step());
+
+        travel_path.set(vec![[1, 1], [2, 2]]);
+        travel_path.clear();
+
+        assert_eq!(TravelPath::default(), travel_path);
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::Resource;
+
+/// [Resource] counting the turns which passed since the game started. A turn passes each time the
+/// `NPC entities` finished acting and control is given back to the `player`.
+///
+/// # Examples
+///
+/// ```
+/// let mut turn_counter = TurnCounter::default();
+///
+/// turn_counter.advance();
+///
+/// assert_eq!(1, turn_counter.current());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Default, Eq, PartialEq, Resource)]
+pub struct TurnCounter {
+    /// (Private) The number of turns which passed.
+    turn: u32,
+}
+
+impl TurnCounter {
+    /// Returns the current turn, starting at `0`.
+    ///
+    /// returns: u32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn current(&self) -> u32 {
+        self.turn
+    }
+
+    /// Advances the counter by a single turn.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn advance(&mut self) {
+        self.turn = self.turn.saturating_add(1);
+    }
+}
+
+impl Debug for TurnCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> TurnCounter {{ turn: {:?} }}",
+            self.turn
+        )
+    }
+}
+
+impl Display for TurnCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.turn)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_advance() {
+        let mut turn_counter = TurnCounter::default();
+
+        assert_eq!(0, turn_counter.current());
+
+        turn_counter.advance();
+        turn_counter.advance();
+
+        assert_eq!(2, turn_counter.current());
+    }
+}
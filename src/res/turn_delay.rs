@@ -0,0 +1,180 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+use bevy::prelude::{Resource, Timer, TimerMode};
+
+/// [Resource] enforcing a minimum delay between two processed turns of the `player`, configured through
+/// [crate::res::gameplay_config::GameplayConfig::turn_delay_ms]. The delay is restarted every time a turn
+/// passes, so held or rapid input can't advance the game faster than the configured pace.
+///
+/// # Examples
+///
+/// ```
+/// let mut turn_delay = TurnDelay::new(100);
+///
+/// assert!(turn_delay.is_elapsed()); // The first turn isn't delayed.
+///
+/// turn_delay.restart();
+/// turn_delay.tick(Duration::from_millis(60));
+///
+/// assert!(!turn_delay.is_elapsed());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Clone, Resource)]
+pub struct TurnDelay {
+    /// (Private) The timer measuring the time since the last turn passed.
+    timer: Timer,
+}
+
+impl TurnDelay {
+    /// Creates a new, already elapsed [TurnDelay] with the passed delay in milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay_ms`: The minimum number of milliseconds between two turns. A delay of `0` disables it.
+    ///
+    /// returns: [TurnDelay]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(delay_ms: u64) -> Self {
+        let delay = Duration::from_millis(delay_ms);
+        let mut timer = Timer::new(delay, TimerMode::Once);
+
+        timer.tick(delay);
+
+        Self { timer }
+    }
+
+    /// Advances the delay by the passed `delta` time.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta`: The time which passed since the last tick.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn tick(&mut self, delta: Duration) {
+        self.timer.tick(delta);
+    }
+
+    /// Restarts the delay, e.g., once a turn passed.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn restart(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Checks if the delay since the last restart has elapsed, which is always the case for a delay of `0`.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn is_elapsed(&self) -> bool {
+        self.timer.duration().is_zero() || self.timer.finished()
+    }
+}
+
+impl Default for TurnDelay {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Debug for TurnDelay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ECS -> Resources -> TurnDelay {{ timer: {:?} }}",
+            self.timer
+        )
+    }
+}
+
+impl Display for TurnDelay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({:?}, {:?})",
+            self.timer.elapsed(),
+            self.timer.duration()
+        )
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_elapses() {
+        let mut turn_delay = TurnDelay::new(100);
+
+        assert!(turn_delay.is_elapsed());
+
+        turn_delay.restart();
+        turn_delay.tick(Duration::from_millis(60));
+
+        assert!(!turn_delay.is_elapsed());
+
+        turn_delay.tick(Duration::from_millis(40));
+
+        assert!(turn_delay.is_elapsed());
+    }
+
+    #[test]
+    fn test_zero_delay_is_always_elapsed() {
+        let mut turn_delay = TurnDelay::default();
+
+        turn_delay.restart();
+
+        assert!(turn_delay.is_elapsed());
+    }
+}
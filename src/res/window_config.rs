@@ -19,11 +19,15 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
+use std::cmp::min;
 use std::fmt::{Display, Formatter};
 
+use bevy::log::warn;
 use bevy::prelude::Resource;
 use bevy::utils::default;
-use bevy::window::{MonitorSelection, Window, WindowPlugin, WindowPosition, WindowResolution};
+use bevy::window::{
+    MonitorSelection, Window, WindowMode, WindowPlugin, WindowPosition, WindowResolution,
+};
 use serde::Deserialize;
 
 use crate::core::constants;
@@ -44,6 +48,8 @@ use crate::res::config_file::ConfigFile;
 /// * `width`: The width of the [Window].
 /// * `height`: The height of the [Window].
 /// * `resizeable`: If the [Window] is resizable.
+/// * `fullscreen`: If the [Window] is displayed in borderless fullscreen mode. Optional in the
+///   configuration file and `false` by default.
 /// * `position`: _(Private)_ The monitor position of the resulting [Window] as an `i32` due to serialization
 /// constraints. See the [WindowConfig::get_position] function for the mapping table.
 ///
@@ -91,6 +97,9 @@ pub struct WindowConfig {
     pub height: i32,
     /// If the [Window] is resizable.
     pub resizeable: bool,
+    /// If the [Window] is displayed in borderless fullscreen mode.
+    #[serde(default)]
+    pub fullscreen: bool,
     /// The monitor position of the resulting [Window] as an `i32` due to serialization
     /// constraints. See the [WindowConfig::get_position] function for the mapping table.
     position: i32,
@@ -145,6 +154,27 @@ impl WindowConfig {
         }
     }
 
+    /// Maps the [WindowConfig::fullscreen] property to its respective bevy [WindowMode].
+    ///
+    /// returns: [WindowMode::BorderlessFullscreen] if `fullscreen` is set, otherwise [WindowMode::Windowed].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    /// * [WindowMode]
+    ///
+    pub fn window_mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
     /// Calculates the display dimension of the [bevy_ascii_terminal::Terminal]
     /// in the resulting [Window].
     ///
@@ -164,16 +194,73 @@ impl WindowConfig {
             self.height / constants::TILES_PER_PIXEL,
         ]
     }
+
+    /// Calculates the display dimension of the [bevy_ascii_terminal::Terminal] like
+    /// [WindowConfig::terminal_size], but clamped to the tiles fitting into the actually available
+    /// `window_size`. This prevents glyphs from overflowing the window, if the configured resolution
+    /// exceeds it. A warning is logged if the size had to be clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size`: The available size of the [Window] in pixels.
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let window_config = WindowConfig::new([800, 640], true, 2);
+    ///
+    /// assert_eq!([50, 40], window_config.clamped_terminal_size(&[400, 320]));
+    /// assert_eq!([100, 80], window_config.clamped_terminal_size(&[1920, 1080]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    /// * [WindowConfig::terminal_size]
+    /// * [constants::TILES_PER_PIXEL]
+    ///
+    pub fn clamped_terminal_size(&self, window_size: &impl Dimension2d) -> [i32; 2] {
+        let terminal_size = self.terminal_size();
+
+        let clamped_size = [
+            min(
+                terminal_size.width(),
+                window_size.width() / constants::TILES_PER_PIXEL,
+            ),
+            min(
+                terminal_size.height(),
+                window_size.height() / constants::TILES_PER_PIXEL,
+            ),
+        ];
+
+        if clamped_size != terminal_size.as_array() {
+            warn!(
+                "Configured terminal size {:?} exceeds the window size {:?}, clamping it to {:?}.",
+                terminal_size.as_array(),
+                window_size.as_array(),
+                clamped_size
+            );
+        }
+
+        clamped_size
+    }
 }
 
 impl Display for WindowConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "({}, {}, {}, {:?})",
+            "({}, {}, {}, {}, {:?})",
             self.width,
             self.height,
             self.resizeable,
+            self.fullscreen,
             self.get_position()
         )
     }
@@ -187,6 +274,7 @@ impl PluginProvider<WindowPlugin> for WindowConfig {
                 resolution: WindowResolution::new(self.width as f32, self.height as f32),
                 resizable: self.resizeable,
                 position: self.get_position(),
+                mode: self.window_mode(),
                 fit_canvas_to_parent: true,
                 ..default()
             }),
@@ -211,6 +299,7 @@ mod unit_tests {
                 width: dimension.width(),
                 height: dimension.height(),
                 resizeable,
+                fullscreen: false,
                 position,
             }
         }
@@ -246,6 +335,40 @@ mod unit_tests {
         )
     }
 
+    #[test]
+    fn test_terminal_size_is_clamped_to_small_window() {
+        assert_eq!(
+            [50, 40],
+            WindowConfig::new([800, 640], false, 0).clamped_terminal_size(&[400, 320])
+        );
+        assert_eq!(
+            [100, 40],
+            WindowConfig::new([800, 640], false, 0).clamped_terminal_size(&[1024, 320])
+        );
+    }
+
+    #[test]
+    fn test_large_configured_resolution_is_clamped() {
+        assert_eq!(
+            [160, 90],
+            WindowConfig::new([7680, 4320], false, 0).clamped_terminal_size(&[1280, 720])
+        );
+    }
+
+    #[test]
+    fn test_terminal_size_fitting_window_is_kept() {
+        let window_config = WindowConfig::new([800, 640], false, 0);
+
+        assert_eq!(
+            window_config.terminal_size().as_array(),
+            window_config.clamped_terminal_size(&[800, 640])
+        );
+        assert_eq!(
+            window_config.terminal_size().as_array(),
+            window_config.clamped_terminal_size(&[1920, 1080])
+        );
+    }
+
     #[test]
     fn test_plugin_provision() {
         let window_config = WindowConfig::new([800, 640], false, 0);
@@ -259,9 +382,28 @@ mod unit_tests {
         );
         assert_eq!(window_config.resizeable, primary_window.resizable);
         assert_eq!(window_config.get_position(), primary_window.position);
+        assert_eq!(WindowMode::Windowed, primary_window.mode);
         assert_eq!(true, primary_window.fit_canvas_to_parent);
     }
 
+    #[test]
+    fn test_window_mode_mapping() {
+        let mut window_config = WindowConfig::new([800, 640], false, 0);
+
+        assert_eq!(WindowMode::Windowed, window_config.window_mode());
+
+        window_config.fullscreen = true;
+
+        assert_eq!(
+            WindowMode::BorderlessFullscreen,
+            window_config.window_mode()
+        );
+        assert_eq!(
+            WindowMode::BorderlessFullscreen,
+            window_config.provide_plugin().primary_window.unwrap().mode
+        );
+    }
+
     #[test]
     fn test_config_file_name() {
         assert_eq!("window.json", WindowConfig::file_name());
@@ -51,3 +51,379 @@ pub const BACKGROUND: Color = Color::BLACK;
 /// Since: `0.1.8`
 ///
 pub const INACTIVE: Color = Color::DARK_GRAY;
+
+/// The color of [crate::res::message_log::LogCategory::Combat] messages.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_COMBAT: Color = Color::RED;
+
+/// The color of [crate::res::message_log::LogCategory::Info] messages.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_INFO: Color = Color::WHITE;
+
+/// The color of [crate::res::message_log::LogCategory::Warning] messages.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_WARNING: Color = Color::YELLOW;
+
+/// The color of [crate::res::message_log::LogCategory::LevelUp] messages.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_LEVEL_UP: Color = Color::GOLD;
+
+/// The color of [crate::res::message_log::LogCategory::Death] messages.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const LOG_DEATH: Color = Color::CRIMSON;
+
+/// The background color highlighting the `entity` selected in the examine mode.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const EXAMINE_CURSOR: Color = Color::TEAL;
+
+/// The background color of the tiles along the targeting line, which a ranged action can reach.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const TARGET_VALID: Color = Color::DARK_GREEN;
+
+/// The background color of the tiles along the targeting line, which are out of sight or behind an obstacle.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const TARGET_INVALID: Color = Color::MAROON;
+
+/// The background color a tile flashes in when the `entity` on it is hit.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const HIT_FLASH: Color = Color::ORANGE_RED;
+
+/// The default color of [crate::components::faction::Faction::Player] entities.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FACTION_PLAYER: Color = Color::ORANGE;
+
+/// The default color of [crate::components::faction::Faction::Hostile] entities.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FACTION_HOSTILE: Color = Color::YELLOW;
+
+/// The default color of [crate::components::faction::Faction::Friendly] entities.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FACTION_FRIENDLY: Color = Color::LIME_GREEN;
+
+/// The default color of [crate::components::faction::Faction::Neutral] entities.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FACTION_NEUTRAL: Color = Color::SILVER;
+
+/// The default color of [crate::components::faction::Faction::Item] entities.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const FACTION_ITEM: Color = Color::PURPLE;
+
+/// The color of the HUD elements, e.g., the [crate::res::cursor_hud::CursorHud].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const HUD: Color = Color::SILVER;
+
+/// The color of the selected option of a menu, e.g., the [crate::res::settings_menu::SettingsMenu].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MENU_SELECTION: Color = Color::GOLD;
+
+/// The color of the border framing a menu, e.g., the [crate::res::main_menu::MainMenu].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MENU_BORDER: Color = Color::GRAY;
+
+/// The color of door tiles on the [crate::ui::game_map::GameMap], while visible.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_DOOR: Color = Color::ORANGE;
+
+/// The color of water tiles on the [crate::ui::game_map::GameMap], while visible.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_WATER: Color = Color::BLUE;
+
+/// The background colors visible water tiles on the [crate::ui::game_map::GameMap] alternate between, so the
+/// water ripples.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_WATER_BACKGROUNDS: [Color; 2] = [Color::MIDNIGHT_BLUE, Color::NAVY];
+
+/// The color of stairs tiles on the [crate::ui::game_map::GameMap], while visible.
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub const MAP_STAIRS: Color = Color::GOLD;
+
+/// The saturation of the colors derived through [name_color].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+const NAME_COLOR_SATURATION: f32 = 0.65;
+
+/// The lightness of the colors derived through [name_color], bright enough to be readable on the
+/// [BACKGROUND].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+const NAME_COLOR_LIGHTNESS: f32 = 0.6;
+
+/// Derives a stable color from the passed `name`, e.g., the one of a monster, by hashing it onto the hue of
+/// the color. New monsters thereby get distinct, but consistent colors without any configuration.
+///
+/// The name is hashed with the FNV-1a algorithm, so the color stays the same across builds and platforms.
+///
+/// # Arguments
+///
+/// * `name`: The name to derive the color from.
+///
+/// returns: [Color]
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(name_color("Mended"), name_color("Mended"));
+/// assert_ne!(name_color("Mended"), name_color("Goblin"));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn name_color(name: &str) -> Color {
+    let hash = name.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    Color::hsl(
+        (hash % 360) as f32,
+        NAME_COLOR_SATURATION,
+        NAME_COLOR_LIGHTNESS,
+    )
+}
+
+/// Fades the passed `color` towards the [BACKGROUND] color by the given `factor`, e.g., to darken
+/// tiles the `player` hasn't seen in a while.
+///
+/// # Arguments
+///
+/// * `color`: The [Color] to fade.
+/// * `factor`: How far to fade the `color`, from `0.0` (unchanged) to `1.0` (the [BACKGROUND] color).
+///
+/// returns: [Color]
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(INACTIVE, fade(INACTIVE, 0.0));
+/// assert_eq!(BACKGROUND, fade(INACTIVE, 1.0));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn fade(color: Color, factor: f32) -> Color {
+    blend(color, BACKGROUND, factor)
+}
+
+/// Blends the passed `color` towards the `target` color by the given `factor`, keeping the alpha of the
+/// `color`, e.g., to let older messages of the message log recede into the [INACTIVE] color.
+///
+/// # Arguments
+///
+/// * `color`: The [Color] to blend.
+/// * `target`: The [Color] to blend the `color` towards.
+/// * `factor`: How far to blend the `color`, from `0.0` (unchanged) to `1.0` (the `target` color).
+///
+/// returns: [Color]
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(LOG_INFO, blend(LOG_INFO, INACTIVE, 0.0));
+/// assert_eq!(INACTIVE, blend(LOG_INFO, INACTIVE, 1.0));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub fn blend(color: Color, target: Color, factor: f32) -> Color {
+    let factor = factor.clamp(0.0, 1.0);
+    let [red, green, blue, alpha] = color.as_rgba_f32();
+    let [target_red, target_green, target_blue, _] = target.as_rgba_f32();
+
+    Color::rgba(
+        red + (target_red - red) * factor,
+        green + (target_green - green) * factor,
+        blue + (target_blue - blue) * factor,
+        alpha,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_color_is_stable() {
+        assert_eq!(name_color("Mended"), name_color("Mended"));
+        assert_eq!(name_color(""), name_color(""));
+    }
+
+    #[test]
+    fn test_name_color_differs_between_names() {
+        let names = ["Mended", "Goblin", "Rat", "Orc", "Wraith", "Mended Brute"];
+        let colors: Vec<Color> = names.iter().map(|name| name_color(name)).collect();
+
+        for (index, color) in colors.iter().enumerate() {
+            assert!(
+                !colors[index + 1..].contains(color),
+                "{} shares its color",
+                names[index]
+            );
+        }
+    }
+
+    #[test]
+    fn test_fade() {
+        assert_eq!(INACTIVE, fade(INACTIVE, 0.0));
+        assert_eq!(BACKGROUND, fade(INACTIVE, 1.0));
+        assert_eq!(BACKGROUND, fade(INACTIVE, 2.0));
+        assert!(fade(INACTIVE, 0.5).r() < INACTIVE.r());
+    }
+
+    #[test]
+    fn test_blend() {
+        assert_eq!(LOG_INFO, blend(LOG_INFO, INACTIVE, 0.0));
+        assert_eq!(INACTIVE, blend(LOG_INFO, INACTIVE, 1.0));
+        assert_eq!(INACTIVE, blend(LOG_INFO, INACTIVE, 1.5));
+
+        let halfway = blend(LOG_INFO, INACTIVE, 0.5);
+
+        assert!(halfway.r() < LOG_INFO.r() && halfway.r() > INACTIVE.r());
+    }
+}
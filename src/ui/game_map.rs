@@ -19,25 +19,42 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
 
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Mut};
+use bevy_ascii_terminal::{Terminal, TileFormatter};
 
+use crate::core::constants;
 use crate::core::dimension_2d::Dimension2d;
+use crate::core::grid::Grid;
 use crate::core::position_2d::Position2d;
-use crate::ui::rectangle::Rectangle;
+use crate::core::rng::RandomNumberGenerator;
+use crate::ui::colors;
+use crate::ui::room::Room;
 use crate::ui::tile::{MapTile, Tile};
-use crate::ui::tile_map::TileMap;
+use crate::ui::tile_map::{RenderOverride, TileMap};
 use crate::ui::tile_map_layout_generator::TileMapLayoutGenerator;
+use crate::ui::viewport::Viewport;
 
 /// A map making up a level of the game, which the `player` can traverse and explore.
 ///
-/// It is made up of a linear vector of tiles in which the different `entities` of the reside in.
+/// It is made up of a [Grid] of tiles in which the different `entities` of the reside in.
+///
+/// The tile layout is shared behind an [Arc] and only copied once it's altered, while the per-run overlays,
+/// i.e., the seen, visible and last seen tiles, are owned by each instance. Cloning a map, e.g., for a
+/// snapshot, is therefore cheap even for large maps.
+///
+/// Next to the overlays, the positions of the seen and visible tiles are tracked, so rendering only visits the
+/// explored part of the map, see [GameMap::render_positions].
 ///
 /// # Properties
 ///
 /// * `width`: The real width of the map.
 /// * `height`: The real height of the map.
+/// * `render_overrides`: [RenderOverride]s keyed by position, which are rendered in place of the
+///   respective tiles, e.g., for special effects.
 ///
 /// # Examples
 ///
@@ -51,7 +68,9 @@ use crate::ui::tile_map_layout_generator::TileMapLayoutGenerator;
 ///
 ///     terminal.clear();
 ///
-///     game_map_query.single().render(&mut terminal);
+///     let viewport = Viewport::new(&Rectangle::new([0, 0], [80, 50]));
+///
+///     game_map_query.single().render(&mut terminal, &viewport, true);
 /// }
 /// ```
 ///
@@ -67,22 +86,41 @@ pub struct GameMap {
     pub width: i32,
     /// The real height of the map.
     pub height: i32,
-    /// (Package-Private) List of all rooms on the map in form of [Rectangle]s.
-    pub(super) rooms: Vec<Rectangle>,
-    /// (Package-Private) List of all tiles which make up the map as a linear vector.
-    pub(super) tiles: Vec<MapTile>,
-    /// (Package-Private) List of all tiles which the player has seen before, e.g., which were in his FOV at least once.
-    pub(super) seen_tiles: Vec<bool>,
-    /// (Package-Private) List of all tiles which the player currently sees, as defined by their FOV.
-    pub(super) visible_tiles: Vec<bool>,
+    /// [RenderOverride]s keyed by position, which are rendered in place of the respective tiles, e.g.,
+    /// for special effects.
+    pub render_overrides: HashMap<(i32, i32), RenderOverride>,
+    /// (Package-Private) List of all [Room]s on the map.
+    pub(super) rooms: Vec<Room>,
+    /// (Package-Private) [Grid] of all tiles which make up the map, shared between clones until it's altered.
+    pub(super) tiles: Arc<Grid<MapTile>>,
+    /// (Package-Private) [Grid] of all tiles which the player has seen before, e.g., which were in his FOV at least
+    /// once.
+    pub(super) seen_tiles: Grid<bool>,
+    /// (Package-Private) [Grid] of all tiles which the player currently sees, as defined by their FOV.
+    pub(super) visible_tiles: Grid<bool>,
+    /// (Package-Private) Positions of all tiles marked as seen, in the order they were first seen. Lets the
+    /// rendering skip the unexplored parts of the map.
+    pub(super) seen_positions: Vec<[i32; 2]>,
+    /// (Package-Private) Positions of all tiles currently marked as visible.
+    pub(super) visible_positions: Vec<[i32; 2]>,
+    /// (Package-Private) [Grid] of the turns in which the player has seen each tile last.
+    pub(super) last_seen_turns: Grid<u32>,
+    /// (Package-Private) The fingerprint of the current layout, see [TileMap::fingerprint].
+    pub(super) fingerprint: u64,
+    /// (Package-Private) The seed the layout was generated from, see [GameMap::seed].
+    pub(super) seed: u64,
 }
 
 impl GameMap {
-    /// Creates a new [GameMap] instance with the passed `dimension`.
+    /// Creates a new [GameMap] instance with the passed `dimension`, whose layout is created by the passed
+    /// `generator` from the passed `seed`. Without a `seed`, a random one is picked, which is recorded as well, so
+    /// the layout can be reproduced through [GameMap::seed].
     ///
     /// # Arguments
     ///
     /// * `dimension`: The [Dimension2d] with which the map should be created.
+    /// * `generator`: The [TileMapLayoutGenerator] creating the layout of the map.
+    /// * `seed`: The seed to generate the layout from, if any.
     ///
     /// returns: [GameMap]
     ///
@@ -90,7 +128,7 @@ impl GameMap {
     ///
     /// ```
     /// fn startup_system(mut commands: Commands) {
-    ///    commands.spawn(GameMap::new([80, 50]));
+    ///    commands.spawn(GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(42)));
     /// }
     /// ```
     ///
@@ -100,30 +138,67 @@ impl GameMap {
     ///
     /// Since: `0.1.5`
     ///
-    pub fn new(dimension: &impl Dimension2d, generator: &impl TileMapLayoutGenerator) -> Self {
-        let width = dimension.width();
-        let height = dimension.height();
-        let area = dimension.area();
-
+    pub fn new(
+        dimension: &impl Dimension2d,
+        generator: &(impl TileMapLayoutGenerator + ?Sized),
+        seed: Option<u64>,
+    ) -> Self {
         let mut map = Self {
-            width,
-            height,
+            width: dimension.width(),
+            height: dimension.height(),
+            render_overrides: HashMap::new(),
             rooms: Vec::new(),
-            tiles: vec![MapTile::default(); area],
-            seen_tiles: vec![false; area],
-            visible_tiles: vec![false; area],
+            tiles: Arc::new(Grid::new(dimension, MapTile::default())),
+            seen_tiles: Grid::new(dimension, false),
+            visible_tiles: Grid::new(dimension, false),
+            seen_positions: Vec::new(),
+            visible_positions: Vec::new(),
+            last_seen_turns: Grid::new(dimension, 0),
+            fingerprint: 0,
+            seed: seed.unwrap_or_else(|| RandomNumberGenerator::new().range(0..=u64::MAX)),
         };
 
         generator.generate_layout(&mut map);
+        map.refresh_fingerprint();
+
+        debug_assert_eq!(
+            Ok(()),
+            map.validate(),
+            "ECS -> Components -> GameMap -> Generated map is invalid!"
+        );
 
         map
     }
 
-    /// Returns an immutable [Vec] reference containing all the rooms on the map as [Rectangle] instances.
+    /// Returns the seed the layout of the map was generated from. Passing it to [GameMap::new] together with the
+    /// same [TileMapLayoutGenerator] and dimension reproduces the layout.
+    ///
+    /// returns: u64
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    /// let copy = GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(map.seed()));
+    ///
+    /// assert_eq!(map.fingerprint(), copy.fingerprint());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns an immutable [Vec] reference containing all the [Room]s on the map.
     ///
     /// # Arguments
     ///
-    /// returns: &[Vec]<[Rectangle]>
+    /// returns: &[Vec]<[Room]>
     ///
     /// # Examples
     ///
@@ -143,9 +218,655 @@ impl GameMap {
     ///
     /// Since: `0.1.8`
     ///
-    pub fn rooms(&self) -> &Vec<Rectangle> {
+    pub fn rooms(&self) -> &Vec<Room> {
         &self.rooms
     }
+
+    /// Returns all positions on the map which can be walked on, i.e., whose tiles have no collision.
+    ///
+    /// # Arguments
+    ///
+    /// returns: [Vec]<[i32; 2]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new([80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// for position in map.walkable_positions() {
+    ///     assert!(!map.tile_has_collision(&position));
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn walkable_positions(&self) -> Vec<[i32; 2]> {
+        self.tiles
+            .iter_positions()
+            .filter(|position| !self.tile_has_collision(position))
+            .collect()
+    }
+
+    /// Returns the walkable [Position2d::cardinal_neighbors] of the passed `position`, i.e., those lying on the map
+    /// without collision.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position whose neighbors to return.
+    ///
+    /// returns: [Vec]<[i32; 2]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// for neighbor in map.walkable_neighbors(&map.starting_position()) {
+    ///     assert!(!map.tile_has_collision(&neighbor));
+    /// }
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn walkable_neighbors(&self, position: &impl Position2d) -> Vec<[i32; 2]> {
+        position
+            .cardinal_neighbors()
+            .into_iter()
+            .filter(|neighbor| self.is_walkable(neighbor))
+            .collect()
+    }
+
+    /// Returns the position the `player` starts at by default, which is the center of the first room
+    /// or the center of the map, if it has no rooms.
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn start_position(&self) -> [i32; 2] {
+        self.rooms
+            .first()
+            .map_or_else(|| self.center(), |room| room.center())
+    }
+
+    /// Returns the position the `player` is spawned at, which is the center of the first room, if it's walkable.
+    /// Otherwise, e.g., for maps without rooms, the walkable tile closest to the center of the map is picked.
+    ///
+    /// In contrast to [GameMap::start_position], the returned position is always walkable.
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # Panics
+    ///
+    /// * If the map has no walkable tile at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// assert_eq!(map.rooms()[0].center(), map.starting_position());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn starting_position(&self) -> [i32; 2] {
+        let center = self.center();
+
+        self.rooms
+            .first()
+            .map(|room| room.center())
+            .filter(|position| self.is_walkable(position))
+            .or_else(|| {
+                self.walkable_positions()
+                    .into_iter()
+                    .min_by_key(|position| {
+                        let [x_delta, y_delta] = position.delta(&center);
+                        (x_delta * x_delta) + (y_delta * y_delta)
+                    })
+            })
+            .expect("ECS -> Components -> GameMap -> The map has no walkable tile to start on!")
+    }
+
+    /// Picks a random walkable position of the map using the passed `rng`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng`: The [RandomNumberGenerator] picking the position.
+    ///
+    /// returns: [Option]<[i32; 2]> - The picked position or [None], if the map has no walkable tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    /// let position = map.random_floor_position(&mut RandomNumberGenerator::new()).unwrap();
+    ///
+    /// assert!(!map.tile_has_collision(&position));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn random_floor_position(&self, rng: &mut RandomNumberGenerator) -> Option<[i32; 2]> {
+        let walkable_positions = self.walkable_positions();
+
+        if walkable_positions.is_empty() {
+            None
+        } else {
+            Some(walkable_positions[rng.range(0..walkable_positions.len())])
+        }
+    }
+
+    /// Decorates the large rooms of the map by randomly turning a share of their
+    /// [Room::decoration_positions] into pillars, i.e., wall tiles, [MapTile::rubble] or puddles of
+    /// [MapTile::water]. As pillars are never placed next to each other or the outline of a room, every room
+    /// stays walkable from its center to all of its doorways. The decorations are derived from the [GameMap::seed], so a replayed map is decorated alike.
+    ///
+    /// # Arguments
+    ///
+    /// * `density`: The share of the decoration positions of each room to decorate, between `0.0` and `1.0`.
+    /// * `reserved`: Positions which must not be decorated, e.g., the spawn positions of `entities`.
+    ///
+    /// returns: usize - The number of decorated tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    /// let decorations = map.decorate_rooms(0.1, &[map.start_position()]);
+    ///
+    /// assert_eq!(Ok(()), map.validate());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn decorate_rooms(&mut self, density: f32, reserved: &[[i32; 2]]) -> usize {
+        let mut rng = RandomNumberGenerator::seeded(self.seed);
+        let mut decoration_count = 0;
+
+        for room in self.rooms.clone() {
+            let mut candidates: Vec<[i32; 2]> = room
+                .decoration_positions()
+                .into_iter()
+                .filter(|position| !reserved.contains(position))
+                .collect();
+
+            let target = (candidates.len() as f32 * density.clamp(0.0, 1.0)).round() as usize;
+            let mut pillars: Vec<[i32; 2]> = Vec::new();
+
+            for _ in 0..target {
+                let position = candidates.swap_remove(rng.range(0..candidates.len()));
+
+                // A pillar next to another one could close off a path, so it's turned into rubble instead.
+                let is_pillar = |position: &[i32; 2]| {
+                    pillars
+                        .iter()
+                        .all(|pillar| pillar.chebyshev_distance(position) > 1)
+                };
+
+                match rng.range(0..4) {
+                    0 | 1 if is_pillar(&position) => {
+                        pillars.push(position);
+                        self.set_tile_at(&position, MapTile::default());
+                    }
+                    3 => self.set_tile_at(&position, MapTile::water()),
+                    _ => self.set_tile_at(&position, MapTile::rubble()),
+                }
+            }
+
+            decoration_count += target;
+        }
+
+        self.refresh_fingerprint();
+
+        decoration_count
+    }
+
+    /// Checks the invariants every generated map has to uphold, in order to catch broken
+    /// [TileMapLayoutGenerator]s early:
+    ///
+    /// * The number of tiles equals the area of the map.
+    /// * There is at least one floor tile.
+    /// * The [GameMap::start_position] is a floor tile, from which all rooms can be reached.
+    /// * No room lies outside the bounds of the map.
+    ///
+    /// returns: [Result]<(), [Vec]<[MapError]>> - All violated invariants, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new([80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// assert_eq!(Ok(()), map.validate());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn validate(&self) -> Result<(), Vec<MapError>> {
+        if self.tiles.cells().len() != self.area() {
+            return Err(vec![MapError::TileCountMismatch {
+                expected: self.area(),
+                actual: self.tiles.cells().len(),
+            }]);
+        }
+
+        let mut errors = Vec::new();
+
+        if self.tiles.cells().iter().all(|tile| tile.has_collision()) {
+            errors.push(MapError::NoFloorTiles);
+        }
+
+        for (index, room) in self.rooms.iter().enumerate() {
+            if room.bounds.left < 0
+                || room.bounds.bottom < 0
+                || room.bounds.right >= self.width
+                || room.bounds.top >= self.height
+            {
+                errors.push(MapError::RoomOutOfBounds(index));
+            }
+        }
+
+        let start = self.start_position();
+
+        if !self.is_walkable(&start) {
+            errors.push(MapError::UnreachableStart(start));
+        } else {
+            let reachable = self.reachable_tiles(&start);
+
+            for (index, room) in self.rooms.iter().enumerate() {
+                let center = room.center();
+
+                if !self.is_walkable(&center) || reachable.get(&center) != Some(&true) {
+                    errors.push(MapError::UnreachableRoom(index));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// (Private) Checks if the passed `position` lies on the map and its tile has no collision.
+    fn is_walkable(&self, position: &[i32; 2]) -> bool {
+        self.tiles.in_bounds(position) && !self.tile_has_collision(position)
+    }
+
+    /// (Package-Private) Flood fills the walkable tiles orthogonally connected to the passed `start`,
+    /// returning whether each tile of the map was reached.
+    pub(super) fn reachable_tiles(&self, start: &[i32; 2]) -> Grid<bool> {
+        let mut reachable = Grid::new(self, false);
+        let mut frontier = VecDeque::from([*start]);
+
+        reachable.set(start, true);
+
+        while let Some(position) = frontier.pop_front() {
+            for neighbour in self.walkable_neighbors(&position) {
+                if reachable.get(&neighbour) == Some(&false) {
+                    reachable.set(&neighbour, true);
+                    frontier.push_back(neighbour);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Records the passed `turn` as the one in which the `player` has seen the tile at the passed `index` last.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The [Position2d] based index of the tile to record.
+    /// * `turn`: The current turn, see [crate::res::turn_counter::TurnCounter].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn remember_tile(&mut self, index: &impl Position2d, turn: u32) {
+        self.last_seen_turns.set(index, turn);
+    }
+
+    /// Marks all tiles of the room containing the passed `position`, including its bordering walls, as seen in
+    /// the passed `turn`. The room is detected through [Rectangle::contains] against the bounds of the
+    /// [GameMap::rooms].
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] inside the room to reveal, e.g., the one of the `player`.
+    /// * `turn`: The current turn, see [crate::res::turn_counter::TurnCounter].
+    ///
+    /// returns: bool - `true` if the `position` lies inside a room, which was revealed, and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    /// let room = map.rooms()[0].bounds;
+    ///
+    /// map.reveal_room_at(&room.center(), 0);
+    ///
+    /// assert!(map.is_tile_seen(&[room.left, room.bottom]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn reveal_room_at(&mut self, position: &impl Position2d, turn: u32) -> bool {
+        let Some(bounds) = self
+            .rooms
+            .iter()
+            .find(|room| room.bounds.contains(position))
+            .map(|room| room.bounds)
+        else {
+            return false;
+        };
+
+        for x in bounds.left..=bounds.right {
+            for y in bounds.bottom..=bounds.top {
+                self.mark_tile_as_seen(&[x, y]);
+                self.remember_tile(&[x, y], turn);
+            }
+        }
+
+        true
+    }
+
+    /// Marks every tile of the map as seen in the passed `turn`, e.g., when the `player` reads a scroll of mapping.
+    /// The visible tiles are left untouched, so the revealed tiles are rendered as remembered ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn`: The current turn, see [crate::res::turn_counter::TurnCounter].
+    ///
+    /// returns: ()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// map.reveal_all(0);
+    ///
+    /// assert!(map.is_tile_seen(&[79, 49]));
+    /// assert!(!map.is_tile_visible(&[79, 49]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn reveal_all(&mut self, turn: u32) {
+        let positions: Vec<[i32; 2]> = self.seen_tiles.iter_positions().collect();
+
+        for position in positions {
+            self.mark_tile_as_seen(&position);
+            self.remember_tile(&position, turn);
+        }
+    }
+
+    /// Calculates by how much the tile at the passed `index` has faded from the `player's` memory, based on
+    /// the number of turns since it was seen last. The fade grows linearly over
+    /// [constants::SIGHT_MEMORY_FADE_TURNS] up to [constants::SIGHT_MEMORY_MAX_FADE].
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The [Position2d] based index of the tile to check.
+    /// * `current_turn`: The current turn, see [crate::res::turn_counter::TurnCounter].
+    ///
+    /// returns: f32 - The fade factor, which is `0.0` for visible or never seen tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// map.mark_tile_as_seen(&[1, 1]);
+    /// map.remember_tile(&[1, 1], 0);
+    ///
+    /// assert_eq!(constants::SIGHT_MEMORY_MAX_FADE, map.memory_fade(&[1, 1], constants::SIGHT_MEMORY_FADE_TURNS));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn memory_fade(&self, index: &impl Position2d, current_turn: u32) -> f32 {
+        if !self.is_tile_seen(index) || self.is_tile_visible(index) {
+            return 0.0;
+        }
+
+        let last_seen_turn = self.last_seen_turns.get(index).copied().unwrap_or_default();
+        let age = current_turn
+            .saturating_sub(last_seen_turn)
+            .min(constants::SIGHT_MEMORY_FADE_TURNS);
+
+        constants::SIGHT_MEMORY_MAX_FADE * age as f32 / constants::SIGHT_MEMORY_FADE_TURNS as f32
+    }
+
+    /// Re-renders all remembered tiles, which are out of the `player's` sight, faded according to
+    /// [GameMap::memory_fade]. Has to be called after [TileMap::render]. Positions with a [RenderOverride]
+    /// are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminal`: [Terminal] which handles the actual rendering.
+    /// * `viewport`: The [Viewport] selecting the rendered section of the map, see [TileMap::render].
+    /// * `current_turn`: The current turn, see [crate::res::turn_counter::TurnCounter].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn render_memory_fade(
+        &self,
+        terminal: &mut Mut<Terminal>,
+        viewport: &Viewport,
+        current_turn: u32,
+    ) {
+        for position in &self.seen_positions {
+            let fade = self.memory_fade(position, current_turn);
+
+            if fade <= 0.0 || self.render_override(position).is_some() {
+                continue;
+            }
+
+            let Some(screen_position) = viewport.world_to_screen(position) else {
+                continue;
+            };
+
+            let tile = self.get_tile_at(position);
+
+            terminal.put_char(
+                screen_position,
+                tile.glyph()
+                    .fg(colors::fade(tile.foreground_color(true, false), fade))
+                    .bg(tile.background_color(true, false)),
+            );
+        }
+    }
+
+    /// Re-renders all visible tiles with an animated background, see [Tile::animated_background], on top of the
+    /// output of [TileMap::render]. Positions with a [RenderOverride] are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminal`: [Terminal] which handles the actual rendering.
+    /// * `viewport`: The [Viewport] selecting the rendered section of the map, see [TileMap::render].
+    /// * `tick`: The current animation tick, see [constants::TILE_ANIMATION_TICK_MS].
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn render_animated_backgrounds(
+        &self,
+        terminal: &mut Mut<Terminal>,
+        viewport: &Viewport,
+        tick: u64,
+    ) {
+        for position in &self.visible_positions {
+            if self.render_override(position).is_some() {
+                continue;
+            }
+
+            let Some(screen_position) = viewport.world_to_screen(position) else {
+                continue;
+            };
+
+            let tile = self.get_tile_at(position);
+
+            if let Some(background) = tile.animated_background(tick, true) {
+                terminal.put_char(
+                    screen_position,
+                    tile.glyph()
+                        .fg(tile.foreground_color(true, true))
+                        .bg(background),
+                );
+            }
+        }
+    }
+
+    /// Returns the positions [TileMap::render] has to consider, which are all seen or visible tiles and the
+    /// positions of the [GameMap::render_overrides]. All other tiles are unexplored and therefore left blank, so
+    /// rendering a mostly unexplored map only touches a fraction of its tiles.
+    ///
+    /// returns: impl [Iterator]<Item = [i32; 2]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+    ///
+    /// map.mark_tile_as_seen(&[1, 1]);
+    ///
+    /// assert_eq!(vec![[1, 1]], map.render_positions().collect::<Vec<[i32; 2]>>());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn render_positions(&self) -> impl Iterator<Item = [i32; 2]> + '_ {
+        let unseen_visible_positions = self
+            .visible_positions
+            .iter()
+            .filter(|position| !self.is_tile_seen(*position));
+        let unexplored_override_positions = self
+            .render_overrides
+            .keys()
+            .map(|&(x, y)| [x, y])
+            .filter(|position| !self.is_tile_seen(position) && !self.is_tile_visible(position));
+
+        self.seen_positions
+            .iter()
+            .chain(unseen_visible_positions)
+            .copied()
+            .chain(unexplored_override_positions)
+    }
+
+    /// Recalculates the cached fingerprint of the map. Has to be called after the layout of the map
+    /// was altered, which is done in response to the [crate::events::map_changed::MapChanged] event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = GameMap::new([80, 50], &BaseTileMapGenerator, None);
+    /// let fingerprint = map.fingerprint();
+    ///
+    /// map.set_tile_at(&[1, 1], MapTile::default());
+    /// map.refresh_fingerprint();
+    ///
+    /// assert_ne!(fingerprint, map.fingerprint());
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn refresh_fingerprint(&mut self) {
+        self.fingerprint = self.calculate_fingerprint();
+    }
+}
+
+/// An invariant violated by a [GameMap], as reported by [GameMap::validate].
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MapError {
+    /// The number of tiles doesn't match the area of the map.
+    TileCountMismatch {
+        /// The area of the map.
+        expected: usize,
+        /// The actual number of tiles.
+        actual: usize,
+    },
+    /// The map has no floor tile to walk on.
+    NoFloorTiles,
+    /// The start position of the `player` isn't a floor tile.
+    UnreachableStart([i32; 2]),
+    /// The room with the given index can't be reached from the start position of the `player`.
+    UnreachableRoom(usize),
+    /// The room with the given index lies outside the bounds of the map.
+    RoomOutOfBounds(usize),
 }
 
 impl Debug for GameMap {
@@ -155,12 +876,29 @@ impl Debug for GameMap {
             "ECS -> Components -> GameMap {{\
             width: {:?}, \
             height: {:?}, \
+            render_overrides: {:?}, \
             rooms: {:?}, \
             tiles: {:?}, \
             seen_tiles: {:?}, \
-            visible_tiles: {:?}\
+            visible_tiles: {:?}, \
+            seen_positions: {:?}, \
+            visible_positions: {:?}, \
+            last_seen_turns: {:?}, \
+            fingerprint: {:?}, \
+            seed: {:?}\
             }}",
-            self.width, self.height, self.rooms, self.tiles, self.seen_tiles, self.visible_tiles
+            self.width,
+            self.height,
+            self.render_overrides.keys(),
+            self.rooms,
+            self.tiles,
+            self.seen_tiles,
+            self.visible_tiles,
+            self.seen_positions,
+            self.visible_positions,
+            self.last_seen_turns,
+            self.fingerprint,
+            self.seed
         )
     }
 }
@@ -173,13 +911,33 @@ impl Display for GameMap {
             self.width,
             self.height,
             self.rooms.len(),
-            self.tiles.len(),
-            self.seen_tiles.len(),
-            self.visible_tiles.len()
+            self.tiles.cells().len(),
+            self.seen_tiles.cells().len(),
+            self.visible_tiles.cells().len()
         )
     }
 }
 
+impl Debug for MapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECS -> Components -> MapError::{}", self)
+    }
+}
+
+impl Display for MapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapError::TileCountMismatch { expected, actual } => {
+                write!(f, "TileCountMismatch({}, {})", expected, actual)
+            }
+            MapError::NoFloorTiles => write!(f, "NoFloorTiles"),
+            MapError::UnreachableStart(start) => write!(f, "UnreachableStart({:?})", start),
+            MapError::UnreachableRoom(index) => write!(f, "UnreachableRoom({})", index),
+            MapError::RoomOutOfBounds(index) => write!(f, "RoomOutOfBounds({})", index),
+        }
+    }
+}
+
 impl Dimension2d for GameMap {
     fn width(&self) -> i32 {
         self.width
@@ -192,11 +950,21 @@ impl Dimension2d for GameMap {
 
 impl TileMap<MapTile> for GameMap {
     fn tiles(&self) -> &Vec<MapTile> {
-        &self.tiles
+        self.tiles.cells()
     }
 
     fn tiles_mut(&mut self) -> &mut Vec<MapTile> {
-        &mut self.tiles
+        Arc::make_mut(&mut self.tiles).cells_mut()
+    }
+
+    fn get_tile_at(&self, index: &impl Position2d) -> &MapTile {
+        self.tiles
+            .get(index)
+            .expect("ECS -> Components -> GameMap -> Tile position out of bounds!")
+    }
+
+    fn set_tile_at(&mut self, index: &impl Position2d, tile: MapTile) {
+        Arc::make_mut(&mut self.tiles).set(index, tile);
     }
 
     fn tile_has_collision(&self, index: &impl Position2d) -> bool {
@@ -204,23 +972,671 @@ impl TileMap<MapTile> for GameMap {
     }
 
     fn is_tile_seen(&self, index: &impl Position2d) -> bool {
-        self.seen_tiles[Self::convert_world_index(self.width, index)]
+        self.seen_tiles.get(index) == Some(&true)
     }
 
     fn mark_tile_as_seen(&mut self, index: &impl Position2d) {
-        self.seen_tiles[Self::convert_world_index(self.width, index)] = true
+        if self.seen_tiles.get(index) == Some(&false) {
+            self.seen_tiles.set(index, true);
+            self.seen_positions.push(index.as_array());
+        }
     }
 
     fn is_tile_visible(&self, index: &impl Position2d) -> bool {
-        self.visible_tiles[Self::convert_world_index(self.width, index)]
+        self.visible_tiles.get(index) == Some(&true)
     }
 
     fn mark_tile_as_visible(&mut self, index: &impl Position2d) {
-        self.visible_tiles[Self::convert_world_index(self.width, index)] = true
+        if self.visible_tiles.get(index) == Some(&false) {
+            self.visible_tiles.set(index, true);
+            self.visible_positions.push(index.as_array());
+        }
     }
 
     fn reset_visible_tiles(&mut self) {
-        self.visible_tiles.clear();
-        self.visible_tiles.resize(self.area(), false);
+        self.visible_tiles.fill(false);
+        self.visible_positions.clear();
+    }
+
+    fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    fn render_override(&self, index: &impl Position2d) -> Option<&RenderOverride> {
+        self.render_overrides.get(&index.as_tuple())
+    }
+
+    fn render(&self, terminal: &mut Mut<Terminal>, viewport: &Viewport, tile_memory: bool) {
+        for position in self.render_positions() {
+            self.render_tile_at(&position, terminal, viewport, tile_memory);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy::app::{App, Update};
+    use bevy::prelude::{Color, Mut, Query};
+    use bevy_ascii_terminal::{Terminal, TileFormatter};
+
+    use crate::ascii_sprite;
+    use crate::ui::rectangle::Rectangle;
+    use crate::ui::room::RoomShape;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+    use crate::ui::tile_map_layout_generator::{BaseTileMapGenerator, TileMapGeneratorRegistry};
+
+    use super::*;
+
+    #[test]
+    fn test_generated_maps_are_valid() {
+        assert_eq!(
+            Ok(()),
+            GameMap::new(&[80, 50], &BaseTileMapGenerator, None).validate()
+        );
+        assert_eq!(
+            Ok(()),
+            GameMap::new(&[10, 10], &TestTileMapGenerator, None).validate()
+        );
+    }
+
+    #[test]
+    fn test_same_seed_reproduces_layout() {
+        let registry = TileMapGeneratorRegistry::default();
+
+        for name in registry.names() {
+            let generator = registry.get(name).unwrap();
+            let map = GameMap::new(&[80, 50], generator, Some(1337));
+            let copy = GameMap::new(&[80, 50], generator, Some(map.seed()));
+
+            assert_eq!(1337, map.seed());
+            assert_eq!(map.fingerprint(), copy.fingerprint(), "{}", name);
+            assert_eq!(map.rooms, copy.rooms, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_unseeded_map_records_its_seed() {
+        let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+        let copy = GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(map.seed()));
+
+        assert_eq!(map.fingerprint(), copy.fingerprint());
+        assert_eq!(map.rooms, copy.rooms);
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_opacity() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+        let fingerprint = map.fingerprint();
+
+        map.set_tile_at(&[5, 5], MapTile::door());
+        map.refresh_fingerprint();
+
+        assert!(!map.tile_has_collision(&[5, 5]));
+        assert!(map.tile_is_opaque(&[5, 5]));
+        assert_ne!(fingerprint, map.fingerprint());
+    }
+
+    #[test]
+    fn test_validate_tile_count() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.tiles_mut().pop();
+
+        assert_eq!(
+            Err(vec![MapError::TileCountMismatch {
+                expected: 100,
+                actual: 99
+            }]),
+            map.validate()
+        );
+    }
+
+    #[test]
+    fn test_validate_without_floor() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.set_tile_at(&map.center(), MapTile::default());
+
+        assert_eq!(
+            Err(vec![
+                MapError::NoFloorTiles,
+                MapError::UnreachableStart([5, 5])
+            ]),
+            map.validate()
+        );
+    }
+
+    #[test]
+    fn test_walkable_neighbors_exclude_walls() {
+        let mut map = GameMap::new(&[5, 5], &TestTileMapGenerator, None);
+
+        for position in [[1, 2], [2, 1], [3, 2], [1, 1], [3, 3]] {
+            map.set_tile_at(&position, MapTile::floor('.'));
+        }
+
+        assert_eq!(
+            vec![[3, 2], [1, 2], [2, 1]],
+            map.walkable_neighbors(&[2, 2])
+        );
+        assert_eq!(vec![[1, 1]], map.walkable_neighbors(&[0, 1]));
+    }
+
+    #[test]
+    fn test_starting_position_prefers_first_room() {
+        let map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
+
+        assert_eq!(map.rooms()[0].center(), map.starting_position());
+    }
+
+    #[test]
+    fn test_starting_position_falls_back_to_scattered_floor() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.set_tile_at(&[5, 5], MapTile::default());
+        map.set_tile_at(&[1, 1], MapTile::floor('.'));
+        map.set_tile_at(&[8, 2], MapTile::floor('.'));
+
+        assert_eq!([8, 2], map.starting_position());
+
+        let mut rng = RandomNumberGenerator::seeded(7);
+
+        for _ in 0..20 {
+            let position = map.random_floor_position(&mut rng).unwrap();
+
+            assert!([[1, 1], [8, 2]].contains(&position));
+        }
+    }
+
+    #[test]
+    fn test_starting_position_fallback_is_deterministic() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.set_tile_at(&[5, 5], MapTile::default());
+
+        // All tiles are equally close to the center, so the tie has to be broken the same way every time.
+        for position in [[7, 5], [3, 5], [5, 7]] {
+            map.set_tile_at(&position, MapTile::floor('.'));
+        }
+
+        assert_eq!([3, 5], map.starting_position());
+
+        let pick = |seed: u64| {
+            let mut rng = RandomNumberGenerator::seeded(seed);
+
+            (0..5)
+                .map(|_| map.random_floor_position(&mut rng).unwrap())
+                .collect::<Vec<[i32; 2]>>()
+        };
+
+        assert_eq!(vec![[7, 5], [7, 5], [7, 5], [7, 5], [5, 7]], pick(1337));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_starting_position_panics_without_floor() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.set_tile_at(&[5, 5], MapTile::default());
+
+        assert_eq!(
+            None,
+            map.random_floor_position(&mut RandomNumberGenerator::seeded(7))
+        );
+
+        map.starting_position();
+    }
+
+    #[test]
+    fn test_validate_rooms() {
+        let mut map = GameMap::new(&[20, 20], &TestTileMapGenerator, None);
+
+        let start_room = Rectangle::new([1, 1], [4, 4]);
+        let isolated_room = Rectangle::new([10, 10], [4, 4]);
+
+        start_room.add_to_map(&mut map);
+        isolated_room.add_to_map(&mut map);
+
+        map.rooms = vec![
+            Room::new(start_room, RoomShape::Rect),
+            Room::new(isolated_room, RoomShape::Rect),
+            Room::new(Rectangle::new([15, 15], [10, 10]), RoomShape::Rect),
+        ];
+
+        assert_eq!(
+            Err(vec![
+                MapError::RoomOutOfBounds(2),
+                MapError::UnreachableRoom(1),
+                MapError::UnreachableRoom(2)
+            ]),
+            map.validate()
+        );
+    }
+
+    #[test]
+    fn test_out_of_bounds_layers_do_not_wrap() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.mark_tile_as_seen(&[-1, 1]);
+        map.mark_tile_as_visible(&[10, 0]);
+
+        assert!(!map.is_tile_seen(&[9, 0]));
+        assert!(!map.is_tile_visible(&[0, 1]));
+        assert!(!map.is_tile_seen(&[-1, 1]));
+        assert!(!map.is_tile_visible(&[10, 0]));
+    }
+
+    #[test]
+    fn test_reveal_room_at() {
+        let mut map = GameMap::new(&[30, 20], &TestTileMapGenerator, None);
+
+        let entered_room = Rectangle::new([1, 1], [6, 6]);
+        let other_room = Rectangle::new([15, 10], [6, 6]);
+
+        entered_room.add_to_map(&mut map);
+        other_room.add_to_map(&mut map);
+
+        map.rooms = vec![
+            Room::new(entered_room, RoomShape::Rect),
+            Room::new(other_room, RoomShape::Rect),
+        ];
+
+        assert!(!map.reveal_room_at(&[10, 5], 0));
+        assert!(map.reveal_room_at(&[3, 3], 0));
+
+        for x in entered_room.left..=entered_room.right {
+            for y in entered_room.bottom..=entered_room.top {
+                assert!(map.is_tile_seen(&[x, y]), "[{}, {}] is unseen", x, y);
+            }
+        }
+
+        assert!(map.is_tile_seen(&[entered_room.left, entered_room.top]));
+        assert!(map.tile_has_collision(&[entered_room.left, entered_room.top]));
+
+        for x in other_room.left..=other_room.right {
+            for y in other_room.bottom..=other_room.top {
+                assert!(!map.is_tile_seen(&[x, y]), "[{}, {}] is seen", x, y);
+            }
+        }
+
+        assert!(!map.is_tile_seen(&[10, 5]));
+    }
+
+    #[test]
+    fn test_clone_preserves_layers() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        map.mark_tile_as_seen(&[1, 1]);
+        map.mark_tile_as_visible(&[1, 1]);
+
+        let mut snapshot = map.clone();
+
+        assert!(Arc::ptr_eq(&map.tiles, &snapshot.tiles));
+        assert!(snapshot.is_tile_seen(&[1, 1]));
+        assert!(snapshot.is_tile_visible(&[1, 1]));
+
+        snapshot.mark_tile_as_seen(&[2, 2]);
+        snapshot.reset_visible_tiles();
+        snapshot.set_tile_at(&[3, 3], MapTile::floor('.'));
+
+        assert!(!Arc::ptr_eq(&map.tiles, &snapshot.tiles));
+        assert!(!map.is_tile_seen(&[2, 2]));
+        assert!(map.is_tile_visible(&[1, 1]));
+        assert!(map.tile_has_collision(&[3, 3]));
+        assert!(!snapshot.tile_has_collision(&[3, 3]));
+        assert_eq!(map.get_tile_at(&[4, 4]), snapshot.get_tile_at(&[4, 4]));
+    }
+
+    /// Benchmark of cloning a large map, run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_clone_large_map() {
+        const ITERATIONS: u32 = 1_000;
+
+        let map = GameMap::new(&[200, 200], &BaseTileMapGenerator, None);
+        let start = std::time::Instant::now();
+
+        for _ in 0..ITERATIONS {
+            std::hint::black_box(map.clone());
+        }
+
+        println!(
+            "Cloning a 200x200 GameMap took {:?} on average.",
+            start.elapsed() / ITERATIONS
+        );
+    }
+
+    #[test]
+    fn test_memory_fade() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        assert_eq!(0.0, map.memory_fade(&[1, 1], 10));
+
+        map.mark_tile_as_seen(&[1, 1]);
+        map.remember_tile(&[1, 1], 0);
+
+        assert!(map.memory_fade(&[1, 1], 10) > map.memory_fade(&[1, 1], 5));
+        assert_eq!(
+            constants::SIGHT_MEMORY_MAX_FADE,
+            map.memory_fade(&[1, 1], constants::SIGHT_MEMORY_FADE_TURNS * 2)
+        );
+
+        map.remember_tile(&[1, 1], 10);
+
+        assert_eq!(0.0, map.memory_fade(&[1, 1], 10));
+
+        map.mark_tile_as_visible(&[1, 1]);
+
+        assert_eq!(0.0, map.memory_fade(&[1, 1], 20));
+    }
+
+    fn render_tiles(map: GameMap, full_map: bool) -> Vec<bevy_ascii_terminal::Tile> {
+        let mut app = App::new();
+        let positions: Vec<[i32; 2]> = map.tiles.iter_positions().collect();
+        let viewport = Viewport::new(&Rectangle::new([0, 0], [map.width, map.height]));
+
+        app.world.spawn(Terminal::new([map.width, map.height]));
+        app.world.spawn(map);
+        app.add_systems(
+            Update,
+            move |mut terminal_query: Query<&mut Terminal>, game_map_query: Query<&GameMap>| {
+                let map = game_map_query.single();
+                let mut terminal = terminal_query.single_mut();
+
+                if full_map {
+                    for x in 0..map.width {
+                        for y in 0..map.height {
+                            map.render_tile_at(&[x, y], &mut terminal, &viewport, true);
+                        }
+                    }
+                } else {
+                    map.render(&mut terminal, &viewport, true);
+                }
+            },
+        );
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        positions
+            .iter()
+            .map(|position| *terminal.get_tile(*position))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_skips_unexplored_tiles() {
+        let mut map = GameMap::new(&[200, 200], &TestTileMapGenerator, None);
+        let room = Rectangle::new([10, 10], [8, 6]);
+
+        room.add_to_map(&mut map);
+        map.rooms.push(Room::new(room, RoomShape::Rect));
+        map.reveal_room_at(&[12, 12], 0);
+        map.mark_tile_as_visible(&[11, 11]);
+        map.mark_tile_as_visible(&[150, 150]);
+        map.render_overrides.insert(
+            (100, 100),
+            Arc::new(|position, terminal: &mut Mut<Terminal>, _, _| {
+                terminal.put_char(*position, '*'.fg(Color::PURPLE));
+            }),
+        );
+
+        let render_count = map.render_positions().count();
+
+        assert_eq!(
+            ((room.right - room.left + 1) * (room.top - room.bottom + 1) + 2) as usize,
+            render_count
+        );
+        assert!(render_count * 100 < (map.width * map.height) as usize);
+        assert_eq!(render_tiles(map.clone(), true), render_tiles(map, false));
+    }
+
+    /// Creates a map with a single large room, which is entered through a doorway on its right side.
+    fn map_with_doorway() -> (GameMap, [i32; 2]) {
+        let mut map = GameMap::new(&[30, 30], &TestTileMapGenerator, None);
+        let room = Room::new(Rectangle::new([2, 2], [12, 12]), RoomShape::Rect);
+        let doorway = [room.bounds.right, room.center()[1]];
+
+        room.carve(&mut map);
+        map.set_tile_at(&doorway, MapTile::floor('.'));
+        map.set_tile_at(&[doorway[0] + 1, doorway[1]], MapTile::floor('.'));
+        map.rooms = vec![room];
+
+        (map, doorway)
+    }
+
+    #[test]
+    fn test_decorations_keep_room_connected() {
+        for _ in 0..50 {
+            let (mut map, doorway) = map_with_doorway();
+            let room = map.rooms()[0];
+
+            assert!(map.decorate_rooms(1.0, &[]) > 0);
+
+            let reachable = map.reachable_tiles(&room.center());
+
+            assert_eq!(Some(&true), reachable.get(&doorway));
+
+            for position in room.floor_positions() {
+                assert_eq!(
+                    map.is_walkable(&position),
+                    reachable.get(&position) == Some(&true),
+                    "{:?} is cut off",
+                    position
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoration_density_scales_count() {
+        let decorate = |density: f32| map_with_doorway().0.decorate_rooms(density, &[]);
+        let candidates = map_with_doorway().0.rooms()[0].decoration_positions().len();
+
+        assert_eq!(0, decorate(0.0));
+        assert_eq!(candidates / 4, decorate(0.25));
+        assert_eq!(candidates, decorate(1.0));
+
+        let (mut map, _) = map_with_doorway();
+        let reserved = map.rooms()[0].decoration_positions();
+
+        assert_eq!(0, map.decorate_rooms(1.0, &reserved));
+        assert_eq!(
+            0,
+            Room::new(Rectangle::new([2, 2], [6, 6]), RoomShape::Rect)
+                .decoration_positions()
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_decorations_are_seeded_by_the_map() {
+        let decorate = |seed: u64| {
+            let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, Some(seed));
+            map.decorate_rooms(0.5, &[]);
+
+            (0..map.height)
+                .flat_map(|y| (0..map.width).map(move |x| [x, y]))
+                .map(|position| *map.get_tile_at(&position))
+                .collect::<Vec<MapTile>>()
+        };
+
+        assert_eq!(decorate(42), decorate(42));
+    }
+
+    fn render_with_entities(
+        map: GameMap,
+        entity_positions: Vec<[i32; 2]>,
+        tile_memory: bool,
+    ) -> Terminal {
+        let mut app = App::new();
+        let viewport = Viewport::new(&Rectangle::new([0, 0], [map.width, map.height]));
+
+        app.world.spawn(Terminal::new([map.width, map.height]));
+        app.world.spawn(map);
+        app.add_systems(
+            Update,
+            move |mut terminal_query: Query<&mut Terminal>, game_map_query: Query<&GameMap>| {
+                let map = game_map_query.single();
+                let mut terminal = terminal_query.single_mut();
+
+                map.render(&mut terminal, &viewport, tile_memory);
+
+                for position in entity_positions.iter() {
+                    map.render_entity_at(
+                        position,
+                        &ascii_sprite!('m', Color::YELLOW),
+                        &mut terminal,
+                        &viewport,
+                        tile_memory,
+                    );
+                }
+            },
+        );
+
+        app.update();
+
+        app.world.query::<&Terminal>().single(&app.world).clone()
+    }
+
+    #[test]
+    fn test_entities_are_gated_like_map_tiles() {
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        Rectangle::new([1, 1], [8, 8]).add_to_map(&mut map);
+
+        for position in [[2, 2], [3, 3]] {
+            map.mark_tile_as_seen(&position);
+        }
+
+        map.mark_tile_as_visible(&[2, 2]);
+
+        for tile_memory in [true, false] {
+            let map_only = render_with_entities(map.clone(), vec![], tile_memory);
+            let with_entities =
+                render_with_entities(map.clone(), vec![[2, 2], [3, 3], [4, 4]], tile_memory);
+
+            assert_eq!('m', with_entities.get_char([2, 2]));
+
+            // Out of sight, neither the remembered nor the unexplored tile shows the entity standing on it.
+            for position in [[3, 3], [4, 4]] {
+                assert_eq!(
+                    map_only.get_tile(position),
+                    with_entities.get_tile(position),
+                    "{:?} with tile memory {}",
+                    position,
+                    tile_memory
+                );
+            }
+        }
+
+        let map_only = render_with_entities(map, vec![], false);
+
+        assert_eq!(' ', map_only.get_char([3, 3]));
+        assert_eq!(' ', map_only.get_char([4, 4]));
+    }
+
+    #[test]
+    fn test_render_override_replaces_tile_render() {
+        let mut app = App::new();
+
+        let mut map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                map.mark_tile_as_seen(&[x, y]);
+            }
+        }
+
+        map.render_overrides.insert(
+            (5, 5),
+            Arc::new(|position, terminal: &mut Mut<Terminal>, _, _| {
+                terminal.put_char(*position, '*'.fg(Color::PURPLE));
+            }),
+        );
+
+        let neighbour_glyph = map.get_tile_at(&[4, 5]).glyph();
+
+        app.world.spawn(map);
+        app.world.spawn(Terminal::new([10, 10]));
+        app.add_systems(
+            Update,
+            |mut terminal_query: Query<&mut Terminal>, game_map_query: Query<&GameMap>| {
+                game_map_query.single().render(
+                    &mut terminal_query.single_mut(),
+                    &Viewport::new(&Rectangle::new([0, 0], [10, 10])),
+                    true,
+                );
+            },
+        );
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!('*', terminal.get_char([5, 5]));
+        assert_eq!(Color::PURPLE, terminal.get_tile([5, 5]).fg_color);
+        assert_eq!(neighbour_glyph, terminal.get_char([4, 5]));
+    }
+
+    #[test]
+    fn test_render_into_offset_viewport() {
+        let mut app = App::new();
+
+        let mut map = GameMap::new(&[4, 3], &TestTileMapGenerator, None);
+
+        map.mark_tile_as_visible(&[0, 0]);
+        map.mark_tile_as_visible(&[1, 1]);
+        map.mark_tile_as_visible(&[3, 2]);
+        map.render_overrides.insert(
+            (3, 2),
+            Arc::new(|position, terminal: &mut Mut<Terminal>, _, _| {
+                terminal.put_char(*position, '*'.fg(Color::PURPLE));
+            }),
+        );
+
+        let glyph = map.get_tile_at(&[0, 0]).glyph();
+
+        app.world.spawn(map);
+        app.world.spawn(Terminal::new([10, 8]));
+        app.add_systems(
+            Update,
+            |mut terminal_query: Query<&mut Terminal>, game_map_query: Query<&GameMap>| {
+                let map = game_map_query.single();
+                let mut terminal = terminal_query.single_mut();
+                let viewport = Viewport::new(&Rectangle::new([5, 4], [3, 3]));
+
+                map.render(&mut terminal, &viewport, true);
+                map.render_entity_at(
+                    &[1, 1],
+                    &ascii_sprite!('m', Color::YELLOW),
+                    &mut terminal,
+                    &viewport,
+                    true,
+                );
+            },
+        );
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!(glyph, terminal.get_char([5, 4]));
+        assert_eq!('m', terminal.get_char([6, 5]));
+        assert_eq!(' ', terminal.get_char([0, 0]));
+
+        for x in 0..10 {
+            for y in 0..8 {
+                assert_ne!(
+                    '*',
+                    terminal.get_char([x, y]),
+                    "[{}, {}] is not clipped",
+                    x,
+                    y
+                );
+            }
+        }
     }
 }
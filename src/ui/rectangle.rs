@@ -134,10 +134,213 @@ impl Rectangle {
     /// Since: `0.1.7`
     ///
     pub fn collides(&self, other: &Rectangle) -> bool {
-        self.left <= other.right
-            && self.right >= other.left
-            && self.bottom <= other.top
-            && self.top >= other.bottom
+        self.intersection(other).is_some()
+    }
+
+    /// Calculates the region in which the passed [Rectangle] and the calling one overlap, including their
+    /// edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The [Rectangle] to intersect with.
+    ///
+    /// returns: [Option]<[Rectangle]> - The overlapping region, or [None] if the rectangles don't overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rectangle1 = Rectangle::new([0, 0], [10, 10]);
+    /// let rectangle2 = Rectangle::new([5, 5], [10, 10]);
+    /// let rectangle3 = Rectangle::new([20, 20], [5, 5]);
+    ///
+    /// assert_eq!(Some(Rectangle::new([5, 5], [5, 5])), rectangle1.intersection(&rectangle2));
+    /// assert_eq!(None, rectangle1.intersection(&rectangle3));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        let intersection = Rectangle {
+            left: max(self.left, other.left),
+            bottom: max(self.bottom, other.bottom),
+            right: min(self.right, other.right),
+            top: min(self.top, other.top),
+        };
+
+        if intersection.left > intersection.right || intersection.bottom > intersection.top {
+            return None;
+        }
+
+        Some(intersection)
+    }
+
+    /// Calculates the smallest [Rectangle] enclosing both, the passed [Rectangle] and the calling one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The [Rectangle] to unite with.
+    ///
+    /// returns: [Rectangle]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rectangle1 = Rectangle::new([0, 0], [10, 10]);
+    /// let rectangle2 = Rectangle::new([20, 5], [5, 10]);
+    ///
+    /// assert_eq!(Rectangle::new([0, 0], [25, 15]), rectangle1.union(&rectangle2));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        Rectangle {
+            left: min(self.left, other.left),
+            bottom: min(self.bottom, other.bottom),
+            right: max(self.right, other.right),
+            top: max(self.top, other.top),
+        }
+    }
+
+    /// Checks if the passed `position` lies inside the [Rectangle], including its edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to check.
+    ///
+    /// returns: bool - `true` if the `position` lies inside or on the edge of the [Rectangle].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let room = Rectangle::new([2, 2], [5, 5]);
+    ///
+    /// assert!(room.contains(&[4, 4]));
+    /// assert!(room.contains(&[7, 2]));
+    /// assert!(!room.contains(&[8, 4]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn contains(&self, position: &impl Position2d) -> bool {
+        (self.left..=self.right).contains(&position.x_coordinate())
+            && (self.bottom..=self.top).contains(&position.y_coordinate())
+    }
+
+    /// Calculates the distance in tiles from the passed `position` to the closest point of the
+    /// [Rectangle], counting diagonal steps as one tile. Positions inside the [Rectangle] have a
+    /// distance of `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to measure the distance from.
+    ///
+    /// returns: i32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let room = Rectangle::new([2, 2], [5, 5]);
+    ///
+    /// assert_eq!(0, room.distance_to(&[4, 4]));
+    /// assert_eq!(3, room.distance_to(&[10, 5]));
+    /// assert_eq!(2, room.distance_to(&[0, 9]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn distance_to(&self, position: &impl Position2d) -> i32 {
+        if self.contains(position) {
+            return 0;
+        }
+
+        let [x, y] = position.as_array();
+        let dx = max(max(self.left - x, x - self.right), 0);
+        let dy = max(max(self.bottom - y, y - self.top), 0);
+
+        max(dx, dy)
+    }
+
+    /// Translates the passed `local` position, relative to the bottom left corner of the [Rectangle], into the space
+    /// surrounding it, e.g., from the [TileMap] space into the [bevy_ascii_terminal::Terminal] space of the panel
+    /// the map is rendered into. Unlike [Rectangle::contains], the `right` and `top` edges are exclusive, so the
+    /// [Rectangle] covers exactly [Dimension2d::width] times [Dimension2d::height] positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `local`: The [Position2d] relative to the [Rectangle] to translate.
+    ///
+    /// returns: [Option]<[i32; 2]> - The translated position or [None], if it lies outside the [Rectangle].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let panel = Rectangle::new([10, 5], [20, 10]);
+    ///
+    /// assert_eq!(Some([12, 8]), panel.outer_position(&[2, 3]));
+    /// assert_eq!(None, panel.outer_position(&[20, 3]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn outer_position(&self, local: &impl Position2d) -> Option<[i32; 2]> {
+        let [x, y] = local.as_array();
+
+        ((0..self.width()).contains(&x) && (0..self.height()).contains(&y))
+            .then_some([self.left + x, self.bottom + y])
+    }
+
+    /// Translates the passed `outer` position into the space relative to the bottom left corner of the
+    /// [Rectangle], reversing [Rectangle::outer_position].
+    ///
+    /// # Arguments
+    ///
+    /// * `outer`: The [Position2d] in the space surrounding the [Rectangle] to translate.
+    ///
+    /// returns: [Option]<[i32; 2]> - The translated position or [None], if it lies outside the [Rectangle].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let panel = Rectangle::new([10, 5], [20, 10]);
+    ///
+    /// assert_eq!(Some([2, 3]), panel.local_position(&[12, 8]));
+    /// assert_eq!(None, panel.local_position(&[5, 8]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn local_position(&self, outer: &impl Position2d) -> Option<[i32; 2]> {
+        let local = [
+            outer.x_coordinate() - self.left,
+            outer.y_coordinate() - self.bottom,
+        ];
+
+        self.outer_position(&local).map(|_| local)
     }
 
     /// Adds the given [Rectangle] the passed [TileMap] as a room the player can traverse.
@@ -267,11 +470,11 @@ impl Display for Rectangle {
 
 impl Dimension2d for Rectangle {
     fn width(&self) -> i32 {
-        self.left + self.right
+        self.right - self.left
     }
 
     fn height(&self) -> i32 {
-        self.bottom + self.top
+        self.top - self.bottom
     }
 
     fn center(&self) -> [i32; 2] {
@@ -299,9 +502,19 @@ mod tests {
         assert!(!rectangle2.collides(&rectangle3));
     }
 
+    #[test]
+    fn test_dimension() {
+        let rect = Rectangle::new([34, 12], [40, 15]);
+
+        assert_eq!(40, rect.width());
+        assert_eq!(15, rect.height());
+        assert_eq!([54, 19], rect.center());
+        assert_eq!(rect, Rectangle::new([rect.left, rect.bottom], rect));
+    }
+
     #[test]
     fn rooms_are_added_to_map_correctly() {
-        let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator);
+        let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
         let rect = Rectangle::new([0, 0], [5, 5]);
 
         rect.add_to_map(&mut map);
@@ -315,7 +528,7 @@ mod tests {
 
     #[test]
     fn rooms_are_connected_correctly() {
-        let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator);
+        let mut map = GameMap::new(&[80, 50], &BaseTileMapGenerator, None);
         let rect1 = Rectangle::new([0, 0], [5, 5]);
         let rect2 = Rectangle::new([6, 6], [5, 5]);
 
@@ -335,4 +548,85 @@ mod tests {
             assert!(!map.tile_has_collision(&[x_start, y]));
         }
     }
+
+    #[test]
+    fn test_intersection() {
+        let rectangle1 = Rectangle::new([0, 0], [10, 10]);
+        let rectangle2 = Rectangle::new([5, 5], [10, 10]);
+        let rectangle3 = Rectangle::new([20, 20], [5, 5]);
+        let rectangle4 = Rectangle::new([10, 2], [4, 4]);
+
+        assert_eq!(
+            Some(Rectangle::new([5, 5], [5, 5])),
+            rectangle1.intersection(&rectangle2)
+        );
+        assert_eq!(
+            rectangle1.intersection(&rectangle2),
+            rectangle2.intersection(&rectangle1)
+        );
+        assert_eq!(None, rectangle1.intersection(&rectangle3));
+        assert_eq!(
+            Some(Rectangle::new([10, 2], [0, 4])),
+            rectangle1.intersection(&rectangle4)
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        let rectangle1 = Rectangle::new([0, 0], [10, 10]);
+        let rectangle2 = Rectangle::new([20, 5], [5, 10]);
+
+        let union = rectangle1.union(&rectangle2);
+
+        assert_eq!(Rectangle::new([0, 0], [25, 15]), union);
+        assert_eq!(union, rectangle2.union(&rectangle1));
+        assert_eq!(Some(rectangle1), union.intersection(&rectangle1));
+        assert_eq!(Some(rectangle2), union.intersection(&rectangle2));
+    }
+
+    #[test]
+    fn test_contains() {
+        let rect = Rectangle::new([2, 2], [5, 5]);
+
+        assert!(rect.contains(&[4, 4]));
+        assert!(rect.contains(&[2, 2]));
+        assert!(rect.contains(&[7, 5]));
+        assert!(rect.contains(&[4, 7]));
+        assert!(rect.contains(&[7, 7]));
+        assert!(rect.contains(&[2, 7]));
+        assert!(rect.contains(&[7, 2]));
+        assert!(!rect.contains(&[1, 4]));
+        assert!(!rect.contains(&[8, 4]));
+        assert!(!rect.contains(&[4, 8]));
+        assert!(!rect.contains(&[4, 1]));
+        assert!(!rect.contains(&[8, 8]));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let rect = Rectangle::new([2, 2], [5, 5]);
+
+        assert_eq!(0, rect.distance_to(&[4, 4]));
+        assert_eq!(0, rect.distance_to(&[7, 7]));
+        assert_eq!(1, rect.distance_to(&[8, 4]));
+        assert_eq!(3, rect.distance_to(&[10, 5]));
+        assert_eq!(2, rect.distance_to(&[0, 9]));
+        assert_eq!(4, rect.distance_to(&[-2, -1]));
+    }
+
+    #[test]
+    fn test_outer_and_local_position() {
+        let panel = Rectangle::new([10, 5], [20, 10]);
+
+        assert_eq!(Some([10, 5]), panel.outer_position(&[0, 0]));
+        assert_eq!(Some([29, 14]), panel.outer_position(&[19, 9]));
+        assert_eq!(None, panel.outer_position(&[20, 0]));
+        assert_eq!(None, panel.outer_position(&[0, 10]));
+        assert_eq!(None, panel.outer_position(&[-1, 0]));
+
+        assert_eq!(Some([0, 0]), panel.local_position(&[10, 5]));
+        assert_eq!(Some([19, 9]), panel.local_position(&[29, 14]));
+        assert_eq!(None, panel.local_position(&[30, 5]));
+        assert_eq!(None, panel.local_position(&[9, 5]));
+    }
 }
@@ -0,0 +1,329 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Display, Formatter};
+
+use crate::core::constants;
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::position_2d::Position2d;
+use crate::ui::rectangle::Rectangle;
+use crate::ui::tile::MapTile;
+use crate::ui::tile_map::TileMap;
+
+/// The shape of a [Room] inside its bounding [Rectangle].
+///
+/// ```text
+///     Rect           Circle          Cross
+///
+/// #########       #########       #########
+/// #.......#       #   .   #       #  ...  #
+/// #.......#       # ..... #       #  ...  #
+/// #.......#       #.......#       #.......#
+/// #.......#       # ..... #       #  ...  #
+/// #.......#       #   .   #       #  ...  #
+/// #########       #########       #########
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum RoomShape {
+    /// Fills the whole interior of the bounding [Rectangle].
+    #[default]
+    Rect,
+    /// A circle around the center, whose radius is half of the interior's shorter side.
+    Circle,
+    /// Two corridors crossing at the center, each a third of the interior's shorter side wide.
+    Cross,
+}
+
+/// A room of the map, which is made up of a bounding [Rectangle] and the [RoomShape] carved into it.
+///
+/// # Properties
+///
+/// * `bounds`: The bounding [Rectangle] of the room, whose outline is made up of walls.
+/// * `shape`: The [RoomShape] carved into the interior of the `bounds`.
+///
+/// # Examples
+///
+/// ```
+/// let room = Room::new(Rectangle::new([0, 0], [8, 8]), RoomShape::Circle);
+///
+/// room.carve(&mut map);
+///
+/// assert!(!map.tile_has_collision(&room.center()));
+/// assert!(map.tile_has_collision(&[1, 1]));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Room {
+    /// The bounding [Rectangle] of the room, whose outline is made up of walls.
+    pub bounds: Rectangle,
+    /// The [RoomShape] carved into the interior of the `bounds`.
+    pub shape: RoomShape,
+}
+
+impl Room {
+    /// Creates a new [Room] with the passed `bounds` and `shape`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds`: The bounding [Rectangle] of the room.
+    /// * `shape`: The [RoomShape] carved into the `bounds`.
+    ///
+    /// returns: [Room]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(bounds: Rectangle, shape: RoomShape) -> Self {
+        Self { bounds, shape }
+    }
+
+    /// Returns the center of the room, which is a floor tile for every [RoomShape].
+    ///
+    /// returns: [i32; 2]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn center(&self) -> [i32; 2] {
+        self.bounds.center()
+    }
+
+    /// Checks if the passed `position` is a floor tile of the room, i.e., lies inside its [RoomShape].
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The [Position2d] to check.
+    ///
+    /// returns: bool
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn contains(&self, position: &impl Position2d) -> bool {
+        let [x, y] = position.as_array();
+
+        let is_interior = (self.bounds.left + 1..self.bounds.right).contains(&x)
+            && (self.bounds.bottom + 1..self.bounds.top).contains(&y);
+
+        if !is_interior {
+            return false;
+        }
+
+        let [dx, dy] = position.delta(&self.center());
+        let shorter_side = (self.bounds.right - self.bounds.left - 1)
+            .min(self.bounds.top - self.bounds.bottom - 1);
+
+        match self.shape {
+            RoomShape::Rect => true,
+            RoomShape::Circle => {
+                let radius = shorter_side / 2;
+                dx * dx + dy * dy <= radius * radius
+            }
+            RoomShape::Cross => {
+                let half_width = (shorter_side / 6).max(1);
+                dx.abs() <= half_width || dy.abs() <= half_width
+            }
+        }
+    }
+
+    /// Returns all floor positions of the room, ordered by row and column.
+    ///
+    /// returns: [Vec]<[i32; 2]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn floor_positions(&self) -> Vec<[i32; 2]> {
+        (self.bounds.bottom + 1..self.bounds.top)
+            .flat_map(|y| (self.bounds.left + 1..self.bounds.right).map(move |x| [x, y]))
+            .filter(|position| self.contains(position))
+            .collect()
+    }
+
+    /// Returns all floor positions of the room, which can be decorated without blocking a path through it. These
+    /// are the ones surrounded by floor on all eight sides, except for the center. Rooms smaller than
+    /// [constants::MAP_DECORATION_MIN_ROOM_SIZE] aren't decorated at all.
+    ///
+    /// returns: [Vec]<[i32; 2]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn decoration_positions(&self) -> Vec<[i32; 2]> {
+        let size = (self.bounds.right - self.bounds.left).min(self.bounds.top - self.bounds.bottom);
+
+        if size < constants::MAP_DECORATION_MIN_ROOM_SIZE {
+            return Vec::new();
+        }
+
+        let center = self.center();
+
+        self.floor_positions()
+            .into_iter()
+            .filter(|position| *position != center)
+            .filter(|[x, y]| (-1..=1).all(|dx| (-1..=1).all(|dy| self.contains(&[x + dx, y + dy]))))
+            .collect()
+    }
+
+    /// Carves the room into the passed [TileMap] by turning all of its floor positions into floor tiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `map`: The [TileMap] on which the room is carved.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [Rectangle::add_to_map]
+    ///
+    pub fn carve(&self, map: &mut impl TileMap<MapTile>) {
+        if self.shape == RoomShape::Rect {
+            self.bounds.add_to_map(map);
+            return;
+        }
+
+        for position in self.floor_positions() {
+            map.set_tile_at(&position, MapTile::floor('.'));
+        }
+    }
+}
+
+impl Display for Room {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {:?})", self.bounds, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::game_map::GameMap;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    fn carve(shape: RoomShape) -> (Room, GameMap) {
+        let mut map = GameMap::new(&[30, 30], &TestTileMapGenerator, None);
+        let room = Room::new(Rectangle::new([2, 2], [10, 10]), shape);
+
+        room.carve(&mut map);
+
+        (room, map)
+    }
+
+    #[test]
+    fn test_rect_room_fills_interior() {
+        let (_, map) = carve(RoomShape::Rect);
+
+        for x in 3..12 {
+            for y in 3..12 {
+                assert!(!map.tile_has_collision(&[x, y]));
+            }
+        }
+
+        assert!(map.tile_has_collision(&[2, 2]));
+        assert!(map.tile_has_collision(&[12, 7]));
+    }
+
+    #[test]
+    fn test_circle_room_carves_within_radius() {
+        let (room, map) = carve(RoomShape::Circle);
+        let center = room.center();
+
+        assert_eq!([7, 7], center);
+
+        for x in 3..12 {
+            for y in 3..12 {
+                let [dx, dy] = [x - center[0], y - center[1]];
+
+                assert_eq!(
+                    dx * dx + dy * dy <= 16,
+                    !map.tile_has_collision(&[x, y]),
+                    "Unexpected tile at {:?}.",
+                    [x, y]
+                );
+            }
+        }
+
+        for corner in [[3, 3], [11, 3], [3, 11], [11, 11]] {
+            assert!(map.tile_has_collision(&corner));
+        }
+    }
+
+    #[test]
+    fn test_cross_room_carves_arms() {
+        let (room, map) = carve(RoomShape::Cross);
+
+        assert!(!map.tile_has_collision(&room.center()));
+        assert!(!map.tile_has_collision(&[3, 7]));
+        assert!(!map.tile_has_collision(&[11, 8]));
+        assert!(!map.tile_has_collision(&[6, 3]));
+        assert!(map.tile_has_collision(&[4, 4]));
+        assert!(map.tile_has_collision(&[10, 10]));
+    }
+
+    #[test]
+    fn test_floor_positions_match_contains() {
+        let room = Room::new(Rectangle::new([0, 0], [8, 8]), RoomShape::Circle);
+        let floor_positions = room.floor_positions();
+
+        assert!(floor_positions.contains(&room.center()));
+        assert!(floor_positions
+            .iter()
+            .all(|position| room.contains(position)));
+        assert!(!room.contains(&[0, 0]));
+        assert!(!room.contains(&[1, 1]));
+    }
+}
@@ -149,6 +149,35 @@ pub trait Tile {
     /// ```
     fn background_color(&self, is_seen: bool, is_visible: bool) -> Color;
 
+    /// An animated background color replacing the [Tile::background_color], e.g., to let water ripple. The
+    /// `tick` advances every [crate::core::constants::TILE_ANIMATION_TICK_MS] milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `tick`: The current animation tick.
+    /// * `is_visible`: If the [Tile] is in the `field of view` of the `player`.
+    ///
+    /// returns: [Option]<Color> - [None] by default, keeping the static [Tile::background_color].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let water = MapTile::water();
+    ///
+    /// assert_ne!(water.animated_background(0, true), water.animated_background(1, true));
+    /// assert_eq!(None, MapTile::floor('.').animated_background(0, true));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn animated_background(&self, _tick: u64, _is_visible: bool) -> Option<Color> {
+        None
+    }
+
     /// If actors, e.g., the player, monsters, items, etc., can be placed on the [Tile], or if it blocks
     /// the space it occupies.
     ///
@@ -160,7 +189,54 @@ pub trait Tile {
     ///
     fn has_collision(&self) -> bool;
 
-    /// Renders the [Tile] at the given `position` using the passed `terminal` reference.
+    /// If the [Tile] blocks the `field of view` and the line of sight, e.g., a closed door, which can be walked
+    /// through, but not looked through.
+    ///
+    /// returns: bool - [Tile::has_collision] by default.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn is_opaque(&self) -> bool {
+        self.has_collision()
+    }
+
+    /// If the [Tile] stays on screen once it has been seen, but is no longer visible, e.g., the walls of an
+    /// explored room. Tiles which can change while out of sight, like the sprites of `entities`, are only
+    /// rendered while visible.
+    ///
+    /// returns: bool - `true` by default.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn is_remembered(&self) -> bool {
+        true
+    }
+
+    /// The cost of entering the [Tile] when searching a path across a [crate::ui::tile_map::TileMap], e.g., rubble
+    /// which slows down the walk compared to the plain floor.
+    ///
+    /// returns: i32 - `1` by default.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn movement_cost(&self) -> i32 {
+        1
+    }
+
+    /// Renders the [Tile] at the given `position` using the passed `terminal` reference. Nothing is rendered
+    /// for a [Tile], which is neither visible nor a seen one that is remembered, see [Tile::is_remembered].
     ///
     /// # Arguments
     ///
@@ -213,7 +289,7 @@ pub trait Tile {
         is_seen: bool,
         is_visible: bool,
     ) {
-        if is_seen || is_visible {
+        if is_visible || (is_seen && self.is_remembered()) {
             terminal.put_char(
                 position.as_array(),
                 self.glyph()
@@ -281,6 +357,91 @@ impl MapTile {
             kind: MapTileType::Floor,
         }
     }
+
+    /// Creates a new [MapTile] of the [MapTileType::Rubble], which decorates the floor of a room.
+    ///
+    /// returns: [MapTile]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn rubble() -> Self {
+        Self {
+            glyph: ',',
+            kind: MapTileType::Rubble,
+        }
+    }
+
+    /// Creates a new [MapTile] of the [MapTileType::Door], which can be walked through.
+    ///
+    /// returns: [MapTile]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn door() -> Self {
+        Self {
+            glyph: '+',
+            kind: MapTileType::Door,
+        }
+    }
+
+    /// Creates a new [MapTile] of the [MapTileType::Water], which can be waded through.
+    ///
+    /// returns: [MapTile]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn water() -> Self {
+        Self {
+            glyph: '~',
+            kind: MapTileType::Water,
+        }
+    }
+
+    /// Creates a new [MapTile] of the [MapTileType::DownStairs], leading to the next level of the dungeon.
+    ///
+    /// returns: [MapTile]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn stairs_down() -> Self {
+        Self {
+            glyph: '>',
+            kind: MapTileType::DownStairs,
+        }
+    }
+
+    /// Creates a new [MapTile] of the [MapTileType::UpStairs], leading to the previous level of the dungeon.
+    ///
+    /// returns: [MapTile]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn stairs_up() -> Self {
+        Self {
+            glyph: '<',
+            kind: MapTileType::UpStairs,
+        }
+    }
 }
 
 impl Default for MapTile {
@@ -303,20 +464,156 @@ impl Tile for MapTile {
         self.glyph
     }
 
+    fn foreground_color(&self, _is_seen: bool, is_visible: bool) -> Color {
+        if !is_visible {
+            return colors::INACTIVE;
+        }
+
+        match self.kind {
+            MapTileType::Door => colors::MAP_DOOR,
+            MapTileType::Water => colors::MAP_WATER,
+            MapTileType::DownStairs | MapTileType::UpStairs => colors::MAP_STAIRS,
+            MapTileType::Floor | MapTileType::Wall | MapTileType::Rubble => Color::SEA_GREEN,
+        }
+    }
+
+    fn background_color(&self, _is_seen: bool, _is_visible: bool) -> Color {
+        colors::BACKGROUND
+    }
+
+    fn animated_background(&self, tick: u64, is_visible: bool) -> Option<Color> {
+        let backgrounds = colors::MAP_WATER_BACKGROUNDS;
+
+        (is_visible && self.kind == MapTileType::Water)
+            .then(|| backgrounds[(tick % backgrounds.len() as u64) as usize])
+    }
+
+    fn has_collision(&self) -> bool {
+        self.kind == MapTileType::Wall
+    }
+
+    fn is_opaque(&self) -> bool {
+        matches!(self.kind, MapTileType::Wall | MapTileType::Door)
+    }
+
+    fn movement_cost(&self) -> i32 {
+        match self.kind {
+            MapTileType::Rubble | MapTileType::Water => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A simple [Tile] made up of a glyph, its colors and a collision flag, for UI elements like menu borders or
+/// decorations, which don't require a bespoke [Tile] implementation.
+///
+/// While visible, the [SimpleTile] is rendered with its configured colors. Once only seen, its foreground is
+/// dimmed to [colors::INACTIVE], like the one of a [MapTile].
+///
+/// # Properties
+///
+/// * `glyph`: The symbol to use when rendering the [SimpleTile].
+/// * `fg`: The foreground color of the [SimpleTile] while visible.
+/// * `bg`: The background color of the [SimpleTile].
+/// * `collision`: If the [SimpleTile] blocks the space it occupies.
+///
+/// # Examples
+///
+/// ```
+/// let border = SimpleTile {
+///     collision: true,
+///     ..SimpleTile::new('+', Color::GRAY)
+/// };
+///
+/// border.render(&[0, 0], &mut terminal, true, true);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [Tile]
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SimpleTile {
+    /// The symbol to use when rendering the [SimpleTile].
+    pub glyph: char,
+    /// The foreground color of the [SimpleTile] while visible.
+    pub fg: Color,
+    /// The background color of the [SimpleTile].
+    pub bg: Color,
+    /// If the [SimpleTile] blocks the space it occupies.
+    pub collision: bool,
+}
+
+impl SimpleTile {
+    /// Creates a new [SimpleTile] without collision, which renders the passed `glyph` in the given `fg` color on
+    /// the [colors::BACKGROUND].
+    ///
+    /// # Arguments
+    ///
+    /// * `glyph`: The symbol to use when rendering the [SimpleTile].
+    /// * `fg`: The foreground color of the [SimpleTile] while visible.
+    ///
+    /// returns: [SimpleTile]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let decoration = SimpleTile::new('~', Color::BLUE);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(glyph: char, fg: Color) -> Self {
+        Self {
+            glyph,
+            fg,
+            bg: colors::BACKGROUND,
+            collision: false,
+        }
+    }
+}
+
+impl Default for SimpleTile {
+    fn default() -> Self {
+        Self::new(' ', Color::WHITE)
+    }
+}
+
+impl Display for SimpleTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.glyph, self.collision)
+    }
+}
+
+impl Tile for SimpleTile {
+    fn glyph(&self) -> char {
+        self.glyph
+    }
+
     fn foreground_color(&self, _is_seen: bool, is_visible: bool) -> Color {
         if is_visible {
-            Color::SEA_GREEN
+            self.fg
         } else {
             colors::INACTIVE
         }
     }
 
     fn background_color(&self, _is_seen: bool, _is_visible: bool) -> Color {
-        colors::BACKGROUND
+        self.bg
     }
 
     fn has_collision(&self) -> bool {
-        self.kind == MapTileType::Wall
+        self.collision
     }
 }
 
@@ -335,6 +632,16 @@ pub enum MapTileType {
     /// An impassable tile, marking the position it occupies as not walkable.
     /// Serves as the default barrier on the map.
     Wall,
+    /// A walkable tile covered in debris, which is more costly to cross than the [MapTileType::Floor].
+    Rubble,
+    /// A walkable doorway between two parts of the map.
+    Door,
+    /// A walkable, but shallow body of water, which is more costly to cross than the [MapTileType::Floor].
+    Water,
+    /// Walkable stairs leading down to the next level of the dungeon.
+    DownStairs,
+    /// Walkable stairs leading up to the previous level of the dungeon.
+    UpStairs,
 }
 
 impl Display for MapTileType {
@@ -342,6 +649,121 @@ impl Display for MapTileType {
         match self {
             MapTileType::Floor => write!(f, "Floor"),
             MapTileType::Wall => write!(f, "Wall"),
+            MapTileType::Rubble => write!(f, "Rubble"),
+            MapTileType::Door => write!(f, "Door"),
+            MapTileType::Water => write!(f, "Water"),
+            MapTileType::DownStairs => write!(f, "DownStairs"),
+            MapTileType::UpStairs => write!(f, "UpStairs"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{App, Update};
+    use bevy::prelude::Query;
+
+    use super::*;
+
+    #[test]
+    fn test_simple_tile_collision() {
+        let decoration = SimpleTile::new('~', Color::BLUE);
+        let border = SimpleTile {
+            collision: true,
+            ..SimpleTile::new('+', Color::GRAY)
+        };
+
+        assert!(!decoration.has_collision());
+        assert!(border.has_collision());
+        assert_eq!(colors::BACKGROUND, border.background_color(true, true));
+    }
+
+    #[test]
+    fn test_rubble_is_walkable_but_costly() {
+        let floor = MapTile::floor('.');
+        let rubble = MapTile::rubble();
+
+        assert!(!rubble.has_collision());
+        assert!(rubble.movement_cost() > floor.movement_cost());
+        assert_eq!(1, floor.movement_cost());
+    }
+
+    #[test]
+    fn test_special_map_tiles() {
+        let tiles = [
+            (MapTile::door(), '+', colors::MAP_DOOR, "(+, Door)"),
+            (MapTile::water(), '~', colors::MAP_WATER, "(~, Water)"),
+            (
+                MapTile::stairs_down(),
+                '>',
+                colors::MAP_STAIRS,
+                "(>, DownStairs)",
+            ),
+            (
+                MapTile::stairs_up(),
+                '<',
+                colors::MAP_STAIRS,
+                "(<, UpStairs)",
+            ),
+        ];
+
+        for (tile, glyph, color, display) in tiles {
+            assert!(!tile.has_collision(), "{}", tile);
+            assert_eq!(glyph, tile.glyph());
+            assert_eq!(color, tile.foreground_color(true, true));
+            assert_eq!(colors::INACTIVE, tile.foreground_color(true, false));
+            assert_eq!(display, tile.to_string());
         }
+
+        assert_eq!(2, MapTile::water().movement_cost());
+        assert_eq!(1, MapTile::door().movement_cost());
+        assert!(MapTile::door().is_opaque());
+        assert!(!MapTile::water().is_opaque());
+        assert!(MapTile::default().is_opaque());
+    }
+
+    #[test]
+    fn test_animated_background() {
+        let water = MapTile::water();
+
+        assert_ne!(
+            water.animated_background(0, true),
+            water.animated_background(1, true)
+        );
+        assert_eq!(
+            water.animated_background(0, true),
+            water.animated_background(2, true)
+        );
+        assert_eq!(None, water.animated_background(0, false));
+        assert_eq!(None, MapTile::floor('.').animated_background(0, true));
+        assert_eq!(None, MapTile::floor('.').animated_background(1, true));
+    }
+
+    #[test]
+    fn test_simple_tile_render() {
+        let mut app = App::new();
+
+        app.world.spawn(Terminal::new([4, 4]));
+        app.add_systems(Update, |mut terminal_query: Query<&mut Terminal>| {
+            let mut terminal = terminal_query.single_mut();
+            let tile = SimpleTile {
+                bg: Color::NAVY,
+                ..SimpleTile::new('+', Color::GOLD)
+            };
+
+            tile.render(&[1, 1], &mut terminal, true, true);
+            tile.render(&[2, 2], &mut terminal, true, false);
+            tile.render(&[3, 3], &mut terminal, false, false);
+        });
+
+        app.update();
+
+        let terminal = app.world.query::<&Terminal>().single(&app.world);
+
+        assert_eq!('+', terminal.get_char([1, 1]));
+        assert_eq!(Color::GOLD, terminal.get_tile([1, 1]).fg_color);
+        assert_eq!(Color::NAVY, terminal.get_tile([1, 1]).bg_color);
+        assert_eq!(colors::INACTIVE, terminal.get_tile([2, 2]).fg_color);
+        assert_eq!(' ', terminal.get_char([3, 3]));
     }
 }
@@ -19,12 +19,45 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
 use bevy::prelude::Mut;
 use bevy_ascii_terminal::Terminal;
 
 use crate::core::dimension_2d::Dimension2d;
 use crate::core::position_2d::Position2d;
 use crate::ui::tile::Tile;
+use crate::ui::viewport::Viewport;
+
+/// Closure rendering a single position of a [TileMap] in place of its [Tile], e.g., to render a glowing
+/// portal or a highlighted target without introducing a new [Tile] type.
+///
+/// The closure receives the rendered position in the [Terminal] space, the [Terminal] to render on and
+/// whether the position is seen and visible by the `player`, in the same order as [Tile::render].
+///
+/// # Examples
+///
+/// ```
+/// let portal: RenderOverride = Arc::new(|position, terminal, _is_seen, is_visible| {
+///     if is_visible {
+///         terminal.put_char(*position, 'O'.fg(Color::PURPLE));
+///     }
+/// });
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+/// # See also
+///
+/// * [TileMap::render_override]
+///
+pub type RenderOverride = Arc<dyn Fn(&[i32; 2], &mut Mut<Terminal>, bool, bool) + Send + Sync>;
 
 /// A map of [Tile]s, which can be rendered on demand. While the map groups the [Tile]s and initiates their rendering
 /// through delegation, the [Tile]s are responsible for their visual representation.
@@ -57,12 +90,12 @@ use crate::ui::tile::Tile;
 /// }
 ///
 /// impl TileMap for MapImpl {
-///     fn render(&self, terminal: &mut Mut<Terminal>) {
+///     fn render(&self, terminal: &mut Mut<Terminal>, viewport: &Viewport, tile_memory: bool) {
 ///         for x in 0..80 {
 ///             for < in 0..50 {
 ///                 let world_index = Self::convert_world_index(80, [x, y]);
 ///                 self.tiles[world_index].render(
-///                     [x, y],
+///                     viewport.world_to_screen(&[x, y]).unwrap(),
 ///                     terminal,
 ///                     self.seen_tiles[world_index],
 ///                     self.visible_tiles[world_index]
@@ -245,6 +278,50 @@ pub trait TileMap<T: Tile>: Dimension2d {
     ///
     fn tile_has_collision(&self, index: &impl Position2d) -> bool;
 
+    /// Checks if the [Tile] at the given `index` blocks the `field of view` and the line of sight.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The [Position2d] based index of the tile to check.
+    ///
+    /// returns: bool - `true` if the [Tile] is opaque and `false` otherwise.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [Tile::is_opaque]
+    ///
+    fn tile_is_opaque(&self, index: &impl Position2d) -> bool {
+        self.get_tile_at(index).is_opaque()
+    }
+
+    /// Returns the cost of entering the [Tile] at the given `index` when searching a path across the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The [Position2d] based index of the tile to check.
+    ///
+    /// returns: i32
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [Tile::movement_cost]
+    ///
+    fn tile_movement_cost(&self, index: &impl Position2d) -> i32 {
+        self.get_tile_at(index).movement_cost()
+    }
+
     /// Checks if the [Tile] at the given `index` has been seen by the `player` at any point during gameplay.
     ///
     /// # Arguments
@@ -350,11 +427,86 @@ pub trait TileMap<T: Tile>: Dimension2d {
     ///
     fn reset_visible_tiles(&mut self);
 
-    /// Renders all tiles which make up the map on screen on the passed [Terminal].
+    /// Calculates a fingerprint of the [TileMap]'s layout, based on its dimension and which of its [Tile]s
+    /// have collision or are opaque. Two maps with the same layout produce the same fingerprint, while the
+    /// fingerprint changes as soon as a [Tile] gains or loses its collision or opacity.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [TileMap::fingerprint]
+    ///
+    fn calculate_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.width().hash(&mut hasher);
+        self.height().hash(&mut hasher);
+
+        for tile in self.tiles() {
+            tile.has_collision().hash(&mut hasher);
+            tile.is_opaque().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Returns the fingerprint of the [TileMap]'s layout, which can be used to key data derived
+    /// from the map, e.g., cached `field of view` results.
+    ///
+    /// By default the fingerprint is calculated on every call, implementations may cache it instead.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    /// # See also
+    ///
+    /// * [TileMap::calculate_fingerprint]
+    ///
+    fn fingerprint(&self) -> u64 {
+        self.calculate_fingerprint()
+    }
+
+    /// Returns the [RenderOverride] registered for the passed `index`, if any, which is consulted by
+    /// [TileMap::render] in place of the [Tile] at the `index`. By default, no overrides are registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The position in the [TileMap] space to check.
+    ///
+    /// returns: [Option]<&[RenderOverride]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn render_override(&self, _index: &impl Position2d) -> Option<&RenderOverride> {
+        None
+    }
+
+    /// Renders all tiles which make up the map on screen on the passed [Terminal]. Positions with a
+    /// [RenderOverride] are rendered by it instead of their [Tile].
+    ///
+    /// Only the section of the map selected by the passed [Viewport] is rendered, translated into the map panel of
+    /// the [Terminal]. Tiles beyond the [Viewport] are clipped, so panels next to it stay untouched.
+    ///
+    /// Without `tile_memory`, tiles which were seen before, but aren't visible anymore, are treated as unseen and
+    /// therefore left blank.
     ///
     /// # Arguments
     ///
     /// * `terminal`: [Terminal] which handles the actual rendering.
+    /// * `viewport`: The [Viewport] selecting the rendered section of the map.
+    /// * `tile_memory`: If seen but no longer visible tiles are rendered dimmed instead of blank.
     ///
     /// returns: ()
     ///
@@ -366,9 +518,9 @@ pub trait TileMap<T: Tile>: Dimension2d {
     /// }
     ///
     /// impl TileMap for Map {
-    ///     fn render(&self, terminal: &mut Mut<Terminal>) {
+    ///     fn render(&self, terminal: &mut Mut<Terminal>, viewport: &Viewport, tile_memory: bool) {
     ///         for x in 0..80 {
-    ///             self.tiles[0].render_at([x, 1], terminal);
+    ///             self.tiles[0].render_at(viewport.world_to_screen(&[x, 1]).unwrap(), terminal);
     ///         }
     ///     }
     /// }
@@ -385,22 +537,227 @@ pub trait TileMap<T: Tile>: Dimension2d {
     /// * [Terminal]
     /// * [Tile]
     ///
-    fn render(&self, terminal: &mut Mut<Terminal>) {
+    fn render(&self, terminal: &mut Mut<Terminal>, viewport: &Viewport, tile_memory: bool) {
         for x in 0..self.width() {
             for y in 0..self.height() {
-                let position_2d = [x, y];
-                let index = Self::convert_world_index(self.width(), &position_2d);
-
-                self.tiles()[index].render(
-                    &position_2d,
-                    terminal,
-                    self.is_tile_seen(&position_2d),
-                    self.is_tile_visible(&position_2d),
-                );
+                self.render_tile_at(&[x, y], terminal, viewport, tile_memory);
+            }
+        }
+    }
+
+    /// Renders the single [Tile] at the passed `position` on the passed [Terminal], or its [RenderOverride]
+    /// if one is registered. Used by [TileMap::render] for every position it considers.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position in the [TileMap] space to render.
+    /// * `terminal`: [Terminal] which handles the actual rendering.
+    /// * `viewport`: The [Viewport] selecting the rendered section of the map, see [TileMap::render].
+    /// * `tile_memory`: If seen but no longer visible tiles are rendered dimmed instead of blank.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn render_tile_at(
+        &self,
+        position: &impl Position2d,
+        terminal: &mut Mut<Terminal>,
+        viewport: &Viewport,
+        tile_memory: bool,
+    ) {
+        let Some(screen_position) = viewport.world_to_screen(position) else {
+            return;
+        };
+
+        let index = Self::convert_world_index(self.width(), position);
+        let (is_seen, is_visible) = self.tile_visibility(position, tile_memory);
+
+        match self.render_override(position) {
+            Some(render_override) => {
+                render_override(&screen_position, terminal, is_seen, is_visible)
+            }
+            None => self.tiles()[index].render(&screen_position, terminal, is_seen, is_visible),
+        }
+    }
+
+    /// Renders the passed `tile` of an `entity`, e.g., its [crate::components::ascii_sprite::AsciiSprite], at the
+    /// passed `position`, gated by the same seen and visible state as the map's own [Tile] at that position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position in the [TileMap] space to render the `tile` at.
+    /// * `tile`: The [Tile] to render.
+    /// * `terminal`: [Terminal] which handles the actual rendering.
+    /// * `viewport`: The [Viewport] selecting the rendered section of the map, see [TileMap::render].
+    /// * `tile_memory`: If seen but no longer visible tiles are rendered dimmed instead of blank.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn render_entity_at(
+        &self,
+        position: &impl Position2d,
+        tile: &impl Tile,
+        terminal: &mut Mut<Terminal>,
+        viewport: &Viewport,
+        tile_memory: bool,
+    ) {
+        let Some(screen_position) = viewport.world_to_screen(position) else {
+            return;
+        };
+
+        let (is_seen, is_visible) = self.tile_visibility(position, tile_memory);
+
+        tile.render(&screen_position, terminal, is_seen, is_visible);
+    }
+
+    /// Determines the seen and visible state, with which a [Tile] at the passed `position` is rendered. A tile
+    /// only counts as seen while out of sight, if the `tile_memory` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position in the [TileMap] space to check.
+    /// * `tile_memory`: If seen but no longer visible tiles are rendered dimmed instead of blank.
+    ///
+    /// returns: (bool, bool) - The seen and the visible state.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn tile_visibility(&self, position: &impl Position2d, tile_memory: bool) -> (bool, bool) {
+        let is_visible = self.is_tile_visible(position);
+
+        (
+            self.is_tile_seen(position) && (tile_memory || is_visible),
+            is_visible,
+        )
+    }
+
+    /// Returns the positions along the straight line from `start` to `end`, both included, as traced by
+    /// Bresenham's line algorithm, e.g., for projectiles, targeting cursors or corridors. The line ends early,
+    /// once it leaves the bounds of the [TileMap].
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: The [Position2d] the line starts at.
+    /// * `end`: The [Position2d] the line ends at.
+    ///
+    /// returns: [Vec]<[i32; 2]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = GameMap::new(&[10, 10], &TestTileMapGenerator, None);
+    ///
+    /// assert_eq!(vec![[1, 1], [2, 1], [3, 2], [4, 2]], map.tiles_along_line(&[1, 1], &[4, 2]));
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    fn tiles_along_line(&self, start: &impl Position2d, end: &impl Position2d) -> Vec<[i32; 2]> {
+        let delta = [
+            (end.x_coordinate() - start.x_coordinate()).abs(),
+            -(end.y_coordinate() - start.y_coordinate()).abs(),
+        ];
+        let step = [
+            (end.x_coordinate() - start.x_coordinate()).signum(),
+            (end.y_coordinate() - start.y_coordinate()).signum(),
+        ];
+
+        let mut position = start.as_array();
+        let mut error = delta[0] + delta[1];
+        let mut tiles = Vec::new();
+
+        while self.is_in_bounds(&position) {
+            tiles.push(position);
+
+            if position == end.as_array() {
+                break;
+            }
+
+            let doubled_error = 2 * error;
+
+            if doubled_error >= delta[1] {
+                error += delta[1];
+                position[0] += step[0];
+            }
+
+            if doubled_error <= delta[0] {
+                error += delta[0];
+                position[1] += step[1];
             }
         }
+
+        tiles
     }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use crate::ui::game_map::GameMap;
+    use crate::ui::tile_map_layout_generator::test::TestTileMapGenerator;
+
+    use super::*;
+
+    fn map() -> GameMap {
+        GameMap::new(&[10, 10], &TestTileMapGenerator, None)
+    }
+
+    #[test]
+    fn test_tiles_along_horizontal_and_vertical_lines() {
+        let map = map();
+
+        assert_eq!(
+            vec![[1, 2], [2, 2], [3, 2], [4, 2]],
+            map.tiles_along_line(&[1, 2], &[4, 2])
+        );
+        assert_eq!(
+            vec![[3, 5], [3, 4], [3, 3]],
+            map.tiles_along_line(&[3, 5], &[3, 3])
+        );
+        assert_eq!(vec![[6, 6]], map.tiles_along_line(&[6, 6], &[6, 6]));
+    }
+
+    #[test]
+    fn test_tiles_along_diagonal_lines() {
+        let map = map();
+
+        assert_eq!(
+            vec![[0, 0], [1, 1], [2, 2], [3, 3]],
+            map.tiles_along_line(&[0, 0], &[3, 3])
+        );
+        assert_eq!(
+            vec![[5, 1], [4, 2], [3, 3]],
+            map.tiles_along_line(&[5, 1], &[3, 3])
+        );
+        assert_eq!(
+            vec![[1, 1], [2, 1], [3, 2], [4, 2]],
+            map.tiles_along_line(&[1, 1], &[4, 2])
+        );
+    }
+
+    #[test]
+    fn test_tiles_along_line_stop_at_map_bounds() {
+        assert_eq!(
+            vec![[7, 5], [8, 5], [9, 5]],
+            map().tiles_along_line(&[7, 5], &[12, 5])
+        );
+    }
+}
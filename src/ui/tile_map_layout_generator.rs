@@ -19,10 +19,17 @@
  * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
  */
 
+use std::collections::HashMap;
+
 use crate::core::constants;
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::position_2d::Position2d;
 use crate::core::rng::RandomNumberGenerator;
 use crate::ui::game_map::GameMap;
 use crate::ui::rectangle::Rectangle;
+use crate::ui::room::{Room, RoomShape};
+use crate::ui::tile::MapTile;
+use crate::ui::tile_map::TileMap;
 
 pub trait TileMapLayoutGenerator {
     fn generate_layout(&self, map: &mut GameMap);
@@ -32,7 +39,7 @@ pub struct BaseTileMapGenerator;
 
 impl TileMapLayoutGenerator for BaseTileMapGenerator {
     fn generate_layout(&self, map: &mut GameMap) {
-        let mut rng = RandomNumberGenerator::new();
+        let mut rng = RandomNumberGenerator::seeded(map.seed());
 
         'rooms: for _ in 0..constants::MAP_MAX_ROOMS {
             let room_width = rng.range(constants::MAP_MIN_ROOM_SIZE..=constants::MAP_MAX_ROOM_SIZE);
@@ -49,19 +56,489 @@ impl TileMapLayoutGenerator for BaseTileMapGenerator {
             );
 
             for existing_room in map.rooms.iter() {
-                if room.collides(existing_room) {
+                if room.collides(&existing_room.bounds) {
                     continue 'rooms;
                 }
             }
 
+            let shape = match rng.range(0..4) {
+                0 => RoomShape::Circle,
+                1 => RoomShape::Cross,
+                _ => RoomShape::Rect,
+            };
+
+            let room = Room::new(room, shape);
+
             if !map.rooms.is_empty() {
                 let previous_room = map.rooms[map.rooms.len() - 1];
-                room.connect(&previous_room, map);
+                room.bounds.connect(&previous_room.bounds, map);
+            }
+
+            room.carve(map);
+            map.rooms.push(room);
+        }
+
+        place_doors(map);
+    }
+}
+
+/// (Private) Closes off the corridors of the `map` with doors, where they break through the outline of a room
+/// between two walls. Corners of the outline and corridors running along it are left open.
+fn place_doors(map: &mut GameMap) {
+    let is_open = |map: &GameMap, position: [i32; 2]| {
+        map.is_in_bounds(&position) && !map.tile_has_collision(&position)
+    };
+
+    let mut doors: Vec<[i32; 2]> = Vec::new();
+
+    for room in map.rooms.iter() {
+        let bounds = room.bounds;
+
+        // Each position of the outline is paired with the direction the outline runs along.
+        let outline = (bounds.left + 1..bounds.right)
+            .flat_map(|x| [([x, bounds.bottom], [1, 0]), ([x, bounds.top], [1, 0])])
+            .chain(
+                (bounds.bottom + 1..bounds.top)
+                    .flat_map(|y| [([bounds.left, y], [0, 1]), ([bounds.right, y], [0, 1])]),
+            );
+
+        for ([x, y], [dx, dy]) in outline {
+            if is_open(map, [x, y])
+                && !is_open(map, [x - dx, y - dy])
+                && !is_open(map, [x + dx, y + dy])
+                && is_open(map, [x - dy, y - dx])
+                && is_open(map, [x + dy, y + dx])
+            {
+                doors.push([x, y]);
+            }
+        }
+    }
+
+    for door in doors {
+        map.set_tile_at(&door, MapTile::door());
+    }
+}
+
+/// [TileMapLayoutGenerator] creating natural looking caves, made up of circular caverns, which are connected by
+/// winding tunnels. Walls surrounded by floor are eroded afterwards, roughening the tunnels.
+///
+/// # Examples
+///
+/// ```
+/// let map = GameMap::new(&[80, 50], &CaveTileMapGenerator, None);
+///
+/// assert_eq!(Ok(()), map.validate());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub struct CaveTileMapGenerator;
+
+impl TileMapLayoutGenerator for CaveTileMapGenerator {
+    fn generate_layout(&self, map: &mut GameMap) {
+        let mut rng = RandomNumberGenerator::seeded(map.seed());
+
+        'caverns: for _ in 0..constants::MAP_MAX_ROOMS {
+            let size = rng.range(constants::MAP_MIN_ROOM_SIZE..=constants::MAP_MAX_ROOM_SIZE);
+
+            let cavern = Rectangle::new(
+                [
+                    rng.roll_dice(1, map.width - size - 1) - 1,
+                    rng.roll_dice(1, map.height - size - 1) - 1,
+                ],
+                [size, size],
+            );
+
+            for existing_cavern in map.rooms.iter() {
+                if cavern.collides(&existing_cavern.bounds) {
+                    continue 'caverns;
+                }
+            }
+
+            let cavern = Room::new(cavern, RoomShape::Circle);
+
+            if let Some(previous_cavern) = map.rooms.last() {
+                let start = previous_cavern.center();
+                dig_tunnel(map, start, cavern.center(), &mut rng);
+            }
+
+            cavern.carve(map);
+            map.rooms.push(cavern);
+        }
+
+        erode_walls(map);
+    }
+}
+
+/// (Private) Carves a winding tunnel from the `start` to the `end` position. Each step heads towards the `end`
+/// on two out of three occasions and in a random direction otherwise, never touching the border of the map.
+fn dig_tunnel(map: &mut GameMap, start: [i32; 2], end: [i32; 2], rng: &mut RandomNumberGenerator) {
+    let [mut x, mut y] = start;
+
+    map.set_tile_at(&start, MapTile::floor('.'));
+
+    while [x, y] != end {
+        if rng.range(0..3) < 2 {
+            if (end[0] - x).abs() >= (end[1] - y).abs() {
+                x += (end[0] - x).signum();
+            } else {
+                y += (end[1] - y).signum();
+            }
+        } else {
+            match rng.range(0..4) {
+                0 => x += 1,
+                1 => x -= 1,
+                2 => y += 1,
+                _ => y -= 1,
+            }
+
+            x = x.clamp(1, map.width - 2);
+            y = y.clamp(1, map.height - 2);
+        }
+
+        map.set_tile_at(&[x, y], MapTile::floor('.'));
+    }
+}
+
+/// (Private) Turns every wall, of which at least five of the eight neighbours are floor, into floor. The border of
+/// the map is left untouched. Every eroded wall has an orthogonal floor neighbour, so the map stays connected.
+fn erode_walls(map: &mut GameMap) {
+    for y in 1..map.height - 1 {
+        for x in 1..map.width - 1 {
+            if !map.tile_has_collision(&[x, y]) {
+                continue;
+            }
+
+            let floor_neighbours = [x, y]
+                .diagonal_neighbors()
+                .iter()
+                .filter(|neighbour| !map.tile_has_collision(*neighbour))
+                .count();
+
+            if floor_neighbours >= 5 {
+                map.set_tile_at(&[x, y], MapTile::floor('.'));
             }
+        }
+    }
+}
+
+/// [TileMapLayoutGenerator] recursively splitting the map into smaller partitions, using binary space
+/// partitioning, and placing a rectangular room in each of them. The rooms are connected in the order of the
+/// partitions, so neighbouring rooms are joined by short corridors.
+///
+/// # Examples
+///
+/// ```
+/// let map = GameMap::new(&[80, 50], &BspTileMapGenerator, None);
+///
+/// assert_eq!(Ok(()), map.validate());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub struct BspTileMapGenerator;
 
-            room.add_to_map(map);
+impl TileMapLayoutGenerator for BspTileMapGenerator {
+    fn generate_layout(&self, map: &mut GameMap) {
+        let mut rng = RandomNumberGenerator::seeded(map.seed());
+        let mut partitions = Vec::new();
+
+        split_partition(
+            Rectangle::new([0, 0], [map.width - 1, map.height - 1]),
+            &mut rng,
+            &mut partitions,
+        );
+
+        for partition in partitions {
+            let partition_width = partition.width();
+            let partition_height = partition.height();
+
+            let room_width = rng.range(
+                constants::MAP_MIN_ROOM_SIZE..=partition_width.min(constants::MAP_MAX_ROOM_SIZE),
+            );
+
+            let room_height = rng.range(
+                constants::MAP_MIN_ROOM_SIZE..=partition_height.min(constants::MAP_MAX_ROOM_SIZE),
+            );
+
+            let room = Room::new(
+                Rectangle::new(
+                    [
+                        partition.left + rng.range(0..=partition_width - room_width),
+                        partition.bottom + rng.range(0..=partition_height - room_height),
+                    ],
+                    [room_width, room_height],
+                ),
+                RoomShape::Rect,
+            );
+
+            if let Some(previous_room) = map.rooms.last().copied() {
+                room.bounds.connect(&previous_room.bounds, map);
+            }
+
+            room.carve(map);
             map.rooms.push(room);
         }
+
+        place_doors(map);
+    }
+}
+
+/// (Private) Splits the `partition` along its longer side at a random position, until neither side can be split
+/// into two partitions of at least [constants::MAP_MIN_ROOM_SIZE] anymore. The resulting partitions are
+/// collected in depth-first order into the passed `partitions`.
+fn split_partition(
+    partition: Rectangle,
+    rng: &mut RandomNumberGenerator,
+    partitions: &mut Vec<Rectangle>,
+) {
+    let width = partition.width();
+    let height = partition.height();
+    let min_size = constants::MAP_MIN_ROOM_SIZE;
+
+    let can_split_vertically = width >= 2 * min_size;
+    let can_split_horizontally = height >= 2 * min_size;
+
+    if can_split_vertically && (width >= height || !can_split_horizontally) {
+        let split = rng.range(min_size..=width - min_size);
+
+        split_partition(
+            Rectangle::new([partition.left, partition.bottom], [split, height]),
+            rng,
+            partitions,
+        );
+        split_partition(
+            Rectangle::new(
+                [partition.left + split, partition.bottom],
+                [width - split, height],
+            ),
+            rng,
+            partitions,
+        );
+    } else if can_split_horizontally {
+        let split = rng.range(min_size..=height - min_size);
+
+        split_partition(
+            Rectangle::new([partition.left, partition.bottom], [width, split]),
+            rng,
+            partitions,
+        );
+        split_partition(
+            Rectangle::new(
+                [partition.left, partition.bottom + split],
+                [width, height - split],
+            ),
+            rng,
+            partitions,
+        );
+    } else if width >= min_size && height >= min_size {
+        partitions.push(partition);
+    }
+}
+
+/// [TileMapLayoutGenerator] carving a perfect maze of one tile wide corridors by recursive backtracking, which
+/// makes for a harder dungeon without any rooms. The maze cells lie on the odd coordinates of the map, so the
+/// corridors never form open areas and every cell is reachable on exactly one path.
+///
+/// The first [Room] of the map is the single cell closest to the center, where the `player` starts. Up to
+/// [constants::MAP_MAX_ROOMS] dead ends of the maze, spaced at least [constants::MAP_MAZE_ALCOVE_SPACING] tiles
+/// apart, follow as single cell rooms, in which monsters and items are spawned.
+///
+/// # Examples
+///
+/// ```
+/// let map = GameMap::new(&[80, 50], &MazeTileMapGenerator, None);
+///
+/// assert!(map.rooms().len() > 1);
+/// assert_eq!(Ok(()), map.validate());
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub struct MazeTileMapGenerator;
+
+impl TileMapLayoutGenerator for MazeTileMapGenerator {
+    fn generate_layout(&self, map: &mut GameMap) {
+        let mut rng = RandomNumberGenerator::seeded(map.seed());
+
+        // Snap the center onto the odd cell grid, keeping it off the border of the map.
+        let [center_x, center_y] = map.center();
+        let start = [(center_x - 1) / 2 * 2 + 1, (center_y - 1) / 2 * 2 + 1];
+
+        let mut stack = vec![start];
+        map.set_tile_at(&start, MapTile::floor('.'));
+
+        while let Some(&[x, y]) = stack.last() {
+            let unvisited: Vec<[i32; 2]> = [[x + 2, y], [x - 2, y], [x, y + 2], [x, y - 2]]
+                .into_iter()
+                .filter(|[next_x, next_y]| {
+                    (1..map.width - 1).contains(next_x)
+                        && (1..map.height - 1).contains(next_y)
+                        && map.tile_has_collision(&[*next_x, *next_y])
+                })
+                .collect();
+
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+
+            let next = unvisited[rng.range(0..unvisited.len())];
+
+            map.set_tile_at(&[(x + next[0]) / 2, (y + next[1]) / 2], MapTile::floor('.'));
+            map.set_tile_at(&next, MapTile::floor('.'));
+            stack.push(next);
+        }
+
+        // Dead ends make for natural alcoves, which are spread across the maze as the rooms to spawn in.
+        let mut dead_ends: Vec<[i32; 2]> = map
+            .walkable_positions()
+            .into_iter()
+            .filter(|position| {
+                position
+                    .cardinal_neighbors()
+                    .iter()
+                    .filter(|neighbor| !map.tile_has_collision(*neighbor))
+                    .count()
+                    == 1
+            })
+            .collect();
+        let mut cells = vec![start];
+
+        while !dead_ends.is_empty() && cells.len() <= constants::MAP_MAX_ROOMS as usize {
+            let dead_end = dead_ends.swap_remove(rng.range(0..dead_ends.len()));
+
+            if cells.iter().all(|cell| {
+                cell.chebyshev_distance(&dead_end) >= constants::MAP_MAZE_ALCOVE_SPACING
+            }) {
+                cells.push(dead_end);
+            }
+        }
+
+        for [x, y] in cells {
+            map.rooms.push(Room::new(
+                Rectangle::new([x - 1, y - 1], [2, 2]),
+                RoomShape::Rect,
+            ));
+        }
+    }
+}
+
+/// Registry mapping names to the available [TileMapLayoutGenerator]s, so the layout of the dungeon can be
+/// selected at runtime, e.g., through [crate::res::gameplay_config::GameplayConfig::generator].
+///
+/// The default registry contains the built-in generators:
+///
+/// * `rooms`: [BaseTileMapGenerator]
+/// * `cave`: [CaveTileMapGenerator]
+/// * `bsp`: [BspTileMapGenerator]
+/// * `maze`: [MazeTileMapGenerator]
+///
+/// # Examples
+///
+/// ```
+/// let registry = TileMapGeneratorRegistry::default();
+/// let generator = registry.get("cave").unwrap();
+///
+/// let map = GameMap::new(&[80, 50], generator, None);
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+pub struct TileMapGeneratorRegistry {
+    /// (Private) The registered [TileMapLayoutGenerator]s keyed by their name.
+    generators: HashMap<String, Box<dyn TileMapLayoutGenerator>>,
+}
+
+impl TileMapGeneratorRegistry {
+    /// The name of the [TileMapLayoutGenerator] used, if none or an unknown one is configured.
+    pub const DEFAULT_GENERATOR: &'static str = "rooms";
+
+    /// Registers the passed `generator` under the passed `name`, replacing any generator previously
+    /// registered under it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name under which the `generator` can be retrieved.
+    /// * `generator`: The [TileMapLayoutGenerator] to register.
+    ///
+    /// returns: ()
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn register(&mut self, name: &str, generator: Box<dyn TileMapLayoutGenerator>) {
+        self.generators.insert(String::from(name), generator);
+    }
+
+    /// Returns the [TileMapLayoutGenerator] registered under the passed `name`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name of the generator.
+    ///
+    /// returns: [Option]<&dyn [TileMapLayoutGenerator]>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn get(&self, name: &str) -> Option<&dyn TileMapLayoutGenerator> {
+        self.generators
+            .get(name)
+            .map(|generator| generator.as_ref())
+    }
+
+    /// Returns the names of all registered [TileMapLayoutGenerator]s in alphabetical order.
+    ///
+    /// returns: [Vec]<&str>
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.generators.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl Default for TileMapGeneratorRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            generators: HashMap::new(),
+        };
+
+        registry.register(Self::DEFAULT_GENERATOR, Box::new(BaseTileMapGenerator));
+        registry.register("cave", Box::new(CaveTileMapGenerator));
+        registry.register("bsp", Box::new(BspTileMapGenerator));
+        registry.register("maze", Box::new(MazeTileMapGenerator));
+
+        registry
     }
 }
 
@@ -69,6 +546,7 @@ impl TileMapLayoutGenerator for BaseTileMapGenerator {
 pub mod test {
     use crate::core::dimension_2d::Dimension2d;
     use crate::ui::game_map::GameMap;
+    use crate::ui::rectangle::Rectangle;
     use crate::ui::tile::MapTile;
     use crate::ui::tile_map::TileMap;
     use crate::ui::tile_map_layout_generator::TileMapLayoutGenerator;
@@ -80,4 +558,159 @@ pub mod test {
             map.set_tile_at(&map.center(), MapTile::floor('.'));
         }
     }
+
+    /// Builds a room spanning from `[1, 1]` to `[9, 5]`, split by a wall at `x = 5`, which leaves a single gap at
+    /// `[5, 5]`.
+    pub fn split_room() -> GameMap {
+        let mut game_map = GameMap::new(&[12, 8], &TestTileMapGenerator, None);
+        Rectangle::new([0, 0], [10, 6]).add_to_map(&mut game_map);
+
+        for y in 1..5 {
+            game_map.set_tile_at(&[5, y], MapTile::default());
+        }
+
+        game_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::tile::MapTileType;
+
+    use super::*;
+
+    #[test]
+    fn test_registry_contains_built_in_generators() {
+        let registry = TileMapGeneratorRegistry::default();
+
+        assert_eq!(vec!["bsp", "cave", "maze", "rooms"], registry.names());
+        assert!(registry.get("labyrinth").is_none());
+    }
+
+    #[test]
+    fn test_registered_generators_create_valid_connected_maps() {
+        let registry = TileMapGeneratorRegistry::default();
+
+        for name in registry.names() {
+            for _ in 0..10 {
+                let map = GameMap::new(&[80, 45], registry.get(name).unwrap(), None);
+                let start = map.start_position();
+
+                assert_eq!(Ok(()), map.validate(), "{} created an invalid map", name);
+                assert!(!map.tile_has_collision(&start));
+                assert!(!map.rooms().is_empty(), "{} created no rooms", name);
+
+                let reachable = map.reachable_tiles(&start);
+
+                assert!(
+                    map.walkable_positions()
+                        .iter()
+                        .all(|position| reachable.get(position) == Some(&true)),
+                    "{} created unreachable floor tiles",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bsp_partitions_cover_the_map() {
+        let mut rng = RandomNumberGenerator::new();
+        let mut partitions = Vec::new();
+
+        split_partition(Rectangle::new([0, 0], [79, 44]), &mut rng, &mut partitions);
+
+        assert!(partitions.len() > 1);
+
+        let area: i32 = partitions
+            .iter()
+            .map(|partition| partition.width() * partition.height())
+            .sum();
+
+        assert_eq!(79 * 44, area);
+
+        for (index, partition) in partitions.iter().enumerate() {
+            assert!(partition.width() >= constants::MAP_MIN_ROOM_SIZE);
+            assert!(partition.height() >= constants::MAP_MIN_ROOM_SIZE);
+
+            for other in partitions.iter().skip(index + 1) {
+                assert!(partition.intersection(other).map_or(true, |shared| {
+                    shared.right == shared.left || shared.top == shared.bottom
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bsp_generator_creates_separate_rooms() {
+        for _ in 0..10 {
+            let map = GameMap::new(&[80, 45], &BspTileMapGenerator, None);
+            let rooms = map.rooms();
+
+            assert!(rooms.len() >= 2);
+
+            for (index, room) in rooms.iter().enumerate() {
+                for other in rooms.iter().skip(index + 1) {
+                    assert!(room
+                        .bounds
+                        .intersection(&other.bounds)
+                        .map_or(true, |shared| shared.width() == 0 || shared.height() == 0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_doors_are_placed_on_room_outlines() {
+        let mut door_count = 0;
+
+        for _ in 0..10 {
+            let map = GameMap::new(&[80, 45], &BaseTileMapGenerator, None);
+
+            for [x, y] in map.walkable_positions() {
+                if map.get_tile_at(&[x, y]).kind != MapTileType::Door {
+                    continue;
+                }
+
+                door_count += 1;
+
+                assert!(map.rooms().iter().any(|room| {
+                    let bounds = room.bounds;
+
+                    bounds.contains(&[x, y])
+                        && (x == bounds.left
+                            || x == bounds.right
+                            || y == bounds.bottom
+                            || y == bounds.top)
+                }));
+            }
+        }
+
+        assert!(door_count > 0);
+    }
+
+    #[test]
+    fn test_maze_generator_carves_perfect_maze() {
+        for size in [[80, 45], [21, 11], [10, 10]] {
+            let map = GameMap::new(&size, &MazeTileMapGenerator, None);
+
+            // Every odd position is a maze cell, connected to the others by one passage less than there are cells.
+            let cells = ((size[0] - 1) / 2) * ((size[1] - 1) / 2);
+
+            assert_eq!(Ok(()), map.validate());
+            assert_eq!((2 * cells - 1) as usize, map.walkable_positions().len());
+
+            for y in 0..size[1] - 1 {
+                for x in 0..size[0] - 1 {
+                    assert!(
+                        [[x, y], [x + 1, y], [x, y + 1], [x + 1, y + 1]]
+                            .iter()
+                            .any(|position| map.tile_has_collision(position)),
+                        "Open 2x2 block at {:?}",
+                        [x, y]
+                    );
+                }
+            }
+        }
+    }
 }
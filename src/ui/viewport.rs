@@ -0,0 +1,261 @@
+/*
+ * Copyright (c)  Sebastian Riga 2023.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+ * and associated
+ * documentation files (the “Software”), to deal in the Software without restriction, including
+ * without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all copies
+ * or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED,
+ * INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR
+ * PURPOSE AND NONINFRINGEMENT.
+ * IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+ * CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+use std::fmt::{Display, Formatter};
+
+use crate::components::coord_2d::Coord2d;
+use crate::core::dimension_2d::Dimension2d;
+use crate::core::position_2d::Position2d;
+use crate::ui::rectangle::Rectangle;
+
+/// Camera of the [crate::ui::game_map::GameMap], which selects the section of the map shown in a panel of the
+/// [bevy_ascii_terminal::Terminal]. Floors larger than the panel scroll with the `player`, as the [Viewport] is
+/// centered on them every frame, while never moving past the map bounds. The [Viewport] of the running game is held by
+/// the [crate::res::map_camera::MapCamera] resource.
+///
+/// # Properties
+///
+/// * `top_left`: The position of the [crate::ui::game_map::GameMap] shown in the top left corner of the map panel.
+/// * `size`: The number of columns and rows of the [crate::ui::game_map::GameMap] shown at once.
+///
+/// # Examples
+///
+/// ```
+/// let mut viewport = Viewport::new(&Rectangle::new([0, 0], [80, 45]));
+///
+/// viewport.center_on(&[150, 20], &[160, 90]);
+///
+/// assert_eq!(Coord2d::new(80, 44), viewport.top_left);
+/// assert_eq!(Some([70, 20]), viewport.world_to_screen(&[150, 20]));
+/// ```
+///
+/// # About
+///
+/// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+///
+/// Since: `0.1.10`
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    /// The position of the [crate::ui::game_map::GameMap] shown in the top left corner of the map panel.
+    pub top_left: Coord2d,
+    /// The number of columns and rows of the [crate::ui::game_map::GameMap] shown at once.
+    pub size: [i32; 2],
+    /// (Private) The bottom left corner of the map panel on the [bevy_ascii_terminal::Terminal].
+    screen_origin: [i32; 2],
+}
+
+impl Viewport {
+    /// Creates a new [Viewport] rendering into the passed `panel` of the [bevy_ascii_terminal::Terminal], which
+    /// shows the bottom left corner of the [crate::ui::game_map::GameMap] until it is centered on a position.
+    ///
+    /// # Arguments
+    ///
+    /// * `panel`: The [Rectangle] of the [bevy_ascii_terminal::Terminal] the map is rendered into, see
+    ///   [crate::res::terminal_layout::TerminalLayout::map].
+    ///
+    /// returns: [Viewport]
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn new(panel: &Rectangle) -> Self {
+        Self {
+            top_left: Coord2d::new(0, panel.height() - 1),
+            size: [panel.width(), panel.height()],
+            screen_origin: [panel.left, panel.bottom],
+        }
+    }
+
+    /// Centers the [Viewport] on the passed `focus`, e.g., the position of the `player`. Close to the edges of the
+    /// map, the [Viewport] is clamped to the map bounds instead, so no space beyond the map is shown. Maps smaller
+    /// than the [Viewport] are placed in its bottom left corner.
+    ///
+    /// # Arguments
+    ///
+    /// * `focus`: The position of the [crate::ui::game_map::GameMap] to center on.
+    /// * `map_size`: The size of the [crate::ui::game_map::GameMap] the [Viewport] is clamped to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut viewport = Viewport::new(&Rectangle::new([0, 0], [20, 10]));
+    ///
+    /// viewport.center_on(&[2, 1], &[100, 50]);
+    ///
+    /// assert_eq!(Coord2d::new(0, 9), viewport.top_left);
+    /// ```
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn center_on(&mut self, focus: &impl Position2d, map_size: &impl Dimension2d) {
+        let [width, height] = self.size;
+        let left = (focus.x_coordinate() - width / 2).clamp(0, (map_size.width() - width).max(0));
+        let bottom =
+            (focus.y_coordinate() - height / 2).clamp(0, (map_size.height() - height).max(0));
+
+        self.top_left = Coord2d::new(left, bottom + height - 1);
+    }
+
+    /// Translates the passed position on the [crate::ui::game_map::GameMap] into its position on the
+    /// [bevy_ascii_terminal::Terminal].
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position in the [crate::ui::game_map::GameMap] space to translate.
+    ///
+    /// returns: [Option]<[i32; 2]> - The position on the [bevy_ascii_terminal::Terminal] or [None], if it
+    /// lies outside the [Viewport].
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn world_to_screen(&self, position: &impl Position2d) -> Option<[i32; 2]> {
+        let [left, bottom] = self.bottom_left();
+
+        self.panel().outer_position(&[
+            position.x_coordinate() - left,
+            position.y_coordinate() - bottom,
+        ])
+    }
+
+    /// Translates the passed position on the [bevy_ascii_terminal::Terminal] into its position on the
+    /// [crate::ui::game_map::GameMap], reversing [Viewport::world_to_screen].
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The position in the [bevy_ascii_terminal::Terminal] space to translate.
+    ///
+    /// returns: [Option]<[i32; 2]> - The position on the [crate::ui::game_map::GameMap] or [None], if it
+    /// lies outside the map panel, e.g., in the sidebar.
+    ///
+    /// # About
+    ///
+    /// Authors: [Sebastian Riga](mailto:sebastian.riga.development@gmail.com)
+    ///
+    /// Since: `0.1.10`
+    ///
+    pub fn screen_to_world(&self, position: &impl Position2d) -> Option<[i32; 2]> {
+        let [left, bottom] = self.bottom_left();
+
+        self.panel()
+            .local_position(position)
+            .map(|[x, y]| [x + left, y + bottom])
+    }
+
+    /// (Private) Returns the position of the [crate::ui::game_map::GameMap] shown in the bottom left corner of the
+    /// map panel, which is the origin of the translation.
+    fn bottom_left(&self) -> [i32; 2] {
+        [self.top_left.x, self.top_left.y - self.size[1] + 1]
+    }
+
+    /// (Private) Returns the [Rectangle] of the map panel on the [bevy_ascii_terminal::Terminal].
+    fn panel(&self) -> Rectangle {
+        Rectangle::new(self.screen_origin, self.size)
+    }
+}
+
+impl Display for Viewport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, ({}, {}))",
+            self.top_left, self.size[0], self.size[1]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::res::terminal_layout::TerminalLayout;
+
+    use super::*;
+
+    #[test]
+    fn test_viewport_is_clamped_at_the_map_edges() {
+        let mut viewport = Viewport::new(&Rectangle::new([0, 0], [20, 10]));
+
+        viewport.center_on(&[1, 2], &[100, 50]);
+
+        assert_eq!(Coord2d::new(0, 9), viewport.top_left);
+        assert_eq!(Some([1, 2]), viewport.world_to_screen(&[1, 2]));
+
+        viewport.center_on(&[99, 49], &[100, 50]);
+
+        assert_eq!(Coord2d::new(80, 49), viewport.top_left);
+        assert_eq!(Some([19, 9]), viewport.world_to_screen(&[99, 49]));
+
+        viewport.center_on(&[-5, -5], &[100, 50]);
+
+        assert!(viewport.top_left.x >= 0);
+        assert!(viewport.top_left.y - viewport.size[1] + 1 >= 0);
+    }
+
+    #[test]
+    fn test_viewport_centers_on_focus() {
+        let mut viewport = Viewport::new(&Rectangle::new([0, 0], [20, 10]));
+
+        viewport.center_on(&[50, 25], &[100, 50]);
+
+        assert_eq!(Coord2d::new(40, 29), viewport.top_left);
+        assert_eq!(Some([10, 5]), viewport.world_to_screen(&[50, 25]));
+        assert_eq!(None, viewport.world_to_screen(&[39, 25]));
+        assert_eq!(Some([50, 25]), viewport.screen_to_world(&[10, 5]));
+    }
+
+    #[test]
+    fn test_small_map_stays_in_the_bottom_left_corner() {
+        let mut viewport = Viewport::new(&Rectangle::new([0, 0], [20, 10]));
+
+        viewport.center_on(&[8, 4], &[10, 5]);
+
+        assert_eq!(Coord2d::new(0, 9), viewport.top_left);
+        assert_eq!(Some([8, 4]), viewport.world_to_screen(&[8, 4]));
+    }
+
+    #[test]
+    fn test_translation_respects_the_panel_offset() {
+        let terminal_layout = TerminalLayout::new(&[100, 80]);
+        let viewport = Viewport::new(&terminal_layout.map);
+        let sidebar_column = terminal_layout.sidebar.left;
+
+        assert_eq!(Some([3, 4]), viewport.world_to_screen(&[3, 4]));
+        assert_eq!(Some([3, 4]), viewport.screen_to_world(&[3, 4]));
+        assert_eq!(None, viewport.screen_to_world(&[sidebar_column, 4]));
+        assert_eq!(None, viewport.world_to_screen(&[sidebar_column, 4]));
+        assert_eq!(None, viewport.screen_to_world(&[3, 79]));
+
+        let offset_viewport = Viewport::new(&Rectangle::new([5, 4], [3, 3]));
+
+        assert_eq!(Some([5, 4]), offset_viewport.world_to_screen(&[0, 0]));
+        assert_eq!(Some([2, 2]), offset_viewport.screen_to_world(&[7, 6]));
+    }
+}
@@ -23,10 +23,14 @@
 
 import input_config from '../../../../config/input.json' assert {type: 'json'};
 import window_config from '../../../../config/window.json' assert {type: 'json'};
+import gameplay_config from '../../../../config/gameplay.json' assert {type: 'json'};
+import palette from '../../../../config/palette.json' assert {type: 'json'};
 
 const fallback_table = {
     "config/window.json": JSON.stringify(window_config),
     "config/input.json": JSON.stringify(input_config),
+    "config/gameplay.json": JSON.stringify(gameplay_config),
+    "config/palette.json": JSON.stringify(palette),
 };
 
 /**